@@ -0,0 +1,372 @@
+use crate::{Sphere, Vector, Vector2, Vector3};
+
+/// An axis-aligned bounding box in 2D space described by its minimum and maximum corners
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AABB2 {
+    pub min: Vector2,
+    pub max: Vector2,
+}
+
+/// An axis-aligned bounding box in 3D space described by its minimum and maximum corners
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AABB3 {
+    pub min: Vector3,
+    pub max: Vector3,
+}
+
+impl AABB2 {
+    /// Creates a new 2D bounding box
+    ///
+    /// ## Arguments
+    ///
+    /// * `min` - The corner with the smallest components
+    /// * `max` - The corner with the largest components
+    ///
+    /// ## Returns
+    ///
+    /// A new 2D bounding box
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{AABB2, Vector2};
+    ///
+    /// let b = AABB2::new(Vector2::new(0., 0.), Vector2::new(1., 1.));
+    /// ```
+    pub fn new(min: Vector2, max: Vector2) -> AABB2 {
+        AABB2 { min, max }
+    }
+
+    /// Calculates the center of the bounding box
+    ///
+    /// ## Returns
+    ///
+    /// The point halfway between the two corners
+    pub fn center(&self) -> Vector2 {
+        (self.min + self.max) / 2.
+    }
+
+    /// Calculates the size of the bounding box along each axis
+    ///
+    /// ## Returns
+    ///
+    /// The vector from the minimum to the maximum corner
+    pub fn size(&self) -> Vector2 {
+        self.max - self.min
+    }
+
+    /// Checks whether a point lies inside (or on the edge of) the bounding box
+    ///
+    /// ## Arguments
+    ///
+    /// * `point` - The point to test
+    ///
+    /// ## Returns
+    ///
+    /// `true` if the point is inside the bounding box
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{AABB2, Vector2};
+    ///
+    /// let b = AABB2::new(Vector2::new(0., 0.), Vector2::new(1., 1.));
+    ///
+    /// assert!(b.contains(&Vector2::new(0.5, 1.)));
+    /// assert!(!b.contains(&Vector2::new(1.5, 0.5)));
+    /// ```
+    pub fn contains(&self, point: &Vector2) -> bool {
+        point.x >= self.min.x
+            && point.x <= self.max.x
+            && point.y >= self.min.y
+            && point.y <= self.max.y
+    }
+
+    /// Finds the point inside the bounding box closest to another point
+    ///
+    /// This never panics. If the box is inverted, with `min` above `max` along an axis, that
+    /// component of the result is `max`, and `NaN` bounds are ignored.
+    ///
+    /// ## Arguments
+    ///
+    /// * `point` - The point to measure from
+    ///
+    /// ## Returns
+    ///
+    /// The point itself if it is inside the box, otherwise the nearest point on its edge
+    pub fn closest_point(&self, point: &Vector2) -> Vector2 {
+        Vector2::new(
+            point.x.max(self.min.x).min(self.max.x),
+            point.y.max(self.min.y).min(self.max.y),
+        )
+    }
+
+    /// Calculates the squared distance from a point to the bounding box
+    ///
+    /// ## Arguments
+    ///
+    /// * `point` - The point to measure from
+    ///
+    /// ## Returns
+    ///
+    /// The squared distance to the closest point, which is zero for points inside the box
+    pub fn distance_squared(&self, point: &Vector2) -> f64 {
+        let offset = *point - self.closest_point(point);
+        offset.dot(&offset)
+    }
+
+    /// Checks whether another bounding box lies entirely inside this one
+    ///
+    /// ## Arguments
+    ///
+    /// * `other` - The bounding box to test
+    ///
+    /// ## Returns
+    ///
+    /// `true` if both corners of `other` are inside this bounding box
+    pub fn contains_aabb(&self, other: &AABB2) -> bool {
+        self.contains(&other.min) && self.contains(&other.max)
+    }
+}
+
+impl AABB3 {
+    /// Creates a new 3D bounding box
+    ///
+    /// ## Arguments
+    ///
+    /// * `min` - The corner with the smallest components
+    /// * `max` - The corner with the largest components
+    ///
+    /// ## Returns
+    ///
+    /// A new 3D bounding box
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{AABB3, Vector3};
+    ///
+    /// let b = AABB3::new(Vector3::new(0., 0., 0.), Vector3::new(1., 1., 1.));
+    /// ```
+    pub fn new(min: Vector3, max: Vector3) -> AABB3 {
+        AABB3 { min, max }
+    }
+
+    /// Calculates the center of the bounding box
+    ///
+    /// ## Returns
+    ///
+    /// The point halfway between the two corners
+    pub fn center(&self) -> Vector3 {
+        (self.min + self.max) / 2.
+    }
+
+    /// Calculates the size of the bounding box along each axis
+    ///
+    /// ## Returns
+    ///
+    /// The vector from the minimum to the maximum corner
+    pub fn size(&self) -> Vector3 {
+        self.max - self.min
+    }
+
+    /// Checks whether a point lies inside (or on the surface of) the bounding box
+    ///
+    /// ## Arguments
+    ///
+    /// * `point` - The point to test
+    ///
+    /// ## Returns
+    ///
+    /// `true` if the point is inside the bounding box
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{AABB3, Vector3};
+    ///
+    /// let b = AABB3::new(Vector3::new(0., 0., 0.), Vector3::new(1., 1., 1.));
+    ///
+    /// assert!(b.contains(&Vector3::new(0.5, 1., 0.)));
+    /// assert!(!b.contains(&Vector3::new(0.5, 0.5, 1.5)));
+    /// ```
+    pub fn contains(&self, point: &Vector3) -> bool {
+        point.x >= self.min.x
+            && point.x <= self.max.x
+            && point.y >= self.min.y
+            && point.y <= self.max.y
+            && point.z >= self.min.z
+            && point.z <= self.max.z
+    }
+
+    /// Finds the point inside the bounding box closest to another point
+    ///
+    /// This never panics. If the box is inverted, with `min` above `max` along an axis, that
+    /// component of the result is `max`, and `NaN` bounds are ignored.
+    ///
+    /// ## Arguments
+    ///
+    /// * `point` - The point to measure from
+    ///
+    /// ## Returns
+    ///
+    /// The point itself if it is inside the box, otherwise the nearest point on its surface
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{AABB3, Vector3};
+    ///
+    /// let b = AABB3::new(Vector3::new(0., 0., 0.), Vector3::new(1., 1., 1.));
+    ///
+    /// assert_eq!(b.closest_point(&Vector3::new(2., 0.5, -1.)), Vector3::new(1., 0.5, 0.));
+    /// ```
+    pub fn closest_point(&self, point: &Vector3) -> Vector3 {
+        Vector3::new(
+            point.x.max(self.min.x).min(self.max.x),
+            point.y.max(self.min.y).min(self.max.y),
+            point.z.max(self.min.z).min(self.max.z),
+        )
+    }
+
+    /// Calculates the squared distance from a point to the bounding box
+    ///
+    /// ## Arguments
+    ///
+    /// * `point` - The point to measure from
+    ///
+    /// ## Returns
+    ///
+    /// The squared distance to the closest point, which is zero for points inside the box
+    pub fn distance_squared(&self, point: &Vector3) -> f64 {
+        let offset = *point - self.closest_point(point);
+        offset.dot(&offset)
+    }
+
+    /// Checks whether another bounding box lies entirely inside this one
+    ///
+    /// ## Arguments
+    ///
+    /// * `other` - The bounding box to test
+    ///
+    /// ## Returns
+    ///
+    /// `true` if both corners of `other` are inside this bounding box
+    pub fn contains_aabb(&self, other: &AABB3) -> bool {
+        self.contains(&other.min) && self.contains(&other.max)
+    }
+
+    /// Checks whether a sphere touches or overlaps the bounding box
+    ///
+    /// ## Arguments
+    ///
+    /// * `sphere` - The sphere to test
+    ///
+    /// ## Returns
+    ///
+    /// `true` if the point of the box closest to the sphere's center is within its radius
+    pub fn intersects_sphere(&self, sphere: &Sphere) -> bool {
+        self.distance_squared(&sphere.center) <= sphere.radius * sphere.radius
+    }
+}
+
+impl Vector2 {
+    /// Clamps the vector so it lies inside a bounding box, see [`AABB2::closest_point`]
+    ///
+    /// ## Arguments
+    ///
+    /// * `bounds` - The box to keep the vector inside
+    ///
+    /// ## Returns
+    ///
+    /// The closest point to the vector inside the box
+    pub fn clamp_to_aabb(&self, bounds: &AABB2) -> Vector2 {
+        bounds.closest_point(self)
+    }
+}
+
+impl Vector3 {
+    /// Clamps the vector so it lies inside a bounding box, see [`AABB3::closest_point`]
+    ///
+    /// ## Arguments
+    ///
+    /// * `bounds` - The box to keep the vector inside
+    ///
+    /// ## Returns
+    ///
+    /// The closest point to the vector inside the box
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{AABB3, Vector3};
+    ///
+    /// let room = AABB3::new(Vector3::new(-5., 0., -5.), Vector3::new(5., 3., 5.));
+    /// let camera = Vector3::new(0., 4., -7.).clamp_to_aabb(&room);
+    ///
+    /// assert_eq!(camera, Vector3::new(0., 3., -5.));
+    /// ```
+    pub fn clamp_to_aabb(&self, bounds: &AABB3) -> Vector3 {
+        bounds.closest_point(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_center_and_size() {
+        let b = AABB2::new(Vector2::new(-1., 0.), Vector2::new(3., 2.));
+        assert_eq!(b.center(), Vector2::new(1., 1.));
+        assert_eq!(b.size(), Vector2::new(4., 2.));
+
+        let b = AABB3::new(Vector3::new(-1., 0., 2.), Vector3::new(3., 2., 4.));
+        assert_eq!(b.center(), Vector3::new(1., 1., 3.));
+        assert_eq!(b.size(), Vector3::new(4., 2., 2.));
+    }
+
+    #[test]
+    fn test_contains() {
+        let b = AABB2::new(Vector2::new(0., 0.), Vector2::new(2., 2.));
+        assert!(b.contains(&Vector2::new(0., 2.)));
+        assert!(!b.contains(&Vector2::new(-0.1, 1.)));
+
+        let b = AABB3::new(Vector3::new(0., 0., 0.), Vector3::new(2., 2., 2.));
+        assert!(b.contains(&Vector3::new(1., 1., 2.)));
+        assert!(!b.contains(&Vector3::new(1., 3., 1.)));
+    }
+
+    #[test]
+    fn test_closest_point() {
+        let b = AABB2::new(Vector2::new(0., 0.), Vector2::new(2., 2.));
+        assert_eq!(b.closest_point(&Vector2::new(1., 1.)), Vector2::new(1., 1.));
+        assert_eq!(
+            Vector2::new(5., -4.).clamp_to_aabb(&b),
+            Vector2::new(2., 0.)
+        );
+        assert_eq!(b.distance_squared(&Vector2::new(5., -4.)), 25.);
+        assert!(b.contains_aabb(&AABB2::new(Vector2::new(1., 1.), Vector2::new(2., 2.))));
+        assert!(!b.contains_aabb(&AABB2::new(Vector2::new(1., 1.), Vector2::new(3., 2.))));
+
+        let b = AABB3::new(Vector3::new(0., 0., 0.), Vector3::new(2., 2., 2.));
+        assert_eq!(b.distance_squared(&Vector3::new(1., 1., 1.)), 0.);
+        assert_eq!(b.distance_squared(&Vector3::new(3., 3., 1.)), 2.);
+
+        // Inverted and NaN bounds do not panic
+        let inverted = AABB2::new(Vector2::new(1., f64::NAN), Vector2::new(0., 1.));
+        assert_eq!(
+            inverted.closest_point(&Vector2::new(0.5, 3.)),
+            Vector2::new(0., 1.)
+        );
+    }
+
+    #[test]
+    fn test_intersects_sphere() {
+        let b = AABB3::new(Vector3::new(0., 0., 0.), Vector3::new(2., 2., 2.));
+        assert!(b.intersects_sphere(&Sphere::new(Vector3::new(1., 1., 1.), 0.1)));
+        assert!(b.intersects_sphere(&Sphere::new(Vector3::new(3., 1., 1.), 1.)));
+        // Close to the corner along each axis, but too far along the diagonal
+        assert!(!b.intersects_sphere(&Sphere::new(Vector3::new(2.8, 2.8, 1.), 1.)));
+    }
+}
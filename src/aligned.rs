@@ -0,0 +1,355 @@
+use crate::Scalar;
+use alloc::alloc::{handle_alloc_error, GlobalAlloc, Layout};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::marker::PhantomData;
+use core::mem::size_of;
+use core::ops::{Deref, DerefMut};
+use core::ptr::{self, NonNull};
+
+/// The alignment in bytes of the buffer backing a [`DynamicVector`](crate::DynamicVector)
+///
+/// This is enough for aligned AVX-512 loads, and so also for AVX and SSE.
+pub const DYNAMIC_VECTOR_ALIGNMENT: usize = 64;
+
+/// An allocator a buffer can be placed in instead of the global allocator
+pub(crate) type BufferAllocator = &'static (dyn GlobalAlloc + Sync);
+
+/// A block of bytes whose alignment matches its size, so consecutive chunks have no padding
+#[derive(Clone, Copy)]
+#[repr(C, align(64))]
+struct Chunk([u8; DYNAMIC_VECTOR_ALIGNMENT]);
+
+/// A growable list of scalars whose first element is always 64-byte aligned
+///
+/// The values are stored in whole chunks of aligned memory, taken from the given allocator or
+/// from the global allocator, and the buffer dereferences to a slice of the first `len` values.
+pub(crate) struct AlignedBuffer<T: Scalar> {
+    chunks: NonNull<Chunk>,
+    /// The number of chunks allocated
+    capacity: usize,
+    len: usize,
+    allocator: Option<BufferAllocator>,
+    scalar: PhantomData<T>,
+}
+
+// SAFETY: the buffer owns its chunks like a `Vec` does, `Scalar` is sealed to primitive numbers
+// and the allocator is `Sync`
+unsafe impl<T: Scalar> Send for AlignedBuffer<T> {}
+unsafe impl<T: Scalar> Sync for AlignedBuffer<T> {}
+
+impl<T: Scalar> AlignedBuffer<T> {
+    /// The number of values that fit in one chunk
+    ///
+    /// Every [`Scalar`] is a primitive number of at most 16 bytes, so this divides evenly.
+    const CHUNK_LEN: usize = DYNAMIC_VECTOR_ALIGNMENT / size_of::<T>();
+
+    /// Creates a buffer of `len` zeros
+    pub(crate) fn zeroed(len: usize, allocator: Option<BufferAllocator>) -> AlignedBuffer<T> {
+        let chunks = len.div_ceil(Self::CHUNK_LEN);
+        AlignedBuffer {
+            chunks: Self::allocate(allocator, chunks, true),
+            capacity: chunks,
+            len,
+            allocator,
+            scalar: PhantomData,
+        }
+    }
+
+    /// Creates an empty buffer with room for at least `capacity` values
+    pub(crate) fn with_capacity(
+        capacity: usize,
+        allocator: Option<BufferAllocator>,
+    ) -> AlignedBuffer<T> {
+        let chunks = capacity.div_ceil(Self::CHUNK_LEN);
+        AlignedBuffer {
+            chunks: Self::allocate(allocator, chunks, false),
+            capacity: chunks,
+            len: 0,
+            allocator,
+            scalar: PhantomData,
+        }
+    }
+
+    /// Makes room for at least `additional` more values
+    pub(crate) fn reserve(&mut self, additional: usize) {
+        let needed = self
+            .len
+            .checked_add(additional)
+            .expect("capacity overflow")
+            .div_ceil(Self::CHUNK_LEN);
+        self.grow_to(needed);
+    }
+
+    /// Removes every value, keeping the allocation
+    pub(crate) fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// The number of values the buffer can hold without reallocating
+    pub(crate) fn capacity(&self) -> usize {
+        self.capacity * Self::CHUNK_LEN
+    }
+
+    pub(crate) fn push(&mut self, value: T) {
+        if self.len == self.capacity() {
+            self.grow_to((self.capacity * 2).max(1));
+        }
+        // SAFETY: the buffer has room for at least one more value
+        unsafe { (self.chunks.as_ptr() as *mut T).add(self.len).write(value) };
+        self.len += 1;
+    }
+
+    fn layout(chunks: usize) -> Layout {
+        Layout::array::<Chunk>(chunks).expect("capacity overflow")
+    }
+
+    /// Allocates `chunks` chunks, or returns a dangling pointer if there are none
+    fn allocate(allocator: Option<BufferAllocator>, chunks: usize, zeroed: bool) -> NonNull<Chunk> {
+        if chunks == 0 {
+            return NonNull::dangling();
+        }
+        let layout = Self::layout(chunks);
+        // SAFETY: the layout has a non-zero size
+        let ptr = unsafe {
+            match (allocator, zeroed) {
+                (Some(allocator), false) => allocator.alloc(layout),
+                (Some(allocator), true) => allocator.alloc_zeroed(layout),
+                (None, false) => alloc::alloc::alloc(layout),
+                (None, true) => alloc::alloc::alloc_zeroed(layout),
+            }
+        };
+        NonNull::new(ptr as *mut Chunk).unwrap_or_else(|| handle_alloc_error(layout))
+    }
+
+    /// Grows the allocation to at least `chunks` chunks, keeping the values
+    fn grow_to(&mut self, chunks: usize) {
+        if chunks <= self.capacity {
+            return;
+        }
+        if self.capacity == 0 {
+            self.chunks = Self::allocate(self.allocator, chunks, false);
+        } else {
+            let old_layout = Self::layout(self.capacity);
+            let new_layout = Self::layout(chunks);
+            let old = self.chunks.as_ptr() as *mut u8;
+            // SAFETY: the chunks were allocated by this allocator with `old_layout`, and the new
+            // size is non-zero and was checked not to overflow by `Layout::array`
+            let ptr = unsafe {
+                match self.allocator {
+                    Some(allocator) => allocator.realloc(old, old_layout, new_layout.size()),
+                    None => alloc::alloc::realloc(old, old_layout, new_layout.size()),
+                }
+            };
+            self.chunks =
+                NonNull::new(ptr as *mut Chunk).unwrap_or_else(|| handle_alloc_error(new_layout));
+        }
+        self.capacity = chunks;
+    }
+}
+
+impl<T: Scalar> Drop for AlignedBuffer<T> {
+    fn drop(&mut self) {
+        if self.capacity == 0 {
+            return;
+        }
+        let layout = Self::layout(self.capacity);
+        let ptr = self.chunks.as_ptr() as *mut u8;
+        // SAFETY: the chunks were allocated by this allocator with this layout
+        unsafe {
+            match self.allocator {
+                Some(allocator) => allocator.dealloc(ptr, layout),
+                None => alloc::alloc::dealloc(ptr, layout),
+            }
+        }
+    }
+}
+
+impl<T: Scalar> Clone for AlignedBuffer<T> {
+    /// Copies the values into a new buffer from the same allocator
+    fn clone(&self) -> Self {
+        let mut buffer = AlignedBuffer::with_capacity(self.len, self.allocator);
+        // SAFETY: the new buffer has room for `len` values and does not overlap this one
+        unsafe {
+            ptr::copy_nonoverlapping(self.as_ptr(), buffer.chunks.as_ptr() as *mut T, self.len)
+        };
+        buffer.len = self.len;
+        buffer
+    }
+}
+
+impl<T: Scalar> Deref for AlignedBuffer<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        // SAFETY: the chunks are one contiguous, 64-byte aligned allocation with room for `len`
+        // values, every one of which was zeroed or written by `push`. Without an allocation the
+        // pointer is dangling but aligned, and `len` is zero
+        unsafe { core::slice::from_raw_parts(self.chunks.as_ptr() as *const T, self.len) }
+    }
+}
+
+impl<T: Scalar> DerefMut for AlignedBuffer<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        // SAFETY: see `deref`
+        unsafe { core::slice::from_raw_parts_mut(self.chunks.as_ptr() as *mut T, self.len) }
+    }
+}
+
+impl<T: Scalar> FromIterator<T> for AlignedBuffer<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let mut buffer = AlignedBuffer::with_capacity(iter.size_hint().0, None);
+        for value in iter {
+            buffer.push(value);
+        }
+        buffer
+    }
+}
+
+impl<T: Scalar> From<Vec<T>> for AlignedBuffer<T> {
+    fn from(values: Vec<T>) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+impl<T: Scalar> From<AlignedBuffer<T>> for Vec<T> {
+    fn from(buffer: AlignedBuffer<T>) -> Self {
+        buffer.to_vec()
+    }
+}
+
+impl<T: Scalar> fmt::Debug for AlignedBuffer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.deref().fmt(f)
+    }
+}
+
+impl<T: Scalar> PartialEq for AlignedBuffer<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.deref() == other.deref()
+    }
+}
+
+impl<T: Scalar> PartialEq<Vec<T>> for AlignedBuffer<T> {
+    fn eq(&self, other: &Vec<T>) -> bool {
+        self.deref() == other.as_slice()
+    }
+}
+
+impl<T: Scalar> PartialOrd for AlignedBuffer<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.deref().partial_cmp(other.deref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{format, vec};
+    use core::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+
+    /// Counts the allocations that are still live, passing them on to the global allocator
+    struct CountingAllocator {
+        live: AtomicUsize,
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            self.live.fetch_add(1, Relaxed);
+            alloc::alloc::alloc(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            self.live.fetch_sub(1, Relaxed);
+            alloc::alloc::dealloc(ptr, layout)
+        }
+    }
+
+    static ARENA: CountingAllocator = CountingAllocator {
+        live: AtomicUsize::new(0),
+    };
+
+    fn is_aligned<T: Scalar>(buffer: &AlignedBuffer<T>) -> bool {
+        (buffer.as_ptr() as usize).is_multiple_of(DYNAMIC_VECTOR_ALIGNMENT)
+    }
+
+    #[test]
+    fn test_alignment() {
+        for len in [0, 1, 7, 8, 9, 100] {
+            let zeroed = AlignedBuffer::<f64>::zeroed(len, None);
+            assert_eq!(zeroed.len(), len);
+            assert!(is_aligned(&zeroed));
+
+            let collected: AlignedBuffer<f64> = (0..len).map(|i| i as f64).collect();
+            assert_eq!(collected.len(), len);
+            assert!(is_aligned(&collected));
+            assert!(is_aligned(&collected.clone()));
+        }
+    }
+
+    #[test]
+    fn test_values() {
+        let mut buffer = AlignedBuffer::from(vec![1., 2., 3.]);
+        buffer[1] = 5.;
+        buffer.push(4.);
+        assert_eq!(buffer, vec![1., 5., 3., 4.]);
+        assert_eq!(Vec::from(buffer.clone()), vec![1., 5., 3., 4.]);
+        let larger: AlignedBuffer<f64> = [2.].into_iter().collect();
+        assert!(buffer < larger);
+        assert_eq!(format!("{:?}", buffer), "[1.0, 5.0, 3.0, 4.0]");
+    }
+
+    #[test]
+    fn test_capacity() {
+        let mut buffer = AlignedBuffer::<f64>::with_capacity(10, None);
+        assert_eq!(buffer.len(), 0);
+        assert_eq!(buffer.capacity(), 16);
+        for _ in 0..9 {
+            buffer.push(1.);
+        }
+        buffer.reserve(20);
+        assert!(buffer.capacity() >= 29);
+        buffer.clear();
+        assert!(buffer.is_empty());
+        assert!(buffer.capacity() >= 29);
+    }
+
+    #[test]
+    fn test_other_scalars() {
+        // Twice as many f32 values fit in each chunk
+        let mut floats = AlignedBuffer::<f32>::with_capacity(10, None);
+        assert_eq!(floats.capacity(), 16);
+        floats.push(1.5);
+        assert_eq!(floats, vec![1.5]);
+
+        let counts: AlignedBuffer<u8> = (0..100).collect();
+        assert_eq!(counts[99], 99);
+        assert!(is_aligned(&counts));
+        assert_eq!(AlignedBuffer::<i64>::zeroed(3, None), vec![0, 0, 0]);
+    }
+
+    #[test]
+    fn test_allocator() {
+        {
+            let mut buffer = AlignedBuffer::<f64>::with_capacity(4, Some(&ARENA));
+            assert_eq!(ARENA.live.load(Relaxed), 1);
+            for i in 0..20 {
+                buffer.push(i as f64);
+            }
+            assert!(is_aligned(&buffer));
+            assert_eq!(buffer[19], 19.);
+
+            let copy = buffer.clone();
+            assert_eq!(copy, buffer);
+            assert_eq!(ARENA.live.load(Relaxed), 2);
+
+            let zeroed = AlignedBuffer::<f32>::zeroed(5, Some(&ARENA));
+            assert_eq!(zeroed, vec![0.; 5]);
+            assert!(is_aligned(&zeroed));
+            assert_eq!(ARENA.live.load(Relaxed), 3);
+        }
+        assert_eq!(ARENA.live.load(Relaxed), 0);
+    }
+}
@@ -0,0 +1,194 @@
+#[cfg(not(feature = "std"))]
+use crate::math::FloatMath;
+use core::f64::consts::{PI, TAU};
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// An angle measured in radians
+///
+/// The rotation APIs take `impl Into<Radians>`, so they accept either a `Radians`, a [`Degrees`]
+/// which is converted for you, or a plain `f64` which is taken to be in radians. Wrapping values
+/// in one of the two types makes the unit visible at the call site.
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{Degrees, Radians};
+/// use core::f64::consts::PI;
+///
+/// assert_eq!(Radians::from(Degrees(180.)), Radians(PI));
+/// assert_eq!(Radians(PI) / 2., Radians(PI / 2.));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct Radians(pub f64);
+
+/// An angle measured in degrees
+///
+/// Pass this anywhere a [`Radians`] is accepted and it is converted automatically.
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct Degrees(pub f64);
+
+impl Radians {
+    /// Calculates the sine and cosine of the angle
+    ///
+    /// ## Returns
+    ///
+    /// The sine and the cosine, in that order
+    pub fn sin_cos(self) -> (f64, f64) {
+        self.0.sin_cos()
+    }
+
+    /// Wraps the angle into the range `[-PI, PI)`
+    ///
+    /// ## Returns
+    ///
+    /// The equivalent angle closest to zero
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::Radians;
+    /// use core::f64::consts::PI;
+    ///
+    /// assert_eq!(Radians(3. * PI).wrapped(), Radians(-PI));
+    /// ```
+    pub fn wrapped(self) -> Radians {
+        Radians(self.0 - TAU * ((self.0 + PI) / TAU).floor())
+    }
+}
+
+impl Degrees {
+    /// Wraps the angle into the range `[-180, 180)`
+    ///
+    /// ## Returns
+    ///
+    /// The equivalent angle closest to zero
+    pub fn wrapped(self) -> Degrees {
+        Degrees(self.0 - 360. * ((self.0 + 180.) / 360.).floor())
+    }
+}
+
+impl From<f64> for Radians {
+    fn from(angle: f64) -> Self {
+        Radians(angle)
+    }
+}
+
+impl From<Degrees> for Radians {
+    fn from(angle: Degrees) -> Self {
+        Radians(angle.0.to_radians())
+    }
+}
+
+impl From<Radians> for Degrees {
+    fn from(angle: Radians) -> Self {
+        Degrees(angle.0.to_degrees())
+    }
+}
+
+impl Add for Radians {
+    type Output = Radians;
+
+    fn add(self, other: Radians) -> Radians {
+        Radians(self.0 + other.0)
+    }
+}
+
+impl Sub for Radians {
+    type Output = Radians;
+
+    fn sub(self, other: Radians) -> Radians {
+        Radians(self.0 - other.0)
+    }
+}
+
+impl Mul<f64> for Radians {
+    type Output = Radians;
+
+    fn mul(self, scalar: f64) -> Radians {
+        Radians(self.0 * scalar)
+    }
+}
+
+impl Div<f64> for Radians {
+    type Output = Radians;
+
+    fn div(self, scalar: f64) -> Radians {
+        Radians(self.0 / scalar)
+    }
+}
+
+impl Neg for Radians {
+    type Output = Radians;
+
+    fn neg(self) -> Radians {
+        Radians(-self.0)
+    }
+}
+
+impl Add for Degrees {
+    type Output = Degrees;
+
+    fn add(self, other: Degrees) -> Degrees {
+        Degrees(self.0 + other.0)
+    }
+}
+
+impl Sub for Degrees {
+    type Output = Degrees;
+
+    fn sub(self, other: Degrees) -> Degrees {
+        Degrees(self.0 - other.0)
+    }
+}
+
+impl Mul<f64> for Degrees {
+    type Output = Degrees;
+
+    fn mul(self, scalar: f64) -> Degrees {
+        Degrees(self.0 * scalar)
+    }
+}
+
+impl Div<f64> for Degrees {
+    type Output = Degrees;
+
+    fn div(self, scalar: f64) -> Degrees {
+        Degrees(self.0 / scalar)
+    }
+}
+
+impl Neg for Degrees {
+    type Output = Degrees;
+
+    fn neg(self) -> Degrees {
+        Degrees(-self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(Radians::from(Degrees(90.)), Radians(PI / 2.));
+        assert_eq!(Degrees::from(Radians(PI)), Degrees(180.));
+        assert_eq!(Radians::from(1.5), Radians(1.5));
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!(Radians(1.) + Radians(0.5) - Radians(2.), Radians(-0.5));
+        assert_eq!(-Radians(1.) * 3., Radians(-3.));
+        assert_eq!(Degrees(90.) + Degrees(45.) / 3., Degrees(105.));
+        assert!(Degrees(10.) < Degrees(20.));
+    }
+
+    #[test]
+    fn test_wrapped() {
+        assert_eq!(Degrees(540.).wrapped(), Degrees(-180.));
+        assert_eq!(Degrees(-190.).wrapped(), Degrees(170.));
+        assert_eq!(Degrees(45.).wrapped(), Degrees(45.));
+        assert_eq!(Radians(PI / 2.).wrapped(), Radians(PI / 2.));
+    }
+}
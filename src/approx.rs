@@ -0,0 +1,284 @@
+#[cfg(feature = "alloc")]
+use crate::{DynamicVector, Float, Scalar};
+use crate::{StackVector, Vector2, Vector3, Vector4};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Vectors whose components can be compared by
+/// [`assert_vec_approx_eq!`](crate::assert_vec_approx_eq!)
+///
+/// This is implemented for the vector types of this library and for arrays, slices and `Vec`s of
+/// `f64`. Other vectors, such as those generated by `vector!`, can be compared by converting them
+/// to arrays first.
+pub trait ApproxComponents {
+    /// Gets the number of components
+    fn component_count(&self) -> usize;
+
+    /// Gets a component as an `f64`
+    ///
+    /// ## Panics
+    ///
+    /// If `index` is not less than [`ApproxComponents::component_count`]
+    fn component(&self, index: usize) -> f64;
+}
+
+impl<T: ApproxComponents + ?Sized> ApproxComponents for &T {
+    fn component_count(&self) -> usize {
+        (**self).component_count()
+    }
+
+    fn component(&self, index: usize) -> f64 {
+        (**self).component(index)
+    }
+}
+
+impl ApproxComponents for [f64] {
+    fn component_count(&self) -> usize {
+        self.len()
+    }
+
+    fn component(&self, index: usize) -> f64 {
+        self[index]
+    }
+}
+
+impl<const N: usize> ApproxComponents for [f64; N] {
+    fn component_count(&self) -> usize {
+        N
+    }
+
+    fn component(&self, index: usize) -> f64 {
+        self[index]
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ApproxComponents for Vec<f64> {
+    fn component_count(&self) -> usize {
+        self.len()
+    }
+
+    fn component(&self, index: usize) -> f64 {
+        self[index]
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Scalar + Float> ApproxComponents for DynamicVector<T> {
+    fn component_count(&self) -> usize {
+        self.len()
+    }
+
+    fn component(&self, index: usize) -> f64 {
+        self.get(index).to_f64()
+    }
+}
+
+impl<const CAP: usize> ApproxComponents for StackVector<CAP> {
+    fn component_count(&self) -> usize {
+        self.len()
+    }
+
+    fn component(&self, index: usize) -> f64 {
+        self.get(index)
+    }
+}
+
+impl ApproxComponents for Vector2 {
+    fn component_count(&self) -> usize {
+        2
+    }
+
+    fn component(&self, index: usize) -> f64 {
+        [self.x, self.y][index]
+    }
+}
+
+impl ApproxComponents for Vector3 {
+    fn component_count(&self) -> usize {
+        3
+    }
+
+    fn component(&self, index: usize) -> f64 {
+        [self.x, self.y, self.z][index]
+    }
+}
+
+impl ApproxComponents for Vector4 {
+    fn component_count(&self) -> usize {
+        4
+    }
+
+    fn component(&self, index: usize) -> f64 {
+        [self.a, self.b, self.c, self.d][index]
+    }
+}
+
+/// Checks whether two components are equal within an absolute or a relative tolerance
+fn component_approx_eq(a: f64, b: f64, epsilon: f64, relative: f64) -> bool {
+    // Also catches equal infinities, whose difference is NaN
+    a == b || (a - b).abs() <= epsilon.max(relative * a.abs().max(b.abs()))
+}
+
+/// Lists the components of two vectors that are not approximately equal, one per line
+struct Mismatches<'a, L: ?Sized, R: ?Sized> {
+    left: &'a L,
+    right: &'a R,
+    epsilon: f64,
+    relative: f64,
+}
+
+impl<L, R> fmt::Display for Mismatches<'_, L, R>
+where
+    L: ApproxComponents + ?Sized,
+    R: ApproxComponents + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let count = self.left.component_count();
+        if count != self.right.component_count() {
+            return write!(
+                f,
+                "\n  lengths differ: {} vs {}",
+                count,
+                self.right.component_count()
+            );
+        }
+        for i in 0..count {
+            let (a, b) = (self.left.component(i), self.right.component(i));
+            if !component_approx_eq(a, b, self.epsilon, self.relative) {
+                write!(f, "\n  [{}]: {} vs {} (difference {})", i, a, b, a - b)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The check behind [`assert_vec_approx_eq!`](crate::assert_vec_approx_eq!), not meant to be
+/// called directly
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_approx_components<L, R>(left: &L, right: &R, epsilon: f64, relative: f64)
+where
+    L: ApproxComponents + fmt::Debug + ?Sized,
+    R: ApproxComponents + fmt::Debug + ?Sized,
+{
+    let count = left.component_count();
+    let equal = count == right.component_count()
+        && (0..count)
+            .all(|i| component_approx_eq(left.component(i), right.component(i), epsilon, relative));
+    if !equal {
+        panic!(
+            "assertion `left ≈ right` failed (epsilon = {}, relative = {})\n  left: {:?}\n right: {:?}{}",
+            epsilon,
+            relative,
+            left,
+            right,
+            Mismatches {
+                left,
+                right,
+                epsilon,
+                relative
+            }
+        );
+    }
+}
+
+/// Asserts that two vectors are equal up to a tolerance
+///
+/// Each pair of components passes if their difference is at most `epsilon`, or at most
+/// `relative` times the larger of their magnitudes. By default `epsilon` is `1e-9` and
+/// `relative` is zero. On failure the message lists every component that differs, with its
+/// index and the difference.
+///
+/// Both sides must implement [`ApproxComponents`], which includes every vector type of this
+/// library and arrays and slices of `f64`, and [`Debug`](core::fmt::Debug).
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{assert_vec_approx_eq, Vector, Vector3};
+///
+/// let v = Vector3::new(1., 2., 2.).normalize();
+///
+/// assert_vec_approx_eq!(v, [1. / 3., 2. / 3., 2. / 3.]);
+/// assert_vec_approx_eq!(v, Vector3::new(0.3333, 0.6667, 0.6667), epsilon = 1e-4);
+/// assert_vec_approx_eq!(v * 1e9, [333333333.4, 666666666.6, 666666666.6], relative = 1e-9);
+/// ```
+///
+/// A failing assertion such as
+///
+/// ```should_panic
+/// # use libvector::{assert_vec_approx_eq, Vector2};
+/// assert_vec_approx_eq!(Vector2::new(1., 2.), Vector2::new(1., 2.1), epsilon = 1e-3);
+/// ```
+///
+/// panics with a message ending in `[1]: 2 vs 2.1 (difference -0.10000000000000009)`.
+#[macro_export]
+macro_rules! assert_vec_approx_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_approx_components(&$left, &$right, 1e-9, 0.)
+    };
+    ($left:expr, $right:expr, epsilon = $epsilon:expr $(,)?) => {
+        $crate::assert_approx_components(&$left, &$right, $epsilon, 0.)
+    };
+    ($left:expr, $right:expr, relative = $relative:expr $(,)?) => {
+        $crate::assert_approx_components(&$left, &$right, 0., $relative)
+    };
+    ($left:expr, $right:expr, epsilon = $epsilon:expr, relative = $relative:expr $(,)?) => {
+        $crate::assert_approx_components(&$left, &$right, $epsilon, $relative)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Vector;
+
+    #[cfg(feature = "std")]
+    fn failure_message(f: impl FnOnce() + std::panic::UnwindSafe) -> std::string::String {
+        let payload = std::panic::catch_unwind(f).unwrap_err();
+        payload
+            .downcast_ref::<std::string::String>()
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn test_passes() {
+        assert_vec_approx_eq!(Vector2::new(1., 2.), [1. + 1e-12, 2.]);
+        assert_vec_approx_eq!(
+            Vector4::new(0., 0., 0., f64::INFINITY),
+            [0., 0., 0., f64::INFINITY]
+        );
+        assert_vec_approx_eq!([1000.], [1001.], relative = 1e-3);
+        assert_vec_approx_eq!(StackVector::<3>::new(2), &[0., 0.][..], epsilon = 0.);
+        #[cfg(feature = "alloc")]
+        assert_vec_approx_eq!(
+            DynamicVector::<f32>::from(alloc::vec![0.1, 0.2]),
+            alloc::vec![0.1, 0.2],
+            epsilon = 1e-7,
+        );
+        assert_vec_approx_eq!(
+            Vector3::new(3., 0., 4.).normalize(),
+            [0.6, 0., 0.8],
+            epsilon = 1e-15,
+            relative = 0.
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_failure_message() {
+        let message = failure_message(|| {
+            assert_vec_approx_eq!(Vector3::new(1., 2., 3.), [1., 2.5, f64::NAN], epsilon = 0.1)
+        });
+        assert!(message.contains("epsilon = 0.1"));
+        assert!(!message.contains("[0]"));
+        assert!(message.contains("[1]: 2 vs 2.5 (difference -0.5)"));
+        assert!(message.contains("[2]: 3 vs NaN"));
+
+        let message = failure_message(|| assert_vec_approx_eq!([1., 2.], [1.]));
+        assert!(message.ends_with("lengths differ: 2 vs 1"));
+    }
+}
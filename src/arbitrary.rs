@@ -0,0 +1,207 @@
+use crate::{DynamicVector, Vector, Vector2, Vector3, Vector4};
+use proptest::collection::{vec, SizeRange};
+use proptest::num::f64::{Any, NEGATIVE, NORMAL, POSITIVE, SUBNORMAL, ZERO};
+use proptest::prelude::*;
+
+/// Any finite `f64`, including zero and subnormals but excluding infinities and NaN
+fn finite() -> Any {
+    POSITIVE | NEGATIVE | NORMAL | SUBNORMAL | ZERO
+}
+
+/// Components used before normalizing, kept small enough that the magnitude never overflows
+fn component() -> std::ops::RangeInclusive<f64> {
+    -1.0..=1.0
+}
+
+/// Creates a strategy for [`Vector2`]s with finite components
+///
+/// This is also the strategy used by `any::<Vector2>()`.
+///
+/// ## Returns
+///
+/// A strategy producing vectors without infinite or NaN components
+pub fn finite_vector2() -> impl Strategy<Value = Vector2> {
+    (finite(), finite()).prop_map(|(x, y)| Vector2::new(x, y))
+}
+
+/// Creates a strategy for [`Vector3`]s with finite components
+///
+/// This is also the strategy used by `any::<Vector3>()`.
+///
+/// ## Returns
+///
+/// A strategy producing vectors without infinite or NaN components
+pub fn finite_vector3() -> impl Strategy<Value = Vector3> {
+    (finite(), finite(), finite()).prop_map(|(x, y, z)| Vector3::new(x, y, z))
+}
+
+/// Creates a strategy for [`Vector4`]s with finite components
+///
+/// This is also the strategy used by `any::<Vector4>()`.
+///
+/// ## Returns
+///
+/// A strategy producing vectors without infinite or NaN components
+pub fn finite_vector4() -> impl Strategy<Value = Vector4> {
+    (finite(), finite(), finite(), finite()).prop_map(|(a, b, c, d)| Vector4::new(a, b, c, d))
+}
+
+/// Creates a strategy for [`Vector2`]s with a magnitude of 1
+///
+/// ## Returns
+///
+/// A strategy producing unit vectors
+pub fn unit_vector2() -> impl Strategy<Value = Vector2> {
+    (component(), component())
+        .prop_map(|(x, y)| Vector2::new(x, y))
+        .prop_filter("vector is too short to normalize", |v| v.magnitude() > 1e-6)
+        .prop_map(|v| v.normalize())
+}
+
+/// Creates a strategy for [`Vector3`]s with a magnitude of 1
+///
+/// ## Returns
+///
+/// A strategy producing unit vectors
+pub fn unit_vector3() -> impl Strategy<Value = Vector3> {
+    (component(), component(), component())
+        .prop_map(|(x, y, z)| Vector3::new(x, y, z))
+        .prop_filter("vector is too short to normalize", |v| v.magnitude() > 1e-6)
+        .prop_map(|v| v.normalize())
+}
+
+/// Creates a strategy for [`Vector4`]s with a magnitude of 1
+///
+/// ## Returns
+///
+/// A strategy producing unit vectors
+pub fn unit_vector4() -> impl Strategy<Value = Vector4> {
+    (component(), component(), component(), component())
+        .prop_map(|(a, b, c, d)| Vector4::new(a, b, c, d))
+        .prop_filter("vector is too short to normalize", |v| v.magnitude() > 1e-6)
+        .prop_map(|v| v.normalize())
+}
+
+/// Creates a strategy for [`Vector2`]s no longer than a given magnitude
+///
+/// ## Arguments
+///
+/// * `max_magnitude` - The largest magnitude to generate, which must not be negative
+///
+/// ## Returns
+///
+/// A strategy producing vectors with a magnitude in `[0, max_magnitude]`
+pub fn bounded_vector2(max_magnitude: f64) -> impl Strategy<Value = Vector2> {
+    (unit_vector2(), 0.0..=max_magnitude).prop_map(|(v, length)| v * length)
+}
+
+/// Creates a strategy for [`Vector3`]s no longer than a given magnitude
+///
+/// ## Arguments
+///
+/// * `max_magnitude` - The largest magnitude to generate, which must not be negative
+///
+/// ## Returns
+///
+/// A strategy producing vectors with a magnitude in `[0, max_magnitude]`
+pub fn bounded_vector3(max_magnitude: f64) -> impl Strategy<Value = Vector3> {
+    (unit_vector3(), 0.0..=max_magnitude).prop_map(|(v, length)| v * length)
+}
+
+/// Creates a strategy for [`Vector4`]s no longer than a given magnitude
+///
+/// ## Arguments
+///
+/// * `max_magnitude` - The largest magnitude to generate, which must not be negative
+///
+/// ## Returns
+///
+/// A strategy producing vectors with a magnitude in `[0, max_magnitude]`
+pub fn bounded_vector4(max_magnitude: f64) -> impl Strategy<Value = Vector4> {
+    (unit_vector4(), 0.0..=max_magnitude).prop_map(|(v, length)| v * length)
+}
+
+impl Arbitrary for Vector2 {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Vector2>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        finite_vector2().boxed()
+    }
+}
+
+impl Arbitrary for Vector3 {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Vector3>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        finite_vector3().boxed()
+    }
+}
+
+impl Arbitrary for Vector4 {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Vector4>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        finite_vector4().boxed()
+    }
+}
+
+impl Arbitrary for DynamicVector {
+    /// The range of lengths to generate
+    type Parameters = SizeRange;
+    type Strategy = BoxedStrategy<DynamicVector>;
+
+    fn arbitrary_with(length: SizeRange) -> Self::Strategy {
+        vec(finite(), length)
+            .prop_map(|data| DynamicVector { data: data.into() })
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::test_runner::TestRunner;
+
+    #[test]
+    fn test_finite() {
+        TestRunner::default()
+            .run(&any::<Vector3>(), |v| {
+                assert!(v.x.is_finite() && v.y.is_finite() && v.z.is_finite());
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn test_unit() {
+        TestRunner::default()
+            .run(&unit_vector4(), |v| {
+                assert!((v.magnitude() - 1.).abs() < 1e-12);
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn test_bounded() {
+        TestRunner::default()
+            .run(&bounded_vector2(5.), |v| {
+                assert!(v.magnitude() <= 5. + 1e-12);
+                Ok(())
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn test_dynamic_vector_length() {
+        TestRunner::default()
+            .run(&any_with::<DynamicVector>((2..5).into()), |v| {
+                assert!((2..5).contains(&v.data.len()));
+                Ok(())
+            })
+            .unwrap();
+    }
+}
@@ -0,0 +1,190 @@
+use crate::{lerp, VectorSpace};
+use alloc::vec::Vec;
+
+/// A lookup table from distance along a curve to the curve's parameter
+///
+/// Most curves, such as Béziers and splines, do not move at a constant speed as their parameter
+/// `t` goes from 0 to 1. The table samples a curve once and can then map any distance along it
+/// back to a parameter or a point, so objects can be moved along the curve at constant speed.
+/// Between samples the curve is treated as a straight line, so more samples give more accurate
+/// results for tightly bent curves.
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{bezier3, ArcLengthTable, Vector2};
+///
+/// let (p0, p1, p2, p3) = (
+///     Vector2::new(0., 0.),
+///     Vector2::new(0., 1.),
+///     Vector2::new(1., 1.),
+///     Vector2::new(1., 0.),
+/// );
+/// let table = ArcLengthTable::new(|t| bezier3(p0, p1, p2, p3, t), 256);
+///
+/// // Move 0.1 units along the curve every step
+/// let mut distance = 0.;
+/// while distance < table.length() {
+///     let position = table.point_at_arc_length(distance);
+///     distance += 0.1;
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArcLengthTable<V> {
+    parameters: Vec<f64>,
+    lengths: Vec<f64>,
+    points: Vec<V>,
+}
+
+impl<V> ArcLengthTable<V>
+where
+    V: VectorSpace,
+{
+    /// Builds a table by sampling a curve at evenly spaced parameters from 0 to 1
+    ///
+    /// ## Arguments
+    ///
+    /// * `curve` - The curve, mapping a parameter in `[0, 1]` to a point
+    /// * `segments` - The number of straight segments to approximate the curve with
+    ///
+    /// ## Returns
+    ///
+    /// A new table
+    ///
+    /// ## Panics
+    ///
+    /// If `segments` is zero
+    pub fn new<F: Fn(f64) -> V>(curve: F, segments: usize) -> ArcLengthTable<V> {
+        assert!(
+            segments > 0,
+            "an arc length table needs at least one segment"
+        );
+
+        let mut parameters = Vec::with_capacity(segments + 1);
+        let mut lengths = Vec::with_capacity(segments + 1);
+        let mut points = Vec::with_capacity(segments + 1);
+        let mut length = 0.;
+        for i in 0..=segments {
+            let t = i as f64 / segments as f64;
+            let point = curve(t);
+            if let Some(previous) = points.last() {
+                length += (point - *previous).magnitude();
+            }
+            parameters.push(t);
+            lengths.push(length);
+            points.push(point);
+        }
+
+        ArcLengthTable {
+            parameters,
+            lengths,
+            points,
+        }
+    }
+
+    /// Gets the total length of the curve
+    pub fn length(&self) -> f64 {
+        self.lengths[self.lengths.len() - 1]
+    }
+
+    /// Finds the segment containing a distance along the curve and how far through it the
+    /// distance lies
+    fn locate(&self, arc_length: f64) -> (usize, f64) {
+        // `max` before `min` so that NaN is treated as the start of the curve
+        let arc_length = arc_length.max(0.).min(self.length());
+        let end = self
+            .lengths
+            .partition_point(|&length| length < arc_length)
+            .max(1);
+        let (start_length, end_length) = (self.lengths[end - 1], self.lengths[end]);
+        let fraction = if end_length > start_length {
+            (arc_length - start_length) / (end_length - start_length)
+        } else {
+            0.
+        };
+        (end - 1, fraction)
+    }
+
+    /// Finds the curve parameter at a distance along the curve
+    ///
+    /// ## Arguments
+    ///
+    /// * `arc_length` - The distance from the start of the curve, clamped to its length
+    ///
+    /// ## Returns
+    ///
+    /// The parameter `t` at which the curve has covered that distance
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{ArcLengthTable, Vector2};
+    ///
+    /// // A straight line that speeds up as t increases
+    /// let table = ArcLengthTable::new(|t| Vector2::new(4. * t * t, 0.), 4);
+    ///
+    /// assert_eq!(table.t_for_arc_length(1.), 0.5);
+    /// ```
+    pub fn t_for_arc_length(&self, arc_length: f64) -> f64 {
+        let (i, fraction) = self.locate(arc_length);
+        self.parameters[i] + (self.parameters[i + 1] - self.parameters[i]) * fraction
+    }
+
+    /// Finds the point at a distance along the curve
+    ///
+    /// ## Arguments
+    ///
+    /// * `arc_length` - The distance from the start of the curve, clamped to its length
+    ///
+    /// ## Returns
+    ///
+    /// The point on the curve
+    pub fn point_at_arc_length(&self, arc_length: f64) -> V {
+        let (i, fraction) = self.locate(arc_length);
+        lerp(self.points[i], self.points[i + 1], fraction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Vector, Vector2, Vector3};
+    use core::f64::consts::FRAC_PI_2;
+
+    #[test]
+    fn test_lookup() {
+        let table = ArcLengthTable::new(|t| Vector2::new(4. * t * t, 0.), 4);
+        assert_eq!(table.length(), 4.);
+        assert_eq!(table.t_for_arc_length(0.), 0.);
+        assert_eq!(table.t_for_arc_length(2.25), 0.75);
+        assert_eq!(table.t_for_arc_length(3.125), 0.875);
+        assert_eq!(table.t_for_arc_length(10.), 1.);
+        assert_eq!(table.t_for_arc_length(-1.), 0.);
+        assert_eq!(table.t_for_arc_length(f64::NAN), 0.);
+        assert_eq!(table.point_at_arc_length(3.), Vector2::new(3., 0.));
+    }
+
+    #[test]
+    fn test_quarter_circle() {
+        let table = ArcLengthTable::new(
+            |t: f64| {
+                let angle = t * t * FRAC_PI_2;
+                Vector3::new(angle.cos(), angle.sin(), 0.)
+            },
+            2000,
+        );
+        assert!((table.length() - FRAC_PI_2).abs() < 1e-5);
+        let halfway = table.point_at_arc_length(FRAC_PI_2 / 2.);
+        let expected = Vector3::new(0.5_f64.sqrt(), 0.5_f64.sqrt(), 0.);
+        assert!((halfway - expected).magnitude() < 1e-4);
+        assert!((table.t_for_arc_length(FRAC_PI_2 / 2.) - 0.5_f64.sqrt()).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_degenerate_curve() {
+        let table = ArcLengthTable::new(|_| Vector2::new(1., 2.), 3);
+        assert_eq!(table.length(), 0.);
+        assert_eq!(table.t_for_arc_length(0.), 0.);
+        assert_eq!(table.point_at_arc_length(1.), Vector2::new(1., 2.));
+    }
+}
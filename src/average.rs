@@ -0,0 +1,227 @@
+#[cfg(feature = "alloc")]
+use crate::DynamicVector;
+use crate::{IVector2, IVector3, Vector2, Vector3, Vector4, VectorSpace};
+
+/// Moves a running mean of `count - 1` values towards the next value
+///
+/// Both terms are divided by `count` before they are subtracted, so the intermediate values stay
+/// within the range of the inputs and cannot overflow the way a plain sum can.
+fn running_mean<V: VectorSpace>(mean: V, value: V, count: f64) -> V {
+    mean + (value / count - mean / count)
+}
+
+/// Calculates the mean of any number of vectors with [`running_mean`]
+///
+/// This backs [`Vector3::average`], [`centroid`](crate::centroid) and the k-means cluster
+/// centers, so they all agree and none of them overflow.
+pub(crate) fn running_average<V: VectorSpace, I: IntoIterator<Item = V>>(points: I) -> Option<V> {
+    let mut points = points.into_iter();
+    let first = points.next()?;
+    let (mean, _) = points.fold((first, 1.), |(mean, count), point| {
+        let count = count + 1.;
+        (running_mean(mean, point, count), count)
+    });
+    Some(mean)
+}
+
+// Implements `midpoint` and `average` for a floating point vector type.
+macro_rules! averages {
+    ($V:ident) => {
+        impl $V {
+            /// Calculates the point halfway between two vectors
+            ///
+            /// Each vector is halved before they are added, so this does not overflow even when
+            /// `self + other` would.
+            ///
+            /// ## Arguments
+            ///
+            /// * `other` - The other end point
+            ///
+            /// ## Returns
+            ///
+            /// The midpoint
+            pub fn midpoint(&self, other: &$V) -> $V {
+                *self / 2. + *other / 2.
+            }
+
+            /// Calculates the mean of any number of vectors
+            ///
+            /// The mean is updated one vector at a time instead of dividing a sum at the end, so
+            /// large components do not overflow.
+            ///
+            /// ## Arguments
+            ///
+            /// * `points` - The vectors to average
+            ///
+            /// ## Returns
+            ///
+            /// The mean, or `None` if there are no vectors
+            pub fn average<I: IntoIterator<Item = $V>>(points: I) -> Option<$V> {
+                running_average(points)
+            }
+        }
+    };
+}
+
+averages!(Vector2);
+averages!(Vector3);
+averages!(Vector4);
+
+// Implements `midpoint` and `average` for an integer vector type, widening before adding so that
+// nothing overflows.
+macro_rules! integer_averages {
+    ($V:ident: $($f:ident),+) => {
+        impl $V {
+            /// Calculates the point halfway between two vectors, rounding each component down
+            ///
+            /// ## Arguments
+            ///
+            /// * `other` - The other end point
+            ///
+            /// ## Returns
+            ///
+            /// The midpoint
+            pub fn midpoint(&self, other: &$V) -> $V {
+                $V { $($f: (self.$f as i64 + other.$f as i64).div_euclid(2) as i32),+ }
+            }
+
+            /// Calculates the mean of any number of vectors, rounding each component down
+            ///
+            /// ## Arguments
+            ///
+            /// * `points` - The vectors to average
+            ///
+            /// ## Returns
+            ///
+            /// The mean, or `None` if there are no vectors
+            pub fn average<I: IntoIterator<Item = $V>>(points: I) -> Option<$V> {
+                let mut count = 0_i128;
+                let mut sum = [0_i128; [$(stringify!($f)),+].len()];
+                for point in points {
+                    count += 1;
+                    for (total, value) in sum.iter_mut().zip([$(point.$f),+]) {
+                        *total += value as i128;
+                    }
+                }
+                if count == 0 {
+                    return None;
+                }
+                let [$($f),+] = sum.map(|total| total.div_euclid(count) as i32);
+                Some($V { $($f),+ })
+            }
+        }
+    };
+}
+
+integer_averages!(IVector2: x, y);
+integer_averages!(IVector3: x, y, z);
+
+#[cfg(feature = "alloc")]
+impl DynamicVector {
+    /// Calculates the point halfway between two vectors
+    ///
+    /// ## Arguments
+    ///
+    /// * `other` - The other end point
+    ///
+    /// ## Returns
+    ///
+    /// The midpoint
+    ///
+    /// ## Panics
+    ///
+    /// If the vectors have different lengths
+    pub fn midpoint(&self, other: &DynamicVector) -> DynamicVector {
+        assert_eq!(self.len(), other.len(), "vectors must have the same length");
+        self.as_slice()
+            .iter()
+            .zip(other.as_slice())
+            .map(|(a, b)| a / 2. + b / 2.)
+            .collect()
+    }
+
+    /// Calculates the mean of any number of vectors without overflowing
+    ///
+    /// ## Arguments
+    ///
+    /// * `points` - The vectors to average
+    ///
+    /// ## Returns
+    ///
+    /// The mean, or `None` if there are no vectors
+    ///
+    /// ## Panics
+    ///
+    /// If the vectors have different lengths
+    pub fn average<'a, I: IntoIterator<Item = &'a DynamicVector>>(
+        points: I,
+    ) -> Option<DynamicVector> {
+        let mut points = points.into_iter();
+        let mut mean = points.next()?.clone();
+        for (count, point) in (2..).zip(points) {
+            assert_eq!(point.len(), mean.len(), "vectors must have the same length");
+            let count = count as f64;
+            mean = mean
+                .as_slice()
+                .iter()
+                .zip(point.as_slice())
+                .map(|(mean, value)| mean + (value / count - mean / count))
+                .collect();
+        }
+        Some(mean)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_midpoint() {
+        assert_eq!(
+            Vector2::new(0., 2.).midpoint(&Vector2::new(4., -2.)),
+            Vector2::new(2., 0.)
+        );
+        let huge = Vector3::new(f64::MAX, -f64::MAX, 1.);
+        assert_eq!(huge.midpoint(&huge), huge);
+        assert_eq!(
+            IVector2::new(i32::MAX, -3).midpoint(&IVector2::new(i32::MAX, 0)),
+            IVector2::new(i32::MAX, -2)
+        );
+    }
+
+    #[test]
+    fn test_average() {
+        let triangle = [
+            Vector2::new(0., 0.),
+            Vector2::new(3., 0.),
+            Vector2::new(0., 3.),
+        ];
+        assert_eq!(Vector2::average(triangle), Some(Vector2::new(1., 1.)));
+        assert_eq!(Vector4::average([]), None);
+        let huge = Vector3::new(f64::MAX, 0., -f64::MAX);
+        assert_eq!(Vector3::average([huge; 4]), Some(huge));
+        assert_eq!(
+            IVector3::average([IVector3::new(i32::MAX, 1, -1); 3]),
+            Some(IVector3::new(i32::MAX, 1, -1))
+        );
+        assert_eq!(
+            IVector2::average([IVector2::new(0, 0), IVector2::new(1, -1)]),
+            Some(IVector2::new(0, -1))
+        );
+        assert_eq!(IVector2::average([]), None);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_dynamic() {
+        let a = DynamicVector::from(alloc::vec![0., 4., f64::MAX]);
+        let b = DynamicVector::from(alloc::vec![2., 0., f64::MAX]);
+        assert_eq!(a.midpoint(&b), [1., 2., f64::MAX]);
+        assert_eq!(
+            DynamicVector::average([&a, &b, &b]).unwrap().get(0),
+            4. / 3.
+        );
+        assert_eq!(DynamicVector::average([]), None);
+    }
+}
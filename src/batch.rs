@@ -0,0 +1,568 @@
+#[cfg(not(feature = "std"))]
+use crate::math::FloatMath;
+use crate::{Quaternion, Vector3};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// A list of 3D vectors stored as a structure of arrays
+///
+/// Each component is kept in its own contiguous buffer, so batch operations walk three flat
+/// arrays of `f64`s. This layout lets the compiler vectorize the loops, which is much faster
+/// than working on a `Vec<Vector3>` for large particle systems.
+///
+/// Batch operations modify the batch in place and panic if two batches have different lengths.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Vector3Batch {
+    xs: Vec<f64>,
+    ys: Vec<f64>,
+    zs: Vec<f64>,
+}
+
+#[cfg(feature = "alloc")]
+impl Vector3Batch {
+    /// Creates an empty batch
+    ///
+    /// ## Returns
+    ///
+    /// A batch without any vectors
+    pub fn new() -> Vector3Batch {
+        Vector3Batch::default()
+    }
+
+    /// Creates an empty batch with room for a number of vectors
+    ///
+    /// ## Arguments
+    ///
+    /// * `capacity` - The number of vectors to allocate space for
+    ///
+    /// ## Returns
+    ///
+    /// A batch without any vectors
+    pub fn with_capacity(capacity: usize) -> Vector3Batch {
+        Vector3Batch {
+            xs: Vec::with_capacity(capacity),
+            ys: Vec::with_capacity(capacity),
+            zs: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Gets the number of vectors in the batch
+    pub fn len(&self) -> usize {
+        self.xs.len()
+    }
+
+    /// Checks if the batch has no vectors
+    pub fn is_empty(&self) -> bool {
+        self.xs.is_empty()
+    }
+
+    /// Gets the x components of every vector
+    pub fn xs(&self) -> &[f64] {
+        &self.xs
+    }
+
+    /// Gets the y components of every vector
+    pub fn ys(&self) -> &[f64] {
+        &self.ys
+    }
+
+    /// Gets the z components of every vector
+    pub fn zs(&self) -> &[f64] {
+        &self.zs
+    }
+
+    /// Adds a vector to the end of the batch
+    ///
+    /// ## Arguments
+    ///
+    /// * `v` - The vector to add
+    pub fn push(&mut self, v: Vector3) {
+        self.xs.push(v.x);
+        self.ys.push(v.y);
+        self.zs.push(v.z);
+    }
+
+    /// Gets a vector from the batch
+    ///
+    /// ## Arguments
+    ///
+    /// * `index` - The index of the vector to get
+    ///
+    /// ## Returns
+    ///
+    /// The vector, or `None` if `index` is out of bounds
+    pub fn get(&self, index: usize) -> Option<Vector3> {
+        if index >= self.len() {
+            return None;
+        }
+        Some(Vector3::new(self.xs[index], self.ys[index], self.zs[index]))
+    }
+
+    /// Replaces a vector in the batch
+    ///
+    /// ## Arguments
+    ///
+    /// * `index` - The index of the vector to replace, which must be in bounds
+    /// * `v` - The new vector
+    pub fn set(&mut self, index: usize, v: Vector3) {
+        self.xs[index] = v.x;
+        self.ys[index] = v.y;
+        self.zs[index] = v.z;
+    }
+
+    /// Iterates over the vectors in the batch
+    ///
+    /// ## Returns
+    ///
+    /// An iterator yielding each vector in order
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Vector3, Vector3Batch};
+    ///
+    /// let batch: Vector3Batch = vec![Vector3::new(1., 2., 3.), Vector3::new(4., 5., 6.)]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// let total = batch.iter().fold(Vector3::new(0., 0., 0.), |sum, v| sum + v);
+    ///
+    /// assert_eq!(total, Vector3::new(5., 7., 9.));
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = Vector3> + '_ {
+        self.xs
+            .iter()
+            .zip(&self.ys)
+            .zip(&self.zs)
+            .map(|((&x, &y), &z)| Vector3::new(x, y, z))
+    }
+
+    /// Adds every vector of another batch to the matching vector of this one
+    ///
+    /// ## Arguments
+    ///
+    /// * `other` - The batch to add, which must have the same length
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Vector3, Vector3Batch};
+    ///
+    /// let mut positions = Vector3Batch::from(&[Vector3::new(0., 0., 0.)][..]);
+    /// let velocities = Vector3Batch::from(&[Vector3::new(1., 2., 3.)][..]);
+    ///
+    /// positions.add(&velocities);
+    ///
+    /// assert_eq!(positions.get(0), Some(Vector3::new(1., 2., 3.)));
+    /// ```
+    pub fn add(&mut self, other: &Vector3Batch) {
+        assert_eq!(self.len(), other.len(), "batches must have the same length");
+        add_slices(&mut self.xs, &other.xs);
+        add_slices(&mut self.ys, &other.ys);
+        add_slices(&mut self.zs, &other.zs);
+    }
+
+    /// Multiplies every vector in the batch by a scalar
+    ///
+    /// ## Arguments
+    ///
+    /// * `scalar` - The value to multiply by
+    pub fn scale(&mut self, scalar: f64) {
+        for c in self
+            .xs
+            .iter_mut()
+            .chain(self.ys.iter_mut())
+            .chain(self.zs.iter_mut())
+        {
+            *c *= scalar;
+        }
+    }
+
+    /// Calculates the dot product of every pair of matching vectors
+    ///
+    /// ## Arguments
+    ///
+    /// * `other` - The other batch, which must have the same length
+    ///
+    /// ## Returns
+    ///
+    /// The dot products, in order
+    pub fn dot(&self, other: &Vector3Batch) -> Vec<f64> {
+        assert_eq!(self.len(), other.len(), "batches must have the same length");
+        (0..self.len())
+            .map(|i| self.xs[i] * other.xs[i] + self.ys[i] * other.ys[i] + self.zs[i] * other.zs[i])
+            .collect()
+    }
+
+    /// Normalizes every vector in the batch
+    ///
+    /// Like [`Vector3`]'s `normalize`, vectors with a magnitude of zero become NaN.
+    pub fn normalize(&mut self) {
+        for i in 0..self.len() {
+            let magnitude =
+                (self.xs[i] * self.xs[i] + self.ys[i] * self.ys[i] + self.zs[i] * self.zs[i])
+                    .sqrt();
+            self.xs[i] /= magnitude;
+            self.ys[i] /= magnitude;
+            self.zs[i] /= magnitude;
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn add_slices(a: &mut [f64], b: &[f64]) {
+    for (a, b) in a.iter_mut().zip(b) {
+        *a += b;
+    }
+}
+
+/// Calculates the dot products of matching pairs of vectors
+///
+/// ## Arguments
+///
+/// * `a` - The first vector of each pair
+/// * `b` - The second vector of each pair
+/// * `out` - The buffer to write the dot products to
+///
+/// ## Panics
+///
+/// If `a`, `b` and `out` do not all have the same length
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{dot_many, Vector3};
+///
+/// let a = [Vector3::new(1., 0., 0.), Vector3::new(1., 2., 3.)];
+/// let b = [Vector3::new(0., 1., 0.), Vector3::new(1., 1., 1.)];
+/// let mut dots = [0.; 2];
+///
+/// dot_many(&a, &b, &mut dots);
+///
+/// assert_eq!(dots, [0., 6.]);
+/// ```
+pub fn dot_many(a: &[Vector3], b: &[Vector3], out: &mut [f64]) {
+    assert!(
+        a.len() == b.len() && a.len() == out.len(),
+        "slices must have the same length"
+    );
+    for ((out, a), b) in out.iter_mut().zip(a).zip(b) {
+        *out = a.x * b.x + a.y * b.y + a.z * b.z;
+    }
+}
+
+/// Calculates the cross products of matching pairs of vectors
+///
+/// ## Arguments
+///
+/// * `a` - The first vector of each pair
+/// * `b` - The second vector of each pair
+/// * `out` - The buffer to write the cross products to
+///
+/// ## Panics
+///
+/// If `a`, `b` and `out` do not all have the same length
+pub fn cross_many(a: &[Vector3], b: &[Vector3], out: &mut [Vector3]) {
+    assert!(
+        a.len() == b.len() && a.len() == out.len(),
+        "slices must have the same length"
+    );
+    for ((out, a), b) in out.iter_mut().zip(a).zip(b) {
+        *out = Vector3::new(
+            a.y * b.z - a.z * b.y,
+            a.z * b.x - a.x * b.z,
+            a.x * b.y - a.y * b.x,
+        );
+    }
+}
+
+/// Normalizes every vector of a slice in place
+///
+/// Like [`Vector3`]'s `normalize`, vectors with a magnitude of zero become NaN.
+///
+/// ## Arguments
+///
+/// * `vectors` - The vectors to normalize
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{normalize_in_place, Vector3};
+///
+/// let mut normals = [Vector3::new(0., 0., 2.), Vector3::new(3., 4., 0.)];
+///
+/// normalize_in_place(&mut normals);
+///
+/// assert_eq!(normals, [Vector3::new(0., 0., 1.), Vector3::new(0.6, 0.8, 0.)]);
+/// ```
+pub fn normalize_in_place(vectors: &mut [Vector3]) {
+    for v in vectors {
+        let magnitude = (v.x * v.x + v.y * v.y + v.z * v.z).sqrt();
+        v.x /= magnitude;
+        v.y /= magnitude;
+        v.z /= magnitude;
+    }
+}
+
+/// Rotates every vector of a slice in place by a quaternion
+///
+/// The quaternion is converted to a rotation matrix once, so each vector costs nine
+/// multiplications in a single pass, instead of the two cross products of
+/// [`Quaternion::rotate`].
+///
+/// ## Arguments
+///
+/// * `vectors` - The vectors to rotate
+/// * `rotation` - The rotation, which must be a unit quaternion like for [`Quaternion::rotate`]
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{assert_vec_approx_eq, rotate_all, Quaternion, Vector3};
+/// use core::f64::consts::FRAC_PI_2;
+///
+/// let q = Quaternion::from_axis_angle(&Vector3::new(0., 0., 1.), FRAC_PI_2);
+/// let mut points = [Vector3::new(1., 0., 0.), Vector3::new(0., 2., 5.)];
+///
+/// rotate_all(&mut points, &q);
+///
+/// assert_vec_approx_eq!(points[0], [0., 1., 0.]);
+/// assert_vec_approx_eq!(points[1], [-2., 0., 5.]);
+/// ```
+pub fn rotate_all(vectors: &mut [Vector3], rotation: &Quaternion) {
+    let Quaternion { w, x, y, z } = *rotation;
+    let matrix = [
+        [
+            1. - 2. * (y * y + z * z),
+            2. * (x * y - w * z),
+            2. * (x * z + w * y),
+            0.,
+        ],
+        [
+            2. * (x * y + w * z),
+            1. - 2. * (x * x + z * z),
+            2. * (y * z - w * x),
+            0.,
+        ],
+        [
+            2. * (x * z - w * y),
+            2. * (y * z + w * x),
+            1. - 2. * (x * x + y * y),
+            0.,
+        ],
+        [0., 0., 0., 1.],
+    ];
+    transform_all(vectors, &matrix);
+}
+
+/// Applies a homogeneous 4x4 matrix to every point of a slice in place
+///
+/// The points are treated as having a `w` component of 1. If the last row of the matrix is
+/// `[0, 0, 0, 1]`, as it is for every affine transform, the points are transformed in a single
+/// pass of multiplications and additions. Otherwise the matrix is projective and each result is
+/// divided by its `w` component, and points sent to infinity become infinite or NaN.
+///
+/// ## Arguments
+///
+/// * `points` - The points to transform
+/// * `matrix` - The matrix, in row-major order with the translation in the last column
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{transform_all, Vector3};
+///
+/// // Scale by 2, then move 1 unit along z
+/// let matrix = [
+///     [2., 0., 0., 0.],
+///     [0., 2., 0., 0.],
+///     [0., 0., 2., 1.],
+///     [0., 0., 0., 1.],
+/// ];
+/// let mut points = [Vector3::new(1., 2., 3.), Vector3::new(0., 0., 0.)];
+///
+/// transform_all(&mut points, &matrix);
+///
+/// assert_eq!(points, [Vector3::new(2., 4., 7.), Vector3::new(0., 0., 1.)]);
+/// ```
+pub fn transform_all(points: &mut [Vector3], matrix: &[[f64; 4]; 4]) {
+    let [r0, r1, r2, r3] = matrix;
+    let row = |r: &[f64; 4], p: &Vector3| r[0] * p.x + r[1] * p.y + r[2] * p.z + r[3];
+    if *r3 == [0., 0., 0., 1.] {
+        for p in points {
+            *p = Vector3::new(row(r0, p), row(r1, p), row(r2, p));
+        }
+    } else {
+        for p in points {
+            let w = row(r3, p);
+            *p = Vector3::new(row(r0, p) / w, row(r1, p) / w, row(r2, p) / w);
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl FromIterator<Vector3> for Vector3Batch {
+    fn from_iter<I: IntoIterator<Item = Vector3>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let mut batch = Vector3Batch::with_capacity(iter.size_hint().0);
+        for v in iter {
+            batch.push(v);
+        }
+        batch
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<&[Vector3]> for Vector3Batch {
+    fn from(vectors: &[Vector3]) -> Self {
+        vectors.iter().copied().collect()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<&Vector3Batch> for Vec<Vector3> {
+    fn from(batch: &Vector3Batch) -> Self {
+        batch.iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_vec_approx_eq, Transform3, Vector};
+    #[cfg(feature = "alloc")]
+    use alloc::vec::Vec;
+
+    fn sample() -> [Vector3; 3] {
+        [
+            Vector3::new(1., 2., 2.),
+            Vector3::new(0., 3., 4.),
+            Vector3::new(-1., 0., 0.),
+        ]
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_push_get_iter() {
+        let mut batch = Vector3Batch::new();
+        assert!(batch.is_empty());
+        for v in sample() {
+            batch.push(v);
+        }
+        assert_eq!(batch.len(), 3);
+        assert_eq!(batch.get(1), Some(Vector3::new(0., 3., 4.)));
+        assert_eq!(batch.get(3), None);
+        assert_eq!(batch.ys(), &[2., 3., 0.]);
+        assert_eq!(Vec::from(&batch), sample());
+
+        batch.set(2, Vector3::new(9., 9., 9.));
+        assert_eq!(batch.xs()[2], 9.);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_batch_ops_match_vector3() {
+        let vectors = sample();
+        let mut batch = Vector3Batch::from(&vectors[..]);
+        let other = Vector3Batch::from(&vectors[..]);
+
+        assert_eq!(
+            batch.dot(&other),
+            vectors.iter().map(|v| v.dot(v)).collect::<Vec<_>>()
+        );
+
+        batch.add(&other);
+        batch.scale(0.25);
+        assert_eq!(batch.get(0), Some(vectors[0] * 0.5));
+
+        batch.normalize();
+        for (a, b) in batch.iter().zip(&vectors) {
+            assert!((a - b.normalize()).magnitude() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_slice_ops_match_vector3() {
+        let a = sample();
+        let b = a.map(|v| Vector3::new(v.z, -v.x, 1.));
+
+        let mut dots = [0.; 3];
+        dot_many(&a, &b, &mut dots);
+        let mut crosses = [Vector3::new(0., 0., 0.); 3];
+        cross_many(&a, &b, &mut crosses);
+        for i in 0..3 {
+            assert_eq!(dots[i], a[i].dot(&b[i]));
+            assert_eq!(crosses[i], a[i].cross(&b[i]));
+        }
+
+        let mut normalized = a;
+        normalize_in_place(&mut normalized);
+        for (n, v) in normalized.iter().zip(&a) {
+            assert!((*n - v.normalize()).magnitude() < 1e-12);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_slice_length_mismatch() {
+        let mut out = [0.; 1];
+        dot_many(&sample(), &sample(), &mut out);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    #[should_panic]
+    fn test_length_mismatch() {
+        let mut batch = Vector3Batch::from(&sample()[..]);
+        batch.add(&Vector3Batch::new());
+    }
+
+    #[test]
+    fn test_rotate_all_matches_quaternion() {
+        let q = Quaternion::from_axis_angle(&Vector3::new(1., -2., 0.5), 2.);
+        let mut rotated = sample();
+        rotate_all(&mut rotated, &q);
+        for (r, v) in rotated.iter().zip(&sample()) {
+            assert_vec_approx_eq!(r, q.rotate(v), epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_transform_all() {
+        let t = Transform3::new(
+            Vector3::new(1., 2., 3.),
+            Quaternion::from_axis_angle(&Vector3::new(0., 1., 0.), 0.5),
+            Vector3::new(2., 2., 2.),
+        );
+        // The columns of the matrix are the transformed basis vectors
+        let columns = [
+            t.transform_vector(&Vector3::new(1., 0., 0.)),
+            t.transform_vector(&Vector3::new(0., 1., 0.)),
+            t.transform_vector(&Vector3::new(0., 0., 1.)),
+            t.translation,
+        ];
+        let matrix = [
+            columns.map(|c| c.x),
+            columns.map(|c| c.y),
+            columns.map(|c| c.z),
+            [0., 0., 0., 1.],
+        ];
+        let mut points = sample();
+        transform_all(&mut points, &matrix);
+        for (p, v) in points.iter().zip(&sample()) {
+            assert_vec_approx_eq!(p, t.transform_point(v), epsilon = 1e-12);
+        }
+
+        // A projective matrix divides by the resulting w
+        let mut points = [Vector3::new(2., 4., 6.)];
+        let projective = [
+            [1., 0., 0., 0.],
+            [0., 1., 0., 0.],
+            [0., 0., 1., 0.],
+            [0., 0., 1., 0.],
+        ];
+        transform_all(&mut points, &projective);
+        assert_eq!(points, [Vector3::new(1. / 3., 2. / 3., 1.)]);
+    }
+}
@@ -0,0 +1,333 @@
+#[cfg(feature = "alloc")]
+use crate::VectorSpace;
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+use core::ops::{Add, Mul, Sub};
+
+/// The deepest subdivision used when flattening, which bounds the output at 2^16 segments
+#[cfg(feature = "alloc")]
+const MAX_FLATTEN_DEPTH: u32 = 16;
+
+/// Evaluates a quadratic Bézier curve
+///
+/// ## Arguments
+///
+/// * `p0` - The start point of the curve
+/// * `p1` - The control point
+/// * `p2` - The end point of the curve
+/// * `t` - The curve parameter, from `0` at `p0` to `1` at `p2`
+///
+/// ## Returns
+///
+/// The point on the curve at `t`
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{bezier2, Vector2};
+///
+/// let p = bezier2(Vector2::new(0., 0.), Vector2::new(1., 2.), Vector2::new(2., 0.), 0.5);
+///
+/// assert_eq!(p, Vector2::new(1., 1.));
+/// ```
+pub fn bezier2<V>(p0: V, p1: V, p2: V, t: f64) -> V
+where
+    V: Copy + Add<Output = V> + Mul<f64, Output = V>,
+{
+    let u = 1. - t;
+    p0 * (u * u) + p1 * (2. * u * t) + p2 * (t * t)
+}
+
+/// Evaluates the derivative (tangent) of a quadratic Bézier curve
+///
+/// ## Arguments
+///
+/// * `p0` - The start point of the curve
+/// * `p1` - The control point
+/// * `p2` - The end point of the curve
+/// * `t` - The curve parameter
+///
+/// ## Returns
+///
+/// The derivative of the curve with respect to `t`. This is not normalized
+pub fn bezier2_derivative<V>(p0: V, p1: V, p2: V, t: f64) -> V
+where
+    V: Copy + Add<Output = V> + Sub<Output = V> + Mul<f64, Output = V>,
+{
+    (p1 - p0) * (2. * (1. - t)) + (p2 - p1) * (2. * t)
+}
+
+/// Evaluates a cubic Bézier curve
+///
+/// ## Arguments
+///
+/// * `p0` - The start point of the curve
+/// * `p1` - The first control point
+/// * `p2` - The second control point
+/// * `p3` - The end point of the curve
+/// * `t` - The curve parameter, from `0` at `p0` to `1` at `p3`
+///
+/// ## Returns
+///
+/// The point on the curve at `t`
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{bezier3, Vector2};
+///
+/// let p = bezier3(
+///     Vector2::new(0., 0.),
+///     Vector2::new(0., 1.),
+///     Vector2::new(1., 1.),
+///     Vector2::new(1., 0.),
+///     0.5,
+/// );
+///
+/// assert_eq!(p, Vector2::new(0.5, 0.75));
+/// ```
+pub fn bezier3<V>(p0: V, p1: V, p2: V, p3: V, t: f64) -> V
+where
+    V: Copy + Add<Output = V> + Mul<f64, Output = V>,
+{
+    let u = 1. - t;
+    p0 * (u * u * u) + p1 * (3. * u * u * t) + p2 * (3. * u * t * t) + p3 * (t * t * t)
+}
+
+/// Evaluates the derivative (tangent) of a cubic Bézier curve
+///
+/// ## Arguments
+///
+/// * `p0` - The start point of the curve
+/// * `p1` - The first control point
+/// * `p2` - The second control point
+/// * `p3` - The end point of the curve
+/// * `t` - The curve parameter
+///
+/// ## Returns
+///
+/// The derivative of the curve with respect to `t`. This is not normalized
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{bezier3_derivative, Vector2};
+///
+/// let tangent = bezier3_derivative(
+///     Vector2::new(0., 0.),
+///     Vector2::new(0., 1.),
+///     Vector2::new(1., 1.),
+///     Vector2::new(1., 0.),
+///     0.,
+/// );
+///
+/// assert_eq!(tangent, Vector2::new(0., 3.));
+/// ```
+pub fn bezier3_derivative<V>(p0: V, p1: V, p2: V, p3: V, t: f64) -> V
+where
+    V: Copy + Add<Output = V> + Sub<Output = V> + Mul<f64, Output = V>,
+{
+    let u = 1. - t;
+    (p1 - p0) * (3. * u * u) + (p2 - p1) * (6. * u * t) + (p3 - p2) * (3. * t * t)
+}
+
+/// Approximates a quadratic Bézier curve with a polyline
+///
+/// The curve is subdivided until every segment is within `tolerance` of the curve, so flat parts
+/// of the curve produce fewer points than tightly bent ones.
+///
+/// ## Arguments
+///
+/// * `p0` - The start point of the curve
+/// * `p1` - The control point
+/// * `p2` - The end point of the curve
+/// * `tolerance` - The maximum allowed distance between the polyline and the curve
+///
+/// ## Returns
+///
+/// The points of the polyline, starting with `p0` and ending with `p2`
+#[cfg(feature = "alloc")]
+pub fn flatten_bezier2<V>(p0: V, p1: V, p2: V, tolerance: f64) -> Vec<V>
+where
+    V: VectorSpace,
+{
+    let mut points = vec![p0];
+    flatten_bezier2_into(p0, p1, p2, tolerance, 0, &mut points);
+    points
+}
+
+#[cfg(feature = "alloc")]
+fn flatten_bezier2_into<V>(p0: V, p1: V, p2: V, tolerance: f64, depth: u32, out: &mut Vec<V>)
+where
+    V: VectorSpace,
+{
+    // Wang's bound: the curve stays within n(n - 1) / 8 * |second difference| of its chord
+    let deviation = (p0 - p1 * 2. + p2).magnitude() * 0.25;
+    if deviation <= tolerance || depth >= MAX_FLATTEN_DEPTH {
+        out.push(p2);
+        return;
+    }
+
+    let q0 = (p0 + p1) * 0.5;
+    let q1 = (p1 + p2) * 0.5;
+    let mid = (q0 + q1) * 0.5;
+    flatten_bezier2_into(p0, q0, mid, tolerance, depth + 1, out);
+    flatten_bezier2_into(mid, q1, p2, tolerance, depth + 1, out);
+}
+
+/// Approximates a cubic Bézier curve with a polyline
+///
+/// The curve is subdivided until every segment is within `tolerance` of the curve, so flat parts
+/// of the curve produce fewer points than tightly bent ones.
+///
+/// ## Arguments
+///
+/// * `p0` - The start point of the curve
+/// * `p1` - The first control point
+/// * `p2` - The second control point
+/// * `p3` - The end point of the curve
+/// * `tolerance` - The maximum allowed distance between the polyline and the curve
+///
+/// ## Returns
+///
+/// The points of the polyline, starting with `p0` and ending with `p3`
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{flatten_bezier3, Vector2};
+///
+/// let line = flatten_bezier3(
+///     Vector2::new(0., 0.),
+///     Vector2::new(1., 0.),
+///     Vector2::new(2., 0.),
+///     Vector2::new(3., 0.),
+///     0.01,
+/// );
+///
+/// assert_eq!(line, vec![Vector2::new(0., 0.), Vector2::new(3., 0.)]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn flatten_bezier3<V>(p0: V, p1: V, p2: V, p3: V, tolerance: f64) -> Vec<V>
+where
+    V: VectorSpace,
+{
+    let mut points = vec![p0];
+    flatten_bezier3_into(p0, p1, p2, p3, tolerance, 0, &mut points);
+    points
+}
+
+#[cfg(feature = "alloc")]
+fn flatten_bezier3_into<V>(p0: V, p1: V, p2: V, p3: V, tolerance: f64, depth: u32, out: &mut Vec<V>)
+where
+    V: VectorSpace,
+{
+    let d1 = (p0 - p1 * 2. + p2).magnitude();
+    let d2 = (p1 - p2 * 2. + p3).magnitude();
+    let deviation = d1.max(d2) * 0.75;
+    if deviation <= tolerance || depth >= MAX_FLATTEN_DEPTH {
+        out.push(p3);
+        return;
+    }
+
+    // de Casteljau subdivision at t = 0.5
+    let q0 = (p0 + p1) * 0.5;
+    let q1 = (p1 + p2) * 0.5;
+    let q2 = (p2 + p3) * 0.5;
+    let r0 = (q0 + q1) * 0.5;
+    let r1 = (q1 + q2) * 0.5;
+    let mid = (r0 + r1) * 0.5;
+    flatten_bezier3_into(p0, q0, r0, mid, tolerance, depth + 1, out);
+    flatten_bezier3_into(mid, r1, q2, p3, tolerance, depth + 1, out);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Vector, Vector2, Vector3};
+
+    #[test]
+    fn test_bezier2() {
+        let p0 = Vector2::new(0., 0.);
+        let p1 = Vector2::new(1., 2.);
+        let p2 = Vector2::new(2., 0.);
+        assert_eq!(bezier2(p0, p1, p2, 0.), p0);
+        assert_eq!(bezier2(p0, p1, p2, 1.), p2);
+        assert_eq!(bezier2(p0, p1, p2, 0.25), Vector2::new(0.5, 0.75));
+        assert_eq!(bezier2_derivative(p0, p1, p2, 0.), Vector2::new(2., 4.));
+        assert_eq!(bezier2_derivative(p0, p1, p2, 0.5), Vector2::new(2., 0.));
+    }
+
+    #[test]
+    fn test_bezier3() {
+        let p0 = Vector3::new(0., 0., 0.);
+        let p1 = Vector3::new(0., 1., 0.);
+        let p2 = Vector3::new(1., 1., 0.);
+        let p3 = Vector3::new(1., 0., 3.);
+        assert_eq!(bezier3(p0, p1, p2, p3, 0.), p0);
+        assert_eq!(bezier3(p0, p1, p2, p3, 1.), p3);
+        assert_eq!(bezier3(p0, p1, p2, p3, 0.5), Vector3::new(0.5, 0.75, 0.375));
+        assert_eq!(
+            bezier3_derivative(p0, p1, p2, p3, 1.),
+            Vector3::new(0., -3., 9.)
+        );
+    }
+
+    #[test]
+    fn test_derivative_matches_finite_difference() {
+        let p0 = Vector2::new(0., 0.);
+        let p1 = Vector2::new(3., 4.);
+        let p2 = Vector2::new(-1., 2.);
+        let p3 = Vector2::new(5., 5.);
+        let h = 1e-6;
+        let t = 0.3;
+        let numeric = (bezier3(p0, p1, p2, p3, t + h) - bezier3(p0, p1, p2, p3, t - h)) / (2. * h);
+        let analytic = bezier3_derivative(p0, p1, p2, p3, t);
+        assert!((numeric - analytic).magnitude() < 1e-6);
+    }
+
+    #[test]
+    fn test_flatten_bezier3() {
+        let p0 = Vector2::new(0., 0.);
+        let p1 = Vector2::new(0., 1.);
+        let p2 = Vector2::new(1., 1.);
+        let p3 = Vector2::new(1., 0.);
+        let tolerance = 0.001;
+
+        let points = flatten_bezier3(p0, p1, p2, p3, tolerance);
+        assert_eq!(*points.first().unwrap(), p0);
+        assert_eq!(*points.last().unwrap(), p3);
+        assert!(points.len() > 2);
+
+        let coarse = flatten_bezier3(p0, p1, p2, p3, 0.1);
+        assert!(coarse.len() < points.len());
+
+        // Every sampled curve point must be close to the polyline
+        for i in 0..=100 {
+            let c = bezier3(p0, p1, p2, p3, i as f64 / 100.);
+            let closest = points
+                .windows(2)
+                .map(|w| {
+                    let d = w[1] - w[0];
+                    let s = ((c - w[0]).dot(&d) / d.dot(&d)).clamp(0., 1.);
+                    (w[0] + d * s - c).magnitude()
+                })
+                .fold(f64::INFINITY, f64::min);
+            assert!(closest <= tolerance);
+        }
+    }
+
+    #[test]
+    fn test_flatten_bezier2() {
+        let p0 = Vector2::new(0., 0.);
+        let p1 = Vector2::new(1., 2.);
+        let p2 = Vector2::new(2., 0.);
+        let points = flatten_bezier2(p0, p1, p2, 0.01);
+        assert_eq!(points[0], p0);
+        assert_eq!(*points.last().unwrap(), p2);
+        assert!(points.len() > 2);
+
+        let straight = flatten_bezier2(p0, Vector2::new(1., 0.), p2, 0.01);
+        assert_eq!(straight, vec![p0, p2]);
+    }
+}
@@ -0,0 +1,666 @@
+use crate::{DynamicVector, Vector2, Vector3, Vector4};
+use std::io::{self, Read, Write};
+
+/// The byte order used to encode each component
+#[derive(Clone, Copy)]
+enum Endian {
+    Little,
+    Big,
+}
+
+fn encode(components: &[f64], endian: Endian, out: &mut Vec<u8>) {
+    for c in components {
+        match endian {
+            Endian::Little => out.extend_from_slice(&c.to_le_bytes()),
+            Endian::Big => out.extend_from_slice(&c.to_be_bytes()),
+        }
+    }
+}
+
+fn decode(bytes: &[u8], endian: Endian) -> Vec<f64> {
+    bytes
+        .chunks_exact(8)
+        .map(|chunk| {
+            let chunk: [u8; 8] = chunk.try_into().unwrap();
+            match endian {
+                Endian::Little => f64::from_le_bytes(chunk),
+                Endian::Big => f64::from_be_bytes(chunk),
+            }
+        })
+        .collect()
+}
+
+/// Multiplies a count by the size of each item, rejecting counts whose total overflows
+fn checked_count(count: usize, size: usize) -> io::Result<usize> {
+    count
+        .checked_mul(size)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "vector count is too large"))
+}
+
+fn write_components<W: Write + ?Sized>(
+    components: &[f64],
+    endian: Endian,
+    writer: &mut W,
+) -> io::Result<()> {
+    let mut bytes = Vec::with_capacity(components.len() * 8);
+    encode(components, endian, &mut bytes);
+    writer.write_all(&bytes)
+}
+
+fn read_components<R: Read + ?Sized>(
+    count: usize,
+    endian: Endian,
+    reader: &mut R,
+) -> io::Result<Vec<f64>> {
+    let expected = checked_count(count, 8)?;
+    // Grows the buffer as data arrives, so a corrupt length cannot trigger a huge allocation
+    let mut bytes = Vec::new();
+    reader.take(expected as u64).read_to_end(&mut bytes)?;
+    if bytes.len() < expected {
+        return Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "not enough bytes for the vector",
+        ));
+    }
+    Ok(decode(&bytes, endian))
+}
+
+impl Vector2 {
+    /// Writes the vector as two little-endian `f64`s
+    ///
+    /// ## Arguments
+    ///
+    /// * `writer` - The destination to write the 16 bytes to
+    ///
+    /// ## Returns
+    ///
+    /// An error if the writer fails
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::Vector2;
+    ///
+    /// let mut bytes = Vec::new();
+    /// Vector2::new(1., 2.).write_le(&mut bytes).unwrap();
+    ///
+    /// assert_eq!(Vector2::read_le(&mut bytes.as_slice()).unwrap(), Vector2::new(1., 2.));
+    /// ```
+    pub fn write_le<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+        write_components(&[self.x, self.y], Endian::Little, writer)
+    }
+
+    /// Writes the vector as two big-endian `f64`s
+    ///
+    /// ## Arguments
+    ///
+    /// * `writer` - The destination to write the 16 bytes to
+    ///
+    /// ## Returns
+    ///
+    /// An error if the writer fails
+    pub fn write_be<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+        write_components(&[self.x, self.y], Endian::Big, writer)
+    }
+
+    /// Reads a vector stored as two little-endian `f64`s
+    ///
+    /// ## Arguments
+    ///
+    /// * `reader` - The source to read 16 bytes from
+    ///
+    /// ## Returns
+    ///
+    /// The vector, or an error if the reader fails or runs out of bytes
+    pub fn read_le<R: Read + ?Sized>(reader: &mut R) -> io::Result<Vector2> {
+        let c = read_components(2, Endian::Little, reader)?;
+        Ok(Vector2::new(c[0], c[1]))
+    }
+
+    /// Reads a vector stored as two big-endian `f64`s
+    ///
+    /// ## Arguments
+    ///
+    /// * `reader` - The source to read 16 bytes from
+    ///
+    /// ## Returns
+    ///
+    /// The vector, or an error if the reader fails or runs out of bytes
+    pub fn read_be<R: Read + ?Sized>(reader: &mut R) -> io::Result<Vector2> {
+        let c = read_components(2, Endian::Big, reader)?;
+        Ok(Vector2::new(c[0], c[1]))
+    }
+
+    /// Writes a slice of vectors back to back as little-endian `f64`s
+    ///
+    /// The whole slice is encoded before writing, so the writer receives a single call.
+    ///
+    /// ## Arguments
+    ///
+    /// * `vectors` - The vectors to write
+    /// * `writer` - The destination to write the bytes to
+    ///
+    /// ## Returns
+    ///
+    /// An error if the writer fails
+    pub fn write_slice_le<W: Write + ?Sized>(
+        vectors: &[Vector2],
+        writer: &mut W,
+    ) -> io::Result<()> {
+        let components: Vec<f64> = vectors.iter().flat_map(|v| [v.x, v.y]).collect();
+        write_components(&components, Endian::Little, writer)
+    }
+
+    /// Writes a slice of vectors back to back as big-endian `f64`s
+    ///
+    /// ## Arguments
+    ///
+    /// * `vectors` - The vectors to write
+    /// * `writer` - The destination to write the bytes to
+    ///
+    /// ## Returns
+    ///
+    /// An error if the writer fails
+    pub fn write_slice_be<W: Write + ?Sized>(
+        vectors: &[Vector2],
+        writer: &mut W,
+    ) -> io::Result<()> {
+        let components: Vec<f64> = vectors.iter().flat_map(|v| [v.x, v.y]).collect();
+        write_components(&components, Endian::Big, writer)
+    }
+
+    /// Reads a number of vectors stored back to back as little-endian `f64`s
+    ///
+    /// ## Arguments
+    ///
+    /// * `reader` - The source to read the bytes from
+    /// * `count` - The number of vectors to read
+    ///
+    /// ## Returns
+    ///
+    /// The vectors, or an error if the reader fails or runs out of bytes
+    pub fn read_vec_le<R: Read + ?Sized>(reader: &mut R, count: usize) -> io::Result<Vec<Vector2>> {
+        let c = read_components(checked_count(count, 2)?, Endian::Little, reader)?;
+        Ok(c.chunks_exact(2)
+            .map(|c| Vector2::new(c[0], c[1]))
+            .collect())
+    }
+
+    /// Reads a number of vectors stored back to back as big-endian `f64`s
+    ///
+    /// ## Arguments
+    ///
+    /// * `reader` - The source to read the bytes from
+    /// * `count` - The number of vectors to read
+    ///
+    /// ## Returns
+    ///
+    /// The vectors, or an error if the reader fails or runs out of bytes
+    pub fn read_vec_be<R: Read + ?Sized>(reader: &mut R, count: usize) -> io::Result<Vec<Vector2>> {
+        let c = read_components(checked_count(count, 2)?, Endian::Big, reader)?;
+        Ok(c.chunks_exact(2)
+            .map(|c| Vector2::new(c[0], c[1]))
+            .collect())
+    }
+}
+
+impl Vector3 {
+    /// Writes the vector as three little-endian `f64`s
+    ///
+    /// ## Arguments
+    ///
+    /// * `writer` - The destination to write the 24 bytes to
+    ///
+    /// ## Returns
+    ///
+    /// An error if the writer fails
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::Vector3;
+    ///
+    /// let mut bytes = Vec::new();
+    /// Vector3::new(1., 2., 3.).write_le(&mut bytes).unwrap();
+    ///
+    /// assert_eq!(bytes.len(), 24);
+    /// ```
+    pub fn write_le<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+        write_components(&[self.x, self.y, self.z], Endian::Little, writer)
+    }
+
+    /// Writes the vector as three big-endian `f64`s
+    ///
+    /// ## Arguments
+    ///
+    /// * `writer` - The destination to write the 24 bytes to
+    ///
+    /// ## Returns
+    ///
+    /// An error if the writer fails
+    pub fn write_be<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+        write_components(&[self.x, self.y, self.z], Endian::Big, writer)
+    }
+
+    /// Reads a vector stored as three little-endian `f64`s
+    ///
+    /// ## Arguments
+    ///
+    /// * `reader` - The source to read 24 bytes from
+    ///
+    /// ## Returns
+    ///
+    /// The vector, or an error if the reader fails or runs out of bytes
+    pub fn read_le<R: Read + ?Sized>(reader: &mut R) -> io::Result<Vector3> {
+        let c = read_components(3, Endian::Little, reader)?;
+        Ok(Vector3::new(c[0], c[1], c[2]))
+    }
+
+    /// Reads a vector stored as three big-endian `f64`s
+    ///
+    /// ## Arguments
+    ///
+    /// * `reader` - The source to read 24 bytes from
+    ///
+    /// ## Returns
+    ///
+    /// The vector, or an error if the reader fails or runs out of bytes
+    pub fn read_be<R: Read + ?Sized>(reader: &mut R) -> io::Result<Vector3> {
+        let c = read_components(3, Endian::Big, reader)?;
+        Ok(Vector3::new(c[0], c[1], c[2]))
+    }
+
+    /// Writes a slice of vectors back to back as little-endian `f64`s
+    ///
+    /// The whole slice is encoded before writing, so the writer receives a single call.
+    ///
+    /// ## Arguments
+    ///
+    /// * `vectors` - The vectors to write
+    /// * `writer` - The destination to write the bytes to
+    ///
+    /// ## Returns
+    ///
+    /// An error if the writer fails
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::Vector3;
+    ///
+    /// let cloud = vec![Vector3::new(1., 2., 3.), Vector3::new(4., 5., 6.)];
+    ///
+    /// let mut bytes = Vec::new();
+    /// Vector3::write_slice_le(&cloud, &mut bytes).unwrap();
+    ///
+    /// assert_eq!(Vector3::read_vec_le(&mut bytes.as_slice(), 2).unwrap(), cloud);
+    /// ```
+    pub fn write_slice_le<W: Write + ?Sized>(
+        vectors: &[Vector3],
+        writer: &mut W,
+    ) -> io::Result<()> {
+        let components: Vec<f64> = vectors.iter().flat_map(|v| [v.x, v.y, v.z]).collect();
+        write_components(&components, Endian::Little, writer)
+    }
+
+    /// Writes a slice of vectors back to back as big-endian `f64`s
+    ///
+    /// ## Arguments
+    ///
+    /// * `vectors` - The vectors to write
+    /// * `writer` - The destination to write the bytes to
+    ///
+    /// ## Returns
+    ///
+    /// An error if the writer fails
+    pub fn write_slice_be<W: Write + ?Sized>(
+        vectors: &[Vector3],
+        writer: &mut W,
+    ) -> io::Result<()> {
+        let components: Vec<f64> = vectors.iter().flat_map(|v| [v.x, v.y, v.z]).collect();
+        write_components(&components, Endian::Big, writer)
+    }
+
+    /// Reads a number of vectors stored back to back as little-endian `f64`s
+    ///
+    /// ## Arguments
+    ///
+    /// * `reader` - The source to read the bytes from
+    /// * `count` - The number of vectors to read
+    ///
+    /// ## Returns
+    ///
+    /// The vectors, or an error if the reader fails or runs out of bytes
+    pub fn read_vec_le<R: Read + ?Sized>(reader: &mut R, count: usize) -> io::Result<Vec<Vector3>> {
+        let c = read_components(checked_count(count, 3)?, Endian::Little, reader)?;
+        Ok(c.chunks_exact(3)
+            .map(|c| Vector3::new(c[0], c[1], c[2]))
+            .collect())
+    }
+
+    /// Reads a number of vectors stored back to back as big-endian `f64`s
+    ///
+    /// ## Arguments
+    ///
+    /// * `reader` - The source to read the bytes from
+    /// * `count` - The number of vectors to read
+    ///
+    /// ## Returns
+    ///
+    /// The vectors, or an error if the reader fails or runs out of bytes
+    pub fn read_vec_be<R: Read + ?Sized>(reader: &mut R, count: usize) -> io::Result<Vec<Vector3>> {
+        let c = read_components(checked_count(count, 3)?, Endian::Big, reader)?;
+        Ok(c.chunks_exact(3)
+            .map(|c| Vector3::new(c[0], c[1], c[2]))
+            .collect())
+    }
+}
+
+impl Vector4 {
+    /// Writes the vector as four little-endian `f64`s
+    ///
+    /// ## Arguments
+    ///
+    /// * `writer` - The destination to write the 32 bytes to
+    ///
+    /// ## Returns
+    ///
+    /// An error if the writer fails
+    pub fn write_le<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+        write_components(&[self.a, self.b, self.c, self.d], Endian::Little, writer)
+    }
+
+    /// Writes the vector as four big-endian `f64`s
+    ///
+    /// ## Arguments
+    ///
+    /// * `writer` - The destination to write the 32 bytes to
+    ///
+    /// ## Returns
+    ///
+    /// An error if the writer fails
+    pub fn write_be<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+        write_components(&[self.a, self.b, self.c, self.d], Endian::Big, writer)
+    }
+
+    /// Reads a vector stored as four little-endian `f64`s
+    ///
+    /// ## Arguments
+    ///
+    /// * `reader` - The source to read 32 bytes from
+    ///
+    /// ## Returns
+    ///
+    /// The vector, or an error if the reader fails or runs out of bytes
+    pub fn read_le<R: Read + ?Sized>(reader: &mut R) -> io::Result<Vector4> {
+        let c = read_components(4, Endian::Little, reader)?;
+        Ok(Vector4::new(c[0], c[1], c[2], c[3]))
+    }
+
+    /// Reads a vector stored as four big-endian `f64`s
+    ///
+    /// ## Arguments
+    ///
+    /// * `reader` - The source to read 32 bytes from
+    ///
+    /// ## Returns
+    ///
+    /// The vector, or an error if the reader fails or runs out of bytes
+    pub fn read_be<R: Read + ?Sized>(reader: &mut R) -> io::Result<Vector4> {
+        let c = read_components(4, Endian::Big, reader)?;
+        Ok(Vector4::new(c[0], c[1], c[2], c[3]))
+    }
+
+    /// Writes a slice of vectors back to back as little-endian `f64`s
+    ///
+    /// The whole slice is encoded before writing, so the writer receives a single call.
+    ///
+    /// ## Arguments
+    ///
+    /// * `vectors` - The vectors to write
+    /// * `writer` - The destination to write the bytes to
+    ///
+    /// ## Returns
+    ///
+    /// An error if the writer fails
+    pub fn write_slice_le<W: Write + ?Sized>(
+        vectors: &[Vector4],
+        writer: &mut W,
+    ) -> io::Result<()> {
+        let components: Vec<f64> = vectors.iter().flat_map(|v| [v.a, v.b, v.c, v.d]).collect();
+        write_components(&components, Endian::Little, writer)
+    }
+
+    /// Writes a slice of vectors back to back as big-endian `f64`s
+    ///
+    /// ## Arguments
+    ///
+    /// * `vectors` - The vectors to write
+    /// * `writer` - The destination to write the bytes to
+    ///
+    /// ## Returns
+    ///
+    /// An error if the writer fails
+    pub fn write_slice_be<W: Write + ?Sized>(
+        vectors: &[Vector4],
+        writer: &mut W,
+    ) -> io::Result<()> {
+        let components: Vec<f64> = vectors.iter().flat_map(|v| [v.a, v.b, v.c, v.d]).collect();
+        write_components(&components, Endian::Big, writer)
+    }
+
+    /// Reads a number of vectors stored back to back as little-endian `f64`s
+    ///
+    /// ## Arguments
+    ///
+    /// * `reader` - The source to read the bytes from
+    /// * `count` - The number of vectors to read
+    ///
+    /// ## Returns
+    ///
+    /// The vectors, or an error if the reader fails or runs out of bytes
+    pub fn read_vec_le<R: Read + ?Sized>(reader: &mut R, count: usize) -> io::Result<Vec<Vector4>> {
+        let c = read_components(checked_count(count, 4)?, Endian::Little, reader)?;
+        Ok(c.chunks_exact(4)
+            .map(|c| Vector4::new(c[0], c[1], c[2], c[3]))
+            .collect())
+    }
+
+    /// Reads a number of vectors stored back to back as big-endian `f64`s
+    ///
+    /// ## Arguments
+    ///
+    /// * `reader` - The source to read the bytes from
+    /// * `count` - The number of vectors to read
+    ///
+    /// ## Returns
+    ///
+    /// The vectors, or an error if the reader fails or runs out of bytes
+    pub fn read_vec_be<R: Read + ?Sized>(reader: &mut R, count: usize) -> io::Result<Vec<Vector4>> {
+        let c = read_components(checked_count(count, 4)?, Endian::Big, reader)?;
+        Ok(c.chunks_exact(4)
+            .map(|c| Vector4::new(c[0], c[1], c[2], c[3]))
+            .collect())
+    }
+}
+
+impl DynamicVector {
+    /// Writes the vector as a little-endian `u64` length followed by its little-endian `f64`s
+    ///
+    /// ## Arguments
+    ///
+    /// * `writer` - The destination to write the bytes to
+    ///
+    /// ## Returns
+    ///
+    /// An error if the writer fails
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::DynamicVector;
+    ///
+    /// let mut v = DynamicVector::new(3);
+    /// v.set(1, 5.);
+    ///
+    /// let mut bytes = Vec::new();
+    /// v.write_le(&mut bytes).unwrap();
+    ///
+    /// assert_eq!(DynamicVector::read_le(&mut bytes.as_slice()).unwrap(), v);
+    /// ```
+    pub fn write_le<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+        let mut bytes = Vec::with_capacity(8 + self.data.len() * 8);
+        bytes.extend_from_slice(&(self.data.len() as u64).to_le_bytes());
+        encode(&self.data, Endian::Little, &mut bytes);
+        writer.write_all(&bytes)
+    }
+
+    /// Writes the vector as a big-endian `u64` length followed by its big-endian `f64`s
+    ///
+    /// ## Arguments
+    ///
+    /// * `writer` - The destination to write the bytes to
+    ///
+    /// ## Returns
+    ///
+    /// An error if the writer fails
+    pub fn write_be<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+        let mut bytes = Vec::with_capacity(8 + self.data.len() * 8);
+        bytes.extend_from_slice(&(self.data.len() as u64).to_be_bytes());
+        encode(&self.data, Endian::Big, &mut bytes);
+        writer.write_all(&bytes)
+    }
+
+    /// Reads a vector written by [`DynamicVector::write_le`]
+    ///
+    /// ## Arguments
+    ///
+    /// * `reader` - The source to read the bytes from
+    ///
+    /// ## Returns
+    ///
+    /// The vector, or an error if the reader fails, runs out of bytes or the stored length does
+    /// not fit in a `usize`
+    pub fn read_le<R: Read + ?Sized>(reader: &mut R) -> io::Result<DynamicVector> {
+        let mut length = [0; 8];
+        reader.read_exact(&mut length)?;
+        let length = to_length(u64::from_le_bytes(length))?;
+        Ok(DynamicVector {
+            data: read_components(length, Endian::Little, reader)?.into(),
+        })
+    }
+
+    /// Reads a vector written by [`DynamicVector::write_be`]
+    ///
+    /// ## Arguments
+    ///
+    /// * `reader` - The source to read the bytes from
+    ///
+    /// ## Returns
+    ///
+    /// The vector, or an error if the reader fails, runs out of bytes or the stored length does
+    /// not fit in a `usize`
+    pub fn read_be<R: Read + ?Sized>(reader: &mut R) -> io::Result<DynamicVector> {
+        let mut length = [0; 8];
+        reader.read_exact(&mut length)?;
+        let length = to_length(u64::from_be_bytes(length))?;
+        Ok(DynamicVector {
+            data: read_components(length, Endian::Big, reader)?.into(),
+        })
+    }
+}
+
+/// Converts a stored length, rejecting values whose byte size cannot be allocated
+fn to_length(length: u64) -> io::Result<usize> {
+    usize::try_from(length)
+        .ok()
+        .filter(|length| length.checked_mul(8).is_some())
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "vector length is too large"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_byte_order() {
+        let v = Vector2::new(1., -2.);
+
+        let mut le = Vec::new();
+        v.write_le(&mut le).unwrap();
+        assert_eq!(&le[..8], &1_f64.to_le_bytes());
+        assert_eq!(&le[8..], &(-2_f64).to_le_bytes());
+
+        let mut be = Vec::new();
+        v.write_be(&mut be).unwrap();
+        assert_eq!(&be[..8], &1_f64.to_be_bytes());
+        assert_eq!(Vector2::read_be(&mut be.as_slice()).unwrap(), v);
+    }
+
+    #[test]
+    fn test_array_encoding() {
+        // The array encoding matches the streaming one
+        let v3 = Vector3::new(7., 8., 9.);
+        let mut streamed = Vec::new();
+        v3.write_be(&mut streamed).unwrap();
+        assert_eq!(streamed, v3.to_be_bytes());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let v3 = Vector3::new(1.5, f64::MAX, -0.);
+        let v4 = Vector4::new(1., 2., 3., f64::MIN_POSITIVE);
+        let mut bytes = Vec::new();
+        v3.write_be(&mut bytes).unwrap();
+        v4.write_le(&mut bytes).unwrap();
+
+        let mut reader = bytes.as_slice();
+        assert_eq!(Vector3::read_be(&mut reader).unwrap(), v3);
+        assert_eq!(Vector4::read_le(&mut reader).unwrap(), v4);
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn test_slices() {
+        let points: Vec<Vector4> = (0..10)
+            .map(|i| Vector4::new(i as f64, 1., 2., -(i as f64)))
+            .collect();
+        let mut bytes = Vec::new();
+        Vector4::write_slice_be(&points, &mut bytes).unwrap();
+        assert_eq!(bytes.len(), 10 * 32);
+        assert_eq!(
+            Vector4::read_vec_be(&mut bytes.as_slice(), 10).unwrap(),
+            points
+        );
+        assert_eq!(
+            Vector2::read_vec_le(&mut [].as_slice(), 0).unwrap(),
+            Vec::<Vector2>::new()
+        );
+    }
+
+    #[test]
+    fn test_truncated_input() {
+        let bytes = [0u8; 20];
+        let error = Vector3::read_le(&mut bytes.as_slice()).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+
+        let huge = u64::MAX.to_le_bytes();
+        assert!(DynamicVector::read_le(&mut huge.as_slice()).is_err());
+
+        // A count whose byte size overflows is rejected before anything is read
+        let error = Vector3::read_vec_le(&mut huge.as_slice(), usize::MAX / 3).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        let error = Vector2::read_vec_be(&mut huge.as_slice(), usize::MAX).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_dynamic_vector() {
+        let mut v = DynamicVector::new(4);
+        v.set(3, 7.);
+        let mut bytes = Vec::new();
+        v.write_be(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), 8 + 4 * 8);
+        assert_eq!(DynamicVector::read_be(&mut bytes.as_slice()).unwrap(), v);
+    }
+}
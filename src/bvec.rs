@@ -0,0 +1,207 @@
+use crate::{Vector2, Vector3, Vector4};
+use core::ops::{BitAnd, BitOr, BitXor, Not};
+
+/// A mask of two booleans, one per component of a [`Vector2`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct BVec2 {
+    pub x: bool,
+    pub y: bool,
+}
+
+/// A mask of three booleans, one per component of a [`Vector3`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct BVec3 {
+    pub x: bool,
+    pub y: bool,
+    pub z: bool,
+}
+
+/// A mask of four booleans, one per component of a [`Vector4`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct BVec4 {
+    pub x: bool,
+    pub y: bool,
+    pub z: bool,
+    pub w: bool,
+}
+
+// Implements a mask type and the comparisons of the vector type it belongs to.
+//
+// The components are given as `mask_field = vector_field` pairs, as the fields of `Vector4` are
+// not named like the `x`, `y`, `z` and `w` of `BVec4`.
+macro_rules! masks {
+    ($B:ident, $V:ident, $N:literal: $($m:ident = $f:ident),+) => {
+        impl $B {
+            /// Creates a new mask
+            pub fn new($($m: bool),+) -> $B {
+                $B { $($m),+ }
+            }
+
+            /// Creates a mask with every component set to the same value
+            pub fn splat(value: bool) -> $B {
+                $B { $($m: value),+ }
+            }
+
+            /// Checks whether any component is set
+            pub fn any(&self) -> bool {
+                false $(|| self.$m)+
+            }
+
+            /// Checks whether every component is set
+            pub fn all(&self) -> bool {
+                true $(&& self.$m)+
+            }
+
+            /// Packs the mask into an integer, with the first component in the lowest bit
+            pub fn bitmask(&self) -> u32 {
+                let mut bits = 0;
+                for (i, set) in <[bool; $N]>::from(*self).into_iter().enumerate() {
+                    bits |= (set as u32) << i;
+                }
+                bits
+            }
+        }
+
+        impl BitAnd for $B {
+            type Output = $B;
+
+            fn bitand(self, other: $B) -> $B {
+                $B { $($m: self.$m & other.$m),+ }
+            }
+        }
+
+        impl BitOr for $B {
+            type Output = $B;
+
+            fn bitor(self, other: $B) -> $B {
+                $B { $($m: self.$m | other.$m),+ }
+            }
+        }
+
+        impl BitXor for $B {
+            type Output = $B;
+
+            fn bitxor(self, other: $B) -> $B {
+                $B { $($m: self.$m ^ other.$m),+ }
+            }
+        }
+
+        impl Not for $B {
+            type Output = $B;
+
+            fn not(self) -> $B {
+                $B { $($m: !self.$m),+ }
+            }
+        }
+
+        impl From<[bool; $N]> for $B {
+            fn from(values: [bool; $N]) -> Self {
+                let [$($m),+] = values;
+                $B { $($m),+ }
+            }
+        }
+
+        impl From<$B> for [bool; $N] {
+            fn from(mask: $B) -> Self {
+                [$(mask.$m),+]
+            }
+        }
+
+        impl $V {
+            /// Compares each component with `<`
+            pub fn cmplt(&self, other: &$V) -> $B {
+                $B { $($m: self.$f < other.$f),+ }
+            }
+
+            /// Compares each component with `<=`
+            pub fn cmple(&self, other: &$V) -> $B {
+                $B { $($m: self.$f <= other.$f),+ }
+            }
+
+            /// Compares each component with `>`
+            pub fn cmpgt(&self, other: &$V) -> $B {
+                $B { $($m: self.$f > other.$f),+ }
+            }
+
+            /// Compares each component with `>=`
+            pub fn cmpge(&self, other: &$V) -> $B {
+                $B { $($m: self.$f >= other.$f),+ }
+            }
+
+            /// Compares each component with `==`
+            pub fn cmpeq(&self, other: &$V) -> $B {
+                $B { $($m: self.$f == other.$f),+ }
+            }
+
+            /// Compares each component with `!=`
+            pub fn cmpne(&self, other: &$V) -> $B {
+                $B { $($m: self.$f != other.$f),+ }
+            }
+
+            /// Blends two vectors component by component, like `select` in SIMD and shader code
+            ///
+            /// Each component comes from `if_true` where the mask is set and from `if_false`
+            /// where it is not.
+            pub fn select(mask: $B, if_true: &$V, if_false: &$V) -> $V {
+                let mut result = *if_false;
+                $(
+                    if mask.$m {
+                        result.$f = if_true.$f;
+                    }
+                )+
+                result
+            }
+        }
+    };
+}
+
+masks!(BVec2, Vector2, 2: x = x, y = y);
+masks!(BVec3, Vector3, 3: x = x, y = y, z = z);
+masks!(BVec4, Vector4, 4: x = a, y = b, z = c, w = d);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_masks() {
+        let mask = BVec3::new(true, false, true);
+        assert!(mask.any());
+        assert!(!mask.all());
+        assert_eq!(mask.bitmask(), 0b101);
+        assert_eq!(!mask, BVec3::new(false, true, false));
+        assert_eq!(mask & BVec3::splat(false), BVec3::splat(false));
+        assert_eq!(mask | !mask, BVec3::splat(true));
+        assert_eq!(mask ^ BVec3::splat(true), !mask);
+        assert_eq!(<[bool; 3]>::from(mask), [true, false, true]);
+        assert!(!BVec2::default().any());
+    }
+
+    #[test]
+    fn test_comparisons() {
+        let a = Vector4::new(1., 2., 3., f64::NAN);
+        let b = Vector4::new(2., 2., 1., 0.);
+        assert_eq!(a.cmplt(&b), BVec4::new(true, false, false, false));
+        assert_eq!(a.cmple(&b), BVec4::new(true, true, false, false));
+        assert_eq!(a.cmpgt(&b), BVec4::new(false, false, true, false));
+        assert_eq!(a.cmpge(&b), BVec4::new(false, true, true, false));
+        assert_eq!(a.cmpeq(&b), BVec4::new(false, true, false, false));
+        assert_eq!(a.cmpne(&b), BVec4::new(true, false, true, true));
+    }
+
+    #[test]
+    fn test_select() {
+        // A branchless component-wise max
+        let a = Vector3::new(1., 5., -2.);
+        let b = Vector3::new(3., 4., -1.);
+        assert_eq!(
+            Vector3::select(a.cmpgt(&b), &a, &b),
+            Vector3::new(3., 5., -1.)
+        );
+        let mask = BVec2::new(false, true);
+        assert_eq!(
+            Vector2::select(mask, &Vector2::new(1., 2.), &Vector2::new(3., 4.)),
+            Vector2::new(3., 2.)
+        );
+    }
+}
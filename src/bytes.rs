@@ -0,0 +1,209 @@
+use crate::{Vector2, Vector3, Vector4};
+
+/// Encodes components into a fixed size array, for the `to_*_bytes` methods
+fn pack<const N: usize>(components: &[f64], to_bytes: fn(f64) -> [u8; 8]) -> [u8; N] {
+    let mut bytes = [0; N];
+    for (chunk, &c) in bytes.chunks_exact_mut(8).zip(components) {
+        chunk.copy_from_slice(&to_bytes(c));
+    }
+    bytes
+}
+
+/// Decodes the component at `index` from an array, for the `from_*_bytes` methods
+fn component(bytes: &[u8], index: usize, from_bytes: fn([u8; 8]) -> f64) -> f64 {
+    from_bytes(bytes[index * 8..][..8].try_into().unwrap())
+}
+
+impl Vector2 {
+    /// Encodes the vector as two little-endian `f64`s
+    ///
+    /// ## Returns
+    ///
+    /// The 16 bytes of the vector, starting with the first component
+    pub fn to_le_bytes(&self) -> [u8; 16] {
+        pack(&[self.x, self.y], f64::to_le_bytes)
+    }
+
+    /// Encodes the vector as two big-endian `f64`s
+    ///
+    /// ## Returns
+    ///
+    /// The 16 bytes of the vector, starting with the first component
+    pub fn to_be_bytes(&self) -> [u8; 16] {
+        pack(&[self.x, self.y], f64::to_be_bytes)
+    }
+
+    /// Decodes a vector from two little-endian `f64`s
+    ///
+    /// ## Arguments
+    ///
+    /// * `bytes` - The bytes of the vector, starting with the first component
+    ///
+    /// ## Returns
+    ///
+    /// The decoded vector
+    pub fn from_le_bytes(bytes: [u8; 16]) -> Vector2 {
+        Vector2::new(
+            component(&bytes, 0, f64::from_le_bytes),
+            component(&bytes, 1, f64::from_le_bytes),
+        )
+    }
+
+    /// Decodes a vector from two big-endian `f64`s
+    ///
+    /// ## Arguments
+    ///
+    /// * `bytes` - The bytes of the vector, starting with the first component
+    ///
+    /// ## Returns
+    ///
+    /// The decoded vector
+    pub fn from_be_bytes(bytes: [u8; 16]) -> Vector2 {
+        Vector2::new(
+            component(&bytes, 0, f64::from_be_bytes),
+            component(&bytes, 1, f64::from_be_bytes),
+        )
+    }
+}
+
+impl Vector3 {
+    /// Encodes the vector as three little-endian `f64`s
+    ///
+    /// ## Returns
+    ///
+    /// The 24 bytes of the vector, starting with the first component
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::Vector3;
+    ///
+    /// let v = Vector3::new(1., 2., 3.);
+    /// let bytes = v.to_le_bytes();
+    ///
+    /// assert_eq!(bytes[..8], 1_f64.to_le_bytes());
+    /// assert_eq!(Vector3::from_le_bytes(bytes), v);
+    /// ```
+    pub fn to_le_bytes(&self) -> [u8; 24] {
+        pack(&[self.x, self.y, self.z], f64::to_le_bytes)
+    }
+
+    /// Encodes the vector as three big-endian `f64`s
+    ///
+    /// ## Returns
+    ///
+    /// The 24 bytes of the vector, starting with the first component
+    pub fn to_be_bytes(&self) -> [u8; 24] {
+        pack(&[self.x, self.y, self.z], f64::to_be_bytes)
+    }
+
+    /// Decodes a vector from three little-endian `f64`s
+    ///
+    /// ## Arguments
+    ///
+    /// * `bytes` - The bytes of the vector, starting with the first component
+    ///
+    /// ## Returns
+    ///
+    /// The decoded vector
+    pub fn from_le_bytes(bytes: [u8; 24]) -> Vector3 {
+        Vector3::new(
+            component(&bytes, 0, f64::from_le_bytes),
+            component(&bytes, 1, f64::from_le_bytes),
+            component(&bytes, 2, f64::from_le_bytes),
+        )
+    }
+
+    /// Decodes a vector from three big-endian `f64`s
+    ///
+    /// ## Arguments
+    ///
+    /// * `bytes` - The bytes of the vector, starting with the first component
+    ///
+    /// ## Returns
+    ///
+    /// The decoded vector
+    pub fn from_be_bytes(bytes: [u8; 24]) -> Vector3 {
+        Vector3::new(
+            component(&bytes, 0, f64::from_be_bytes),
+            component(&bytes, 1, f64::from_be_bytes),
+            component(&bytes, 2, f64::from_be_bytes),
+        )
+    }
+}
+
+impl Vector4 {
+    /// Encodes the vector as four little-endian `f64`s
+    ///
+    /// ## Returns
+    ///
+    /// The 32 bytes of the vector, starting with the first component
+    pub fn to_le_bytes(&self) -> [u8; 32] {
+        pack(&[self.a, self.b, self.c, self.d], f64::to_le_bytes)
+    }
+
+    /// Encodes the vector as four big-endian `f64`s
+    ///
+    /// ## Returns
+    ///
+    /// The 32 bytes of the vector, starting with the first component
+    pub fn to_be_bytes(&self) -> [u8; 32] {
+        pack(&[self.a, self.b, self.c, self.d], f64::to_be_bytes)
+    }
+
+    /// Decodes a vector from four little-endian `f64`s
+    ///
+    /// ## Arguments
+    ///
+    /// * `bytes` - The bytes of the vector, starting with the first component
+    ///
+    /// ## Returns
+    ///
+    /// The decoded vector
+    pub fn from_le_bytes(bytes: [u8; 32]) -> Vector4 {
+        Vector4::new(
+            component(&bytes, 0, f64::from_le_bytes),
+            component(&bytes, 1, f64::from_le_bytes),
+            component(&bytes, 2, f64::from_le_bytes),
+            component(&bytes, 3, f64::from_le_bytes),
+        )
+    }
+
+    /// Decodes a vector from four big-endian `f64`s
+    ///
+    /// ## Arguments
+    ///
+    /// * `bytes` - The bytes of the vector, starting with the first component
+    ///
+    /// ## Returns
+    ///
+    /// The decoded vector
+    pub fn from_be_bytes(bytes: [u8; 32]) -> Vector4 {
+        Vector4::new(
+            component(&bytes, 0, f64::from_be_bytes),
+            component(&bytes, 1, f64::from_be_bytes),
+            component(&bytes, 2, f64::from_be_bytes),
+            component(&bytes, 3, f64::from_be_bytes),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_from_bytes() {
+        let v2 = Vector2::new(0.5, -3.);
+        assert_eq!(Vector2::from_le_bytes(v2.to_le_bytes()), v2);
+        assert_eq!(v2.to_be_bytes()[..8], 0.5_f64.to_be_bytes());
+
+        let v3 = Vector3::new(1.5, f64::MAX, -0.);
+        assert_eq!(Vector3::from_be_bytes(v3.to_be_bytes()), v3);
+        assert_eq!(v3.to_le_bytes()[8..16], f64::MAX.to_le_bytes());
+
+        let v4 = Vector4::new(1., 2., 3., 4.);
+        assert_eq!(v4.to_le_bytes()[24..], 4_f64.to_le_bytes());
+        assert_eq!(Vector4::from_be_bytes(v4.to_be_bytes()), v4);
+    }
+}
@@ -0,0 +1,130 @@
+use crate::{Vector, Vector2, Vector3, Vector4, VectorError};
+
+// Implements finiteness checks and checked arithmetic for a vector type.
+//
+// The fields of the vector are listed so that every component can be tested.
+macro_rules! checked {
+    ($V:ident: $($f:ident),+) => {
+        impl $V {
+            /// Checks whether every component is finite, neither infinite nor NaN
+            pub fn is_finite(&self) -> bool {
+                true $(&& self.$f.is_finite())+
+            }
+
+            /// Checks whether any component is NaN
+            pub fn is_nan(&self) -> bool {
+                false $(|| self.$f.is_nan())+
+            }
+
+            /// Fails with [`VectorError::NonFinite`] unless every component is finite
+            fn finite(self) -> Result<$V, VectorError> {
+                if self.is_finite() {
+                    Ok(self)
+                } else {
+                    Err(VectorError::NonFinite)
+                }
+            }
+
+            /// Adds two vectors, failing if the result is not finite
+            ///
+            /// ## Returns
+            ///
+            /// The sum, or [`VectorError::NonFinite`] if it overflowed or either input was not
+            /// finite
+            pub fn checked_add(&self, other: &$V) -> Result<$V, VectorError> {
+                (*self + *other).finite()
+            }
+
+            /// Subtracts a vector, failing if the result is not finite
+            ///
+            /// ## Returns
+            ///
+            /// The difference, or [`VectorError::NonFinite`]
+            pub fn checked_sub(&self, other: &$V) -> Result<$V, VectorError> {
+                (*self - *other).finite()
+            }
+
+            /// Scales the vector, failing if the result is not finite
+            ///
+            /// ## Returns
+            ///
+            /// The scaled vector, or [`VectorError::NonFinite`]
+            pub fn checked_mul(&self, scalar: f64) -> Result<$V, VectorError> {
+                (*self * scalar).finite()
+            }
+
+            /// Divides the vector by a scalar, failing if the result is not finite
+            ///
+            /// ## Returns
+            ///
+            /// The divided vector, or [`VectorError::NonFinite`], which includes dividing by zero
+            pub fn checked_div(&self, scalar: f64) -> Result<$V, VectorError> {
+                (*self / scalar).finite()
+            }
+
+            /// Normalizes the vector, failing instead of producing NaNs or infinities
+            ///
+            /// ## Returns
+            ///
+            /// The unit vector, [`VectorError::ZeroMagnitude`] for a zero vector or
+            /// [`VectorError::NonFinite`] if the vector was not finite
+            pub fn checked_normalize(&self) -> Result<$V, VectorError> {
+                self.finite()?;
+                if self.magnitude() == 0. {
+                    return Err(VectorError::ZeroMagnitude);
+                }
+                self.normalize().finite()
+            }
+        }
+    };
+}
+
+checked!(Vector2: x, y);
+checked!(Vector3: x, y, z);
+checked!(Vector4: a, b, c, d);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_finiteness() {
+        assert!(Vector2::new(1., -2.).is_finite());
+        assert!(!Vector3::new(1., f64::INFINITY, 0.).is_finite());
+        assert!(!Vector3::new(1., f64::INFINITY, 0.).is_nan());
+        assert!(Vector4::new(0., 0., 0., f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        let v = Vector3::new(1., 2., 3.);
+        assert_eq!(v.checked_add(&v), Ok(Vector3::new(2., 4., 6.)));
+        assert_eq!(v.checked_sub(&v), Ok(Vector3::new(0., 0., 0.)));
+        assert_eq!(v.checked_div(0.), Err(VectorError::NonFinite));
+        assert_eq!(
+            Vector2::new(f64::MAX, 0.).checked_mul(2.),
+            Err(VectorError::NonFinite)
+        );
+        let nan = Vector2::new(f64::NAN, 0.);
+        assert_eq!(
+            nan.checked_add(&Vector2::new(1., 1.)),
+            Err(VectorError::NonFinite)
+        );
+    }
+
+    #[test]
+    fn test_checked_normalize() {
+        assert_eq!(
+            Vector2::new(0., 4.).checked_normalize(),
+            Ok(Vector2::new(0., 1.))
+        );
+        assert_eq!(
+            Vector4::new(0., 0., 0., 0.).checked_normalize(),
+            Err(VectorError::ZeroMagnitude)
+        );
+        assert_eq!(
+            Vector3::new(f64::INFINITY, 0., 0.).checked_normalize(),
+            Err(VectorError::NonFinite)
+        );
+    }
+}
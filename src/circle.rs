@@ -0,0 +1,347 @@
+#[cfg(not(feature = "std"))]
+use crate::math::FloatMath;
+use crate::{Vector, Vector2};
+
+/// A circle in 2D space described by a center point and a radius
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Circle {
+    pub center: Vector2,
+    pub radius: f64,
+}
+
+/// The points where a circle meets another shape
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CircleIntersection {
+    /// The shapes do not meet
+    None,
+    /// The shapes touch at a single point
+    One(Vector2),
+    /// The shapes cross at two points
+    Two(Vector2, Vector2),
+}
+
+impl Circle {
+    /// Creates a new circle
+    ///
+    /// ## Arguments
+    ///
+    /// * `center` - The center of the circle
+    /// * `radius` - The radius of the circle
+    ///
+    /// ## Returns
+    ///
+    /// A new circle
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Circle, Vector2};
+    ///
+    /// let c = Circle::new(Vector2::new(0., 0.), 1.);
+    /// ```
+    pub fn new(center: Vector2, radius: f64) -> Circle {
+        Circle { center, radius }
+    }
+
+    /// Checks whether a point lies inside (or on the edge of) the circle
+    ///
+    /// ## Arguments
+    ///
+    /// * `point` - The point to test
+    ///
+    /// ## Returns
+    ///
+    /// `true` if the point is inside the circle
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Circle, Vector2};
+    ///
+    /// let c = Circle::new(Vector2::new(0., 0.), 1.);
+    ///
+    /// assert!(c.contains(&Vector2::new(0.5, 0.5)));
+    /// assert!(!c.contains(&Vector2::new(1., 1.)));
+    /// ```
+    pub fn contains(&self, point: &Vector2) -> bool {
+        let offset = *point - self.center;
+        offset.dot(&offset) <= self.radius * self.radius
+    }
+
+    /// Checks whether two circles overlap
+    ///
+    /// Circles that only touch at a single point are considered to be intersecting.
+    ///
+    /// ## Arguments
+    ///
+    /// * `other` - The other circle to test against
+    ///
+    /// ## Returns
+    ///
+    /// `true` if the circles intersect
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Circle, Vector2};
+    ///
+    /// let a = Circle::new(Vector2::new(0., 0.), 1.);
+    /// let b = Circle::new(Vector2::new(1.5, 0.), 1.);
+    ///
+    /// assert!(a.intersects(&b));
+    /// ```
+    pub fn intersects(&self, other: &Circle) -> bool {
+        let offset = other.center - self.center;
+        let radii = self.radius + other.radius;
+        offset.dot(&offset) <= radii * radii
+    }
+
+    /// Computes a circle enclosing all of the given points
+    ///
+    /// This uses Ritter's algorithm, which is fast but only approximate. The resulting circle
+    /// always contains every point, however it may be slightly larger than the minimal one.
+    ///
+    /// ## Arguments
+    ///
+    /// * `points` - The points to enclose
+    ///
+    /// ## Returns
+    ///
+    /// The bounding circle, or `None` if `points` is empty
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Circle, Vector2};
+    ///
+    /// let points = [Vector2::new(-1., 0.), Vector2::new(1., 0.), Vector2::new(0., 0.5)];
+    ///
+    /// let c = Circle::from_points(&points).unwrap();
+    ///
+    /// assert_eq!(c, Circle::new(Vector2::new(0., 0.), 1.));
+    /// ```
+    pub fn from_points(points: &[Vector2]) -> Option<Circle> {
+        let first = *points.first()?;
+        let farthest_from = |from: Vector2| {
+            points.iter().copied().fold(from, |best, p| {
+                if (p - from).magnitude() > (best - from).magnitude() {
+                    p
+                } else {
+                    best
+                }
+            })
+        };
+
+        let a = farthest_from(first);
+        let b = farthest_from(a);
+
+        let mut center = (a + b) / 2.;
+        let mut radius = (b - a).magnitude() / 2.;
+
+        for p in points {
+            let distance = (*p - center).magnitude();
+            if distance > radius {
+                let new_radius = (radius + distance) / 2.;
+                center = center + (*p - center) * ((new_radius - radius) / distance);
+                radius = new_radius;
+            }
+        }
+
+        Some(Circle { center, radius })
+    }
+
+    /// Finds the points where the outlines of two circles cross
+    ///
+    /// ## Arguments
+    ///
+    /// * `other` - The other circle
+    ///
+    /// ## Returns
+    ///
+    /// The intersection points. When there are two, the first lies to the left of the line from
+    /// this circle's center to the other's. Circles that do not touch, lie inside one another or
+    /// are concentric have no intersections
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Circle, CircleIntersection, Vector2};
+    ///
+    /// let a = Circle::new(Vector2::new(0., 0.), 5.);
+    /// let b = Circle::new(Vector2::new(8., 0.), 5.);
+    ///
+    /// assert_eq!(
+    ///     a.intersect_circle(&b),
+    ///     CircleIntersection::Two(Vector2::new(4., 3.), Vector2::new(4., -3.))
+    /// );
+    /// ```
+    pub fn intersect_circle(&self, other: &Circle) -> CircleIntersection {
+        let offset = other.center - self.center;
+        let distance = offset.magnitude();
+        if distance == 0.
+            || distance > self.radius + other.radius
+            || distance < (self.radius - other.radius).abs()
+        {
+            return CircleIntersection::None;
+        }
+
+        // Distance from this center to the chord between the two points, and half its length
+        let along = (distance * distance + self.radius * self.radius - other.radius * other.radius)
+            / (2. * distance);
+        let half_chord_square = self.radius * self.radius - along * along;
+        let direction = offset / distance;
+        let middle = self.center + direction * along;
+        if half_chord_square <= 0. {
+            return CircleIntersection::One(middle);
+        }
+
+        let left = Vector2::new(-direction.y, direction.x) * half_chord_square.sqrt();
+        CircleIntersection::Two(middle + left, middle - left)
+    }
+
+    /// Finds the points where a line segment crosses the outline of the circle
+    ///
+    /// ## Arguments
+    ///
+    /// * `a` - The start of the segment
+    /// * `b` - The end of the segment
+    ///
+    /// ## Returns
+    ///
+    /// The intersection points, ordered from `a` to `b`. A segment lying entirely inside the
+    /// circle has no intersections
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Circle, CircleIntersection, Vector2};
+    ///
+    /// let c = Circle::new(Vector2::new(0., 0.), 1.);
+    ///
+    /// assert_eq!(
+    ///     c.intersect_segment(&Vector2::new(0., 0.), &Vector2::new(0., 3.)),
+    ///     CircleIntersection::One(Vector2::new(0., 1.))
+    /// );
+    /// ```
+    pub fn intersect_segment(&self, a: &Vector2, b: &Vector2) -> CircleIntersection {
+        let direction = *b - *a;
+        let offset = *a - self.center;
+
+        // Solve |offset + direction * t| = radius for t
+        let quadratic = direction.dot(&direction);
+        let linear = 2. * offset.dot(&direction);
+        let constant = offset.dot(&offset) - self.radius * self.radius;
+        if quadratic == 0. {
+            return if constant == 0. {
+                CircleIntersection::One(*a)
+            } else {
+                CircleIntersection::None
+            };
+        }
+
+        let discriminant = linear * linear - 4. * quadratic * constant;
+        if discriminant < 0. {
+            return CircleIntersection::None;
+        }
+        let root = discriminant.sqrt();
+        let on_segment = |t: f64| (0. ..=1.).contains(&t).then(|| *a + direction * t);
+        let first = on_segment((-linear - root) / (2. * quadratic));
+        let second = on_segment((-linear + root) / (2. * quadratic));
+        match (first, second) {
+            (Some(p), Some(q)) if discriminant > 0. => CircleIntersection::Two(p, q),
+            (Some(p), _) | (None, Some(p)) => CircleIntersection::One(p),
+            (None, None) => CircleIntersection::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contains() {
+        let c = Circle::new(Vector2::new(1., 1.), 2.);
+        assert!(c.contains(&Vector2::new(1., 1.)));
+        assert!(c.contains(&Vector2::new(3., 1.)));
+        assert!(!c.contains(&Vector2::new(3., 3.)));
+    }
+
+    #[test]
+    fn test_intersects() {
+        let a = Circle::new(Vector2::new(0., 0.), 1.);
+        let b = Circle::new(Vector2::new(2., 0.), 1.);
+        let c = Circle::new(Vector2::new(3., 0.), 1.);
+        assert!(a.intersects(&b));
+        assert!(!a.intersects(&c));
+    }
+
+    #[test]
+    fn test_intersect_circle() {
+        let a = Circle::new(Vector2::new(0., 0.), 1.);
+        assert_eq!(
+            a.intersect_circle(&Circle::new(Vector2::new(3., 0.), 2.)),
+            CircleIntersection::One(Vector2::new(1., 0.))
+        );
+        assert_eq!(
+            a.intersect_circle(&Circle::new(Vector2::new(0., 1.), 2.)),
+            CircleIntersection::One(Vector2::new(0., -1.))
+        );
+        assert_eq!(
+            a.intersect_circle(&Circle::new(Vector2::new(0., 3.), 1.)),
+            CircleIntersection::None
+        );
+        assert_eq!(
+            a.intersect_circle(&Circle::new(Vector2::new(0., 0.), 0.5)),
+            CircleIntersection::None
+        );
+        assert_eq!(a.intersect_circle(&a), CircleIntersection::None);
+        let b = Circle::new(Vector2::new(0., 0.), 5.);
+        assert_eq!(
+            b.intersect_circle(&Circle::new(Vector2::new(6., 0.), 5.)),
+            CircleIntersection::Two(Vector2::new(3., 4.), Vector2::new(3., -4.))
+        );
+    }
+
+    #[test]
+    fn test_intersect_segment() {
+        let c = Circle::new(Vector2::new(1., 1.), 5.);
+        assert_eq!(
+            c.intersect_segment(&Vector2::new(-10., 5.), &Vector2::new(10., 5.)),
+            CircleIntersection::Two(Vector2::new(-2., 5.), Vector2::new(4., 5.))
+        );
+        assert_eq!(
+            c.intersect_segment(&Vector2::new(10., 6.), &Vector2::new(-10., 6.)),
+            CircleIntersection::One(Vector2::new(1., 6.))
+        );
+        assert_eq!(
+            c.intersect_segment(&Vector2::new(0., 0.), &Vector2::new(2., 2.)),
+            CircleIntersection::None
+        );
+        assert_eq!(
+            c.intersect_segment(&Vector2::new(10., 0.), &Vector2::new(10., 2.)),
+            CircleIntersection::None
+        );
+        let p = Vector2::new(1., -4.);
+        assert_eq!(c.intersect_segment(&p, &p), CircleIntersection::One(p));
+    }
+
+    #[test]
+    fn test_from_points() {
+        let points = [
+            Vector2::new(0., 0.),
+            Vector2::new(4., 0.),
+            Vector2::new(2., 3.),
+            Vector2::new(1., -1.),
+        ];
+        let c = Circle::from_points(&points).unwrap();
+        for p in &points {
+            assert!((*p - c.center).magnitude() <= c.radius + 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_from_points_empty() {
+        assert_eq!(Circle::from_points(&[]), None);
+    }
+}
@@ -0,0 +1,287 @@
+#[cfg(not(feature = "std"))]
+use crate::math::FloatMath;
+use crate::{lerp, Vector3, Vector4};
+
+/// Converts one sRGB encoded channel to linear light
+fn channel_to_linear(c: f64) -> f64 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Converts one linear channel to sRGB encoding
+fn channel_to_srgb(c: f64) -> f64 {
+    if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1. / 2.4) - 0.055
+    }
+}
+
+/// Converts an sRGB encoded color, as stored in images and color pickers, to linear RGB
+///
+/// Lighting, blending and averaging are only correct on linear colors, so convert with this
+/// before doing math on colors and with [`linear_to_srgb`] before displaying them.
+///
+/// ## Arguments
+///
+/// * `color` - The sRGB color, with each channel in `[0, 1]`
+///
+/// ## Returns
+///
+/// The color in linear RGB
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{srgb_to_linear, Vector3};
+///
+/// let linear = srgb_to_linear(&Vector3::new(0., 1., 0.5));
+///
+/// assert_eq!((linear.x, linear.y), (0., 1.));
+/// assert!((linear.z - 0.214).abs() < 1e-3);
+/// ```
+pub fn srgb_to_linear(color: &Vector3) -> Vector3 {
+    Vector3::new(
+        channel_to_linear(color.x),
+        channel_to_linear(color.y),
+        channel_to_linear(color.z),
+    )
+}
+
+/// Converts a linear RGB color to sRGB encoding
+///
+/// ## Arguments
+///
+/// * `color` - The linear color, with each channel in `[0, 1]`
+///
+/// ## Returns
+///
+/// The color in sRGB
+pub fn linear_to_srgb(color: &Vector3) -> Vector3 {
+    Vector3::new(
+        channel_to_srgb(color.x),
+        channel_to_srgb(color.y),
+        channel_to_srgb(color.z),
+    )
+}
+
+/// Converts an sRGB encoded color with alpha to linear RGBA, leaving the alpha unchanged
+///
+/// ## Arguments
+///
+/// * `color` - The sRGB color, with its alpha in the last component
+///
+/// ## Returns
+///
+/// The color in linear RGBA
+pub fn srgba_to_linear(color: &Vector4) -> Vector4 {
+    Vector4::new(
+        channel_to_linear(color.a),
+        channel_to_linear(color.b),
+        channel_to_linear(color.c),
+        color.d,
+    )
+}
+
+/// Converts a linear RGBA color to sRGB encoding, leaving the alpha unchanged
+///
+/// ## Arguments
+///
+/// * `color` - The linear color, with its alpha in the last component
+///
+/// ## Returns
+///
+/// The color in sRGB with alpha
+pub fn linear_to_srgba(color: &Vector4) -> Vector4 {
+    Vector4::new(
+        channel_to_srgb(color.a),
+        channel_to_srgb(color.b),
+        channel_to_srgb(color.c),
+        color.d,
+    )
+}
+
+/// Converts an RGB color to hue, saturation and value
+///
+/// ## Arguments
+///
+/// * `color` - The color, with each channel in `[0, 1]`
+///
+/// ## Returns
+///
+/// The hue, saturation and value in `x`, `y` and `z`, each in `[0, 1]`. The hue is measured in
+/// turns starting from red, and is zero for greys
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{rgb_to_hsv, Vector3};
+///
+/// assert_eq!(rgb_to_hsv(&Vector3::new(0., 0.5, 0.5)), Vector3::new(0.5, 1., 0.5));
+/// ```
+pub fn rgb_to_hsv(color: &Vector3) -> Vector3 {
+    let max = color.x.max(color.y).max(color.z);
+    let min = color.x.min(color.y).min(color.z);
+    let range = max - min;
+    let saturation = if max > 0. { range / max } else { 0. };
+    if range == 0. {
+        return Vector3::new(0., saturation, max);
+    }
+    let sector = if max == color.x {
+        (color.y - color.z) / range
+    } else if max == color.y {
+        (color.z - color.x) / range + 2.
+    } else {
+        (color.x - color.y) / range + 4.
+    };
+    let hue = sector / 6.;
+    Vector3::new(hue - hue.floor(), saturation, max)
+}
+
+/// Converts hue, saturation and value to an RGB color
+///
+/// ## Arguments
+///
+/// * `hsv` - The hue in turns, saturation and value in `x`, `y` and `z`. Hues outside `[0, 1)`
+///   wrap around
+///
+/// ## Returns
+///
+/// The RGB color
+pub fn hsv_to_rgb(hsv: &Vector3) -> Vector3 {
+    let (hue, saturation, value) = (hsv.x - hsv.x.floor(), hsv.y, hsv.z);
+    let sector = hue * 6.;
+    let i = sector.floor();
+    let f = sector - i;
+    let p = value * (1. - saturation);
+    let q = value * (1. - saturation * f);
+    let t = value * (1. - saturation * (1. - f));
+    match i as u8 {
+        0 => Vector3::new(value, t, p),
+        1 => Vector3::new(q, value, p),
+        2 => Vector3::new(p, value, t),
+        3 => Vector3::new(p, q, value),
+        4 => Vector3::new(t, p, value),
+        _ => Vector3::new(value, p, q),
+    }
+}
+
+/// Calculates the relative luminance of a linear RGB color, as perceived brightness
+///
+/// ## Arguments
+///
+/// * `color` - The linear color
+///
+/// ## Returns
+///
+/// The luminance, using the Rec. 709 weights for the primaries of sRGB
+pub fn luminance(color: &Vector3) -> f64 {
+    0.2126 * color.x + 0.7152 * color.y + 0.0722 * color.z
+}
+
+/// Blends two sRGB colors in linear light
+///
+/// Interpolating the encoded values directly makes the midpoint of two bright colors too dark.
+/// This decodes both colors, interpolates and encodes the result again.
+///
+/// ## Arguments
+///
+/// * `a` - The sRGB color at `t = 0`
+/// * `b` - The sRGB color at `t = 1`
+/// * `t` - The interpolation factor
+///
+/// ## Returns
+///
+/// The blended sRGB color
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{lerp_srgb, Vector3};
+///
+/// let black = Vector3::new(0., 0., 0.);
+/// let white = Vector3::new(1., 1., 1.);
+///
+/// // Half the light of white is brighter than half the encoded value
+/// assert!(lerp_srgb(black, white, 0.5).x > 0.7);
+/// ```
+pub fn lerp_srgb(a: Vector3, b: Vector3, t: f64) -> Vector3 {
+    linear_to_srgb(&lerp(srgb_to_linear(&a), srgb_to_linear(&b), t))
+}
+
+/// Blends two sRGB colors with alpha in linear light, see [`lerp_srgb`]
+///
+/// ## Arguments
+///
+/// * `a` - The sRGB color at `t = 0`
+/// * `b` - The sRGB color at `t = 1`
+/// * `t` - The interpolation factor
+///
+/// ## Returns
+///
+/// The blended sRGB color, with the alpha interpolated linearly
+pub fn lerp_srgba(a: Vector4, b: Vector4, t: f64) -> Vector4 {
+    linear_to_srgba(&lerp(srgba_to_linear(&a), srgba_to_linear(&b), t))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Vector;
+
+    #[test]
+    fn test_srgb_round_trip() {
+        for c in [0., 0.002, 0.04, 0.25, 0.5, 1.] {
+            let color = Vector3::new(c, 1. - c, c / 2.);
+            let back = linear_to_srgb(&srgb_to_linear(&color));
+            assert!((back - color).magnitude() < 1e-12);
+        }
+        let rgba = srgba_to_linear(&Vector4::new(1., 0., 0., 0.25));
+        assert_eq!(rgba, Vector4::new(1., 0., 0., 0.25));
+        assert!((linear_to_srgba(&rgba) - rgba).magnitude() < 1e-12);
+    }
+
+    #[test]
+    fn test_hsv() {
+        assert_eq!(
+            rgb_to_hsv(&Vector3::new(1., 0., 0.)),
+            Vector3::new(0., 1., 1.)
+        );
+        assert_eq!(
+            rgb_to_hsv(&Vector3::new(0.5, 0.5, 0.5)),
+            Vector3::new(0., 0., 0.5)
+        );
+        assert_eq!(
+            hsv_to_rgb(&Vector3::new(1. / 3., 1., 1.)),
+            Vector3::new(0., 1., 0.)
+        );
+        assert_eq!(
+            hsv_to_rgb(&Vector3::new(1.5, 0.5, 1.)),
+            Vector3::new(0.5, 1., 1.)
+        );
+        for color in [
+            Vector3::new(0.25, 0.5, 0.75),
+            Vector3::new(1., 0.5, 0.),
+            Vector3::new(0.5, 0., 0.25),
+        ] {
+            let back = hsv_to_rgb(&rgb_to_hsv(&color));
+            assert!((back - color).magnitude() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_luminance_and_lerp() {
+        assert!((luminance(&Vector3::new(1., 1., 1.)) - 1.).abs() < 1e-12);
+        assert_eq!(luminance(&Vector3::new(0., 1., 0.)), 0.7152);
+        let a = Vector4::new(1., 0., 0., 1.);
+        let b = Vector4::new(0., 0., 1., 0.);
+        let mid = lerp_srgba(a, b, 0.5);
+        assert_eq!(mid.d, 0.5);
+        assert_eq!(mid.a, mid.c);
+        let red = Vector3::new(1., 0., 0.);
+        assert!((lerp_srgb(red, Vector3::new(0., 1., 0.), 0.) - red).magnitude() < 1e-12);
+    }
+}
@@ -0,0 +1,149 @@
+#[cfg(not(feature = "std"))]
+use crate::math::FloatMath;
+use crate::Radians;
+use core::ops::{Add, Mul, Neg, Sub};
+
+/// A complex number with `f64` real and imaginary parts
+///
+/// This is the element type of [`DynamicComplexVector`](crate::DynamicComplexVector).
+///
+/// ## Example
+///
+/// ```
+/// use libvector::Complex;
+///
+/// let i = Complex::new(0., 1.);
+///
+/// assert_eq!(i * i, Complex::new(-1., 0.));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Complex {
+    pub re: f64,
+    pub im: f64,
+}
+
+impl Complex {
+    /// Creates a new complex number
+    ///
+    /// ## Arguments
+    ///
+    /// * `re` - The real part
+    /// * `im` - The imaginary part
+    ///
+    /// ## Returns
+    ///
+    /// A new complex number
+    pub fn new(re: f64, im: f64) -> Complex {
+        Complex { re, im }
+    }
+
+    /// Creates a complex number from its magnitude and phase
+    ///
+    /// ## Arguments
+    ///
+    /// * `magnitude` - The distance from zero
+    /// * `phase` - The counter-clockwise angle from the positive real axis. A plain `f64` is
+    ///   taken to be in radians
+    ///
+    /// ## Returns
+    ///
+    /// The complex number `magnitude * e^(i * phase)`
+    pub fn from_polar(magnitude: f64, phase: impl Into<Radians>) -> Complex {
+        let (sin, cos) = phase.into().sin_cos();
+        Complex::new(magnitude * cos, magnitude * sin)
+    }
+
+    /// Calculates the complex conjugate
+    ///
+    /// ## Returns
+    ///
+    /// The number with its imaginary part negated
+    pub fn conjugate(&self) -> Complex {
+        Complex::new(self.re, -self.im)
+    }
+
+    /// Calculates the squared magnitude, which avoids a square root
+    pub fn magnitude_squared(&self) -> f64 {
+        self.re * self.re + self.im * self.im
+    }
+
+    /// Calculates the magnitude, the distance from zero
+    pub fn magnitude(&self) -> f64 {
+        self.magnitude_squared().sqrt()
+    }
+
+    /// Calculates the phase, the angle from the positive real axis
+    ///
+    /// ## Returns
+    ///
+    /// The counter-clockwise angle, in the range `[-PI, PI]`
+    pub fn phase(&self) -> Radians {
+        Radians(self.im.atan2(self.re))
+    }
+}
+
+impl From<f64> for Complex {
+    fn from(re: f64) -> Self {
+        Complex::new(re, 0.)
+    }
+}
+
+impl Add for Complex {
+    type Output = Complex;
+
+    fn add(self, other: Complex) -> Complex {
+        Complex::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl Sub for Complex {
+    type Output = Complex;
+
+    fn sub(self, other: Complex) -> Complex {
+        Complex::new(self.re - other.re, self.im - other.im)
+    }
+}
+
+impl Mul for Complex {
+    type Output = Complex;
+
+    fn mul(self, other: Complex) -> Complex {
+        Complex::new(
+            self.re * other.re - self.im * other.im,
+            self.re * other.im + self.im * other.re,
+        )
+    }
+}
+
+impl Mul<f64> for Complex {
+    type Output = Complex;
+
+    fn mul(self, scalar: f64) -> Complex {
+        Complex::new(self.re * scalar, self.im * scalar)
+    }
+}
+
+impl Neg for Complex {
+    type Output = Complex;
+
+    fn neg(self) -> Complex {
+        Complex::new(-self.re, -self.im)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::consts::FRAC_PI_2;
+
+    #[test]
+    fn test_polar() {
+        let z = Complex::new(3., -4.);
+        assert_eq!(z.magnitude(), 5.);
+        assert_eq!(z * z.conjugate(), Complex::from(25.));
+        let back = Complex::from_polar(z.magnitude(), z.phase());
+        assert!((back - z).magnitude() < 1e-12);
+        let i = Complex::from_polar(2., FRAC_PI_2);
+        assert!((i - Complex::new(0., 2.)).magnitude() < 1e-12);
+    }
+}
@@ -0,0 +1,258 @@
+use crate::Vector3;
+
+/// A direction along one of the coordinate axes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    PosX,
+    NegX,
+    PosY,
+    NegY,
+    PosZ,
+    NegZ,
+}
+
+impl Axis {
+    /// Gets the unit vector pointing in this direction
+    pub fn to_vector(self) -> Vector3 {
+        match self {
+            Axis::PosX => Vector3::new(1., 0., 0.),
+            Axis::NegX => Vector3::new(-1., 0., 0.),
+            Axis::PosY => Vector3::new(0., 1., 0.),
+            Axis::NegY => Vector3::new(0., -1., 0.),
+            Axis::PosZ => Vector3::new(0., 0., 1.),
+            Axis::NegZ => Vector3::new(0., 0., -1.),
+        }
+    }
+
+    /// The length of a vector along this direction
+    fn component(self, v: Vector3) -> f64 {
+        match self {
+            Axis::PosX => v.x,
+            Axis::NegX => -v.x,
+            Axis::PosY => v.y,
+            Axis::NegY => -v.y,
+            Axis::PosZ => v.z,
+            Axis::NegZ => -v.z,
+        }
+    }
+}
+
+/// Whether a coordinate system follows the left or the right hand rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Handedness {
+    Left,
+    Right,
+}
+
+/// The meaning of the X, Y and Z axes in a tool, engine or file format
+///
+/// A system is described by which axis points right, which points up, and which points forward,
+/// the direction a camera looks in. Converting between two systems re-expresses a vector so it
+/// keeps the same meaning, which replaces the usual ad-hoc sign flips and swaps in asset import
+/// code. Only directions change, so differences in units, such as Unreal's centimetres, still
+/// have to be scaled separately.
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{CoordinateSystem, Handedness, Vector3};
+///
+/// let up_in_blender = Vector3::new(0., 0., 1.);
+/// let up_in_unity = CoordinateSystem::BLENDER.convert(up_in_blender, &CoordinateSystem::UNITY);
+///
+/// assert_eq!(up_in_unity, Vector3::new(0., 1., 0.));
+/// assert_eq!(CoordinateSystem::UNITY.handedness(), Handedness::Left);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CoordinateSystem {
+    pub right: Axis,
+    pub up: Axis,
+    pub forward: Axis,
+}
+
+impl CoordinateSystem {
+    /// OpenGL and most right-handed Y-up tools such as Maya: X right, Y up, looking down -Z
+    pub const OPENGL: CoordinateSystem = CoordinateSystem {
+        right: Axis::PosX,
+        up: Axis::PosY,
+        forward: Axis::NegZ,
+    };
+
+    /// Unity and Direct3D: X right, Y up, looking down +Z
+    pub const UNITY: CoordinateSystem = CoordinateSystem {
+        right: Axis::PosX,
+        up: Axis::PosY,
+        forward: Axis::PosZ,
+    };
+
+    /// Unreal Engine: Y right, Z up, looking down +X
+    pub const UNREAL: CoordinateSystem = CoordinateSystem {
+        right: Axis::PosY,
+        up: Axis::PosZ,
+        forward: Axis::PosX,
+    };
+
+    /// Blender: X right, Z up, looking down +Y as in its front view
+    pub const BLENDER: CoordinateSystem = CoordinateSystem {
+        right: Axis::PosX,
+        up: Axis::PosZ,
+        forward: Axis::PosY,
+    };
+
+    /// The right-handed Z-up convention used by most CAD packages: X right, Y into the screen,
+    /// Z up
+    pub const Z_UP_CAD: CoordinateSystem = CoordinateSystem {
+        right: Axis::PosX,
+        up: Axis::PosZ,
+        forward: Axis::PosY,
+    };
+
+    /// Creates a coordinate system from the directions of right, up and forward
+    ///
+    /// ## Arguments
+    ///
+    /// * `right` - The axis pointing right
+    /// * `up` - The axis pointing up
+    /// * `forward` - The axis a camera looks along
+    ///
+    /// ## Returns
+    ///
+    /// The coordinate system, or `None` if two of the directions lie on the same axis
+    pub fn new(right: Axis, up: Axis, forward: Axis) -> Option<CoordinateSystem> {
+        // The cross product of two perpendicular axes lies along the third one
+        let right_cross_up = right.to_vector().cross(&up.to_vector());
+        let f = forward.to_vector();
+        if right_cross_up != f && right_cross_up != f * -1. {
+            return None;
+        }
+        Some(CoordinateSystem { right, up, forward })
+    }
+
+    /// Gets whether the system is left or right handed
+    ///
+    /// In a right-handed system right × up points backwards, towards the camera.
+    pub fn handedness(&self) -> Handedness {
+        let right_cross_up = self.right.to_vector().cross(&self.up.to_vector());
+        if right_cross_up == self.forward.to_vector() {
+            Handedness::Left
+        } else {
+            Handedness::Right
+        }
+    }
+
+    /// Converts a vector from this system into another
+    ///
+    /// This works for positions, directions and normals alike, as the conversion is a rotation
+    /// or a reflection.
+    ///
+    /// ## Arguments
+    ///
+    /// * `v` - The vector in this coordinate system
+    /// * `to` - The coordinate system to convert into
+    ///
+    /// ## Returns
+    ///
+    /// The same point or direction expressed in `to`
+    pub fn convert(&self, v: Vector3, to: &CoordinateSystem) -> Vector3 {
+        to.right.to_vector() * self.right.component(v)
+            + to.up.to_vector() * self.up.component(v)
+            + to.forward.to_vector() * self.forward.component(v)
+    }
+
+    /// Converts a list of vectors from this system into another in place
+    ///
+    /// ## Arguments
+    ///
+    /// * `vectors` - The vectors to convert
+    /// * `to` - The coordinate system to convert into
+    pub fn convert_slice(&self, vectors: &mut [Vector3], to: &CoordinateSystem) {
+        for v in vectors {
+            *v = self.convert(*v, to);
+        }
+    }
+
+    /// Checks whether converting into another system mirrors geometry
+    ///
+    /// Mirroring reverses the winding order of triangles, so index buffers have to swap two
+    /// indices of every triangle to keep faces pointing outwards.
+    ///
+    /// ## Arguments
+    ///
+    /// * `to` - The coordinate system being converted into
+    ///
+    /// ## Returns
+    ///
+    /// `true` if the two systems have different handedness
+    pub fn flips_winding(&self, to: &CoordinateSystem) -> bool {
+        self.handedness() != to.handedness()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRESETS: [CoordinateSystem; 5] = [
+        CoordinateSystem::OPENGL,
+        CoordinateSystem::UNITY,
+        CoordinateSystem::UNREAL,
+        CoordinateSystem::BLENDER,
+        CoordinateSystem::Z_UP_CAD,
+    ];
+
+    #[test]
+    fn test_handedness() {
+        assert_eq!(CoordinateSystem::OPENGL.handedness(), Handedness::Right);
+        assert_eq!(CoordinateSystem::UNITY.handedness(), Handedness::Left);
+        assert_eq!(CoordinateSystem::UNREAL.handedness(), Handedness::Left);
+        assert_eq!(CoordinateSystem::BLENDER.handedness(), Handedness::Right);
+        assert!(CoordinateSystem::OPENGL.flips_winding(&CoordinateSystem::UNITY));
+        assert!(!CoordinateSystem::BLENDER.flips_winding(&CoordinateSystem::OPENGL));
+    }
+
+    #[test]
+    fn test_convert_keeps_meaning() {
+        for from in PRESETS {
+            for to in PRESETS {
+                let forward = from.convert(from.forward.to_vector(), &to);
+                assert_eq!(forward, to.forward.to_vector());
+                let up = from.convert(from.up.to_vector(), &to);
+                assert_eq!(up, to.up.to_vector());
+            }
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let v = Vector3::new(1., 2., 3.);
+        for from in PRESETS {
+            for to in PRESETS {
+                assert_eq!(to.convert(from.convert(v, &to), &from), v);
+            }
+        }
+    }
+
+    #[test]
+    fn test_blender_to_opengl() {
+        // Blender's Z-up, Y-forward becomes OpenGL's Y-up, -Z-forward
+        let mut points = [Vector3::new(1., 2., 3.)];
+        CoordinateSystem::BLENDER.convert_slice(&mut points, &CoordinateSystem::OPENGL);
+        assert_eq!(points, [Vector3::new(1., 3., -2.)]);
+    }
+
+    #[test]
+    fn test_new() {
+        assert_eq!(
+            CoordinateSystem::new(Axis::PosX, Axis::PosY, Axis::NegZ),
+            Some(CoordinateSystem::OPENGL)
+        );
+        assert_eq!(
+            CoordinateSystem::new(Axis::PosX, Axis::NegX, Axis::PosZ),
+            None
+        );
+        assert_eq!(
+            CoordinateSystem::new(Axis::PosX, Axis::PosY, Axis::PosY),
+            None
+        );
+    }
+}
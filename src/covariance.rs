@@ -0,0 +1,399 @@
+use crate::average::running_average;
+#[cfg(not(feature = "std"))]
+use crate::math::FloatMath;
+use crate::{Vector, Vector2, Vector3, VectorSpace};
+
+/// The largest number of Jacobi sweeps used by [`symmetric_eigen3`]. Each sweep roughly squares
+/// the size of the off-diagonal entries, so well conditioned matrices need fewer than ten
+const MAX_JACOBI_SWEEPS: usize = 50;
+
+/// The directions along which a 2D point set varies the most and the least
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PrincipalAxes2 {
+    /// The mean of the points
+    pub mean: Vector2,
+    /// Unit axes, ordered from the largest to the smallest variance
+    pub axes: [Vector2; 2],
+    /// The variance of the points along each axis
+    pub variances: [f64; 2],
+}
+
+/// The directions along which a 3D point set varies the most and the least
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PrincipalAxes3 {
+    /// The mean of the points
+    pub mean: Vector3,
+    /// Unit axes, ordered from the largest to the smallest variance. They form a right-handed
+    /// basis, so they can be used directly as the rotation of an oriented bounding box
+    pub axes: [Vector3; 3],
+    /// The variance of the points along each axis
+    pub variances: [f64; 3],
+}
+
+/// Calculates the centroid, or mean, of a set of points
+///
+/// This is the same mean as [`Vector3::average`](crate::Vector3::average), updated one point at
+/// a time so that large coordinates do not overflow.
+///
+/// ## Arguments
+///
+/// * `points` - The points to average
+///
+/// ## Returns
+///
+/// The centroid, or `None` if there are no points
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{centroid, Vector2};
+///
+/// let triangle = [Vector2::new(0., 0.), Vector2::new(3., 0.), Vector2::new(0., 3.)];
+///
+/// assert_eq!(centroid(&triangle), Some(Vector2::new(1., 1.)));
+/// ```
+pub fn centroid<V>(points: &[V]) -> Option<V>
+where
+    V: VectorSpace,
+{
+    running_average(points.iter().copied())
+}
+
+/// Calculates the weighted average of a set of points, such as the center of mass of particles
+///
+/// ## Arguments
+///
+/// * `points` - Each point with its weight
+///
+/// ## Returns
+///
+/// The weighted average, or `None` if there are no points or the weights sum to zero
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{weighted_average, Vector3};
+///
+/// let masses = [(Vector3::new(0., 0., 0.), 3.), (Vector3::new(4., 0., 0.), 1.)];
+///
+/// assert_eq!(weighted_average(&masses), Some(Vector3::new(1., 0., 0.)));
+/// ```
+pub fn weighted_average<V>(points: &[(V, f64)]) -> Option<V>
+where
+    V: VectorSpace,
+{
+    let ((first, first_weight), rest) = points.split_first()?;
+    let (sum, total_weight) = rest.iter().fold(
+        (*first * *first_weight, *first_weight),
+        |(sum, total_weight), (point, weight)| (sum + *point * *weight, total_weight + weight),
+    );
+    if total_weight == 0. {
+        return None;
+    }
+    Some(sum / total_weight)
+}
+
+/// Calculates the mean of a set of 2D points
+///
+/// ## Arguments
+///
+/// * `points` - The points to average
+///
+/// ## Returns
+///
+/// The mean point, or `None` if there are no points
+pub fn mean2(points: &[Vector2]) -> Option<Vector2> {
+    centroid(points)
+}
+
+/// Calculates the mean of a set of 3D points
+///
+/// ## Arguments
+///
+/// * `points` - The points to average
+///
+/// ## Returns
+///
+/// The mean point, or `None` if there are no points
+pub fn mean3(points: &[Vector3]) -> Option<Vector3> {
+    centroid(points)
+}
+
+/// Calculates the covariance matrix of a set of 2D points
+///
+/// This is the population covariance, dividing by the number of points.
+///
+/// ## Arguments
+///
+/// * `points` - The points to measure
+///
+/// ## Returns
+///
+/// The symmetric covariance matrix indexed by `[row][column]`, or `None` if there are no points
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{covariance2, Vector2};
+///
+/// let points = [Vector2::new(-1., 0.), Vector2::new(1., 0.)];
+///
+/// assert_eq!(covariance2(&points), Some([[1., 0.], [0., 0.]]));
+/// ```
+pub fn covariance2(points: &[Vector2]) -> Option<[[f64; 2]; 2]> {
+    let mean = mean2(points)?;
+    let mut matrix = [[0.; 2]; 2];
+    for point in points {
+        let d = [point.x - mean.x, point.y - mean.y];
+        for (row, a) in matrix.iter_mut().zip(d) {
+            for (entry, b) in row.iter_mut().zip(d) {
+                *entry += a * b;
+            }
+        }
+    }
+    let n = points.len() as f64;
+    Some(matrix.map(|row| row.map(|entry| entry / n)))
+}
+
+/// Calculates the covariance matrix of a set of 3D points
+///
+/// This is the population covariance, dividing by the number of points.
+///
+/// ## Arguments
+///
+/// * `points` - The points to measure
+///
+/// ## Returns
+///
+/// The symmetric covariance matrix indexed by `[row][column]`, or `None` if there are no points
+pub fn covariance3(points: &[Vector3]) -> Option<[[f64; 3]; 3]> {
+    let mean = mean3(points)?;
+    let mut matrix = [[0.; 3]; 3];
+    for point in points {
+        let d = [point.x - mean.x, point.y - mean.y, point.z - mean.z];
+        for (row, a) in matrix.iter_mut().zip(d) {
+            for (entry, b) in row.iter_mut().zip(d) {
+                *entry += a * b;
+            }
+        }
+    }
+    let n = points.len() as f64;
+    Some(matrix.map(|row| row.map(|entry| entry / n)))
+}
+
+/// Calculates the eigenvalues and eigenvectors of a symmetric 2x2 matrix
+///
+/// ## Arguments
+///
+/// * `matrix` - The matrix, indexed by `[row][column]`. Only the upper triangle is read
+///
+/// ## Returns
+///
+/// The eigenvalues from largest to smallest and their unit eigenvectors
+pub fn symmetric_eigen2(matrix: [[f64; 2]; 2]) -> ([f64; 2], [Vector2; 2]) {
+    let (a, b, d) = (matrix[0][0], matrix[0][1], matrix[1][1]);
+    let half_sum = (a + d) / 2.;
+    let half_difference = (a - d) / 2.;
+    let radius = (half_difference * half_difference + b * b).sqrt();
+    let largest = half_sum + radius;
+    let axis = if b != 0. {
+        Vector2::new(largest - d, b).normalize()
+    } else if a >= d {
+        Vector2::new(1., 0.)
+    } else {
+        Vector2::new(0., 1.)
+    };
+    (
+        [largest, half_sum - radius],
+        [axis, Vector2::new(-axis.y, axis.x)],
+    )
+}
+
+/// Calculates the eigenvalues and eigenvectors of a symmetric 3x3 matrix
+///
+/// This uses the cyclic Jacobi method, which is accurate for the small, well behaved matrices
+/// produced by [`covariance3`].
+///
+/// ## Arguments
+///
+/// * `matrix` - The matrix, indexed by `[row][column]`. It must be symmetric
+///
+/// ## Returns
+///
+/// The eigenvalues from largest to smallest and their unit eigenvectors, which form a
+/// right-handed basis
+pub fn symmetric_eigen3(matrix: [[f64; 3]; 3]) -> ([f64; 3], [Vector3; 3]) {
+    let mut a = matrix;
+    // The columns of v converge to the eigenvectors
+    let mut v = [[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]];
+    let scale: f64 = a.iter().flatten().map(|entry| entry * entry).sum();
+    for _ in 0..MAX_JACOBI_SWEEPS {
+        let off_diagonal = a[0][1] * a[0][1] + a[0][2] * a[0][2] + a[1][2] * a[1][2];
+        if off_diagonal <= scale * 1e-30 {
+            break;
+        }
+        for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+            if a[p][q] == 0. {
+                continue;
+            }
+            // Choose the rotation that zeroes a[p][q]
+            let theta = (a[q][q] - a[p][p]) / (2. * a[p][q]);
+            let t = theta.signum() / (theta.abs() + (theta * theta + 1.).sqrt());
+            let c = 1. / (t * t + 1.).sqrt();
+            let s = t * c;
+            for row in a.iter_mut() {
+                let (akp, akq) = (row[p], row[q]);
+                row[p] = c * akp - s * akq;
+                row[q] = s * akp + c * akq;
+            }
+            let (row_p, row_q) = (a[p], a[q]);
+            a[p] = core::array::from_fn(|k| c * row_p[k] - s * row_q[k]);
+            a[q] = core::array::from_fn(|k| s * row_p[k] + c * row_q[k]);
+            for row in v.iter_mut() {
+                let (vp, vq) = (row[p], row[q]);
+                row[p] = c * vp - s * vq;
+                row[q] = s * vp + c * vq;
+            }
+        }
+    }
+
+    let mut order = [0, 1, 2];
+    order.sort_unstable_by(|&i, &j| a[j][j].total_cmp(&a[i][i]));
+    let column = |i: usize| Vector3::new(v[0][i], v[1][i], v[2][i]);
+    let (first, second) = (column(order[0]), column(order[1]));
+    (
+        order.map(|i| a[i][i]),
+        [first, second, first.cross(&second)],
+    )
+}
+
+/// Finds the principal axes of a set of 2D points
+///
+/// ## Arguments
+///
+/// * `points` - The points to analyse
+///
+/// ## Returns
+///
+/// The mean, axes and variances of the points, or `None` if there are no points
+pub fn principal_axes2(points: &[Vector2]) -> Option<PrincipalAxes2> {
+    let mean = mean2(points)?;
+    let (variances, axes) = symmetric_eigen2(covariance2(points)?);
+    Some(PrincipalAxes2 {
+        mean,
+        axes,
+        variances,
+    })
+}
+
+/// Finds the principal axes of a set of 3D points
+///
+/// The last axis has the least variance, so for points sampled from a surface it is an estimate
+/// of the surface normal, up to sign.
+///
+/// ## Arguments
+///
+/// * `points` - The points to analyse
+///
+/// ## Returns
+///
+/// The mean, axes and variances of the points, or `None` if there are no points
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{principal_axes3, Vector3};
+///
+/// // Points on the plane z = 1
+/// let points = [
+///     Vector3::new(0., 0., 1.),
+///     Vector3::new(2., 0., 1.),
+///     Vector3::new(0., 1., 1.),
+///     Vector3::new(2., 1., 1.),
+/// ];
+/// let axes = principal_axes3(&points).unwrap();
+///
+/// assert_eq!(axes.mean, Vector3::new(1., 0.5, 1.));
+/// assert_eq!(axes.axes[2].z.abs(), 1.);
+/// assert_eq!(axes.variances, [1., 0.25, 0.]);
+/// ```
+pub fn principal_axes3(points: &[Vector3]) -> Option<PrincipalAxes3> {
+    let mean = mean3(points)?;
+    let (variances, axes) = symmetric_eigen3(covariance3(points)?);
+    Some(PrincipalAxes3 {
+        mean,
+        axes,
+        variances,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mean_and_covariance() {
+        assert_eq!(mean2(&[]), None);
+        assert_eq!(covariance3(&[]), None);
+        let points = [
+            Vector3::new(1., 2., 3.),
+            Vector3::new(3., 2., 1.),
+            Vector3::new(2., 5., 2.),
+        ];
+        assert_eq!(mean3(&points), Some(Vector3::new(2., 3., 2.)));
+        let expected = [
+            [2. / 3., 0., -2. / 3.],
+            [0., 2., 0.],
+            [-2. / 3., 0., 2. / 3.],
+        ];
+        assert_eq!(covariance3(&points), Some(expected));
+    }
+
+    #[test]
+    fn test_weighted_average() {
+        assert_eq!(centroid::<Vector2>(&[]), None);
+        let huge = Vector2::new(f64::MAX, -f64::MAX);
+        assert_eq!(centroid(&[huge, huge]), Some(huge));
+        assert_eq!(weighted_average::<Vector2>(&[]), None);
+        let points = [(Vector2::new(1., 2.), 1.), (Vector2::new(3., 4.), -1.)];
+        assert_eq!(weighted_average(&points), None);
+        let points = [(Vector2::new(1., 2.), 2.), (Vector2::new(4., 8.), 1.)];
+        assert_eq!(weighted_average(&points), Some(Vector2::new(2., 4.)));
+    }
+
+    #[test]
+    fn test_principal_axes2() {
+        // A line along (1, 1)
+        let points: [Vector2; 3] = [-1., 0., 2.].map(|t| Vector2::new(t + 1., t));
+        let axes = principal_axes2(&points).unwrap();
+        let diagonal = Vector2::new(1., 1.).normalize();
+        assert!((axes.axes[0].dot(&diagonal).abs() - 1.).abs() < 1e-12);
+        assert!(axes.variances[1].abs() < 1e-12);
+        assert!(axes.variances[0] > 0.);
+    }
+
+    #[test]
+    fn test_symmetric_eigen3() {
+        let matrix = [[4., 1., -2.], [1., 2., 0.], [-2., 0., 3.]];
+        let (values, vectors) = symmetric_eigen3(matrix);
+        assert!(values[0] >= values[1] && values[1] >= values[2]);
+        for (value, vector) in values.iter().zip(vectors) {
+            // M v = lambda v
+            let rows = matrix.map(|row| Vector3::new(row[0], row[1], row[2]).dot(&vector));
+            let mv = Vector3::new(rows[0], rows[1], rows[2]);
+            assert!((mv - vector * *value).magnitude() < 1e-10);
+            assert!((vector.magnitude() - 1.).abs() < 1e-12);
+        }
+        assert!((vectors[0].cross(&vectors[1]) - vectors[2]).magnitude() < 1e-12);
+    }
+
+    #[test]
+    fn test_diagonal_matrix() {
+        let (values, vectors) = symmetric_eigen3([[1., 0., 0.], [0., 3., 0.], [0., 0., 2.]]);
+        assert_eq!(values, [3., 2., 1.]);
+        assert_eq!(vectors[0], Vector3::new(0., 1., 0.));
+        assert_eq!(vectors[1], Vector3::new(0., 0., 1.));
+        let (values, _) = symmetric_eigen2([[0., 0.], [0., 5.]]);
+        assert_eq!(values, [5., 0.]);
+    }
+}
@@ -0,0 +1,303 @@
+use crate::{DynamicVector, Vector2, Vector3};
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Write};
+
+/// An error produced while parsing CSV data
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CsvError {
+    /// A field could not be parsed as a number
+    InvalidNumber { line: usize, field: String },
+    /// A row had a different number of fields than the vector type has components
+    WrongFieldCount {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CsvError::InvalidNumber { line, field } => {
+                write!(f, "line {}: invalid number {:?}", line, field)
+            }
+            CsvError::WrongFieldCount {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {}: expected {} fields, found {}",
+                line, expected, found
+            ),
+        }
+    }
+}
+
+impl Error for CsvError {}
+
+/// Parses every field of a row, reporting errors against the given line number
+fn parse_row(row: &str, delimiter: char, line: usize) -> Result<Vec<f64>, CsvError> {
+    row.split(delimiter)
+        .map(|field| {
+            let field = field.trim();
+            field.parse().map_err(|_| CsvError::InvalidNumber {
+                line,
+                field: field.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Parses the non-empty lines of a CSV file into rows of exactly `width` numbers
+fn parse_rows<'a, I>(lines: I, delimiter: char, width: usize) -> Result<Vec<Vec<f64>>, CsvError>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    lines
+        .into_iter()
+        .enumerate()
+        .filter(|(_, row)| !row.trim().is_empty())
+        .map(|(i, row)| {
+            let values = parse_row(row, delimiter, i + 1)?;
+            if values.len() != width {
+                return Err(CsvError::WrongFieldCount {
+                    line: i + 1,
+                    expected: width,
+                    found: values.len(),
+                });
+            }
+            Ok(values)
+        })
+        .collect()
+}
+
+impl DynamicVector {
+    /// Parses a comma separated row of numbers
+    ///
+    /// Whitespace around each field is ignored.
+    ///
+    /// ## Arguments
+    ///
+    /// * `row` - A single line of CSV data
+    ///
+    /// ## Returns
+    ///
+    /// A vector with one element per field, or an error if a field is not a number
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::DynamicVector;
+    ///
+    /// let v = DynamicVector::from_csv_row("1.5, 2, -3").unwrap();
+    ///
+    /// assert_eq!(v.get(2), -3.);
+    /// ```
+    pub fn from_csv_row(row: &str) -> Result<DynamicVector, CsvError> {
+        DynamicVector::from_csv_row_with(row, ',')
+    }
+
+    /// Parses a row of numbers separated by a given delimiter
+    ///
+    /// ## Arguments
+    ///
+    /// * `row` - A single line of CSV data
+    /// * `delimiter` - The character between fields, such as `';'` or `'\t'`
+    ///
+    /// ## Returns
+    ///
+    /// A vector with one element per field, or an error if a field is not a number
+    pub fn from_csv_row_with(row: &str, delimiter: char) -> Result<DynamicVector, CsvError> {
+        Ok(DynamicVector {
+            data: parse_row(row, delimiter, 1)?.into(),
+        })
+    }
+
+    /// Formats the vector as a row of numbers separated by a given delimiter
+    ///
+    /// Numbers are written in their shortest form that parses back to the same value.
+    ///
+    /// ## Arguments
+    ///
+    /// * `delimiter` - The character between fields
+    ///
+    /// ## Returns
+    ///
+    /// The row, without a trailing newline
+    pub fn to_csv_row(&self, delimiter: char) -> String {
+        join(&self.data, delimiter)
+    }
+}
+
+fn join(values: &[f64], delimiter: char) -> String {
+    values
+        .iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>()
+        .join(&delimiter.to_string())
+}
+
+/// Parses lines of CSV data with two columns into a list of [`Vector2`]s
+///
+/// Empty lines are skipped. Header rows should be skipped before calling this, for example with
+/// `text.lines().skip(1)`.
+///
+/// ## Arguments
+///
+/// * `lines` - The lines of CSV data
+/// * `delimiter` - The character between fields
+///
+/// ## Returns
+///
+/// One vector per non-empty line, or an error naming the first invalid line
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{read_csv_vector2, Vector2};
+///
+/// let text = "x;y\n1;2\n3;4\n";
+/// let points = read_csv_vector2(text.lines().skip(1), ';').unwrap();
+///
+/// assert_eq!(points, vec![Vector2::new(1., 2.), Vector2::new(3., 4.)]);
+/// ```
+pub fn read_csv_vector2<'a, I>(lines: I, delimiter: char) -> Result<Vec<Vector2>, CsvError>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    Ok(parse_rows(lines, delimiter, 2)?
+        .iter()
+        .map(|row| Vector2::new(row[0], row[1]))
+        .collect())
+}
+
+/// Parses lines of CSV data with three columns into a list of [`Vector3`]s
+///
+/// Empty lines are skipped. Header rows should be skipped before calling this, for example with
+/// `text.lines().skip(1)`.
+///
+/// ## Arguments
+///
+/// * `lines` - The lines of CSV data
+/// * `delimiter` - The character between fields
+///
+/// ## Returns
+///
+/// One vector per non-empty line, or an error naming the first invalid line
+pub fn read_csv_vector3<'a, I>(lines: I, delimiter: char) -> Result<Vec<Vector3>, CsvError>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    Ok(parse_rows(lines, delimiter, 3)?
+        .iter()
+        .map(|row| Vector3::new(row[0], row[1], row[2]))
+        .collect())
+}
+
+/// Writes a list of [`Vector2`]s as CSV data with two columns
+///
+/// ## Arguments
+///
+/// * `points` - The vectors to write, one per line
+/// * `delimiter` - The character between fields
+/// * `writer` - The destination to write the lines to
+///
+/// ## Returns
+///
+/// An error if the writer fails
+pub fn write_csv_vector2<W: Write + ?Sized>(
+    points: &[Vector2],
+    delimiter: char,
+    writer: &mut W,
+) -> io::Result<()> {
+    for p in points {
+        writeln!(writer, "{}", join(&[p.x, p.y], delimiter))?;
+    }
+    Ok(())
+}
+
+/// Writes a list of [`Vector3`]s as CSV data with three columns
+///
+/// ## Arguments
+///
+/// * `points` - The vectors to write, one per line
+/// * `delimiter` - The character between fields
+/// * `writer` - The destination to write the lines to
+///
+/// ## Returns
+///
+/// An error if the writer fails
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{write_csv_vector3, Vector3};
+///
+/// let mut out = Vec::new();
+/// write_csv_vector3(&[Vector3::new(1., 0.5, -2.)], ',', &mut out).unwrap();
+///
+/// assert_eq!(String::from_utf8(out).unwrap(), "1,0.5,-2\n");
+/// ```
+pub fn write_csv_vector3<W: Write + ?Sized>(
+    points: &[Vector3],
+    delimiter: char,
+    writer: &mut W,
+) -> io::Result<()> {
+    for p in points {
+        writeln!(writer, "{}", join(&[p.x, p.y, p.z], delimiter))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_csv_row() {
+        let v = DynamicVector::from_csv_row(" 1, 2.5 ,3e2\r").unwrap();
+        assert_eq!(v.data, vec![1., 2.5, 300.]);
+
+        let tabbed = DynamicVector::from_csv_row_with("4\t5", '\t').unwrap();
+        assert_eq!(tabbed.to_csv_row(';'), "4;5");
+
+        assert_eq!(
+            DynamicVector::from_csv_row("1,x"),
+            Err(CsvError::InvalidNumber {
+                line: 1,
+                field: "x".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_read_csv_vector3() {
+        let text = "1,2,3\n\n4,5,6\n";
+        assert_eq!(
+            read_csv_vector3(text.lines(), ',').unwrap(),
+            vec![Vector3::new(1., 2., 3.), Vector3::new(4., 5., 6.)]
+        );
+
+        let error = read_csv_vector3("1,2,3\n4,5".lines(), ',').unwrap_err();
+        assert_eq!(
+            error,
+            CsvError::WrongFieldCount {
+                line: 2,
+                expected: 3,
+                found: 2
+            }
+        );
+        assert_eq!(error.to_string(), "line 2: expected 3 fields, found 2");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let points = vec![Vector2::new(0.1, -1e-300), Vector2::new(f64::MAX, 7.)];
+        let mut out = Vec::new();
+        write_csv_vector2(&points, ' ', &mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert_eq!(read_csv_vector2(text.lines(), ' ').unwrap(), points);
+    }
+}
@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+
+/// Finds the grid cell of a point, for cells `epsilon` wide
+fn cell<const N: usize>(point: &[f64; N], epsilon: f64) -> [i64; N] {
+    point.map(|c| {
+        if epsilon == 0. {
+            // Only identical points merge, so every distinct value gets its own cell. Adding zero
+            // turns -0 into 0, which is the same point
+            (c + 0.).to_bits() as i64
+        } else {
+            (c / epsilon).floor() as i64
+        }
+    })
+}
+
+/// Merges points that lie within a distance of each other
+///
+/// Points are visited in order and each one either joins the first kept point within `epsilon`
+/// of it or is kept itself, so the kept points are at least `epsilon` apart. Nearby points are
+/// found through a spatial hash of cells `epsilon` wide, so this takes `O(n)` time for points
+/// that are not densely clustered. This is the usual way to weld the duplicated vertices of a
+/// mesh or to clean up overlapping scans.
+///
+/// ## Arguments
+///
+/// * `points` - The points to deduplicate
+/// * `epsilon` - The largest distance at which two points merge. Zero merges only identical
+///   points
+///
+/// ## Returns
+///
+/// The kept points, in the order they first appear, and for every input point the index of the
+/// kept point it was merged into
+///
+/// ## Panics
+///
+/// If `epsilon` is negative or `NaN`
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{dedup_points, Vector3};
+///
+/// let points = [
+///     Vector3::new(0., 0., 0.),
+///     Vector3::new(1., 0., 0.),
+///     Vector3::new(0., 0., 1e-9),
+///     Vector3::new(1., 1e-9, 0.),
+/// ];
+/// let (unique, remap) = dedup_points(&points, 1e-6);
+///
+/// assert_eq!(unique, vec![points[0], points[1]]);
+/// assert_eq!(remap, vec![0, 1, 0, 1]);
+/// ```
+pub fn dedup_points<V, const N: usize>(points: &[V], epsilon: f64) -> (Vec<V>, Vec<usize>)
+where
+    V: Copy + Into<[f64; N]>,
+{
+    assert!(epsilon >= 0., "epsilon must not be negative");
+    let mut grid: HashMap<[i64; N], Vec<usize>> = HashMap::new();
+    let mut unique: Vec<V> = Vec::new();
+    let mut remap = Vec::with_capacity(points.len());
+    // Points within epsilon can be at most one cell apart along each axis
+    let neighbours = if epsilon == 0. {
+        1
+    } else {
+        3_usize.pow(N as u32)
+    };
+
+    for &point in points {
+        let coordinates: [f64; N] = point.into();
+        let home = cell(&coordinates, epsilon);
+        let within = |&&kept: &&usize| {
+            let other: [f64; N] = unique[kept].into();
+            let distance_squared: f64 = coordinates
+                .iter()
+                .zip(&other)
+                .map(|(a, b)| (a - b) * (a - b))
+                .sum();
+            distance_squared <= epsilon * epsilon
+        };
+        let found = (0..neighbours).find_map(|offset| {
+            let mut key = home;
+            let mut digits = offset;
+            if neighbours > 1 {
+                for k in key.iter_mut() {
+                    *k = k.saturating_add((digits % 3) as i64 - 1);
+                    digits /= 3;
+                }
+            }
+            grid.get(&key)?.iter().find(within).copied()
+        });
+
+        match found {
+            Some(kept) => remap.push(kept),
+            None => {
+                remap.push(unique.len());
+                grid.entry(home).or_default().push(unique.len());
+                unique.push(point);
+            }
+        }
+    }
+    (unique, remap)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Vector2;
+
+    #[test]
+    fn test_dedup_across_cells() {
+        // Close points on either side of a cell boundary still merge
+        let points = [
+            Vector2::new(0.999, 0.),
+            Vector2::new(1.001, 0.),
+            Vector2::new(2.5, 0.),
+            Vector2::new(-0.5, -0.5),
+        ];
+        let (unique, remap) = dedup_points(&points, 1.);
+        assert_eq!(unique, vec![points[0], points[2], points[3]]);
+        assert_eq!(remap, vec![0, 0, 1, 2]);
+    }
+
+    #[test]
+    fn test_dedup_exact() {
+        let points = [
+            Vector2::new(1., 2.),
+            Vector2::new(-0., 0.),
+            Vector2::new(1., 2.),
+            Vector2::new(0., 0.),
+            Vector2::new(1., 2. + 1e-12),
+        ];
+        let (unique, remap) = dedup_points(&points, 0.);
+        assert_eq!(unique.len(), 3);
+        assert_eq!(remap, vec![0, 1, 0, 1, 2]);
+        assert_eq!(dedup_points::<Vector2, 2>(&[], 0.5), (vec![], vec![]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_dedup_negative_epsilon() {
+        dedup_points(&[Vector2::new(0., 0.)], -1.);
+    }
+}
@@ -0,0 +1,175 @@
+use crate::{DynamicMatrix, DynamicVector};
+
+/// Estimates the gradient of a scalar function with central differences
+///
+/// Each partial derivative is `(f(x + h eᵢ) - f(x - h eᵢ)) / 2h`, which is second order accurate
+/// and takes two evaluations of `f` per component. This is mostly useful for checking analytic
+/// gradients in tests. A step of around `1e-6` times the scale of `x` balances the truncation
+/// error against rounding.
+///
+/// ## Arguments
+///
+/// * `f` - The function to differentiate
+/// * `x` - The point to differentiate at
+/// * `h` - The step size
+///
+/// ## Returns
+///
+/// The estimated gradient, with one element per component of `x`
+///
+/// ## Panics
+///
+/// If `h` is not positive
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{numerical_gradient, DynamicVector, Vector};
+///
+/// let x = DynamicVector::from(vec![1., 2., 3.]);
+/// // The gradient of |x|² is 2x
+/// let gradient = numerical_gradient(|v| v.dot(v), &x, 1e-6);
+///
+/// for (g, x) in gradient.as_slice().iter().zip(x.as_slice()) {
+///     assert!((g - 2. * x).abs() < 1e-8);
+/// }
+/// ```
+pub fn numerical_gradient(
+    f: impl Fn(&DynamicVector) -> f64,
+    x: &DynamicVector,
+    h: f64,
+) -> DynamicVector {
+    assert!(h > 0., "the step size must be positive");
+    let mut probe = x.clone();
+    (0..x.len())
+        .map(|i| {
+            let centre = x.get(i);
+            probe.set(i, centre + h);
+            let forward = f(&probe);
+            probe.set(i, centre - h);
+            let backward = f(&probe);
+            probe.set(i, centre);
+            (forward - backward) / (2. * h)
+        })
+        .collect()
+}
+
+/// Estimates the Jacobian of a vector function with central differences
+///
+/// Column `j` is `(f(x + h eⱼ) - f(x - h eⱼ)) / 2h`, taking two evaluations of `f` per component
+/// of `x`, like [`numerical_gradient`].
+///
+/// ## Arguments
+///
+/// * `f` - The function to differentiate, which must always return vectors of the same length
+/// * `x` - The point to differentiate at
+/// * `h` - The step size
+///
+/// ## Returns
+///
+/// The estimated Jacobian, with one row per output and one column per component of `x`
+///
+/// ## Panics
+///
+/// If `h` is not positive, or if `f` returns vectors of different lengths
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{numerical_jacobian, DynamicVector};
+///
+/// // Polar to Cartesian coordinates
+/// let f = |v: &DynamicVector| {
+///     let (r, theta) = (v.get(0), v.get(1));
+///     DynamicVector::from(vec![r * theta.cos(), r * theta.sin()])
+/// };
+/// let jacobian = numerical_jacobian(f, &DynamicVector::from(vec![2., 0.]), 1e-6);
+///
+/// assert_eq!((jacobian.rows(), jacobian.cols()), (2, 2));
+/// // d(y)/d(theta) is r cos(theta)
+/// assert!((jacobian.get(1, 1) - 2.).abs() < 1e-8);
+/// ```
+pub fn numerical_jacobian(
+    f: impl Fn(&DynamicVector) -> DynamicVector,
+    x: &DynamicVector,
+    h: f64,
+) -> DynamicMatrix {
+    assert!(h > 0., "the step size must be positive");
+    if x.is_empty() {
+        return DynamicMatrix::new(f(x).len(), 0);
+    }
+
+    let mut probe = x.clone();
+    let mut jacobian = DynamicMatrix::new(0, 0);
+    for j in 0..x.len() {
+        let centre = x.get(j);
+        probe.set(j, centre + h);
+        let forward = f(&probe);
+        probe.set(j, centre - h);
+        let backward = f(&probe);
+        probe.set(j, centre);
+
+        if j == 0 {
+            jacobian = DynamicMatrix::new(forward.len(), x.len());
+        }
+        assert!(
+            forward.len() == jacobian.rows() && backward.len() == jacobian.rows(),
+            "the function must return vectors of the same length"
+        );
+        for (i, (a, b)) in forward
+            .as_slice()
+            .iter()
+            .zip(backward.as_slice())
+            .enumerate()
+        {
+            jacobian.set(i, j, (a - b) / (2. * h));
+        }
+    }
+    jacobian
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_vec_approx_eq;
+    use alloc::vec;
+
+    #[test]
+    fn test_gradient() {
+        // f(x, y) = x² y + y³, so the gradient is (2xy, x² + 3y²)
+        let f = |v: &DynamicVector| v.get(0) * v.get(0) * v.get(1) + v.get(1) * v.get(1) * v.get(1);
+        let x = DynamicVector::from(vec![1.5, -2.]);
+        assert_vec_approx_eq!(
+            numerical_gradient(f, &x, 1e-5),
+            [-6., 14.25],
+            epsilon = 1e-7
+        );
+        assert!(numerical_gradient(f, &DynamicVector::new(0), 1e-5).is_empty());
+    }
+
+    #[test]
+    fn test_jacobian() {
+        // A linear map is differentiated exactly, up to rounding
+        let m = DynamicMatrix::from_row_major(3, 2, vec![1., 2., -3., 0.5, 4., -1.]);
+        let jacobian = numerical_jacobian(|v| m.mul_vector(v), &DynamicVector::new(2), 1e-3);
+        assert_vec_approx_eq!(jacobian.as_slice(), m.as_slice());
+
+        // The Jacobian of a gradient is the Hessian
+        let f = |v: &DynamicVector| v.get(0) * v.get(0) * v.get(1);
+        let hessian = numerical_jacobian(
+            |v| numerical_gradient(f, v, 1e-4),
+            &DynamicVector::from(vec![1., 2.]),
+            1e-4,
+        );
+        assert_vec_approx_eq!(hessian.as_slice(), [4., 2., 2., 0.], epsilon = 1e-5);
+
+        let empty = numerical_jacobian(|_| DynamicVector::new(3), &DynamicVector::new(0), 1e-3);
+        assert_eq!((empty.rows(), empty.cols()), (3, 0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_non_positive_step() {
+        numerical_gradient(|v| v.get(0), &DynamicVector::new(1), 0.);
+    }
+}
@@ -0,0 +1,228 @@
+#[cfg(not(feature = "std"))]
+use crate::math::FloatMath;
+use crate::{Complex, DynamicVector};
+use alloc::{vec, vec::Vec};
+
+/// A Vector of complex numbers that can be expanded to any length
+///
+/// This holds frequency domain data, such as the spectrum of a
+/// [`DynamicVector`] produced by an FFT.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DynamicComplexVector {
+    pub(crate) data: Vec<Complex>,
+}
+
+impl DynamicComplexVector {
+    /// Create a new dynamic complex vector
+    ///
+    /// ## Arguments
+    ///
+    /// * `length` - The length of the vector
+    ///
+    /// ## Returns
+    ///
+    /// A new vector of the specified length, filled with zeros
+    pub fn new(length: usize) -> Self {
+        DynamicComplexVector {
+            data: vec![Complex::default(); length],
+        }
+    }
+
+    /// Gets the number of elements in the Vector
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Checks whether the Vector has no elements
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Gets a value from the Vector
+    ///
+    /// ## Arguments
+    ///
+    /// * `index` - The index of the value to get
+    ///
+    /// ## Returns
+    ///
+    /// The value at the specified index
+    pub fn get(&self, index: usize) -> Complex {
+        self.data[index]
+    }
+
+    /// Sets a value in the Vector
+    ///
+    /// ## Arguments
+    ///
+    /// * `index` - The index of the value to set
+    pub fn set(&mut self, index: usize, value: Complex) {
+        self.data[index] = value;
+    }
+
+    /// Gets the elements of the Vector as a slice
+    pub fn as_slice(&self) -> &[Complex] {
+        &self.data
+    }
+
+    /// Gets the elements of the Vector as a mutable slice
+    pub fn as_mut_slice(&mut self) -> &mut [Complex] {
+        &mut self.data
+    }
+
+    /// Gets the real part of every element
+    ///
+    /// ## Returns
+    ///
+    /// A real vector of the same length
+    pub fn real(&self) -> DynamicVector {
+        self.data.iter().map(|z| z.re).collect()
+    }
+
+    /// Gets the imaginary part of every element
+    ///
+    /// ## Returns
+    ///
+    /// A real vector of the same length
+    pub fn imaginary(&self) -> DynamicVector {
+        self.data.iter().map(|z| z.im).collect()
+    }
+
+    /// Calculates the Hermitian inner product of two vectors
+    ///
+    /// The elements of this vector are conjugated, so the result is the sum of
+    /// `conj(self[i]) * other[i]`, like NumPy's `vdot`. The product of a vector with itself is
+    /// then real and equal to its squared magnitude.
+    ///
+    /// ## Arguments
+    ///
+    /// * `other` - The other vector
+    ///
+    /// ## Returns
+    ///
+    /// The inner product of the two vectors
+    ///
+    /// ## Panics
+    ///
+    /// If the vectors have different lengths
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Complex, DynamicComplexVector};
+    ///
+    /// let a = DynamicComplexVector::from(vec![Complex::new(0., 1.), Complex::new(1., 0.)]);
+    /// let b = DynamicComplexVector::from(vec![Complex::new(0., 1.), Complex::new(2., 0.)]);
+    ///
+    /// assert_eq!(a.dot(&b), Complex::new(3., 0.));
+    /// assert_eq!(b.dot(&a), a.dot(&b).conjugate());
+    /// ```
+    pub fn dot(&self, other: &DynamicComplexVector) -> Complex {
+        assert_eq!(
+            self.data.len(),
+            other.data.len(),
+            "vectors must have the same length"
+        );
+        self.data
+            .iter()
+            .zip(&other.data)
+            .fold(Complex::default(), |sum, (a, &b)| sum + a.conjugate() * b)
+    }
+
+    /// Calculates the magnitude of the vector
+    ///
+    /// ## Returns
+    ///
+    /// The square root of the sum of the squared magnitudes of the elements
+    pub fn magnitude(&self) -> f64 {
+        self.data
+            .iter()
+            .map(|z| z.magnitude_squared())
+            .sum::<f64>()
+            .sqrt()
+    }
+
+    /// Conjugates every element
+    ///
+    /// ## Returns
+    ///
+    /// A new vector with every imaginary part negated
+    pub fn conjugate(&self) -> DynamicComplexVector {
+        self.data.iter().map(|z| z.conjugate()).collect()
+    }
+
+    /// Conjugates every element in place
+    pub fn conjugate_mut(&mut self) {
+        for z in self.data.iter_mut() {
+            *z = z.conjugate();
+        }
+    }
+
+    /// Gets the magnitude of every element
+    ///
+    /// For the spectrum of a signal this is the amplitude of each frequency.
+    ///
+    /// ## Returns
+    ///
+    /// A real vector of the same length
+    pub fn magnitudes(&self) -> DynamicVector {
+        self.data.iter().map(|z| z.magnitude()).collect()
+    }
+}
+
+impl From<Vec<Complex>> for DynamicComplexVector {
+    fn from(values: Vec<Complex>) -> Self {
+        DynamicComplexVector { data: values }
+    }
+}
+
+impl From<&DynamicVector> for DynamicComplexVector {
+    /// Converts a real vector, with every imaginary part set to zero
+    fn from(v: &DynamicVector) -> Self {
+        v.as_slice().iter().map(|&re| Complex::from(re)).collect()
+    }
+}
+
+impl FromIterator<Complex> for DynamicComplexVector {
+    fn from_iter<I: IntoIterator<Item = Complex>>(iter: I) -> Self {
+        DynamicComplexVector {
+            data: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vector(values: &[(f64, f64)]) -> DynamicComplexVector {
+        values
+            .iter()
+            .map(|&(re, im)| Complex::new(re, im))
+            .collect()
+    }
+
+    #[test]
+    fn test_hermitian() {
+        let a = vector(&[(3., 4.), (0., -2.)]);
+        assert_eq!(a.magnitude(), 29_f64.sqrt());
+        assert_eq!(a.dot(&a), Complex::new(29., 0.));
+        assert_eq!(a.conjugate(), vector(&[(3., -4.), (0., 2.)]));
+        assert_eq!(a.magnitudes(), [5., 2.]);
+
+        let b = vector(&[(1., 1.), (2., 0.)]);
+        // (3 - 4i)(1 + i) + (2i)(2) = 7 - i + 4i
+        assert_eq!(a.dot(&b), Complex::new(7., 3.));
+
+        let mut c = a.clone();
+        c.conjugate_mut();
+        assert_eq!(c.dot(&b), a.conjugate().dot(&b));
+        assert_eq!(DynamicComplexVector::new(0).magnitude(), 0.);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_dot_length_mismatch() {
+        DynamicComplexVector::new(2).dot(&DynamicComplexVector::new(1));
+    }
+}
@@ -0,0 +1,381 @@
+use crate::DynamicVector;
+use alloc::{vec, vec::Vec};
+
+/// A matrix of `f64` whose size is chosen at runtime
+///
+/// The elements are stored row by row, so each row is a contiguous slice. This is meant for the
+/// small systems that come up next to [`DynamicVector`], such as least-squares fits and
+/// Jacobians, rather than as a replacement for a linear algebra crate.
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{DynamicMatrix, DynamicVector};
+///
+/// let a = DynamicMatrix::from_rows(&[
+///     DynamicVector::from(vec![1., 2.]),
+///     DynamicVector::from(vec![3., 4.]),
+///     DynamicVector::from(vec![5., 6.]),
+/// ]);
+///
+/// assert_eq!((a.rows(), a.cols()), (3, 2));
+/// assert_eq!(a.mul_vector(&DynamicVector::from(vec![1., -1.])), [-1., -1., -1.]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DynamicMatrix {
+    rows: usize,
+    cols: usize,
+    data: Vec<f64>,
+}
+
+impl DynamicMatrix {
+    /// Create a new matrix of zeros
+    ///
+    /// ## Arguments
+    ///
+    /// * `rows` - The number of rows
+    /// * `cols` - The number of columns
+    ///
+    /// ## Returns
+    ///
+    /// A new matrix of the specified size
+    pub fn new(rows: usize, cols: usize) -> Self {
+        DynamicMatrix {
+            rows,
+            cols,
+            data: vec![0.; rows * cols],
+        }
+    }
+
+    /// Create a square matrix with ones on the diagonal and zeros elsewhere
+    ///
+    /// ## Arguments
+    ///
+    /// * `size` - The number of rows and columns
+    ///
+    /// ## Returns
+    ///
+    /// The identity matrix of the specified size
+    pub fn identity(size: usize) -> Self {
+        let mut matrix = DynamicMatrix::new(size, size);
+        for i in 0..size {
+            matrix.set(i, i, 1.);
+        }
+        matrix
+    }
+
+    /// Create a matrix from its elements in row-major order
+    ///
+    /// ## Arguments
+    ///
+    /// * `rows` - The number of rows
+    /// * `cols` - The number of columns
+    /// * `data` - The elements, one row after another
+    ///
+    /// ## Returns
+    ///
+    /// A new matrix holding the elements
+    ///
+    /// ## Panics
+    ///
+    /// If the length of `data` is not `rows * cols`
+    pub fn from_row_major(rows: usize, cols: usize, data: Vec<f64>) -> Self {
+        assert_eq!(
+            data.len(),
+            rows * cols,
+            "data does not match the matrix size"
+        );
+        DynamicMatrix { rows, cols, data }
+    }
+
+    /// Create a matrix whose rows are the given vectors
+    ///
+    /// ## Arguments
+    ///
+    /// * `rows` - The rows of the matrix, which must all have the same length
+    ///
+    /// ## Returns
+    ///
+    /// A new matrix with one row per vector. With no rows the matrix is `0 x 0`
+    ///
+    /// ## Panics
+    ///
+    /// If the rows differ in length
+    pub fn from_rows(rows: &[DynamicVector]) -> Self {
+        let cols = rows.first().map_or(0, DynamicVector::len);
+        let mut data = Vec::with_capacity(rows.len() * cols);
+        for row in rows {
+            assert_eq!(row.len(), cols, "rows must have the same length");
+            data.extend_from_slice(row.as_slice());
+        }
+        DynamicMatrix {
+            rows: rows.len(),
+            cols,
+            data,
+        }
+    }
+
+    /// Calculates the outer product of two vectors
+    ///
+    /// ## Arguments
+    ///
+    /// * `a` - The vector giving the rows
+    /// * `b` - The vector giving the columns
+    ///
+    /// ## Returns
+    ///
+    /// The matrix `a bᵀ`, whose element `(i, j)` is `a[i] * b[j]`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{DynamicMatrix, DynamicVector};
+    ///
+    /// let m = DynamicMatrix::outer(
+    ///     &DynamicVector::from(vec![1., 2.]),
+    ///     &DynamicVector::from(vec![3., 4., 5.]),
+    /// );
+    ///
+    /// assert_eq!(m.row(1), [6., 8., 10.]);
+    /// ```
+    pub fn outer(a: &DynamicVector, b: &DynamicVector) -> Self {
+        let data = a
+            .as_slice()
+            .iter()
+            .flat_map(|&x| b.as_slice().iter().map(move |&y| x * y))
+            .collect();
+        DynamicMatrix {
+            rows: a.len(),
+            cols: b.len(),
+            data,
+        }
+    }
+
+    /// Gets the number of rows
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Gets the number of columns
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Gets an element of the matrix
+    ///
+    /// ## Arguments
+    ///
+    /// * `row` - The row of the element
+    /// * `col` - The column of the element
+    ///
+    /// ## Returns
+    ///
+    /// The element at the specified position
+    ///
+    /// ## Panics
+    ///
+    /// If the position is outside the matrix
+    pub fn get(&self, row: usize, col: usize) -> f64 {
+        assert!(row < self.rows && col < self.cols, "index out of bounds");
+        self.data[row * self.cols + col]
+    }
+
+    /// Sets an element of the matrix
+    ///
+    /// ## Arguments
+    ///
+    /// * `row` - The row of the element
+    /// * `col` - The column of the element
+    /// * `value` - The new value
+    ///
+    /// ## Panics
+    ///
+    /// If the position is outside the matrix
+    pub fn set(&mut self, row: usize, col: usize, value: f64) {
+        assert!(row < self.rows && col < self.cols, "index out of bounds");
+        self.data[row * self.cols + col] = value;
+    }
+
+    /// Gets a row of the matrix
+    ///
+    /// ## Panics
+    ///
+    /// If `row` is not less than the number of rows
+    pub fn row(&self, row: usize) -> &[f64] {
+        assert!(row < self.rows, "index out of bounds");
+        &self.data[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// Gets a column of the matrix
+    ///
+    /// ## Returns
+    ///
+    /// A copy of the column, as columns are not contiguous
+    ///
+    /// ## Panics
+    ///
+    /// If `col` is not less than the number of columns
+    pub fn column(&self, col: usize) -> DynamicVector {
+        assert!(col < self.cols, "index out of bounds");
+        (0..self.rows).map(|row| self.get(row, col)).collect()
+    }
+
+    /// Gets the elements of the matrix in row-major order
+    pub fn as_slice(&self) -> &[f64] {
+        &self.data
+    }
+
+    /// Gets the elements of the matrix in row-major order as a mutable slice
+    pub fn as_mut_slice(&mut self) -> &mut [f64] {
+        &mut self.data
+    }
+
+    /// Swaps the rows and columns of the matrix
+    ///
+    /// ## Returns
+    ///
+    /// A new matrix whose element `(i, j)` is element `(j, i)` of this one
+    pub fn transpose(&self) -> DynamicMatrix {
+        let mut transposed = DynamicMatrix::new(self.cols, self.rows);
+        for row in 0..self.rows {
+            for col in 0..self.cols {
+                transposed.data[col * self.rows + row] = self.data[row * self.cols + col];
+            }
+        }
+        transposed
+    }
+
+    /// Multiplies a vector by the matrix
+    ///
+    /// ## Arguments
+    ///
+    /// * `v` - The vector, with one element per column
+    ///
+    /// ## Returns
+    ///
+    /// The vector `M v`, with one element per row
+    ///
+    /// ## Panics
+    ///
+    /// If the length of `v` is not the number of columns
+    pub fn mul_vector(&self, v: &DynamicVector) -> DynamicVector {
+        assert_eq!(
+            v.len(),
+            self.cols,
+            "vector length does not match the columns"
+        );
+        (0..self.rows)
+            .map(|row| {
+                self.row(row)
+                    .iter()
+                    .zip(v.as_slice())
+                    .map(|(a, b)| a * b)
+                    .sum::<f64>()
+            })
+            .collect()
+    }
+
+    /// Multiplies two matrices
+    ///
+    /// ## Arguments
+    ///
+    /// * `other` - The matrix on the right, with as many rows as this one has columns
+    ///
+    /// ## Returns
+    ///
+    /// The product `self * other`
+    ///
+    /// ## Panics
+    ///
+    /// If the number of rows of `other` is not the number of columns of `self`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{DynamicMatrix, DynamicVector};
+    ///
+    /// // The normal equations of a least-squares fit of y = c0 + c1 x
+    /// let a = DynamicMatrix::from_row_major(3, 2, vec![1., 0., 1., 1., 1., 2.]);
+    /// let normal = a.transpose().mul_matrix(&a);
+    ///
+    /// assert_eq!(normal, DynamicMatrix::from_row_major(2, 2, vec![3., 3., 3., 5.]));
+    /// ```
+    pub fn mul_matrix(&self, other: &DynamicMatrix) -> DynamicMatrix {
+        assert_eq!(
+            other.rows, self.cols,
+            "inner dimensions of the matrices do not match"
+        );
+        let mut product = DynamicMatrix::new(self.rows, other.cols);
+        for row in 0..self.rows {
+            let out = &mut product.data[row * other.cols..(row + 1) * other.cols];
+            // Accumulating whole rows of `other` keeps every access contiguous
+            for (k, &a) in self.row(row).iter().enumerate() {
+                for (o, &b) in out.iter_mut().zip(other.row(k)) {
+                    *o += a * b;
+                }
+            }
+        }
+        product
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_construction() {
+        let a = DynamicVector::from(vec![1., 2., 3.]);
+        let b = DynamicVector::from(vec![4., 5., 6.]);
+        let m = DynamicMatrix::from_rows(&[a.clone(), b.clone()]);
+        assert_eq!(m.as_slice(), [1., 2., 3., 4., 5., 6.]);
+        assert_eq!(m.get(1, 0), 4.);
+        assert_eq!(m.column(2), [3., 6.]);
+        assert_eq!(DynamicMatrix::from_rows(&[]), DynamicMatrix::new(0, 0));
+
+        let outer = DynamicMatrix::outer(&a, &DynamicVector::from(vec![1., -1.]));
+        assert_eq!((outer.rows(), outer.cols()), (3, 2));
+        assert_eq!(outer.row(2), [3., -3.]);
+        assert_eq!(
+            DynamicMatrix::identity(2).as_slice(),
+            DynamicMatrix::from_row_major(2, 2, vec![1., 0., 0., 1.]).as_slice()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_rows_ragged() {
+        DynamicMatrix::from_rows(&[
+            DynamicVector::from(vec![1., 2.]),
+            DynamicVector::from(vec![3.]),
+        ]);
+    }
+
+    #[test]
+    fn test_transpose_and_products() {
+        let mut m = DynamicMatrix::from_row_major(2, 3, vec![1., 2., 3., 4., 5., 6.]);
+        let t = m.transpose();
+        assert_eq!(t.as_slice(), [1., 4., 2., 5., 3., 6.]);
+        assert_eq!(t.transpose(), m);
+
+        let v = DynamicVector::from(vec![1., 0., -1.]);
+        assert_eq!(m.mul_vector(&v), [-2., -2.]);
+        assert_eq!(m.mul_matrix(&DynamicMatrix::identity(3)), m);
+        assert_eq!(
+            m.mul_matrix(&t),
+            DynamicMatrix::from_row_major(2, 2, vec![14., 32., 32., 77.])
+        );
+
+        m.set(0, 0, 0.);
+        m.as_mut_slice()[5] = 0.;
+        assert_eq!(m.row(0), [0., 2., 3.]);
+        assert_eq!(m.row(1), [4., 5., 0.]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mul_vector_mismatch() {
+        DynamicMatrix::new(2, 3).mul_vector(&DynamicVector::new(2));
+    }
+}
@@ -1,19 +1,48 @@
-use crate::Vector;
+use crate::aligned::AlignedBuffer;
+use crate::error::{parse_components, VectorError};
+use crate::{Float, Scalar, Vector, Vector2, Vector3, Vector4};
+use alloc::alloc::GlobalAlloc;
+use alloc::vec::Vec;
+use core::str::FromStr;
 
 /// A Vector that can be expanded to any length
 ///
-/// This is a dynamic vector that can be expanded to any length. It is implemented using a Vec<f64>
+/// This is a dynamic vector that can be expanded to any length. Its elements are stored in a
+/// buffer aligned to [`DYNAMIC_VECTOR_ALIGNMENT`](crate::DYNAMIC_VECTOR_ALIGNMENT) bytes, so loops
+/// over them can be vectorized with aligned SIMD loads
+///
+/// The elements are `f64` unless another [`Scalar`] type is given, such as `DynamicVector<f32>`
+/// to halve the memory of large float vectors, or `DynamicVector<u32>` for counts. Operations
+/// that measure or rescale the vector need a [`Float`] element type, and most of the other
+/// functionality of the library, such as signal processing and file formats, works on `f64`
+/// vectors only. Use [`DynamicVector::cast`] or [`DynamicVector::map`] to convert between them
 ///
 /// **NOTE:** All operations done with this vector will have a time complexity of **O(n)** where **n** is the length of the vector
 /// if you need a more performant custom Vector, consider using the `vector!` macro
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{DynamicVector, Vector};
+///
+/// let embedding: DynamicVector<f32> = DynamicVector::from(vec![0.6, 0.8]);
+/// let counts: DynamicVector<u32> = [3, 0, 2].into_iter().collect();
+///
+/// assert_eq!(embedding.magnitude(), 1.);
+/// assert_eq!(counts.as_slice().iter().sum::<u32>(), 5);
+/// assert_eq!(counts.map(|c| c as f64), [3., 0., 2.]);
+/// ```
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
-pub struct DynamicVector {
-    data: Vec<f64>,
+pub struct DynamicVector<T: Scalar = f64> {
+    pub(crate) data: AlignedBuffer<T>,
 }
 
 impl DynamicVector {
     /// Create a new dynamic vector
     ///
+    /// This creates an `f64` vector, so the element type never needs spelling out. Use
+    /// [`DynamicVector::zeros`] for other element types.
+    ///
     /// ## Arguments
     ///
     /// * `length` - The length of the vector
@@ -22,13 +51,209 @@ impl DynamicVector {
     ///
     /// A new dynamic vector of the specified length
     pub fn new(length: usize) -> Self {
-        let mut data = Vec::with_capacity(length);
+        DynamicVector::zeros(length)
+    }
+
+    /// Create an empty dynamic vector with room for a number of elements
+    ///
+    /// Together with [`DynamicVector::clear`] and [`DynamicVector::push`] this lets one
+    /// allocation be reused, for example refilling the same vector every frame instead of
+    /// allocating a new one. Like [`DynamicVector::new`] this creates an `f64` vector, use
+    /// [`DynamicVector::empty`] for other element types.
+    ///
+    /// ## Arguments
+    ///
+    /// * `capacity` - The number of elements to make room for
+    ///
+    /// ## Returns
+    ///
+    /// A new dynamic vector with a length of zero
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::DynamicVector;
+    ///
+    /// let mut v = DynamicVector::with_capacity(100);
+    ///
+    /// for frame in 0..3 {
+    ///     v.clear();
+    ///     for i in 0..100 {
+    ///         v.push((frame * i) as f64);
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(v.len(), 100);
+    /// assert!(v.capacity() >= 100);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        DynamicVector::empty(capacity)
+    }
+
+    /// Create an empty dynamic vector whose elements are stored in the given allocator
+    ///
+    /// The buffer is allocated, grown and freed through `allocator` instead of the global
+    /// allocator, so vector data can be placed in a frame arena or a memory pool. Growing the
+    /// vector with [`DynamicVector::push`] and cloning it stay in the same allocator, while
+    /// operations that build a new vector, such as `+` or [`DynamicVector::map`], use the global
+    /// allocator. Like [`DynamicVector::with_capacity`] this creates an `f64` vector, use
+    /// [`DynamicVector::empty_in`] for other element types.
+    ///
+    /// ## Arguments
+    ///
+    /// * `capacity` - The number of elements to make room for
+    /// * `allocator` - The allocator to store the elements in
+    ///
+    /// ## Returns
+    ///
+    /// A new dynamic vector with a length of zero
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::DynamicVector;
+    /// use std::alloc::{GlobalAlloc, Layout, System};
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    ///
+    /// struct FrameArena {
+    ///     allocated: AtomicUsize,
+    /// }
+    ///
+    /// unsafe impl GlobalAlloc for FrameArena {
+    ///     unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+    ///         self.allocated.fetch_add(layout.size(), Ordering::Relaxed);
+    ///         System.alloc(layout)
+    ///     }
+    ///
+    ///     unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+    ///         System.dealloc(ptr, layout)
+    ///     }
+    /// }
+    ///
+    /// static ARENA: FrameArena = FrameArena { allocated: AtomicUsize::new(0) };
+    ///
+    /// let mut v = DynamicVector::with_capacity_in(8, &ARENA);
+    /// v.push(1.);
+    /// v.push(2.);
+    ///
+    /// assert_eq!(v, [1., 2.]);
+    /// assert_eq!(ARENA.allocated.load(Ordering::Relaxed), 64);
+    /// ```
+    pub fn with_capacity_in(capacity: usize, allocator: &'static (dyn GlobalAlloc + Sync)) -> Self {
+        DynamicVector::empty_in(capacity, allocator)
+    }
+}
+
+impl<T: Scalar> DynamicVector<T> {
+    /// Create a new dynamic vector of zeros, of any element type
+    ///
+    /// ## Arguments
+    ///
+    /// * `length` - The length of the vector
+    ///
+    /// ## Returns
+    ///
+    /// A new dynamic vector of the specified length
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::DynamicVector;
+    ///
+    /// let mut counts = DynamicVector::<u32>::zeros(3);
+    /// counts.set(1, counts.get(1) + 1);
+    ///
+    /// assert_eq!(counts, [0, 1, 0]);
+    /// ```
+    pub fn zeros(length: usize) -> Self {
+        DynamicVector {
+            data: AlignedBuffer::zeroed(length, None),
+        }
+    }
+
+    /// Create a new dynamic vector of zeros, of any element type, stored in the given allocator
+    ///
+    /// See [`DynamicVector::with_capacity_in`] for which operations use the allocator.
+    ///
+    /// ## Arguments
+    ///
+    /// * `length` - The length of the vector
+    /// * `allocator` - The allocator to store the elements in
+    ///
+    /// ## Returns
+    ///
+    /// A new dynamic vector of the specified length
+    pub fn zeros_in(length: usize, allocator: &'static (dyn GlobalAlloc + Sync)) -> Self {
+        DynamicVector {
+            data: AlignedBuffer::zeroed(length, Some(allocator)),
+        }
+    }
+
+    /// Create an empty dynamic vector of any element type, with room for a number of elements
+    ///
+    /// ## Arguments
+    ///
+    /// * `capacity` - The number of elements to make room for
+    ///
+    /// ## Returns
+    ///
+    /// A new dynamic vector with a length of zero
+    pub fn empty(capacity: usize) -> Self {
+        DynamicVector {
+            data: AlignedBuffer::with_capacity(capacity, None),
+        }
+    }
 
-        for _ in 0..length {
-            data.push(0.0);
+    /// Create an empty dynamic vector of any element type, stored in the given allocator
+    ///
+    /// See [`DynamicVector::with_capacity_in`] for which operations use the allocator.
+    ///
+    /// ## Arguments
+    ///
+    /// * `capacity` - The number of elements to make room for
+    /// * `allocator` - The allocator to store the elements in
+    ///
+    /// ## Returns
+    ///
+    /// A new dynamic vector with a length of zero
+    pub fn empty_in(capacity: usize, allocator: &'static (dyn GlobalAlloc + Sync)) -> Self {
+        DynamicVector {
+            data: AlignedBuffer::with_capacity(capacity, Some(allocator)),
         }
+    }
+
+    /// Gets the number of elements in the Vector
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Checks whether the Vector has no elements
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Gets the number of elements the Vector can hold without reallocating
+    pub fn capacity(&self) -> usize {
+        self.data.capacity()
+    }
+
+    /// Makes room for at least `additional` more elements
+    pub fn reserve(&mut self, additional: usize) {
+        self.data.reserve(additional);
+    }
+
+    /// Adds a value to the end of the Vector
+    ///
+    /// ## Arguments
+    ///
+    /// * `value` - The value to add
+    pub fn push(&mut self, value: T) {
+        self.data.push(value);
+    }
 
-        DynamicVector { data }
+    /// Removes every element, keeping the allocated memory for reuse
+    pub fn clear(&mut self) {
+        self.data.clear();
     }
 
     /// Gets a value from the Vector
@@ -40,7 +265,7 @@ impl DynamicVector {
     /// ## Returns
     ///
     /// The value at the specified index
-    pub fn get(&self, index: usize) -> f64 {
+    pub fn get(&self, index: usize) -> T {
         self.data[index]
     }
 
@@ -49,12 +274,425 @@ impl DynamicVector {
     /// ## Arguments
     ///
     /// * `index` - The index of the value to set
-    pub fn set(&mut self, index: usize, value: f64) {
+    pub fn set(&mut self, index: usize, value: T) {
         self.data[index] = value;
     }
+
+    /// Gets a value from the Vector, failing instead of panicking if the index is out of bounds
+    ///
+    /// ## Arguments
+    ///
+    /// * `index` - The index of the value to get
+    ///
+    /// ## Returns
+    ///
+    /// The value, or [`VectorError::IndexOutOfBounds`]
+    pub fn try_get(&self, index: usize) -> Result<T, VectorError> {
+        self.data
+            .get(index)
+            .copied()
+            .ok_or(VectorError::IndexOutOfBounds {
+                index,
+                length: self.data.len(),
+            })
+    }
+
+    /// Sets a value in the Vector, failing instead of panicking if the index is out of bounds
+    ///
+    /// ## Arguments
+    ///
+    /// * `index` - The index of the value to set
+    /// * `value` - The new value
+    ///
+    /// ## Returns
+    ///
+    /// [`VectorError::IndexOutOfBounds`] if there is no element at `index`
+    pub fn try_set(&mut self, index: usize, value: T) -> Result<(), VectorError> {
+        let length = self.data.len();
+        let slot = self
+            .data
+            .get_mut(index)
+            .ok_or(VectorError::IndexOutOfBounds { index, length })?;
+        *slot = value;
+        Ok(())
+    }
+
+    /// Gets the elements of the Vector
+    ///
+    /// ## Returns
+    ///
+    /// The elements as a slice, whose first element is aligned to
+    /// [`DYNAMIC_VECTOR_ALIGNMENT`](crate::DYNAMIC_VECTOR_ALIGNMENT) bytes
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{DynamicVector, DYNAMIC_VECTOR_ALIGNMENT};
+    ///
+    /// let v = DynamicVector::new(10);
+    ///
+    /// assert_eq!(v.as_slice().as_ptr() as usize % DYNAMIC_VECTOR_ALIGNMENT, 0);
+    /// ```
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Gets the elements of the Vector mutably
+    ///
+    /// ## Returns
+    ///
+    /// The elements as a mutable slice, aligned like [`DynamicVector::as_slice`]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    /// Multiplies every element by a scalar in place
+    ///
+    /// ## Arguments
+    ///
+    /// * `scalar` - The value to multiply each element by
+    pub fn scale_mut(&mut self, scalar: T) {
+        for a in self.data.iter_mut() {
+            *a = *a * scalar;
+        }
+    }
+
+    /// Adds another vector to this one in place
+    ///
+    /// ## Arguments
+    ///
+    /// * `other` - The vector to add
+    ///
+    /// ## Panics
+    ///
+    /// If the vectors have different lengths
+    pub fn add_mut(&mut self, other: &DynamicVector<T>) {
+        assert_eq!(
+            self.data.len(),
+            other.data.len(),
+            "vectors must have the same length"
+        );
+        for (a, &b) in self.data.iter_mut().zip(other.data.iter()) {
+            *a = *a + b;
+        }
+    }
+
+    /// Clamps every element to a range in place
+    ///
+    /// ## Arguments
+    ///
+    /// * `min` - The smallest allowed value
+    /// * `max` - The largest allowed value
+    ///
+    /// ## Panics
+    ///
+    /// If `min` is greater than `max`, or either is `NaN`
+    pub fn clamp_mut(&mut self, min: T, max: T) {
+        assert!(min <= max, "min must not be greater than max");
+        for a in self.data.iter_mut() {
+            if *a < min {
+                *a = min;
+            } else if *a > max {
+                *a = max;
+            }
+        }
+    }
+
+    /// Adds a multiple of another vector to this one in place, in a single pass
+    ///
+    /// This is the BLAS `axpy` operation, `self = self + alpha * x`, and avoids allocating a
+    /// temporary vector for `alpha * x`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `alpha` - The amount to scale `x` by
+    /// * `x` - The vector to add
+    ///
+    /// ## Panics
+    ///
+    /// If the vectors have different lengths
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::DynamicVector;
+    ///
+    /// let mut y = DynamicVector::new(2);
+    /// let mut x = DynamicVector::new(2);
+    /// x.set(0, 1.);
+    /// x.set(1, 2.);
+    /// y.axpy(3., &x);
+    ///
+    /// assert_eq!(y.as_slice(), &[3., 6.]);
+    /// ```
+    pub fn axpy(&mut self, alpha: T, x: &DynamicVector<T>) {
+        assert_eq!(
+            self.data.len(),
+            x.data.len(),
+            "vectors must have the same length"
+        );
+        for (a, &b) in self.data.iter_mut().zip(x.data.iter()) {
+            *a = *a + alpha * b;
+        }
+    }
+
+    /// Adds a multiple of another vector to this one in place, failing instead of panicking if
+    /// the lengths differ
+    ///
+    /// ## Arguments
+    ///
+    /// * `alpha` - The amount to scale `x` by
+    /// * `x` - The vector to add
+    ///
+    /// ## Returns
+    ///
+    /// [`VectorError::LengthMismatch`] if the vectors have different lengths, in which case this
+    /// vector is left unchanged
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{DynamicVector, VectorError};
+    ///
+    /// let mut y = DynamicVector::new(2);
+    /// let x = DynamicVector::new(3);
+    ///
+    /// assert_eq!(
+    ///     y.try_axpy(1., &x),
+    ///     Err(VectorError::LengthMismatch { expected: 2, found: 3 })
+    /// );
+    /// ```
+    pub fn try_axpy(&mut self, alpha: T, x: &DynamicVector<T>) -> Result<(), VectorError> {
+        if self.data.len() != x.data.len() {
+            return Err(VectorError::LengthMismatch {
+                expected: self.data.len(),
+                found: x.data.len(),
+            });
+        }
+        self.axpy(alpha, x);
+        Ok(())
+    }
+
+    /// Adds a multiple of another vector to this one, in a single pass
+    ///
+    /// ## Arguments
+    ///
+    /// * `other` - The vector to add
+    /// * `scalar` - The amount to scale `other` by
+    ///
+    /// ## Returns
+    ///
+    /// A new vector equal to `self + other * scalar`
+    ///
+    /// ## Panics
+    ///
+    /// If the vectors have different lengths
+    pub fn add_scaled(&self, other: &DynamicVector<T>, scalar: T) -> DynamicVector<T> {
+        let mut result = self.clone();
+        result.axpy(scalar, other);
+        result
+    }
+
+    /// Evaluates a weighted sum of vectors into one new vector, in a single pass
+    ///
+    /// An expression such as `a + b * 2 - c` can be written as
+    /// `linear_combination(&[(1., &a), (2., &b), (-1., &c)])`, which allocates only the result
+    /// and reads each element of the inputs once.
+    ///
+    /// ## Arguments
+    ///
+    /// * `terms` - Pairs of a weight and the vector it multiplies
+    ///
+    /// ## Returns
+    ///
+    /// The sum of every weighted vector, or an empty vector if there are no terms
+    ///
+    /// ## Panics
+    ///
+    /// If the vectors have different lengths
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::DynamicVector;
+    ///
+    /// let a = DynamicVector::from_csv_row("1, 2").unwrap();
+    /// let b = DynamicVector::from_csv_row("3, 4").unwrap();
+    /// let c = DynamicVector::from_csv_row("1, 1").unwrap();
+    ///
+    /// let v = DynamicVector::linear_combination(&[(1., &a), (2., &b), (-1., &c)]);
+    ///
+    /// assert_eq!(v.as_slice(), &[6., 9.]);
+    /// ```
+    pub fn linear_combination(terms: &[(T, &DynamicVector<T>)]) -> DynamicVector<T> {
+        let length = terms.first().map_or(0, |(_, v)| v.data.len());
+        assert!(
+            terms.iter().all(|(_, v)| v.data.len() == length),
+            "vectors must have the same length"
+        );
+        DynamicVector {
+            data: (0..length)
+                .map(|i| terms.iter().map(|&(w, v)| w * v.data[i]).sum())
+                .collect(),
+        }
+    }
+
+    /// Applies a function to every element, producing a vector of a possibly different type
+    ///
+    /// ## Arguments
+    ///
+    /// * `f` - The function to apply
+    ///
+    /// ## Returns
+    ///
+    /// A vector of the results, in the same order
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::DynamicVector;
+    ///
+    /// let v = DynamicVector::from(vec![0.4, 1.6, 2.5]);
+    ///
+    /// assert_eq!(v.map(|a: f64| a.round() as i32), [0, 2, 3]);
+    /// ```
+    pub fn map<U: Scalar, F: FnMut(T) -> U>(&self, f: F) -> DynamicVector<U> {
+        self.data.iter().copied().map(f).collect()
+    }
+}
+
+impl<T: Scalar + Float> DynamicVector<T> {
+    /// Rescales the vector to an exact length, keeping its direction
+    ///
+    /// ## Arguments
+    ///
+    /// * `length` - The magnitude of the new vector. A negative length reverses the direction
+    ///
+    /// ## Returns
+    ///
+    /// The rescaled vector, or `None` if the vector is zero and so has no direction
+    pub fn with_magnitude(&self, length: f64) -> Option<DynamicVector<T>> {
+        let magnitude = self.magnitude();
+        if magnitude == 0. {
+            return None;
+        }
+        let mut rescaled = self.clone();
+        rescaled.scale_mut(T::from_f64(length / magnitude));
+        Some(rescaled)
+    }
+
+    /// Rescales the vector in place to an exact length, keeping its direction
+    ///
+    /// A zero vector has no direction, so it is left unchanged. Use `with_magnitude` to detect
+    /// that case.
+    ///
+    /// ## Arguments
+    ///
+    /// * `length` - The new magnitude of the vector
+    pub fn set_magnitude(&mut self, length: f64) {
+        let magnitude = self.magnitude();
+        if magnitude != 0. {
+            self.scale_mut(T::from_f64(length / magnitude));
+        }
+    }
+
+    /// Shortens the vector if it is longer than a maximum length
+    ///
+    /// ## Arguments
+    ///
+    /// * `max` - The largest allowed magnitude
+    ///
+    /// ## Returns
+    ///
+    /// A vector in the same direction with a magnitude of at most `max`
+    pub fn clamp_length(&self, max: f64) -> DynamicVector<T> {
+        self.clamp_length_between(0., max)
+    }
+
+    /// Rescales the vector so its length lies within a range
+    ///
+    /// A zero vector has no direction, so it is returned unchanged even if `min` is positive.
+    ///
+    /// ## Arguments
+    ///
+    /// * `min` - The smallest allowed magnitude
+    /// * `max` - The largest allowed magnitude
+    ///
+    /// ## Returns
+    ///
+    /// A vector in the same direction with a magnitude between `min` and `max`
+    ///
+    /// ## Panics
+    ///
+    /// If `min` is greater than `max`
+    pub fn clamp_length_between(&self, min: f64, max: f64) -> DynamicVector<T> {
+        assert!(min <= max, "min must not be greater than max");
+        let magnitude = self.magnitude();
+        let mut clamped = self.clone();
+        if magnitude > max {
+            clamped.scale_mut(T::from_f64(max / magnitude));
+        } else if magnitude < min && magnitude > 0. {
+            clamped.scale_mut(T::from_f64(min / magnitude));
+        }
+        clamped
+    }
+
+    /// Normalizes the vector, failing instead of producing NaNs for a zero vector
+    ///
+    /// ## Returns
+    ///
+    /// The unit vector in the same direction, or [`VectorError::ZeroMagnitude`]
+    pub fn try_normalize(&self) -> Result<DynamicVector<T>, VectorError> {
+        if self.magnitude() == 0. {
+            return Err(VectorError::ZeroMagnitude);
+        }
+        Ok(self.normalize())
+    }
+
+    /// Normalizes the vector in place, without allocating
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::DynamicVector;
+    ///
+    /// let mut v = DynamicVector::new(2);
+    /// v.set(0, 3.);
+    /// v.set(1, 4.);
+    /// v.normalize_mut();
+    ///
+    /// assert_eq!(v.as_slice(), &[0.6, 0.8]);
+    /// ```
+    pub fn normalize_mut(&mut self) {
+        let magnitude = T::from_f64(self.magnitude());
+        for a in self.data.iter_mut() {
+            *a = *a / magnitude;
+        }
+    }
+
+    /// Converts the elements to another float type
+    ///
+    /// ## Returns
+    ///
+    /// A vector of the same values, rounded to the nearest value of `U` if needed
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::DynamicVector;
+    ///
+    /// let v = DynamicVector::from(vec![0.1, 2.5]);
+    /// let compact: DynamicVector<f32> = v.cast();
+    ///
+    /// assert_eq!(compact, [0.1_f32, 2.5]);
+    /// assert_eq!(compact.cast::<f64>().get(1), 2.5);
+    /// ```
+    pub fn cast<U: Scalar + Float>(&self) -> DynamicVector<U> {
+        self.map(|a| U::from_f64(a.to_f64()))
+    }
 }
 
-impl Vector for DynamicVector {
+impl<T: Scalar + Float> Vector for DynamicVector<T> {
     /// Calculate the dot product of two vectors
     ///
     /// ## Arguments
@@ -68,8 +706,9 @@ impl Vector for DynamicVector {
         self.data
             .iter()
             .zip(other.data.iter())
-            .map(|(a, b)| a * b)
-            .sum()
+            .map(|(&a, &b)| a * b)
+            .sum::<T>()
+            .to_f64()
     }
 
     /// Calculate the magnitude of the vector
@@ -78,7 +717,7 @@ impl Vector for DynamicVector {
     ///
     /// The magnitude of the vector
     fn magnitude(&self) -> f64 {
-        self.data.iter().map(|a| a * a).sum::<f64>().sqrt()
+        Float::sqrt(self.data.iter().map(|&a| a * a).sum::<T>()).to_f64()
     }
 
     /// Normalize the vector
@@ -87,28 +726,185 @@ impl Vector for DynamicVector {
     ///
     /// A new vector that is the normalized version of the original vector
     fn normalize(&self) -> Self {
-        let magnitude = self.magnitude();
+        let magnitude = T::from_f64(self.magnitude());
         DynamicVector {
-            data: self.data.iter().map(|a| a / magnitude).collect(),
+            data: self.data.iter().map(|&a| a / magnitude).collect(),
         }
     }
+
+    /// Creates an empty vector, as a dynamic vector has no fixed dimension
+    fn zero() -> Self {
+        DynamicVector::zeros(0)
+    }
+
+    /// Creates a vector of zeros with the same length as this one
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{DynamicVector, Vector};
+    ///
+    /// let v = DynamicVector::from(vec![1e-9, -1e-9, 0.]);
+    ///
+    /// assert_eq!(v.zero_like(), [0., 0., 0.]);
+    /// assert!(v.is_zero(1e-6));
+    /// assert!(!v.is_zero(0.));
+    /// ```
+    fn zero_like(&self) -> Self {
+        DynamicVector::zeros(self.len())
+    }
+}
+
+impl<T: Scalar> From<Vec<T>> for DynamicVector<T> {
+    fn from(values: Vec<T>) -> Self {
+        DynamicVector {
+            data: values.into(),
+        }
+    }
+}
+
+impl<T: Scalar + Float> FromStr for DynamicVector<T> {
+    type Err = VectorError;
+
+    /// Parses comma separated numbers, optionally wrapped in parentheses or brackets
+    fn from_str(s: &str) -> Result<Self, VectorError> {
+        let mut values = Vec::new();
+        parse_components(s, |_, value| values.push(T::from_f64(value)))?;
+        Ok(DynamicVector::from(values))
+    }
+}
+
+impl TryFrom<&DynamicVector> for Vector2 {
+    type Error = VectorError;
+
+    /// Converts a dynamic vector with exactly 2 elements
+    fn try_from(v: &DynamicVector) -> Result<Self, VectorError> {
+        Vector2::try_from(v.as_slice())
+    }
+}
+
+impl TryFrom<&DynamicVector> for Vector3 {
+    type Error = VectorError;
+
+    /// Converts a dynamic vector with exactly 3 elements
+    fn try_from(v: &DynamicVector) -> Result<Self, VectorError> {
+        Vector3::try_from(v.as_slice())
+    }
+}
+
+impl TryFrom<&DynamicVector> for Vector4 {
+    type Error = VectorError;
+
+    /// Converts a dynamic vector with exactly 4 elements
+    fn try_from(v: &DynamicVector) -> Result<Self, VectorError> {
+        Vector4::try_from(v.as_slice())
+    }
+}
+
+impl<T: Scalar> FromIterator<T> for DynamicVector<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        DynamicVector {
+            data: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T: Scalar> PartialEq<[T]> for DynamicVector<T> {
+    fn eq(&self, other: &[T]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T: Scalar> PartialEq<&[T]> for DynamicVector<T> {
+    fn eq(&self, other: &&[T]) -> bool {
+        self.as_slice() == *other
+    }
+}
+
+impl<T: Scalar, const N: usize> PartialEq<[T; N]> for DynamicVector<T> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T: Scalar> PartialEq<Vec<T>> for DynamicVector<T> {
+    fn eq(&self, other: &Vec<T>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Scalar> PartialEq<DynamicVector<T>> for [T] {
+    fn eq(&self, other: &DynamicVector<T>) -> bool {
+        self == other.as_slice()
+    }
+}
+
+impl<T: Scalar, const N: usize> PartialEq<DynamicVector<T>> for [T; N] {
+    fn eq(&self, other: &DynamicVector<T>) -> bool {
+        self == other.as_slice()
+    }
+}
+
+impl<T: Scalar> PartialEq<DynamicVector<T>> for Vec<T> {
+    fn eq(&self, other: &DynamicVector<T>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_eq_slices() {
+        let v = DynamicVector::from(alloc::vec![1., 2., 3.]);
+        assert_eq!(v, [1., 2., 3.]);
+        assert_eq!(v, alloc::vec![1., 2., 3.]);
+        assert_eq!(v, &[1., 2., 3.][..]);
+        assert_eq!([1., 2., 3.], v);
+        assert_eq!(alloc::vec![1., 2., 3.], v);
+        assert!(v != [1., 2.]);
+        assert!(v != [1., 2., 4.]);
+    }
+
+    #[test]
+    fn test_fallible() {
+        let mut v: DynamicVector = "[3, 4]".parse().unwrap();
+        assert_eq!(v.try_get(1), Ok(4.));
+        assert_eq!(
+            v.try_set(2, 1.),
+            Err(VectorError::IndexOutOfBounds {
+                index: 2,
+                length: 2
+            })
+        );
+        assert_eq!(v.try_normalize().unwrap().as_slice(), &[0.6, 0.8]);
+        assert_eq!(
+            DynamicVector::new(2).try_normalize(),
+            Err(VectorError::ZeroMagnitude)
+        );
+        assert_eq!(Vector2::try_from(&v), Ok(Vector2::new(3., 4.)));
+        assert!(Vector3::try_from(&v).is_err());
+        assert_eq!("".parse::<DynamicVector>(), Ok(DynamicVector::new(0)));
+        assert_eq!(
+            "1, a".parse::<DynamicVector>(),
+            Err(VectorError::ParseError { component: 1 })
+        );
+    }
 
     #[test]
     fn test_new() {
         let vector = DynamicVector::new(5);
         assert_eq!(vector.data.len(), 5);
-        assert_eq!(vector.data.capacity(), 5);
+        assert_eq!(vector.data.capacity(), 8);
+        assert_eq!(vector.as_slice().as_ptr() as usize % 64, 0);
     }
 
     #[test]
     fn test_get() {
         let mut vector = DynamicVector::new(3);
-        vector.data = vec![1.0, 2.0, 3.0];
+        vector.data = vec![1.0, 2.0, 3.0].into();
         assert_eq!(vector.get(0), 1.0);
         assert_eq!(vector.get(1), 2.0);
         assert_eq!(vector.get(2), 3.0);
@@ -126,10 +922,10 @@ mod tests {
     #[test]
     fn test_dot() {
         let vector1 = DynamicVector {
-            data: vec![1.0, 2.0, 3.0],
+            data: vec![1.0, 2.0, 3.0].into(),
         };
         let vector2 = DynamicVector {
-            data: vec![4.0, 5.0, 6.0],
+            data: vec![4.0, 5.0, 6.0].into(),
         };
         assert_eq!(vector1.dot(&vector2), 32.0);
     }
@@ -137,7 +933,7 @@ mod tests {
     #[test]
     fn test_magnitude() {
         let vector = DynamicVector {
-            data: vec![3.0, 4.0],
+            data: vec![3.0, 4.0].into(),
         };
         assert_eq!(vector.magnitude(), 5.0);
     }
@@ -145,9 +941,134 @@ mod tests {
     #[test]
     fn test_normalize() {
         let vector = DynamicVector {
-            data: vec![3.0, 4.0],
+            data: vec![3.0, 4.0].into(),
         };
         let normalized_vector = vector.normalize();
         assert_eq!(normalized_vector.data, vec![0.6, 0.8]);
     }
+
+    #[test]
+    fn test_in_place() {
+        let mut vector = DynamicVector {
+            data: vec![1.0, -2.0, 3.0].into(),
+        };
+        let other = DynamicVector {
+            data: vec![1.0, 1.0, 1.0].into(),
+        };
+        vector.add_mut(&other);
+        assert_eq!(vector.data, vec![2.0, -1.0, 4.0]);
+        vector.scale_mut(2.0);
+        assert_eq!(vector.data, vec![4.0, -2.0, 8.0]);
+        vector.clamp_mut(-1.0, 5.0);
+        assert_eq!(vector.data, vec![4.0, -1.0, 5.0]);
+        let ptr = vector.as_slice().as_ptr();
+        vector.normalize_mut();
+        assert_eq!(vector.as_slice().as_ptr(), ptr);
+        assert!((vector.magnitude() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_mut_length_mismatch() {
+        DynamicVector::new(2).add_mut(&DynamicVector::new(3));
+    }
+
+    #[test]
+    fn test_fused() {
+        let a = DynamicVector {
+            data: vec![1.0, 2.0, 3.0].into(),
+        };
+        let b = DynamicVector {
+            data: vec![4.0, 5.0, 6.0].into(),
+        };
+        assert_eq!(a.add_scaled(&b, 0.5).data, vec![3.0, 4.5, 6.0]);
+
+        let mut y = a.clone();
+        y.axpy(-1.0, &a);
+        assert_eq!(y, DynamicVector::new(3));
+
+        let sum = DynamicVector::linear_combination(&[(2.0, &a), (1.0, &b), (-1.0, &a)]);
+        assert_eq!(sum.data, vec![5.0, 7.0, 9.0]);
+        assert_eq!(
+            DynamicVector::linear_combination(&[]),
+            DynamicVector::new(0)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_linear_combination_length_mismatch() {
+        let (a, b) = (DynamicVector::new(2), DynamicVector::new(3));
+        DynamicVector::linear_combination(&[(1.0, &a), (1.0, &b)]);
+    }
+
+    #[test]
+    fn test_from_values() {
+        let vector = DynamicVector::from(vec![1.0, 2.0]);
+        assert_eq!(vector.data, vec![1.0, 2.0]);
+        let collected: DynamicVector = (0..3).map(f64::from).collect();
+        assert_eq!(collected.data, vec![0.0, 1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_with_capacity() {
+        let mut vector = DynamicVector::with_capacity(10);
+        assert!(vector.is_empty());
+        assert_eq!(vector.capacity(), 16);
+        vector.push(1.0);
+        vector.push(2.0);
+        assert_eq!(vector.len(), 2);
+        vector.clear();
+        assert_eq!(vector.len(), 0);
+        assert_eq!(vector.capacity(), 16);
+        vector.reserve(30);
+        assert!(vector.capacity() >= 30);
+    }
+
+    #[test]
+    fn test_clamp_length() {
+        let vector = DynamicVector::from(vec![3.0, 4.0]);
+        assert_eq!(vector.clamp_length(2.5).data, vec![1.5, 2.0]);
+        assert_eq!(vector.clamp_length_between(10.0, 20.0).data, vec![6.0, 8.0]);
+        assert_eq!(vector.clamp_length(5.0), vector);
+    }
+
+    #[test]
+    fn test_with_magnitude() {
+        let mut vector = DynamicVector::from(vec![3.0, 4.0]);
+        assert_eq!(vector.with_magnitude(10.0).unwrap().data, vec![6.0, 8.0]);
+        assert_eq!(DynamicVector::new(2).with_magnitude(1.0), None);
+        vector.set_magnitude(2.5);
+        assert_eq!(vector.data, vec![1.5, 2.0]);
+    }
+
+    #[test]
+    fn test_f32() {
+        let mut vector: DynamicVector<f32> = "3, 4".parse().unwrap();
+        assert_eq!(vector.capacity(), 16);
+        assert_eq!(vector.magnitude(), 5.0);
+        assert_eq!(vector.dot(&vector), 25.0);
+        assert_eq!(vector.normalize(), [0.6, 0.8]);
+        vector.set_magnitude(10.0);
+        assert_eq!(vector, [6.0, 8.0]);
+        vector.axpy(0.5, &DynamicVector::from(vec![2.0, 2.0]));
+        assert_eq!(vector, [7.0, 9.0]);
+        assert_eq!(vector.cast::<f64>(), DynamicVector::from(vec![7.0, 9.0]));
+        assert_eq!(DynamicVector::from(vec![0.1]).cast::<f32>(), [0.1_f32]);
+    }
+
+    #[test]
+    fn test_integers() {
+        let mut counts = DynamicVector::<u64>::empty(4);
+        for _ in 0..3 {
+            counts.push(2);
+        }
+        counts.add_mut(&DynamicVector::from(vec![1, 0, 5]));
+        counts.clamp_mut(0, 4);
+        assert_eq!(counts, [3, 2, 4]);
+        let sum = DynamicVector::linear_combination(&[(2, &counts), (1, &counts)]);
+        assert_eq!(sum, vec![9, 6, 12]);
+        assert_eq!(counts.map(|c| c as f64 / 2.0), [1.5, 1.0, 2.0]);
+        assert_eq!(DynamicVector::<i32>::zeros(2), [0, 0]);
+    }
 }
@@ -0,0 +1,141 @@
+use core::fmt;
+
+/// An error produced by a fallible vector operation
+///
+/// Operations that can fail because of their input, such as parsing or converting between
+/// vectors of different lengths, return this instead of panicking.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum VectorError {
+    /// Two vectors, or a vector and a slice, had different lengths
+    LengthMismatch { expected: usize, found: usize },
+    /// A zero vector was used where a direction was needed
+    ZeroMagnitude,
+    /// An operation produced an infinite or NaN component
+    NonFinite,
+    /// An index was past the end of a vector
+    IndexOutOfBounds { index: usize, length: usize },
+    /// A component could not be parsed as a number
+    ParseError {
+        /// The position of the component that failed to parse, starting at zero
+        component: usize,
+    },
+}
+
+impl fmt::Display for VectorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VectorError::LengthMismatch { expected, found } => {
+                write!(f, "expected {} components, found {}", expected, found)
+            }
+            VectorError::ZeroMagnitude => write!(f, "vector has zero magnitude"),
+            VectorError::NonFinite => write!(f, "vector has an infinite or NaN component"),
+            VectorError::IndexOutOfBounds { index, length } => write!(
+                f,
+                "index {} is out of bounds for a vector of length {}",
+                index, length
+            ),
+            VectorError::ParseError { component } => {
+                write!(f, "component {} is not a valid number", component)
+            }
+        }
+    }
+}
+
+impl core::error::Error for VectorError {}
+
+/// Calls `each` with every component of a vector written like `(1, 2, 3)`, `[1, 2, 3]` or
+/// `1, 2, 3`, stopping at the first one that is not a number
+///
+/// ## Returns
+///
+/// The number of components, or the error for the first invalid one
+pub(crate) fn parse_components(
+    s: &str,
+    mut each: impl FnMut(usize, f64),
+) -> Result<usize, VectorError> {
+    let s = s.trim();
+    let inner = s
+        .strip_prefix('(')
+        .and_then(|s| s.strip_suffix(')'))
+        .or_else(|| s.strip_prefix('[').and_then(|s| s.strip_suffix(']')))
+        .unwrap_or(s);
+    if inner.trim().is_empty() {
+        return Ok(0);
+    }
+    let mut count = 0;
+    for (component, field) in inner.split(',').enumerate() {
+        let value = field
+            .trim()
+            .parse()
+            .map_err(|_| VectorError::ParseError { component })?;
+        each(component, value);
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// Parses a vector with exactly `N` components, see [`parse_components`]
+pub(crate) fn parse_array<const N: usize>(s: &str) -> Result<[f64; N], VectorError> {
+    let mut values = [0.; N];
+    let found = parse_components(s, |i, value| {
+        if let Some(slot) = values.get_mut(i) {
+            *slot = value;
+        }
+    })?;
+    if found != N {
+        return Err(VectorError::LengthMismatch { expected: N, found });
+    }
+    Ok(values)
+}
+
+/// Copies a slice into an array, failing if the lengths differ
+pub(crate) fn slice_to_array<const N: usize>(values: &[f64]) -> Result<[f64; N], VectorError> {
+    values.try_into().map_err(|_| VectorError::LengthMismatch {
+        expected: N,
+        found: values.len(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_parse_array() {
+        assert_eq!(parse_array::<2>("(1, -2.5)"), Ok([1., -2.5]));
+        assert_eq!(parse_array::<3>(" [1,2,3] "), Ok([1., 2., 3.]));
+        assert_eq!(parse_array::<2>("4, 5"), Ok([4., 5.]));
+        assert_eq!(
+            parse_array::<2>("1, 2, 3"),
+            Err(VectorError::LengthMismatch {
+                expected: 2,
+                found: 3
+            })
+        );
+        assert_eq!(
+            parse_array::<2>("1, x"),
+            Err(VectorError::ParseError { component: 1 })
+        );
+        assert_eq!(
+            parse_array::<2>("()"),
+            Err(VectorError::LengthMismatch {
+                expected: 2,
+                found: 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let error = VectorError::IndexOutOfBounds {
+            index: 3,
+            length: 2,
+        };
+        assert_eq!(
+            error.to_string(),
+            "index 3 is out of bounds for a vector of length 2"
+        );
+    }
+}
@@ -0,0 +1,222 @@
+#[cfg(not(feature = "std"))]
+use crate::math::FloatMath;
+use crate::{Complex, DynamicComplexVector, DynamicVector};
+use alloc::{vec, vec::Vec};
+use core::f64::consts::PI;
+
+/// Transforms a power of two number of values in place with the iterative radix-2 algorithm,
+/// without scaling the result
+fn radix2(data: &mut [Complex], inverse: bool) {
+    let n = data.len();
+    let sign = if inverse { 1. } else { -1. };
+
+    // Bit reversal permutation
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            data.swap(i, j);
+        }
+    }
+
+    let mut size = 2;
+    while size <= n {
+        let half = size / 2;
+        for k in 0..half {
+            // Each twiddle factor is computed directly, so errors do not build up along a stage
+            let (sin, cos) = (sign * 2. * PI * k as f64 / size as f64).sin_cos();
+            let twiddle = Complex::new(cos, sin);
+            for start in (0..n).step_by(size) {
+                let even = data[start + k];
+                let odd = data[start + k + half] * twiddle;
+                data[start + k] = even + odd;
+                data[start + k + half] = even - odd;
+            }
+        }
+        size *= 2;
+    }
+}
+
+/// Transforms any number of values with Bluestein's algorithm, which rewrites the transform as a
+/// convolution that can be done with power of two transforms. The result is not scaled.
+fn bluestein(input: &[Complex], inverse: bool) -> Vec<Complex> {
+    let n = input.len();
+    let sign = if inverse { 1. } else { -1. };
+    // `k * k` is reduced modulo `2n` first, as the chirp repeats with that period and large
+    // angles lose precision
+    let chirp: Vec<Complex> = (0..n)
+        .map(|k| {
+            let angle = sign * PI * ((k * k) % (2 * n)) as f64 / n as f64;
+            let (sin, cos) = angle.sin_cos();
+            Complex::new(cos, sin)
+        })
+        .collect();
+
+    let m = (2 * n - 1).next_power_of_two();
+    let mut a = vec![Complex::default(); m];
+    let mut b = vec![Complex::default(); m];
+    for k in 0..n {
+        a[k] = input[k] * chirp[k];
+        b[k] = chirp[k].conjugate();
+        if k > 0 {
+            b[m - k] = chirp[k].conjugate();
+        }
+    }
+
+    radix2(&mut a, false);
+    radix2(&mut b, false);
+    for (x, y) in a.iter_mut().zip(&b) {
+        *x = *x * *y;
+    }
+    radix2(&mut a, true);
+
+    let scale = 1. / m as f64;
+    (0..n).map(|k| a[k] * chirp[k] * scale).collect()
+}
+
+/// Calculates the discrete Fourier transform of any number of values, without scaling
+fn transform(input: &[Complex], inverse: bool) -> Vec<Complex> {
+    if input.is_empty() {
+        Vec::new()
+    } else if input.len().is_power_of_two() {
+        let mut data = input.to_vec();
+        radix2(&mut data, inverse);
+        data
+    } else {
+        bluestein(input, inverse)
+    }
+}
+
+impl DynamicVector {
+    /// Calculates the discrete Fourier transform of a real signal
+    ///
+    /// Element `k` of the result is the sum of `self[j] * e^(-2πi jk / n)`. Lengths that are a
+    /// power of two are fastest, but any length is supported in `O(n log n)` time.
+    ///
+    /// ## Returns
+    ///
+    /// The full complex spectrum, of the same length as the signal. For a real signal element
+    /// `n - k` is the conjugate of element `k`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Complex, DynamicVector};
+    ///
+    /// let signal = DynamicVector::from(vec![1., 0., -1., 0.]);
+    /// let spectrum = signal.fft();
+    ///
+    /// assert_eq!(
+    ///     spectrum.as_slice(),
+    ///     &[Complex::new(0., 0.), Complex::new(2., 0.), Complex::new(0., 0.), Complex::new(2., 0.)]
+    /// );
+    /// ```
+    pub fn fft(&self) -> DynamicComplexVector {
+        DynamicComplexVector::from(self).fft()
+    }
+}
+
+impl DynamicComplexVector {
+    /// Calculates the discrete Fourier transform
+    ///
+    /// Element `k` of the result is the sum of `self[j] * e^(-2πi jk / n)`. Lengths that are a
+    /// power of two are fastest, but any length is supported in `O(n log n)` time.
+    ///
+    /// ## Returns
+    ///
+    /// The spectrum, of the same length as this vector
+    pub fn fft(&self) -> DynamicComplexVector {
+        DynamicComplexVector::from(transform(self.as_slice(), false))
+    }
+
+    /// Calculates the inverse discrete Fourier transform
+    ///
+    /// The result is scaled by `1 / n`, so `v.fft().ifft()` gives back `v` up to rounding.
+    ///
+    /// ## Returns
+    ///
+    /// The signal, of the same length as this vector. Use [`DynamicComplexVector::real`] to get
+    /// the signal back as a [`DynamicVector`] if it was real to begin with
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::DynamicVector;
+    ///
+    /// let signal = DynamicVector::from(vec![0.5, 2., -1., 3., 0.]);
+    /// let round_trip = signal.fft().ifft().real();
+    ///
+    /// for (a, b) in round_trip.as_slice().iter().zip(signal.as_slice()) {
+    ///     assert!((a - b).abs() < 1e-12);
+    /// }
+    /// ```
+    pub fn ifft(&self) -> DynamicComplexVector {
+        let scale = 1. / self.len() as f64;
+        transform(self.as_slice(), true)
+            .into_iter()
+            .map(|z| z * scale)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The transform computed straight from its definition
+    fn naive_dft(input: &[Complex]) -> Vec<Complex> {
+        let n = input.len();
+        (0..n)
+            .map(|k| {
+                input
+                    .iter()
+                    .enumerate()
+                    .fold(Complex::default(), |sum, (j, x)| {
+                        let angle = -2. * PI * ((j * k) % n) as f64 / n as f64;
+                        sum + *x * Complex::from_polar(1., angle)
+                    })
+            })
+            .collect()
+    }
+
+    fn signal(n: usize) -> DynamicComplexVector {
+        (0..n)
+            .map(|i| Complex::new((i as f64 * 0.7).sin() + 0.3, (i as f64 * 1.3).cos()))
+            .collect()
+    }
+
+    #[test]
+    fn test_matches_definition() {
+        for n in [1, 2, 3, 5, 6, 8, 12, 16, 17] {
+            let v = signal(n);
+            let expected = naive_dft(v.as_slice());
+            for (a, b) in v.fft().as_slice().iter().zip(&expected) {
+                assert!((*a - *b).magnitude() < 1e-10, "n = {}", n);
+            }
+            for (a, b) in v.fft().ifft().as_slice().iter().zip(v.as_slice()) {
+                assert!((*a - *b).magnitude() < 1e-12, "n = {}", n);
+            }
+        }
+        assert!(DynamicComplexVector::new(0).fft().is_empty());
+        assert!(DynamicComplexVector::new(0).ifft().is_empty());
+    }
+
+    #[test]
+    fn test_real_signal() {
+        // A cosine at 3 cycles per window puts all of its energy in bins 3 and n - 3
+        let n = 20;
+        let signal: DynamicVector = (0..n)
+            .map(|i| (2. * PI * 3. * i as f64 / n as f64).cos())
+            .collect();
+        let spectrum = signal.fft();
+        for k in 0..n {
+            let expected = if k == 3 || k == n - 3 { 10. } else { 0. };
+            assert!((spectrum.get(k).magnitude() - expected).abs() < 1e-10);
+        }
+    }
+}
@@ -0,0 +1,480 @@
+use crate::{Vector2, Vector3};
+use core::fmt;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+const FRAC_BITS: u32 = 32;
+
+/// A signed Q32.32 fixed-point number
+///
+/// The value is stored as an `i64` counting steps of 2^-32, so it holds about ±2 billion with a
+/// resolution of roughly 2.3e-10. Every operation is plain integer arithmetic, so results are
+/// bit-for-bit identical on every platform, which floats do not guarantee. This makes it suitable
+/// for lockstep simulations where all peers must stay in sync.
+///
+/// Multiplication and division round towards negative infinity, and results that do not fit wrap
+/// around like the underlying integers do.
+///
+/// ## Example
+///
+/// ```
+/// use libvector::Fixed;
+///
+/// let a = Fixed::from_int(3);
+/// let b = Fixed::from_f64(0.5);
+///
+/// assert_eq!(a * b, Fixed::from_f64(1.5));
+/// assert_eq!((a / b).to_f64(), 6.);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Fixed(i64);
+
+impl Fixed {
+    pub const ZERO: Fixed = Fixed(0);
+    pub const ONE: Fixed = Fixed(1 << FRAC_BITS);
+
+    /// Creates a fixed-point number from its raw bits
+    ///
+    /// ## Arguments
+    ///
+    /// * `bits` - The value multiplied by 2^32
+    pub const fn from_bits(bits: i64) -> Fixed {
+        Fixed(bits)
+    }
+
+    /// Gets the raw bits of the number, which is the value multiplied by 2^32
+    pub const fn to_bits(self) -> i64 {
+        self.0
+    }
+
+    /// Creates a fixed-point number from an integer
+    pub const fn from_int(value: i32) -> Fixed {
+        Fixed((value as i64) << FRAC_BITS)
+    }
+
+    /// Converts a float to the nearest fixed-point number
+    ///
+    /// This should only be used for input such as level data, as floats are not deterministic.
+    /// Halfway values round away from zero, values out of range saturate and NaN becomes zero.
+    pub fn from_f64(value: f64) -> Fixed {
+        let scaled = value * (1_u64 << FRAC_BITS) as f64;
+        let truncated = scaled as i64;
+        let remainder = scaled - truncated as f64;
+        if remainder >= 0.5 {
+            Fixed(truncated.saturating_add(1))
+        } else if remainder <= -0.5 {
+            Fixed(truncated.saturating_sub(1))
+        } else {
+            Fixed(truncated)
+        }
+    }
+
+    /// Converts the number to the nearest float
+    pub fn to_f64(self) -> f64 {
+        self.0 as f64 / (1_u64 << FRAC_BITS) as f64
+    }
+
+    /// Calculates the square root of the number
+    ///
+    /// ## Returns
+    ///
+    /// The square root rounded down, or `None` if the number is negative
+    pub fn sqrt(self) -> Option<Fixed> {
+        if self.0 < 0 {
+            return None;
+        }
+        // sqrt(bits * 2^32) = sqrt(bits / 2^32) * 2^32
+        Some(Fixed(((self.0 as u128) << FRAC_BITS).isqrt() as i64))
+    }
+
+    /// Calculates the absolute value of the number
+    pub fn abs(self) -> Fixed {
+        Fixed(self.0.wrapping_abs())
+    }
+}
+
+impl Add for Fixed {
+    type Output = Fixed;
+
+    fn add(self, other: Fixed) -> Fixed {
+        Fixed(self.0.wrapping_add(other.0))
+    }
+}
+
+impl Sub for Fixed {
+    type Output = Fixed;
+
+    fn sub(self, other: Fixed) -> Fixed {
+        Fixed(self.0.wrapping_sub(other.0))
+    }
+}
+
+impl Mul for Fixed {
+    type Output = Fixed;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, other: Fixed) -> Fixed {
+        Fixed(((self.0 as i128 * other.0 as i128) >> FRAC_BITS) as i64)
+    }
+}
+
+impl Div for Fixed {
+    type Output = Fixed;
+
+    /// ## Panics
+    ///
+    /// If `other` is zero
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, other: Fixed) -> Fixed {
+        let (numerator, denominator) = ((self.0 as i128) << FRAC_BITS, other.0 as i128);
+        let mut quotient = numerator / denominator;
+        if numerator % denominator != 0 && (numerator < 0) != (denominator < 0) {
+            quotient -= 1;
+        }
+        Fixed(quotient as i64)
+    }
+}
+
+impl Neg for Fixed {
+    type Output = Fixed;
+
+    fn neg(self) -> Fixed {
+        Fixed(self.0.wrapping_neg())
+    }
+}
+
+impl fmt::Display for Fixed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.to_f64().fmt(f)
+    }
+}
+
+/// Sums the products of pairs of fixed-point numbers at full precision and rounds once
+///
+/// Each product of two Q32.32 numbers is exact as a Q64.64 `i128`, so only the final shift loses
+/// precision, which keeps dot products and magnitudes as accurate as the format allows.
+fn sum_of_products<const N: usize>(pairs: [(Fixed, Fixed); N]) -> i128 {
+    pairs.iter().fold(0_i128, |sum, (a, b)| {
+        sum.wrapping_add(a.0 as i128 * b.0 as i128)
+    })
+}
+
+/// Converts a Q64.64 sum back to a Q32.32 number
+fn from_wide(wide: i128) -> Fixed {
+    Fixed((wide >> FRAC_BITS) as i64)
+}
+
+/// The square root of a non-negative Q64.64 sum, as a Q32.32 number
+fn sqrt_wide(wide: i128) -> Fixed {
+    // sqrt(bits / 2^64) * 2^32 = sqrt(bits)
+    Fixed((wide as u128).isqrt() as i64)
+}
+
+/// A 2D vector of [`Fixed`] components for deterministic simulation
+///
+/// This does not implement [`Vector`](crate::Vector), whose methods return `f64`. Instead its
+/// own `dot`, `magnitude` and `normalize` stay in fixed-point, so two machines running the same
+/// simulation always produce the same bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct FixedVector2 {
+    pub x: Fixed,
+    pub y: Fixed,
+}
+
+impl FixedVector2 {
+    /// Create a new fixed-point vector
+    pub fn new(x: Fixed, y: Fixed) -> FixedVector2 {
+        FixedVector2 { x, y }
+    }
+
+    /// Calculate the dot product of two vectors, rounding once at the end
+    ///
+    /// ## Arguments
+    ///
+    /// * `other` - The other vector to calculate the dot product with
+    ///
+    /// ## Returns
+    ///
+    /// The dot product of the two vectors
+    pub fn dot(&self, other: &FixedVector2) -> Fixed {
+        from_wide(sum_of_products([(self.x, other.x), (self.y, other.y)]))
+    }
+
+    /// Calculate the magnitude of the vector
+    ///
+    /// The squared length is kept at double precision before taking the square root, so this
+    /// does not overflow for any vector whose magnitude fits in a [`Fixed`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Fixed, FixedVector2};
+    ///
+    /// let v = FixedVector2::new(Fixed::from_int(3), Fixed::from_int(4));
+    ///
+    /// assert_eq!(v.magnitude(), Fixed::from_int(5));
+    /// ```
+    pub fn magnitude(&self) -> Fixed {
+        sqrt_wide(sum_of_products([(self.x, self.x), (self.y, self.y)]))
+    }
+
+    /// Normalize the vector
+    ///
+    /// ## Returns
+    ///
+    /// A vector with a magnitude of 1, or `None` if the vector is zero
+    pub fn normalize(&self) -> Option<FixedVector2> {
+        let magnitude = self.magnitude();
+        if magnitude == Fixed::ZERO {
+            return None;
+        }
+        Some(*self / magnitude)
+    }
+
+    /// Converts a float vector to the nearest fixed-point vector
+    pub fn from_vector2(v: Vector2) -> FixedVector2 {
+        FixedVector2::new(Fixed::from_f64(v.x), Fixed::from_f64(v.y))
+    }
+
+    /// Converts the vector to the nearest float vector, for rendering
+    pub fn to_vector2(&self) -> Vector2 {
+        Vector2::new(self.x.to_f64(), self.y.to_f64())
+    }
+}
+
+impl Add for FixedVector2 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        FixedVector2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for FixedVector2 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        FixedVector2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<Fixed> for FixedVector2 {
+    type Output = Self;
+
+    fn mul(self, scalar: Fixed) -> Self {
+        FixedVector2::new(self.x * scalar, self.y * scalar)
+    }
+}
+
+impl Div<Fixed> for FixedVector2 {
+    type Output = Self;
+
+    fn div(self, scalar: Fixed) -> Self {
+        FixedVector2::new(self.x / scalar, self.y / scalar)
+    }
+}
+
+impl Neg for FixedVector2 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        FixedVector2::new(-self.x, -self.y)
+    }
+}
+
+/// A 3D vector of [`Fixed`] components for deterministic simulation
+///
+/// Like [`FixedVector2`], its `dot`, `magnitude` and `normalize` stay in fixed-point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct FixedVector3 {
+    pub x: Fixed,
+    pub y: Fixed,
+    pub z: Fixed,
+}
+
+impl FixedVector3 {
+    /// Create a new fixed-point vector
+    pub fn new(x: Fixed, y: Fixed, z: Fixed) -> FixedVector3 {
+        FixedVector3 { x, y, z }
+    }
+
+    /// Calculate the dot product of two vectors, rounding once at the end
+    ///
+    /// ## Arguments
+    ///
+    /// * `other` - The other vector to calculate the dot product with
+    ///
+    /// ## Returns
+    ///
+    /// The dot product of the two vectors
+    pub fn dot(&self, other: &FixedVector3) -> Fixed {
+        from_wide(sum_of_products([
+            (self.x, other.x),
+            (self.y, other.y),
+            (self.z, other.z),
+        ]))
+    }
+
+    /// Calculate the cross product of two vectors
+    ///
+    /// ## Arguments
+    ///
+    /// * `other` - The other vector to calculate the cross product with
+    ///
+    /// ## Returns
+    ///
+    /// A vector perpendicular to both vectors
+    pub fn cross(&self, other: &FixedVector3) -> FixedVector3 {
+        FixedVector3::new(
+            from_wide(sum_of_products([(self.y, other.z), (-self.z, other.y)])),
+            from_wide(sum_of_products([(self.z, other.x), (-self.x, other.z)])),
+            from_wide(sum_of_products([(self.x, other.y), (-self.y, other.x)])),
+        )
+    }
+
+    /// Calculate the magnitude of the vector
+    ///
+    /// The squared length is kept at double precision before taking the square root, so this
+    /// does not overflow for any vector whose magnitude fits in a [`Fixed`].
+    pub fn magnitude(&self) -> Fixed {
+        sqrt_wide(sum_of_products([
+            (self.x, self.x),
+            (self.y, self.y),
+            (self.z, self.z),
+        ]))
+    }
+
+    /// Normalize the vector
+    ///
+    /// ## Returns
+    ///
+    /// A vector with a magnitude of 1, or `None` if the vector is zero
+    pub fn normalize(&self) -> Option<FixedVector3> {
+        let magnitude = self.magnitude();
+        if magnitude == Fixed::ZERO {
+            return None;
+        }
+        Some(*self / magnitude)
+    }
+
+    /// Converts a float vector to the nearest fixed-point vector
+    pub fn from_vector3(v: Vector3) -> FixedVector3 {
+        FixedVector3::new(
+            Fixed::from_f64(v.x),
+            Fixed::from_f64(v.y),
+            Fixed::from_f64(v.z),
+        )
+    }
+
+    /// Converts the vector to the nearest float vector, for rendering
+    pub fn to_vector3(&self) -> Vector3 {
+        Vector3::new(self.x.to_f64(), self.y.to_f64(), self.z.to_f64())
+    }
+}
+
+impl Add for FixedVector3 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        FixedVector3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for FixedVector3 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        FixedVector3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl Mul<Fixed> for FixedVector3 {
+    type Output = Self;
+
+    fn mul(self, scalar: Fixed) -> Self {
+        FixedVector3::new(self.x * scalar, self.y * scalar, self.z * scalar)
+    }
+}
+
+impl Div<Fixed> for FixedVector3 {
+    type Output = Self;
+
+    fn div(self, scalar: Fixed) -> Self {
+        FixedVector3::new(self.x / scalar, self.y / scalar, self.z / scalar)
+    }
+}
+
+impl Neg for FixedVector3 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        FixedVector3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fx(value: f64) -> Fixed {
+        Fixed::from_f64(value)
+    }
+
+    #[test]
+    fn test_fixed_arithmetic() {
+        assert_eq!(fx(1.5) + fx(2.25), fx(3.75));
+        assert_eq!(fx(1.5) - fx(2.25), fx(-0.75));
+        assert_eq!(fx(-1.5) * fx(2.), fx(-3.));
+        assert_eq!(fx(1.) / fx(4.), fx(0.25));
+        // Division rounds down whatever the signs
+        let third = Fixed::ONE / Fixed::from_int(3);
+        assert_eq!(
+            Fixed::ONE / Fixed::from_int(-3),
+            -third - Fixed::from_bits(1)
+        );
+        assert_eq!(-Fixed::ONE, Fixed::from_int(-1));
+        assert_eq!(Fixed::from_int(7).to_bits(), 7 << 32);
+        assert_eq!(fx(-2.).abs(), fx(2.));
+    }
+
+    #[test]
+    fn test_fixed_sqrt() {
+        assert_eq!(Fixed::from_int(9).sqrt(), Some(Fixed::from_int(3)));
+        assert_eq!(Fixed::from_int(-1).sqrt(), None);
+        let root2 = Fixed::from_int(2).sqrt().unwrap().to_f64();
+        assert!((root2 - 2_f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_dot_keeps_precision() {
+        // Each product is just over half the resolution, so multiplying first would give zero
+        let tiny = Fixed::from_bits(46341);
+        let v = FixedVector2::new(tiny, tiny);
+        assert_eq!(tiny * tiny, Fixed::ZERO);
+        assert_eq!(v.dot(&v), Fixed::from_bits(1));
+    }
+
+    #[test]
+    fn test_magnitude_does_not_overflow() {
+        // The squared magnitude is far beyond the range of Fixed
+        let big = Fixed::from_int(1_000_000_000);
+        let v = FixedVector3::new(big, big, Fixed::ZERO);
+        let expected = 1e9 * 2_f64.sqrt();
+        assert!((v.magnitude().to_f64() - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_normalize() {
+        let v = FixedVector2::new(Fixed::from_int(3), Fixed::from_int(4));
+        let n = v.normalize().unwrap();
+        assert!((n.to_vector2().x - 0.6).abs() < 1e-9);
+        assert!((n.to_vector2().y - 0.8).abs() < 1e-9);
+        assert_eq!(FixedVector3::default().normalize(), None);
+    }
+
+    #[test]
+    fn test_cross() {
+        let x = FixedVector3::from_vector3(Vector3::new(1., 0., 0.));
+        let y = FixedVector3::from_vector3(Vector3::new(0., 1., 0.));
+        assert_eq!(x.cross(&y).to_vector3(), Vector3::new(0., 0., 1.));
+    }
+}
@@ -0,0 +1,269 @@
+use crate::{Plane, Sphere, Vector3, AABB3};
+
+/// A viewing volume bounded by six planes, used for visibility culling
+///
+/// The planes are ordered left, right, bottom, top, near, far and their normals point into the
+/// frustum, so a point is inside when it is on the positive side of every plane. A plane is
+/// `None` when the frustum is unbounded on that side, such as the far plane of a projection with
+/// an infinite far distance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Frustum {
+    pub planes: [Option<Plane>; 6],
+}
+
+impl Frustum {
+    /// Creates a frustum from its bounding planes
+    ///
+    /// ## Arguments
+    ///
+    /// * `planes` - The left, right, bottom, top, near and far planes, facing inwards
+    ///
+    /// ## Returns
+    ///
+    /// A new frustum
+    pub fn new(planes: [Plane; 6]) -> Frustum {
+        Frustum {
+            planes: planes.map(Some),
+        }
+    }
+
+    /// Extracts the frustum of a view-projection matrix
+    ///
+    /// The matrix is given row by row and transforms column vectors, so a clip space position is
+    /// `matrix * [x, y, z, 1]`. Clip space depth is expected to range from `-w` to `w`, as in
+    /// OpenGL.
+    ///
+    /// A row combination with no normal and a non-negative constant holds for every point, as for
+    /// the far plane of a perspective projection with an infinite far distance, so that side of
+    /// the frustum is left unbounded.
+    ///
+    /// ## Arguments
+    ///
+    /// * `matrix` - The combined view and projection matrix, in row-major order
+    ///
+    /// ## Returns
+    ///
+    /// The frustum, or `None` if a plane has no normal but excludes every point, or if the matrix
+    /// is not finite
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Frustum, Vector3};
+    ///
+    /// // An orthographic projection of the cube from -1 to 1
+    /// let identity = [
+    ///     [1., 0., 0., 0.],
+    ///     [0., 1., 0., 0.],
+    ///     [0., 0., 1., 0.],
+    ///     [0., 0., 0., 1.],
+    /// ];
+    /// let frustum = Frustum::from_matrix(&identity).unwrap();
+    ///
+    /// assert!(frustum.contains_point(&Vector3::new(0.5, -0.5, 1.)));
+    /// assert!(!frustum.contains_point(&Vector3::new(0., 0., 2.)));
+    /// ```
+    pub fn from_matrix(matrix: &[[f64; 4]; 4]) -> Option<Frustum> {
+        let [x, y, z, w] = matrix;
+        let plane = |sign: f64, row: &[f64; 4]| {
+            let [a, b, c, d] = [0, 1, 2, 3].map(|i| w[i] + sign * row[i]);
+            if a == 0. && b == 0. && c == 0. && d >= 0. {
+                return Some(None);
+            }
+            Plane::from_coefficients(a, b, c, d).map(Some)
+        };
+        Some(Frustum {
+            planes: [
+                plane(1., x)?,
+                plane(-1., x)?,
+                plane(1., y)?,
+                plane(-1., y)?,
+                plane(1., z)?,
+                plane(-1., z)?,
+            ],
+        })
+    }
+
+    /// Checks whether a point lies inside (or on the boundary of) the frustum
+    ///
+    /// ## Arguments
+    ///
+    /// * `point` - The point to test
+    ///
+    /// ## Returns
+    ///
+    /// `true` if the point is inside the frustum
+    pub fn contains_point(&self, point: &Vector3) -> bool {
+        self.planes
+            .iter()
+            .flatten()
+            .all(|plane| plane.signed_distance(point) >= 0.)
+    }
+
+    /// Checks whether a sphere is at least partly inside the frustum
+    ///
+    /// Like most culling tests this is conservative: spheres near the corners of the frustum can
+    /// be reported as intersecting while lying just outside it, but visible spheres are never
+    /// rejected.
+    ///
+    /// ## Arguments
+    ///
+    /// * `sphere` - The sphere to test
+    ///
+    /// ## Returns
+    ///
+    /// `false` if the sphere is certainly outside the frustum
+    pub fn intersects_sphere(&self, sphere: &Sphere) -> bool {
+        self.planes
+            .iter()
+            .flatten()
+            .all(|plane| plane.signed_distance(&sphere.center) >= -sphere.radius)
+    }
+
+    /// Checks whether a bounding box is at least partly inside the frustum
+    ///
+    /// For each plane only the corner of the box furthest along its normal is tested, so this is
+    /// conservative in the same way as [`Frustum::intersects_sphere`].
+    ///
+    /// ## Arguments
+    ///
+    /// * `aabb` - The bounding box to test
+    ///
+    /// ## Returns
+    ///
+    /// `false` if the box is certainly outside the frustum
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Frustum, Plane, UnitVector3, Vector3, AABB3};
+    ///
+    /// let wall = |x: f64, y: f64, z: f64, d: f64| {
+    ///     Plane::new(UnitVector3::new_checked(Vector3::new(x, y, z)).unwrap(), d)
+    /// };
+    /// let frustum = Frustum::new([
+    ///     wall(1., 0., 0., -1.),
+    ///     wall(-1., 0., 0., -1.),
+    ///     wall(0., 1., 0., -1.),
+    ///     wall(0., -1., 0., -1.),
+    ///     wall(0., 0., 1., -1.),
+    ///     wall(0., 0., -1., -1.),
+    /// ]);
+    ///
+    /// assert!(frustum.intersects_aabb(&AABB3::new(Vector3::new(0.5, 0.5, 0.5), Vector3::new(3., 3., 3.))));
+    /// assert!(!frustum.intersects_aabb(&AABB3::new(Vector3::new(2., 0., 0.), Vector3::new(3., 1., 1.))));
+    /// ```
+    pub fn intersects_aabb(&self, aabb: &AABB3) -> bool {
+        self.planes.iter().flatten().all(|plane| {
+            let pick = |normal: f64, min: f64, max: f64| if normal >= 0. { max } else { min };
+            let corner = Vector3::new(
+                pick(plane.normal.x, aabb.min.x, aabb.max.x),
+                pick(plane.normal.y, aabb.min.y, aabb.max.y),
+                pick(plane.normal.z, aabb.min.z, aabb.max.z),
+            );
+            plane.signed_distance(&corner) >= 0.
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A perspective projection looking down -z with a 90 degree field of view, near plane at 1
+    /// and far plane at 9
+    fn perspective() -> Frustum {
+        let (near, far) = (1., 9.);
+        Frustum::from_matrix(&[
+            [1., 0., 0., 0.],
+            [0., 1., 0., 0.],
+            [
+                0.,
+                0.,
+                (far + near) / (near - far),
+                2. * far * near / (near - far),
+            ],
+            [0., 0., -1., 0.],
+        ])
+        .unwrap()
+    }
+
+    /// The same projection with the far plane at infinity
+    fn infinite_perspective() -> Frustum {
+        let near = 1.;
+        Frustum::from_matrix(&[
+            [1., 0., 0., 0.],
+            [0., 1., 0., 0.],
+            [0., 0., -1., -2. * near],
+            [0., 0., -1., 0.],
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_from_matrix() {
+        let frustum = perspective();
+        assert!(frustum.planes.iter().all(Option::is_some));
+        let near = frustum.planes[4].unwrap();
+        assert!(near.normal.z < 0.);
+        assert!((near.signed_distance(&Vector3::new(0., 0., -1.))).abs() < 1e-12);
+        let far = frustum.planes[5].unwrap();
+        assert!((far.signed_distance(&Vector3::new(0., 0., -9.))).abs() < 1e-12);
+        // Every point is mapped to the origin of clip space, which is on every boundary
+        let everywhere = Frustum::from_matrix(&[[0.; 4]; 4]).unwrap();
+        assert_eq!(everywhere.planes, [None; 6]);
+        assert!(everywhere.contains_point(&Vector3::new(1e9, -1e9, 1e9)));
+    }
+
+    #[test]
+    fn test_from_infinite_matrix() {
+        let frustum = infinite_perspective();
+        assert_eq!(frustum.planes[5], None);
+        assert!(frustum.planes[..5].iter().all(Option::is_some));
+        let near = frustum.planes[4].unwrap();
+        assert!((near.signed_distance(&Vector3::new(0., 0., -1.))).abs() < 1e-12);
+
+        assert!(frustum.contains_point(&Vector3::new(0., 0., -5.)));
+        assert!(frustum.contains_point(&Vector3::new(900., 0., -1000.)));
+        assert!(!frustum.contains_point(&Vector3::new(1100., 0., -1000.)));
+        assert!(!frustum.contains_point(&Vector3::new(0., 0., -0.5)));
+        assert!(frustum.intersects_sphere(&Sphere::new(Vector3::new(0., 0., -1e6), 1.)));
+        let distant = AABB3::new(Vector3::new(-1., -1., -1e9), Vector3::new(1., 1., -1e8));
+        assert!(frustum.intersects_aabb(&distant));
+    }
+
+    #[test]
+    fn test_from_matrix_excluding_everything() {
+        let mut matrix = [[0.; 4]; 4];
+        matrix[0] = [1., 0., 0., 0.];
+        matrix[1] = [0., 1., 0., 0.];
+        matrix[2] = [0., 0., 0., 1.];
+        matrix[3] = [0., 0., 0., 0.5];
+        // w - z is the constant -0.5, which no point satisfies
+        assert_eq!(Frustum::from_matrix(&matrix), None);
+    }
+
+    #[test]
+    fn test_contains_point() {
+        let frustum = perspective();
+        assert!(frustum.contains_point(&Vector3::new(0., 0., -5.)));
+        assert!(frustum.contains_point(&Vector3::new(4., -4., -5.)));
+        assert!(!frustum.contains_point(&Vector3::new(6., 0., -5.)));
+        assert!(!frustum.contains_point(&Vector3::new(0., 0., -0.5)));
+        assert!(!frustum.contains_point(&Vector3::new(0., 0., -10.)));
+        assert!(!frustum.contains_point(&Vector3::new(0., 0., 5.)));
+    }
+
+    #[test]
+    fn test_intersections() {
+        let frustum = perspective();
+        assert!(frustum.intersects_sphere(&Sphere::new(Vector3::new(0., 0., -10.), 2.)));
+        assert!(!frustum.intersects_sphere(&Sphere::new(Vector3::new(0., 0., 2.), 0.5)));
+        let straddling = AABB3::new(Vector3::new(-1., -1., -12.), Vector3::new(1., 1., -8.));
+        assert!(frustum.intersects_aabb(&straddling));
+        let behind = AABB3::new(Vector3::new(-1., -1., 1.), Vector3::new(1., 1., 2.));
+        assert!(!frustum.intersects_aabb(&behind));
+        let beside = AABB3::new(Vector3::new(7., -1., -6.), Vector3::new(8., 1., -4.));
+        assert!(!frustum.intersects_aabb(&beside));
+    }
+}
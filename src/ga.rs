@@ -0,0 +1,466 @@
+#[cfg(not(feature = "std"))]
+use crate::math::FloatMath;
+use crate::{Quaternion, Radians, Vector, Vector3};
+use core::ops::{Add, Mul, Neg, Sub};
+
+/// An oriented plane segment in 3D space, the outer (wedge) product of two vectors
+///
+/// Where a cross product gives a vector perpendicular to two vectors, the wedge product gives the
+/// plane they span, with a magnitude equal to the area of their parallelogram. Bivectors describe
+/// rotations directly as "in this plane" instead of "around this axis", which is what
+/// [`Rotor3`] is built from.
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{Bivector3, Vector3};
+///
+/// let plane = Vector3::new(2., 0., 0.).wedge(&Vector3::new(0., 3., 0.));
+///
+/// assert_eq!(plane, Bivector3::new(6., 0., 0.));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Bivector3 {
+    /// The component in the plane of the X and Y axes
+    pub xy: f64,
+    /// The component in the plane of the Y and Z axes
+    pub yz: f64,
+    /// The component in the plane of the Z and X axes
+    pub zx: f64,
+}
+
+impl Bivector3 {
+    /// Creates a new bivector
+    ///
+    /// ## Arguments
+    ///
+    /// * `xy` - The component in the XY plane
+    /// * `yz` - The component in the YZ plane
+    /// * `zx` - The component in the ZX plane
+    ///
+    /// ## Returns
+    ///
+    /// A new bivector
+    pub fn new(xy: f64, yz: f64, zx: f64) -> Bivector3 {
+        Bivector3 { xy, yz, zx }
+    }
+
+    /// Calculates the magnitude of the bivector, the area of the plane segment
+    pub fn magnitude(&self) -> f64 {
+        (self.xy * self.xy + self.yz * self.yz + self.zx * self.zx).sqrt()
+    }
+
+    /// Calculates the exponential of the bivector
+    ///
+    /// For a bivector `B` this is `cos(|B|) + B / |B| * sin(|B|)`, the rotor rotating by `2|B|`
+    /// in the plane of `B`, against its orientation. It is the inverse of [`Rotor3::log`].
+    ///
+    /// ## Returns
+    ///
+    /// The rotor
+    pub fn exp(&self) -> Rotor3 {
+        let angle = self.magnitude();
+        if angle == 0. {
+            return Rotor3::identity();
+        }
+        let (sin, cos) = angle.sin_cos();
+        let scale = sin / angle;
+        Rotor3::new(cos, self.xy * scale, self.yz * scale, self.zx * scale)
+    }
+}
+
+impl Add for Bivector3 {
+    type Output = Bivector3;
+
+    fn add(self, other: Bivector3) -> Bivector3 {
+        Bivector3::new(self.xy + other.xy, self.yz + other.yz, self.zx + other.zx)
+    }
+}
+
+impl Sub for Bivector3 {
+    type Output = Bivector3;
+
+    fn sub(self, other: Bivector3) -> Bivector3 {
+        Bivector3::new(self.xy - other.xy, self.yz - other.yz, self.zx - other.zx)
+    }
+}
+
+impl Mul<f64> for Bivector3 {
+    type Output = Bivector3;
+
+    fn mul(self, scalar: f64) -> Bivector3 {
+        Bivector3::new(self.xy * scalar, self.yz * scalar, self.zx * scalar)
+    }
+}
+
+impl Neg for Bivector3 {
+    type Output = Bivector3;
+
+    fn neg(self) -> Bivector3 {
+        Bivector3::new(-self.xy, -self.yz, -self.zx)
+    }
+}
+
+impl Vector3 {
+    /// Calculates the wedge (outer) product of two vectors
+    ///
+    /// ## Arguments
+    ///
+    /// * `other` - The second vector
+    ///
+    /// ## Returns
+    ///
+    /// The bivector of the plane spanned by both vectors, oriented from `self` towards `other`.
+    /// Its components are the same numbers as the cross product, assigned to the plane
+    /// perpendicular to each axis
+    pub fn wedge(&self, other: &Vector3) -> Bivector3 {
+        Bivector3::new(
+            self.x * other.y - self.y * other.x,
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+        )
+    }
+}
+
+/// A rotation in 3D space expressed in geometric algebra, a scalar plus a bivector
+///
+/// Rotors hold the same four numbers as a [`Quaternion`] and compose the same way, but they name
+/// the plane of rotation rather than its axis. That makes rotations built from two vectors, and
+/// their interpolation through [`Rotor3::log`] and [`Bivector3::exp`], easy to derive and read.
+/// Only rotors with unit magnitude represent rotations. Use [`Rotor3::normalize`] to correct any
+/// drift after composing many rotations.
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{Rotor3, Vector, Vector3};
+///
+/// let x = Vector3::new(1., 0., 0.);
+/// let y = Vector3::new(0., 1., 0.);
+/// let rotor = Rotor3::between(&x, &y).unwrap();
+///
+/// assert!((rotor.rotate(&x) - y).magnitude() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rotor3 {
+    /// The scalar part
+    pub s: f64,
+    /// The component in the plane of the X and Y axes
+    pub xy: f64,
+    /// The component in the plane of the Y and Z axes
+    pub yz: f64,
+    /// The component in the plane of the Z and X axes
+    pub zx: f64,
+}
+
+impl Rotor3 {
+    /// Creates a new rotor
+    ///
+    /// ## Arguments
+    ///
+    /// * `s` - The scalar part
+    /// * `xy` - The XY component of the bivector part
+    /// * `yz` - The YZ component of the bivector part
+    /// * `zx` - The ZX component of the bivector part
+    ///
+    /// ## Returns
+    ///
+    /// A new rotor
+    pub fn new(s: f64, xy: f64, yz: f64, zx: f64) -> Rotor3 {
+        Rotor3 { s, xy, yz, zx }
+    }
+
+    /// Creates the rotor representing no rotation
+    ///
+    /// ## Returns
+    ///
+    /// The identity rotor
+    pub fn identity() -> Rotor3 {
+        Rotor3::new(1., 0., 0., 0.)
+    }
+
+    /// Creates a rotor rotating in a plane
+    ///
+    /// ## Arguments
+    ///
+    /// * `plane` - The plane to rotate in. It does not need to be normalized
+    /// * `angle` - The angle to rotate by, following the orientation of `plane` so that the plane
+    ///   of `a.wedge(&b)` turns `a` towards `b`. A plain `f64` is taken to be in radians
+    ///
+    /// ## Returns
+    ///
+    /// A unit rotor, or `None` if `plane` is zero
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Bivector3, Degrees, Rotor3, Vector, Vector3};
+    ///
+    /// let rotor = Rotor3::from_plane_angle(&Bivector3::new(1., 0., 0.), Degrees(90.)).unwrap();
+    /// let v = rotor.rotate(&Vector3::new(1., 0., 0.));
+    ///
+    /// assert!((v - Vector3::new(0., 1., 0.)).magnitude() < 1e-12);
+    /// ```
+    pub fn from_plane_angle(plane: &Bivector3, angle: impl Into<Radians>) -> Option<Rotor3> {
+        let magnitude = plane.magnitude();
+        if magnitude == 0. {
+            return None;
+        }
+        Some((*plane * (-angle.into().0 / 2. / magnitude)).exp())
+    }
+
+    /// Creates the rotor that turns one direction into another along the shortest arc
+    ///
+    /// ## Arguments
+    ///
+    /// * `from` - The starting direction, which does not need to be normalized
+    /// * `to` - The target direction, which does not need to be normalized
+    ///
+    /// ## Returns
+    ///
+    /// The rotor, or `None` if either direction is zero or they point in exactly opposite
+    /// directions, where the plane of rotation is not defined
+    pub fn between(from: &Vector3, to: &Vector3) -> Option<Rotor3> {
+        let from = from.with_magnitude(1.)?;
+        let to = to.with_magnitude(1.)?;
+        // The geometric product `to * from` is the rotor for twice the angle, so adding one and
+        // normalizing halves it
+        let plane = from.wedge(&to);
+        let rotor = Rotor3::new(1. + from.dot(&to), -plane.xy, -plane.yz, -plane.zx);
+        let magnitude = rotor.magnitude();
+        if magnitude <= f64::EPSILON {
+            return None;
+        }
+        Some(rotor * (1. / magnitude))
+    }
+
+    /// Gets the bivector part of the rotor
+    pub fn bivector(&self) -> Bivector3 {
+        Bivector3::new(self.xy, self.yz, self.zx)
+    }
+
+    /// Calculates the magnitude of the rotor
+    pub fn magnitude(&self) -> f64 {
+        (self.s * self.s + self.xy * self.xy + self.yz * self.yz + self.zx * self.zx).sqrt()
+    }
+
+    /// Scales the rotor back to unit magnitude
+    ///
+    /// ## Returns
+    ///
+    /// The normalized rotor
+    pub fn normalize(&self) -> Rotor3 {
+        *self * (1. / self.magnitude())
+    }
+
+    /// Calculates the reverse of the rotor, which for a unit rotor is the opposite rotation
+    ///
+    /// ## Returns
+    ///
+    /// The rotor with its bivector part negated
+    pub fn reverse(&self) -> Rotor3 {
+        Rotor3::new(self.s, -self.xy, -self.yz, -self.zx)
+    }
+
+    /// Calculates the logarithm of a unit rotor
+    ///
+    /// ## Returns
+    ///
+    /// The bivector `B` with `B.exp() == self` and a magnitude of at most `PI`. Its magnitude is
+    /// half the angle of rotation
+    pub fn log(&self) -> Bivector3 {
+        let bivector = self.bivector();
+        let sin = bivector.magnitude();
+        if sin == 0. {
+            return Bivector3::default();
+        }
+        bivector * (sin.atan2(self.s) / sin)
+    }
+
+    /// Interpolates between two rotations at a constant angular speed
+    ///
+    /// ## Arguments
+    ///
+    /// * `other` - The rotation to interpolate towards
+    /// * `t` - How far to interpolate, where 0 gives `self` and 1 gives `other`
+    ///
+    /// ## Returns
+    ///
+    /// The interpolated rotor, following the shorter of the two arcs between the rotations
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Bivector3, Rotor3};
+    ///
+    /// let plane = Bivector3::new(0., 1., 0.);
+    /// let a = Rotor3::identity();
+    /// let b = Rotor3::from_plane_angle(&plane, 2.).unwrap();
+    /// let halfway = a.slerp(&b, 0.5);
+    ///
+    /// let expected = Rotor3::from_plane_angle(&plane, 1.).unwrap();
+    /// assert!((halfway.s - expected.s).abs() < 1e-12 && (halfway.yz - expected.yz).abs() < 1e-12);
+    /// ```
+    pub fn slerp(&self, other: &Rotor3, t: f64) -> Rotor3 {
+        let mut difference = self.reverse() * *other;
+        // R and -R are the same rotation, pick the one that takes the short way round
+        if difference.s < 0. {
+            difference = difference * -1.;
+        }
+        *self * (difference.log() * t).exp()
+    }
+
+    /// Rotates a vector
+    ///
+    /// ## Arguments
+    ///
+    /// * `v` - The vector to rotate
+    ///
+    /// ## Returns
+    ///
+    /// The rotated vector, `R v R̃`
+    pub fn rotate(&self, v: &Vector3) -> Vector3 {
+        // The same sandwich product as the quaternion rotation, the axis of the rotation being
+        // the negated dual of the bivector part
+        let u = Vector3::new(-self.yz, -self.zx, -self.xy);
+        let t = u.cross(v) * 2.;
+        *v + t * self.s + u.cross(&t)
+    }
+}
+
+impl Default for Rotor3 {
+    fn default() -> Self {
+        Rotor3::identity()
+    }
+}
+
+impl Mul for Rotor3 {
+    type Output = Rotor3;
+
+    /// Composes two rotations with the geometric product, applying `other` first and then `self`
+    fn mul(self, other: Rotor3) -> Rotor3 {
+        Rotor3::new(
+            self.s * other.s - self.xy * other.xy - self.yz * other.yz - self.zx * other.zx,
+            self.s * other.xy + self.xy * other.s + self.zx * other.yz - self.yz * other.zx,
+            self.s * other.yz + self.yz * other.s + self.xy * other.zx - self.zx * other.xy,
+            self.s * other.zx + self.zx * other.s + self.yz * other.xy - self.xy * other.yz,
+        )
+    }
+}
+
+impl Mul<f64> for Rotor3 {
+    type Output = Rotor3;
+
+    fn mul(self, scalar: f64) -> Rotor3 {
+        Rotor3::new(
+            self.s * scalar,
+            self.xy * scalar,
+            self.yz * scalar,
+            self.zx * scalar,
+        )
+    }
+}
+
+impl From<Quaternion> for Rotor3 {
+    fn from(q: Quaternion) -> Self {
+        Rotor3::new(q.w, -q.z, -q.x, -q.y)
+    }
+}
+
+impl From<Rotor3> for Quaternion {
+    fn from(r: Rotor3) -> Self {
+        Quaternion::new(r.s, -r.yz, -r.zx, -r.xy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::consts::{FRAC_PI_2, PI};
+
+    fn assert_close(a: Vector3, b: Vector3) {
+        assert!((a - b).magnitude() < 1e-12, "{:?} != {:?}", a, b);
+    }
+
+    #[test]
+    fn test_wedge() {
+        let a = Vector3::new(1., 2., 3.);
+        let b = Vector3::new(-2., 0.5, 4.);
+        let plane = a.wedge(&b);
+        let cross = a.cross(&b);
+        assert_eq!(plane, Bivector3::new(cross.z, cross.x, cross.y));
+        assert_eq!(b.wedge(&a), -plane);
+        assert_eq!(a.wedge(&(a * 2.)), Bivector3::default());
+    }
+
+    #[test]
+    fn test_rotate() {
+        let yz = Bivector3::new(0., 1., 0.);
+        let r = Rotor3::from_plane_angle(&yz, FRAC_PI_2).unwrap();
+        assert_close(
+            r.rotate(&Vector3::new(0., 1., 0.)),
+            Vector3::new(0., 0., 1.),
+        );
+        assert_close(
+            r.rotate(&Vector3::new(1., 2., 0.)),
+            Vector3::new(1., 0., 2.),
+        );
+        assert_eq!(Rotor3::from_plane_angle(&Bivector3::default(), 1.), None);
+
+        // Agrees with the quaternion for the same rotation
+        let q = Quaternion::from_axis_angle(&Vector3::new(1., -2., 0.5), 2.5);
+        let r = Rotor3::from(q);
+        let v = Vector3::new(0.3, 4., -1.);
+        assert_close(r.rotate(&v), q.rotate(&v));
+        assert_eq!(Quaternion::from(r), q);
+    }
+
+    #[test]
+    fn test_composition() {
+        let a = Rotor3::from_plane_angle(&Bivector3::new(1., 0., 0.), FRAC_PI_2).unwrap();
+        let b = Rotor3::from_plane_angle(&Bivector3::new(1., 2., -1.), 0.7).unwrap();
+        let v = Vector3::new(0., 1., 2.);
+        assert_close((a * b).rotate(&v), a.rotate(&b.rotate(&v)));
+        assert_close((a * a.reverse()).rotate(&v), v);
+        assert!(((a * b * 3.).normalize().magnitude() - 1.).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_between() {
+        let from = Vector3::new(1., 2., -1.);
+        let to = Vector3::new(-3., 0., 2.);
+        let r = Rotor3::between(&from, &to).unwrap();
+        assert_close(r.rotate(&from).normalize(), to.normalize());
+        assert_close(
+            Rotor3::between(&from, &(from * 5.)).unwrap().rotate(&to),
+            to,
+        );
+        assert_eq!(Rotor3::between(&from, &(-from)), None);
+        assert_eq!(Rotor3::between(&from, &Vector3::new(0., 0., 0.)), None);
+    }
+
+    #[test]
+    fn test_exp_log() {
+        let plane = Bivector3::new(0.2, -0.4, 0.1);
+        let log = plane.exp().log();
+        assert!((log - plane).magnitude() < 1e-12);
+        assert_eq!(Rotor3::identity().log(), Bivector3::default());
+        // A half turn has a logarithm of magnitude PI / 2
+        let half = Rotor3::from_plane_angle(&Bivector3::new(0., 0., 3.), PI).unwrap();
+        assert!((half.log().magnitude() - FRAC_PI_2).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_slerp() {
+        let plane = Bivector3::new(1., 1., 0.);
+        let a = Rotor3::from_plane_angle(&plane, 0.5).unwrap();
+        let b = Rotor3::from_plane_angle(&plane, 2.5).unwrap();
+        let v = Vector3::new(2., -1., 0.5);
+        assert_close(a.slerp(&b, 0.).rotate(&v), a.rotate(&v));
+        assert_close(a.slerp(&b, 1.).rotate(&v), b.rotate(&v));
+        let quarter = Rotor3::from_plane_angle(&plane, 1.).unwrap();
+        assert_close(a.slerp(&b, 0.25).rotate(&v), quarter.rotate(&v));
+        // -b is the same rotation as b, and is still interpolated along the short arc
+        assert_close(a.slerp(&(b * -1.), 0.25).rotate(&v), quarter.rotate(&v));
+    }
+}
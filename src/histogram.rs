@@ -0,0 +1,321 @@
+use crate::{DynamicVector, Vector2};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Counts of values falling into equally wide bins
+///
+/// Created with [`histogram`] or [`histogram_range`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram {
+    /// The boundaries of the bins, one more than there are bins. Bin `i` covers
+    /// `edges[i]..edges[i + 1]`, and the last bin also includes its upper edge
+    pub edges: Vec<f64>,
+    /// The number of values in each bin
+    pub counts: Vec<usize>,
+}
+
+/// Counts of points falling into the cells of an equally spaced grid
+///
+/// Created with [`histogram2d`] or [`histogram2d_range`]. The counts are stored row by row with
+/// `x` varying fastest, the layout of an image whose rows run along `y`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram2d {
+    /// The boundaries of the columns, one more than there are columns
+    pub x_edges: Vec<f64>,
+    /// The boundaries of the rows, one more than there are rows
+    pub y_edges: Vec<f64>,
+    /// The number of points in each cell, the cell in column `x` and row `y` at
+    /// `y * (x_edges.len() - 1) + x`
+    pub counts: Vec<usize>,
+}
+
+impl Histogram2d {
+    /// Gets the number of columns of the grid
+    pub fn x_bins(&self) -> usize {
+        self.x_edges.len() - 1
+    }
+
+    /// Gets the number of rows of the grid
+    pub fn y_bins(&self) -> usize {
+        self.y_edges.len() - 1
+    }
+
+    /// Gets the number of points in a cell
+    ///
+    /// ## Arguments
+    ///
+    /// * `x` - The column of the cell
+    /// * `y` - The row of the cell
+    ///
+    /// ## Returns
+    ///
+    /// The number of points that fell into the cell
+    ///
+    /// ## Panics
+    ///
+    /// If the cell is out of bounds
+    pub fn get(&self, x: usize, y: usize) -> usize {
+        assert!(x < self.x_bins() && y < self.y_bins(), "cell out of bounds");
+        self.counts[y * self.x_bins() + x]
+    }
+}
+
+/// Calculates the boundaries of `bins` equally wide bins from `min` to `max`
+fn bin_edges(min: f64, max: f64, bins: usize) -> Vec<f64> {
+    (0..=bins)
+        .map(|i| min + (max - min) * i as f64 / bins as f64)
+        .collect()
+}
+
+/// Finds the bin a value falls into, if any
+fn bin_index(value: f64, min: f64, max: f64, bins: usize) -> Option<usize> {
+    if !(min..=max).contains(&value) {
+        return None;
+    }
+    // The upper edge belongs to the last bin
+    Some((((value - min) / (max - min) * bins as f64) as usize).min(bins - 1))
+}
+
+/// Finds the range covered by the finite values, widened if it is empty like NumPy does
+fn value_range(values: impl Iterator<Item = f64>) -> (f64, f64) {
+    let (min, max) = values
+        .filter(|value| value.is_finite())
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+            (min.min(value), max.max(value))
+        });
+    if min > max {
+        (0., 1.)
+    } else if min == max {
+        (min - 0.5, max + 0.5)
+    } else {
+        (min, max)
+    }
+}
+
+/// Counts values into equally wide bins spanning the range of the values
+///
+/// `NaN` and infinite values are left out. If every value is the same, the bins span from half
+/// below it to half above it.
+///
+/// ## Arguments
+///
+/// * `data` - The values to count
+/// * `bins` - The number of bins
+///
+/// ## Returns
+///
+/// The histogram of the values
+///
+/// ## Panics
+///
+/// If `bins` is zero
+///
+/// ## Example
+///
+/// ```
+/// use libvector::histogram;
+///
+/// let h = histogram(&[1., 2., 2., 3., 4.], 3);
+///
+/// assert_eq!(h.edges, vec![1., 2., 3., 4.]);
+/// assert_eq!(h.counts, vec![1, 2, 2]);
+/// ```
+pub fn histogram(data: &[f64], bins: usize) -> Histogram {
+    histogram_range(data, bins, value_range(data.iter().copied()))
+}
+
+/// Counts values into equally wide bins spanning a given range
+///
+/// Values outside of the range, including `NaN`, are left out.
+///
+/// ## Arguments
+///
+/// * `data` - The values to count
+/// * `bins` - The number of bins
+/// * `range` - The lower edge of the first bin and the upper edge of the last bin
+///
+/// ## Returns
+///
+/// The histogram of the values
+///
+/// ## Panics
+///
+/// If `bins` is zero or the range is empty
+pub fn histogram_range(data: &[f64], bins: usize, range: (f64, f64)) -> Histogram {
+    let (min, max) = range;
+    assert!(bins > 0, "a histogram needs at least one bin");
+    assert!(min < max, "the range of a histogram must not be empty");
+    let mut counts = vec![0; bins];
+    for &value in data {
+        if let Some(i) = bin_index(value, min, max, bins) {
+            counts[i] += 1;
+        }
+    }
+    Histogram {
+        edges: bin_edges(min, max, bins),
+        counts,
+    }
+}
+
+/// Counts points into the cells of a grid spanning the bounding box of the points
+///
+/// Points with a `NaN` or infinite component are left out. Along an axis where every point has
+/// the same coordinate, the grid spans from half below it to half above it.
+///
+/// ## Arguments
+///
+/// * `points` - The points to count
+/// * `x_bins` - The number of columns of the grid
+/// * `y_bins` - The number of rows of the grid
+///
+/// ## Returns
+///
+/// The counts of every cell of the grid
+///
+/// ## Panics
+///
+/// If either number of bins is zero
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{histogram2d, Vector2};
+///
+/// let points = [
+///     Vector2::new(0., 0.),
+///     Vector2::new(0.2, 0.1),
+///     Vector2::new(1., 1.),
+/// ];
+/// let grid = histogram2d(&points, 2, 2);
+///
+/// assert_eq!(grid.get(0, 0), 2);
+/// assert_eq!(grid.get(1, 1), 1);
+/// assert_eq!(grid.counts, vec![2, 0, 0, 1]);
+/// ```
+pub fn histogram2d(points: &[Vector2], x_bins: usize, y_bins: usize) -> Histogram2d {
+    let finite = || points.iter().filter(|p| p.x.is_finite() && p.y.is_finite());
+    let (min_x, max_x) = value_range(finite().map(|p| p.x));
+    let (min_y, max_y) = value_range(finite().map(|p| p.y));
+    histogram2d_range(
+        points,
+        x_bins,
+        y_bins,
+        Vector2::new(min_x, min_y),
+        Vector2::new(max_x, max_y),
+    )
+}
+
+/// Counts points into the cells of a grid spanning a given rectangle
+///
+/// Points outside of the rectangle are left out.
+///
+/// ## Arguments
+///
+/// * `points` - The points to count
+/// * `x_bins` - The number of columns of the grid
+/// * `y_bins` - The number of rows of the grid
+/// * `min` - The lower corner of the grid
+/// * `max` - The upper corner of the grid
+///
+/// ## Returns
+///
+/// The counts of every cell of the grid
+///
+/// ## Panics
+///
+/// If either number of bins is zero or the rectangle is empty along either axis
+pub fn histogram2d_range(
+    points: &[Vector2],
+    x_bins: usize,
+    y_bins: usize,
+    min: Vector2,
+    max: Vector2,
+) -> Histogram2d {
+    assert!(
+        x_bins > 0 && y_bins > 0,
+        "a histogram needs at least one bin"
+    );
+    assert!(
+        min.x < max.x && min.y < max.y,
+        "the range of a histogram must not be empty"
+    );
+    let mut counts = vec![0; x_bins * y_bins];
+    for p in points {
+        let x = bin_index(p.x, min.x, max.x, x_bins);
+        let y = bin_index(p.y, min.y, max.y, y_bins);
+        if let (Some(x), Some(y)) = (x, y) {
+            counts[y * x_bins + x] += 1;
+        }
+    }
+    Histogram2d {
+        x_edges: bin_edges(min.x, max.x, x_bins),
+        y_edges: bin_edges(min.y, max.y, y_bins),
+        counts,
+    }
+}
+
+impl DynamicVector {
+    /// Counts the elements of the vector into equally wide bins
+    ///
+    /// See [`histogram`] for details.
+    ///
+    /// ## Arguments
+    ///
+    /// * `bins` - The number of bins
+    ///
+    /// ## Returns
+    ///
+    /// The histogram of the elements
+    ///
+    /// ## Panics
+    ///
+    /// If `bins` is zero
+    pub fn histogram(&self, bins: usize) -> Histogram {
+        histogram(self.as_slice(), bins)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_histogram() {
+        let h = histogram(&[0., 0.5, 1., f64::NAN, 0.25], 2);
+        assert_eq!(h.edges, vec![0., 0.5, 1.]);
+        assert_eq!(h.counts, vec![2, 2]);
+
+        let h = histogram_range(&[-1., 0., 0.99, 1., 2.], 4, (0., 1.));
+        assert_eq!(h.counts, vec![1, 0, 0, 2]);
+
+        // A single repeated value and no values at all still give usable bins
+        assert_eq!(histogram(&[3., 3.], 1).edges, vec![2.5, 3.5]);
+        let empty = DynamicVector::new(0).histogram(2);
+        assert_eq!(empty.edges, vec![0., 0.5, 1.]);
+        assert_eq!(empty.counts, vec![0, 0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_histogram_no_bins() {
+        histogram(&[1.], 0);
+    }
+
+    #[test]
+    fn test_histogram2d() {
+        let points = [
+            Vector2::new(0., 0.),
+            Vector2::new(3., 0.),
+            Vector2::new(3., 1.),
+            Vector2::new(f64::NAN, 0.5),
+        ];
+        let grid = histogram2d(&points, 3, 1);
+        assert_eq!(grid.x_edges, vec![0., 1., 2., 3.]);
+        assert_eq!(grid.y_edges, vec![0., 1.]);
+        assert_eq!(grid.counts, vec![1, 0, 2]);
+
+        let grid = histogram2d_range(&points, 2, 2, Vector2::new(0., 0.), Vector2::new(2., 2.));
+        assert_eq!((grid.x_bins(), grid.y_bins()), (2, 2));
+        assert_eq!(grid.counts, vec![1, 0, 0, 0]);
+    }
+}
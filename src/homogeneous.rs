@@ -0,0 +1,143 @@
+use crate::{Vector2, Vector3};
+
+impl Vector3 {
+    /// Creates the homogeneous coordinates of a 2D point
+    ///
+    /// ## Arguments
+    ///
+    /// * `point` - The point
+    ///
+    /// ## Returns
+    ///
+    /// The point with a `z` of 1, so that it is moved by the translation of a 3x3 matrix
+    pub fn from_point2(point: &Vector2) -> Vector3 {
+        Vector3::new(point.x, point.y, 1.)
+    }
+
+    /// Creates the homogeneous coordinates of a 2D direction
+    ///
+    /// ## Arguments
+    ///
+    /// * `direction` - The direction
+    ///
+    /// ## Returns
+    ///
+    /// The direction with a `z` of 0, so that it is not affected by the translation of a 3x3
+    /// matrix
+    pub fn from_direction2(direction: &Vector2) -> Vector3 {
+        Vector3::new(direction.x, direction.y, 0.)
+    }
+
+    /// Converts homogeneous coordinates back to a 2D point, dividing by `z`
+    ///
+    /// ## Returns
+    ///
+    /// The point, or `None` if `z` is zero and the coordinates are a direction
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Vector2, Vector3};
+    ///
+    /// assert_eq!(Vector3::new(4., 6., 2.).to_point2(), Some(Vector2::new(2., 3.)));
+    /// assert_eq!(Vector3::new(4., 6., 0.).to_point2(), None);
+    /// ```
+    pub fn to_point2(&self) -> Option<Vector2> {
+        if self.z == 0. {
+            return None;
+        }
+        Some(Vector2::new(self.x / self.z, self.y / self.z))
+    }
+
+    /// Multiplies the vector by a 3x3 matrix
+    ///
+    /// ## Arguments
+    ///
+    /// * `matrix` - The matrix, in row-major order. It transforms column vectors, so the result
+    ///   is `matrix * self`
+    ///
+    /// ## Returns
+    ///
+    /// The transformed vector
+    pub fn transform(&self, matrix: &[[f64; 3]; 3]) -> Vector3 {
+        let [x, y, z] = matrix.map(|row| row[0] * self.x + row[1] * self.y + row[2] * self.z);
+        Vector3::new(x, y, z)
+    }
+}
+
+impl Vector2 {
+    /// Applies a homogeneous 3x3 matrix to the point
+    ///
+    /// ## Arguments
+    ///
+    /// * `matrix` - The matrix, in row-major order with the translation in the last column
+    ///
+    /// ## Returns
+    ///
+    /// The transformed point. `None` if the matrix is projective and sends the point to infinity,
+    /// which never happens for affine matrices
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::Vector2;
+    ///
+    /// // Scale by 2, then move 5 units along x
+    /// let matrix = [[2., 0., 5.], [0., 2., 0.], [0., 0., 1.]];
+    ///
+    /// assert_eq!(
+    ///     Vector2::new(1., 3.).transform_point(&matrix),
+    ///     Some(Vector2::new(7., 6.))
+    /// );
+    /// ```
+    pub fn transform_point(&self, matrix: &[[f64; 3]; 3]) -> Option<Vector2> {
+        Vector3::from_point2(self).transform(matrix).to_point2()
+    }
+
+    /// Applies a homogeneous 3x3 matrix to the direction, ignoring the translation
+    ///
+    /// ## Arguments
+    ///
+    /// * `matrix` - The matrix, in row-major order with the translation in the last column
+    ///
+    /// ## Returns
+    ///
+    /// The transformed direction
+    pub fn transform_direction(&self, matrix: &[[f64; 3]; 3]) -> Vector2 {
+        let transformed = Vector3::from_direction2(self).transform(matrix);
+        Vector2::new(transformed.x, transformed.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Transform2, Vector, Vector2, Vector3};
+    use core::f64::consts::FRAC_PI_2;
+
+    #[test]
+    fn test_round_trip() {
+        let p = Vector2::new(-2., 0.5);
+        assert_eq!(Vector3::from_point2(&p), Vector3::new(-2., 0.5, 1.));
+        assert_eq!(Vector3::from_point2(&p).to_point2(), Some(p));
+        assert_eq!(Vector3::from_direction2(&p).to_point2(), None);
+    }
+
+    #[test]
+    fn test_transform() {
+        let t = Transform2::new(Vector2::new(1., -2.), FRAC_PI_2, Vector2::new(2., 3.));
+        let matrix = t.to_matrix();
+        let v = Vector2::new(0.5, 4.);
+        let point = v.transform_point(&matrix).unwrap();
+        assert!((point - t.transform_point(&v)).magnitude() < 1e-12);
+        let direction = v.transform_direction(&matrix);
+        assert!((direction - t.transform_vector(&v)).magnitude() < 1e-12);
+
+        // A projective matrix divides by the resulting z
+        let projective = [[1., 0., 0.], [0., 1., 0.], [1., 0., 0.]];
+        assert_eq!(
+            Vector2::new(2., 4.).transform_point(&projective),
+            Some(Vector2::new(1., 2.))
+        );
+        assert_eq!(Vector2::new(0., 4.).transform_point(&projective), None);
+    }
+}
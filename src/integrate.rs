@@ -0,0 +1,201 @@
+use crate::VectorSpace;
+
+/// Advances a first order ODE `y' = f(t, y)` by one step of the explicit Euler method
+///
+/// This is first order accurate and can gain energy over time, so it is mostly useful as a
+/// baseline or for heavily damped systems.
+///
+/// ## Arguments
+///
+/// * `f` - The derivative of the state at a time
+/// * `t` - The current time
+/// * `y` - The current state
+/// * `dt` - The time step
+///
+/// ## Returns
+///
+/// The state at `t + dt`
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{euler_step, Vector2};
+///
+/// // Constant velocity
+/// let y = euler_step(|_, _| Vector2::new(1., 2.), 0., &Vector2::new(0., 0.), 0.5);
+///
+/// assert_eq!(y, Vector2::new(0.5, 1.));
+/// ```
+pub fn euler_step<V>(f: impl Fn(f64, &V) -> V, t: f64, y: &V, dt: f64) -> V
+where
+    V: VectorSpace,
+{
+    *y + f(t, y) * dt
+}
+
+/// Advances a first order ODE `y' = f(t, y)` by one step of the classic fourth order Runge-Kutta
+/// method
+///
+/// ## Arguments
+///
+/// * `f` - The derivative of the state at a time
+/// * `t` - The current time
+/// * `y` - The current state
+/// * `dt` - The time step
+///
+/// ## Returns
+///
+/// The state at `t + dt`
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{rk4_step, Vector2};
+///
+/// // Exponential decay, y' = -y
+/// let mut y = Vector2::new(1., 2.);
+/// for i in 0..10 {
+///     y = rk4_step(|_, y| *y * -1., i as f64 * 0.1, &y, 0.1);
+/// }
+///
+/// assert!((y.x - (-1_f64).exp()).abs() < 1e-6);
+/// ```
+pub fn rk4_step<V>(f: impl Fn(f64, &V) -> V, t: f64, y: &V, dt: f64) -> V
+where
+    V: VectorSpace,
+{
+    let half = dt * 0.5;
+    let k1 = f(t, y);
+    let k2 = f(t + half, &(*y + k1 * half));
+    let k3 = f(t + half, &(*y + k2 * half));
+    let k4 = f(t + dt, &(*y + k3 * dt));
+    *y + (k1 + k2 * 2. + k3 * 2. + k4) * (dt / 6.)
+}
+
+/// Advances a position and velocity by one step of the semi-implicit (symplectic) Euler method
+///
+/// The velocity is updated first and the new velocity moves the position. This costs the same as
+/// explicit Euler but keeps the energy of oscillating systems bounded, which is why it is the
+/// usual choice in game physics.
+///
+/// ## Arguments
+///
+/// * `acceleration` - The acceleration at a time, position and velocity
+/// * `t` - The current time
+/// * `position` - The current position
+/// * `velocity` - The current velocity
+/// * `dt` - The time step
+///
+/// ## Returns
+///
+/// The position and velocity at `t + dt`
+pub fn semi_implicit_euler_step<V>(
+    acceleration: impl Fn(f64, &V, &V) -> V,
+    t: f64,
+    position: &V,
+    velocity: &V,
+    dt: f64,
+) -> (V, V)
+where
+    V: VectorSpace,
+{
+    let velocity = *velocity + acceleration(t, position, velocity) * dt;
+    (*position + velocity * dt, velocity)
+}
+
+/// Advances a position and velocity by one step of the velocity Verlet method
+///
+/// This is second order accurate and symplectic, so it conserves energy well over long runs such
+/// as orbits. The acceleration may only depend on time and position, and is evaluated twice per
+/// step.
+///
+/// ## Arguments
+///
+/// * `acceleration` - The acceleration at a time and position
+/// * `t` - The current time
+/// * `position` - The current position
+/// * `velocity` - The current velocity
+/// * `dt` - The time step
+///
+/// ## Returns
+///
+/// The position and velocity at `t + dt`
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{velocity_verlet_step, Vector2};
+///
+/// // Falling under gravity is integrated exactly
+/// let gravity = |_: f64, _: &Vector2| Vector2::new(0., -10.);
+/// let (position, velocity) = (Vector2::new(0., 0.), Vector2::new(1., 0.));
+/// let (p, v) = velocity_verlet_step(gravity, 0., &position, &velocity, 1.);
+///
+/// assert_eq!(p, Vector2::new(1., -5.));
+/// assert_eq!(v, Vector2::new(1., -10.));
+/// ```
+pub fn velocity_verlet_step<V>(
+    acceleration: impl Fn(f64, &V) -> V,
+    t: f64,
+    position: &V,
+    velocity: &V,
+    dt: f64,
+) -> (V, V)
+where
+    V: VectorSpace,
+{
+    let a0 = acceleration(t, position);
+    let next_position = *position + *velocity * dt + a0 * (0.5 * dt * dt);
+    let a1 = acceleration(t + dt, &next_position);
+    (next_position, *velocity + (a0 + a1) * (0.5 * dt))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Vector, Vector2, Vector4};
+
+    /// A unit spring, whose energy should stay at its starting value
+    fn spring(_: f64, position: &Vector2) -> Vector2 {
+        *position * -1.
+    }
+
+    fn energy(position: Vector2, velocity: Vector2) -> f64 {
+        0.5 * (position.dot(&position) + velocity.dot(&velocity))
+    }
+
+    #[test]
+    fn test_euler_step() {
+        let y = euler_step(|_, y: &Vector2| *y, 0., &Vector2::new(1., 2.), 0.5);
+        assert_eq!(y, Vector2::new(1.5, 3.));
+    }
+
+    #[test]
+    fn test_rk4_accuracy() {
+        // y' = (t, 1) has the exact solution y = (t^2 / 2, t), which RK4 reproduces exactly
+        let mut y = Vector2::new(0., 0.);
+        for i in 0..4 {
+            y = rk4_step(|t, _| Vector2::new(t, 1.), i as f64 * 0.25, &y, 0.25);
+        }
+        assert!((y.x - 0.5).abs() < 1e-12);
+        assert!((y.y - 1.).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_energy_conservation() {
+        let (mut p1, mut v1) = (Vector2::new(1., 0.), Vector2::new(0., 0.));
+        let (mut p2, mut v2) = (p1, v1);
+        // Explicit Euler needs the position and velocity in one state vector
+        let mut state = Vector4::new(1., 0., 0., 0.);
+        for i in 0..10_000 {
+            let t = i as f64 * 0.01;
+            (p1, v1) = velocity_verlet_step(spring, t, &p1, &v1, 0.01);
+            (p2, v2) = semi_implicit_euler_step(|t, p, _| spring(t, p), t, &p2, &v2, 0.01);
+            state = euler_step(|_, s| Vector4::new(s.c, s.d, -s.a, -s.b), t, &state, 0.01);
+        }
+        assert!((energy(p1, v1) - 0.5).abs() < 1e-4);
+        assert!((energy(p2, v2) - 0.5).abs() < 1e-2);
+        // Explicit Euler steadily gains energy
+        assert!(state.dot(&state) * 0.5 > 0.6);
+    }
+}
@@ -0,0 +1,337 @@
+//! Conversions to and from the vector types of other crates, each behind a feature of the same
+//! name as the crate
+
+#[cfg(feature = "mint")]
+mod mint_impl {
+    use crate::{Vector2, Vector3, Vector4};
+
+    impl From<mint::Vector2<f64>> for Vector2 {
+        fn from(v: mint::Vector2<f64>) -> Self {
+            Vector2::new(v.x, v.y)
+        }
+    }
+
+    impl From<Vector2> for mint::Vector2<f64> {
+        fn from(v: Vector2) -> Self {
+            mint::Vector2 { x: v.x, y: v.y }
+        }
+    }
+
+    impl From<mint::Vector3<f64>> for Vector3 {
+        fn from(v: mint::Vector3<f64>) -> Self {
+            Vector3::new(v.x, v.y, v.z)
+        }
+    }
+
+    impl From<Vector3> for mint::Vector3<f64> {
+        fn from(v: Vector3) -> Self {
+            mint::Vector3 {
+                x: v.x,
+                y: v.y,
+                z: v.z,
+            }
+        }
+    }
+
+    /// The components `a`, `b`, `c` and `d` map to `x`, `y`, `z` and `w`
+    impl From<mint::Vector4<f64>> for Vector4 {
+        fn from(v: mint::Vector4<f64>) -> Self {
+            Vector4::new(v.x, v.y, v.z, v.w)
+        }
+    }
+
+    /// The components `a`, `b`, `c` and `d` map to `x`, `y`, `z` and `w`
+    impl From<Vector4> for mint::Vector4<f64> {
+        fn from(v: Vector4) -> Self {
+            mint::Vector4 {
+                x: v.a,
+                y: v.b,
+                z: v.c,
+                w: v.d,
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_mint_round_trip() {
+            let v2 = Vector2::new(1., 2.);
+            let m2: mint::Vector2<f64> = v2.into();
+            assert_eq!(m2, mint::Vector2 { x: 1., y: 2. });
+            assert_eq!(Vector2::from(m2), v2);
+
+            let v3 = Vector3::new(1., 2., 3.);
+            assert_eq!(Vector3::from(mint::Vector3::from(v3)), v3);
+
+            let v4 = Vector4::new(1., 2., 3., 4.);
+            let m4: mint::Vector4<f64> = v4.into();
+            assert_eq!(m4.w, 4.);
+            assert_eq!(Vector4::from(m4), v4);
+        }
+    }
+}
+
+#[cfg(feature = "glam")]
+mod glam_impl {
+    use crate::{Vector2, Vector3, Vector4};
+    use glam::{DVec2, DVec3, DVec4, Vec2, Vec3, Vec4};
+
+    impl From<DVec2> for Vector2 {
+        fn from(v: DVec2) -> Self {
+            Vector2::new(v.x, v.y)
+        }
+    }
+
+    impl From<Vector2> for DVec2 {
+        fn from(v: Vector2) -> Self {
+            DVec2::new(v.x, v.y)
+        }
+    }
+
+    impl From<DVec3> for Vector3 {
+        fn from(v: DVec3) -> Self {
+            Vector3::new(v.x, v.y, v.z)
+        }
+    }
+
+    impl From<Vector3> for DVec3 {
+        fn from(v: Vector3) -> Self {
+            DVec3::new(v.x, v.y, v.z)
+        }
+    }
+
+    /// The components `a`, `b`, `c` and `d` map to `x`, `y`, `z` and `w`
+    impl From<DVec4> for Vector4 {
+        fn from(v: DVec4) -> Self {
+            Vector4::new(v.x, v.y, v.z, v.w)
+        }
+    }
+
+    /// The components `a`, `b`, `c` and `d` map to `x`, `y`, `z` and `w`
+    impl From<Vector4> for DVec4 {
+        fn from(v: Vector4) -> Self {
+            DVec4::new(v.a, v.b, v.c, v.d)
+        }
+    }
+
+    impl From<Vec2> for Vector2 {
+        fn from(v: Vec2) -> Self {
+            Vector2::new(v.x as f64, v.y as f64)
+        }
+    }
+
+    /// Components are rounded to the nearest `f32`, so precision is lost
+    impl From<Vector2> for Vec2 {
+        fn from(v: Vector2) -> Self {
+            Vec2::new(v.x as f32, v.y as f32)
+        }
+    }
+
+    impl From<Vec3> for Vector3 {
+        fn from(v: Vec3) -> Self {
+            Vector3::new(v.x as f64, v.y as f64, v.z as f64)
+        }
+    }
+
+    /// Components are rounded to the nearest `f32`, so precision is lost
+    impl From<Vector3> for Vec3 {
+        fn from(v: Vector3) -> Self {
+            Vec3::new(v.x as f32, v.y as f32, v.z as f32)
+        }
+    }
+
+    /// The components `a`, `b`, `c` and `d` map to `x`, `y`, `z` and `w`
+    impl From<Vec4> for Vector4 {
+        fn from(v: Vec4) -> Self {
+            Vector4::new(v.x as f64, v.y as f64, v.z as f64, v.w as f64)
+        }
+    }
+
+    /// Components are rounded to the nearest `f32`, so precision is lost
+    impl From<Vector4> for Vec4 {
+        fn from(v: Vector4) -> Self {
+            Vec4::new(v.a as f32, v.b as f32, v.c as f32, v.d as f32)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_glam_f64_round_trip() {
+            let v2 = Vector2::new(1., 2.);
+            assert_eq!(DVec2::from(v2), DVec2::new(1., 2.));
+            assert_eq!(Vector2::from(DVec2::from(v2)), v2);
+
+            let v3 = Vector3::new(0.1, 0.2, 0.3);
+            assert_eq!(Vector3::from(DVec3::from(v3)), v3);
+
+            let v4 = Vector4::new(1., 2., 3., 4.);
+            assert_eq!(DVec4::from(v4).w, 4.);
+            assert_eq!(Vector4::from(DVec4::from(v4)), v4);
+        }
+
+        #[test]
+        fn test_glam_f32() {
+            let v3: Vec3 = Vector3::new(1., 2.5, -3.).into();
+            assert_eq!(v3, Vec3::new(1., 2.5, -3.));
+            assert_eq!(Vector3::from(v3), Vector3::new(1., 2.5, -3.));
+
+            let v2 = Vec2::from(Vector2::new(0.1, 0.));
+            assert_eq!(v2.x, 0.1_f32);
+            assert_eq!(
+                Vector4::from(Vec4::new(1., 2., 3., 4.)),
+                Vector4::new(1., 2., 3., 4.)
+            );
+        }
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+mod nalgebra_impl {
+    use crate::{DynamicVector, Vector2, Vector3, Vector4};
+
+    impl From<nalgebra::Vector2<f64>> for Vector2 {
+        fn from(v: nalgebra::Vector2<f64>) -> Self {
+            Vector2::new(v.x, v.y)
+        }
+    }
+
+    impl From<Vector2> for nalgebra::Vector2<f64> {
+        fn from(v: Vector2) -> Self {
+            nalgebra::Vector2::new(v.x, v.y)
+        }
+    }
+
+    impl From<nalgebra::Vector3<f64>> for Vector3 {
+        fn from(v: nalgebra::Vector3<f64>) -> Self {
+            Vector3::new(v.x, v.y, v.z)
+        }
+    }
+
+    impl From<Vector3> for nalgebra::Vector3<f64> {
+        fn from(v: Vector3) -> Self {
+            nalgebra::Vector3::new(v.x, v.y, v.z)
+        }
+    }
+
+    /// The components `a`, `b`, `c` and `d` map to `x`, `y`, `z` and `w`
+    impl From<nalgebra::Vector4<f64>> for Vector4 {
+        fn from(v: nalgebra::Vector4<f64>) -> Self {
+            Vector4::new(v.x, v.y, v.z, v.w)
+        }
+    }
+
+    /// The components `a`, `b`, `c` and `d` map to `x`, `y`, `z` and `w`
+    impl From<Vector4> for nalgebra::Vector4<f64> {
+        fn from(v: Vector4) -> Self {
+            nalgebra::Vector4::new(v.a, v.b, v.c, v.d)
+        }
+    }
+
+    impl From<DynamicVector> for nalgebra::DVector<f64> {
+        fn from(v: DynamicVector) -> Self {
+            nalgebra::DVector::from_column_slice(v.as_slice())
+        }
+    }
+
+    impl From<nalgebra::DVector<f64>> for DynamicVector {
+        fn from(v: nalgebra::DVector<f64>) -> Self {
+            DynamicVector {
+                data: v.as_slice().iter().copied().collect(),
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_nalgebra_fixed() {
+            let v2 = Vector2::new(1., 2.);
+            assert_eq!(Vector2::from(nalgebra::Vector2::from(v2)), v2);
+
+            let v3: nalgebra::Vector3<f64> = Vector3::new(1., 2., 3.).into();
+            assert_eq!(v3, nalgebra::Vector3::new(1., 2., 3.));
+
+            let v4 = Vector4::new(1., 2., 3., 4.);
+            assert_eq!(nalgebra::Vector4::from(v4).w, 4.);
+            assert_eq!(Vector4::from(nalgebra::Vector4::from(v4)), v4);
+        }
+
+        #[test]
+        fn test_nalgebra_dynamic() {
+            let v = DynamicVector::from_csv_row("1,2,3,4,5").unwrap();
+            let d: nalgebra::DVector<f64> = v.clone().into();
+            assert_eq!(d.as_slice(), &[1., 2., 3., 4., 5.]);
+            assert_eq!(DynamicVector::from(d), v);
+        }
+    }
+}
+
+#[cfg(feature = "cgmath")]
+mod cgmath_impl {
+    use crate::{Vector2, Vector3, Vector4};
+
+    impl From<cgmath::Vector2<f64>> for Vector2 {
+        fn from(v: cgmath::Vector2<f64>) -> Self {
+            Vector2::new(v.x, v.y)
+        }
+    }
+
+    impl From<Vector2> for cgmath::Vector2<f64> {
+        fn from(v: Vector2) -> Self {
+            cgmath::Vector2::new(v.x, v.y)
+        }
+    }
+
+    impl From<cgmath::Vector3<f64>> for Vector3 {
+        fn from(v: cgmath::Vector3<f64>) -> Self {
+            Vector3::new(v.x, v.y, v.z)
+        }
+    }
+
+    impl From<Vector3> for cgmath::Vector3<f64> {
+        fn from(v: Vector3) -> Self {
+            cgmath::Vector3::new(v.x, v.y, v.z)
+        }
+    }
+
+    /// The components `a`, `b`, `c` and `d` map to `x`, `y`, `z` and `w`
+    impl From<cgmath::Vector4<f64>> for Vector4 {
+        fn from(v: cgmath::Vector4<f64>) -> Self {
+            Vector4::new(v.x, v.y, v.z, v.w)
+        }
+    }
+
+    /// The components `a`, `b`, `c` and `d` map to `x`, `y`, `z` and `w`
+    impl From<Vector4> for cgmath::Vector4<f64> {
+        fn from(v: Vector4) -> Self {
+            cgmath::Vector4::new(v.a, v.b, v.c, v.d)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_cgmath_round_trip() {
+            let v2 = Vector2::new(1., 2.);
+            assert_eq!(cgmath::Vector2::from(v2), cgmath::Vector2::new(1., 2.));
+            assert_eq!(Vector2::from(cgmath::Vector2::from(v2)), v2);
+
+            let v3 = Vector3::new(1., 2., 3.);
+            assert_eq!(Vector3::from(cgmath::Vector3::from(v3)), v3);
+
+            let v4 = Vector4::new(1., 2., 3., 4.);
+            assert_eq!(cgmath::Vector4::from(v4).w, 4.);
+            assert_eq!(Vector4::from(cgmath::Vector4::from(v4)), v4);
+        }
+    }
+}
@@ -0,0 +1,519 @@
+use crate::{Vector2, Vector3, Vector4, VectorSpace};
+use core::ops::{Add, Mul, Sub};
+
+/// Smoothly maps a value from the range `[edge0, edge1]` to `[0, 1]`
+///
+/// Uses the cubic Hermite curve `3t^2 - 2t^3`, which has zero slope at both edges. Values outside
+/// of the range are clamped.
+///
+/// ## Arguments
+///
+/// * `edge0` - The value that maps to `0`
+/// * `edge1` - The value that maps to `1`
+/// * `x` - The value to map
+///
+/// ## Returns
+///
+/// The eased value between `0` and `1`
+///
+/// ## Example
+///
+/// ```
+/// use libvector::smoothstep;
+///
+/// assert_eq!(smoothstep(0., 2., 1.), 0.5);
+/// assert_eq!(smoothstep(0., 2., 3.), 1.);
+/// ```
+pub fn smoothstep(edge0: f64, edge1: f64, x: f64) -> f64 {
+    let t = ((x - edge0) / (edge1 - edge0)).clamp(0., 1.);
+    t * t * (3. - 2. * t)
+}
+
+/// Smoothly maps a value from the range `[edge0, edge1]` to `[0, 1]`
+///
+/// Uses Ken Perlin's quintic curve `6t^5 - 15t^4 + 10t^3`, which has zero slope and zero
+/// curvature at both edges. Values outside of the range are clamped.
+///
+/// ## Arguments
+///
+/// * `edge0` - The value that maps to `0`
+/// * `edge1` - The value that maps to `1`
+/// * `x` - The value to map
+///
+/// ## Returns
+///
+/// The eased value between `0` and `1`
+///
+/// ## Example
+///
+/// ```
+/// use libvector::smootherstep;
+///
+/// assert_eq!(smootherstep(0., 2., 1.), 0.5);
+/// assert_eq!(smootherstep(0., 2., -1.), 0.);
+/// ```
+pub fn smootherstep(edge0: f64, edge1: f64, x: f64) -> f64 {
+    let t = ((x - edge0) / (edge1 - edge0)).clamp(0., 1.);
+    t * t * t * (t * (t * 6. - 15.) + 10.)
+}
+
+/// Linearly interpolates between two values
+///
+/// ## Arguments
+///
+/// * `a` - The value returned when `t` is `0`
+/// * `b` - The value returned when `t` is `1`
+/// * `t` - The interpolation factor. Values outside of `[0, 1]` extrapolate
+///
+/// ## Returns
+///
+/// The interpolated value `a + (b - a) * t`
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{lerp, Vector2};
+///
+/// let v = lerp(Vector2::new(0., 0.), Vector2::new(2., 4.), 0.25);
+///
+/// assert_eq!(v, Vector2::new(0.5, 1.));
+/// ```
+pub fn lerp<V>(a: V, b: V, t: f64) -> V
+where
+    V: Copy + Add<Output = V> + Sub<Output = V> + Mul<f64, Output = V>,
+{
+    a + (b - a) * t
+}
+
+/// Interpolates values stored at the corners of a triangle
+///
+/// Pairs with [`Triangle2::barycentric`](crate::Triangle2::barycentric),
+/// [`Triangle3::barycentric`](crate::Triangle3::barycentric) and the barycentric coordinates of a
+/// ray [`Hit`](crate::Hit) to shade a point inside a triangle from per-vertex positions, texture
+/// coordinates, colors or plain numbers such as depth.
+///
+/// ## Arguments
+///
+/// * `bary` - The barycentric coordinates of the point, the weights of `a`, `b` and `c`
+/// * `a` - The value at the first corner
+/// * `b` - The value at the second corner
+/// * `c` - The value at the third corner
+///
+/// ## Returns
+///
+/// The weighted sum `a * bary.x + b * bary.y + c * bary.z`
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{interpolate_barycentric, Vector2, Vector3};
+///
+/// let bary = Vector3::new(0.5, 0.25, 0.25);
+/// let uv = interpolate_barycentric(
+///     bary,
+///     Vector2::new(0., 0.),
+///     Vector2::new(1., 0.),
+///     Vector2::new(0., 1.),
+/// );
+///
+/// assert_eq!(uv, Vector2::new(0.25, 0.25));
+/// assert_eq!(interpolate_barycentric(bary, 2., 4., 8.), 4.);
+/// ```
+pub fn interpolate_barycentric<V>(bary: Vector3, a: V, b: V, c: V) -> V
+where
+    V: Copy + Add<Output = V> + Mul<f64, Output = V>,
+{
+    a * bary.x + b * bary.y + c * bary.z
+}
+
+/// Interpolates between two values, easing in and out with [`smoothstep`]
+///
+/// ## Arguments
+///
+/// * `a` - The value returned when `t` is `0`
+/// * `b` - The value returned when `t` is `1`
+/// * `t` - The interpolation factor, clamped to `[0, 1]`
+///
+/// ## Returns
+///
+/// The interpolated value
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{smooth_lerp, Vector3};
+///
+/// let a = Vector3::new(0., 0., 0.);
+/// let b = Vector3::new(4., 0., 0.);
+///
+/// assert_eq!(smooth_lerp(a, b, 0.5), Vector3::new(2., 0., 0.));
+/// assert_eq!(smooth_lerp(a, b, 0.25), Vector3::new(0.625, 0., 0.));
+/// ```
+pub fn smooth_lerp<V>(a: V, b: V, t: f64) -> V
+where
+    V: Copy + Add<Output = V> + Sub<Output = V> + Mul<f64, Output = V>,
+{
+    lerp(a, b, smoothstep(0., 1., t))
+}
+
+/// Evaluates a cubic Hermite curve defined by two end points and their tangents
+///
+/// ## Arguments
+///
+/// * `p0` - The start point of the curve
+/// * `m0` - The tangent at the start point
+/// * `p1` - The end point of the curve
+/// * `m1` - The tangent at the end point
+/// * `t` - The curve parameter, from `0` at `p0` to `1` at `p1`
+///
+/// ## Returns
+///
+/// The point on the curve at `t`
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{hermite, Vector2};
+///
+/// let p = hermite(
+///     Vector2::new(0., 0.),
+///     Vector2::new(0., 1.),
+///     Vector2::new(1., 0.),
+///     Vector2::new(0., -1.),
+///     0.5,
+/// );
+///
+/// assert_eq!(p, Vector2::new(0.5, 0.25));
+/// ```
+pub fn hermite<V>(p0: V, m0: V, p1: V, m1: V, t: f64) -> V
+where
+    V: Copy + Add<Output = V> + Mul<f64, Output = V>,
+{
+    let t2 = t * t;
+    let t3 = t2 * t;
+    p0 * (2. * t3 - 3. * t2 + 1.)
+        + m0 * (t3 - 2. * t2 + t)
+        + p1 * (-2. * t3 + 3. * t2)
+        + m1 * (t3 - t2)
+}
+
+/// Gradually moves a value towards a target like a critically damped spring
+///
+/// This is the same follower as Unity's `SmoothDamp`. It never overshoots the target and copes
+/// with a target that moves every frame, which makes it well suited for camera follow. The
+/// velocity must be kept between calls and should start out as zero.
+///
+/// ## Arguments
+///
+/// * `current` - The current value
+/// * `target` - The value to move towards
+/// * `velocity` - The current velocity, which is updated by this call
+/// * `smooth_time` - Roughly the time it takes to reach the target
+/// * `dt` - The time elapsed since the last call
+///
+/// ## Returns
+///
+/// The new value
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{smooth_damp, Vector, Vector2};
+///
+/// let target = Vector2::new(10., 0.);
+/// let mut position = Vector2::new(0., 0.);
+/// let mut velocity = Vector2::new(0., 0.);
+///
+/// for _ in 0..600 {
+///     position = smooth_damp(position, target, &mut velocity, 0.5, 1. / 60.);
+/// }
+///
+/// assert!((position - target).magnitude() < 1e-3);
+/// ```
+pub fn smooth_damp<V>(current: V, target: V, velocity: &mut V, smooth_time: f64, dt: f64) -> V
+where
+    V: VectorSpace,
+{
+    let smooth_time = smooth_time.max(0.0001);
+    let omega = 2. / smooth_time;
+
+    // Padé approximation of exp(-omega * dt)
+    let x = omega * dt;
+    let decay = 1. / (1. + x + 0.48 * x * x + 0.235 * x * x * x);
+
+    let change = current - target;
+    let temp = (*velocity + change * omega) * dt;
+    *velocity = (*velocity - temp * omega) * decay;
+    let output = target + (change + temp) * decay;
+
+    // Snap to the target instead of overshooting it
+    if (target - current).dot(&(output - target)) > 0. {
+        *velocity = *velocity * 0.;
+        return target;
+    }
+
+    output
+}
+
+impl Vector2 {
+    /// Applies [`smoothstep`] to each component of the vector
+    ///
+    /// ## Arguments
+    ///
+    /// * `edge0` - The per-component values that map to `0`
+    /// * `edge1` - The per-component values that map to `1`
+    /// * `x` - The vector to map
+    ///
+    /// ## Returns
+    ///
+    /// A vector with every component eased between `0` and `1`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::Vector2;
+    ///
+    /// let edge0 = Vector2::new(0., 0.);
+    /// let edge1 = Vector2::new(2., 4.);
+    ///
+    /// let v = Vector2::smoothstep(&edge0, &edge1, &Vector2::new(1., 4.));
+    ///
+    /// assert_eq!(v, Vector2::new(0.5, 1.));
+    /// ```
+    pub fn smoothstep(edge0: &Vector2, edge1: &Vector2, x: &Vector2) -> Vector2 {
+        Vector2::new(
+            smoothstep(edge0.x, edge1.x, x.x),
+            smoothstep(edge0.y, edge1.y, x.y),
+        )
+    }
+
+    /// Applies [`smootherstep`] to each component of the vector
+    ///
+    /// ## Arguments
+    ///
+    /// * `edge0` - The per-component values that map to `0`
+    /// * `edge1` - The per-component values that map to `1`
+    /// * `x` - The vector to map
+    ///
+    /// ## Returns
+    ///
+    /// A vector with every component eased between `0` and `1`
+    pub fn smootherstep(edge0: &Vector2, edge1: &Vector2, x: &Vector2) -> Vector2 {
+        Vector2::new(
+            smootherstep(edge0.x, edge1.x, x.x),
+            smootherstep(edge0.y, edge1.y, x.y),
+        )
+    }
+}
+
+impl Vector3 {
+    /// Applies [`smoothstep`] to each component of the vector
+    ///
+    /// ## Arguments
+    ///
+    /// * `edge0` - The per-component values that map to `0`
+    /// * `edge1` - The per-component values that map to `1`
+    /// * `x` - The vector to map
+    ///
+    /// ## Returns
+    ///
+    /// A vector with every component eased between `0` and `1`
+    pub fn smoothstep(edge0: &Vector3, edge1: &Vector3, x: &Vector3) -> Vector3 {
+        Vector3::new(
+            smoothstep(edge0.x, edge1.x, x.x),
+            smoothstep(edge0.y, edge1.y, x.y),
+            smoothstep(edge0.z, edge1.z, x.z),
+        )
+    }
+
+    /// Applies [`smootherstep`] to each component of the vector
+    ///
+    /// ## Arguments
+    ///
+    /// * `edge0` - The per-component values that map to `0`
+    /// * `edge1` - The per-component values that map to `1`
+    /// * `x` - The vector to map
+    ///
+    /// ## Returns
+    ///
+    /// A vector with every component eased between `0` and `1`
+    pub fn smootherstep(edge0: &Vector3, edge1: &Vector3, x: &Vector3) -> Vector3 {
+        Vector3::new(
+            smootherstep(edge0.x, edge1.x, x.x),
+            smootherstep(edge0.y, edge1.y, x.y),
+            smootherstep(edge0.z, edge1.z, x.z),
+        )
+    }
+}
+
+impl Vector4 {
+    /// Applies [`smoothstep`] to each component of the vector
+    ///
+    /// ## Arguments
+    ///
+    /// * `edge0` - The per-component values that map to `0`
+    /// * `edge1` - The per-component values that map to `1`
+    /// * `x` - The vector to map
+    ///
+    /// ## Returns
+    ///
+    /// A vector with every component eased between `0` and `1`
+    pub fn smoothstep(edge0: &Vector4, edge1: &Vector4, x: &Vector4) -> Vector4 {
+        Vector4::new(
+            smoothstep(edge0.a, edge1.a, x.a),
+            smoothstep(edge0.b, edge1.b, x.b),
+            smoothstep(edge0.c, edge1.c, x.c),
+            smoothstep(edge0.d, edge1.d, x.d),
+        )
+    }
+
+    /// Applies [`smootherstep`] to each component of the vector
+    ///
+    /// ## Arguments
+    ///
+    /// * `edge0` - The per-component values that map to `0`
+    /// * `edge1` - The per-component values that map to `1`
+    /// * `x` - The vector to map
+    ///
+    /// ## Returns
+    ///
+    /// A vector with every component eased between `0` and `1`
+    pub fn smootherstep(edge0: &Vector4, edge1: &Vector4, x: &Vector4) -> Vector4 {
+        Vector4::new(
+            smootherstep(edge0.a, edge1.a, x.a),
+            smootherstep(edge0.b, edge1.b, x.b),
+            smootherstep(edge0.c, edge1.c, x.c),
+            smootherstep(edge0.d, edge1.d, x.d),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Vector;
+
+    #[test]
+    fn test_smoothstep() {
+        assert_eq!(smoothstep(1., 3., 0.), 0.);
+        assert_eq!(smoothstep(1., 3., 1.), 0.);
+        assert_eq!(smoothstep(1., 3., 1.5), 0.15625);
+        assert_eq!(smoothstep(1., 3., 2.), 0.5);
+        assert_eq!(smoothstep(1., 3., 4.), 1.);
+    }
+
+    #[test]
+    fn test_smootherstep() {
+        assert_eq!(smootherstep(0., 1., 0.), 0.);
+        assert_eq!(smootherstep(0., 1., 0.25), 0.103515625);
+        assert_eq!(smootherstep(0., 1., 0.5), 0.5);
+        assert_eq!(smootherstep(0., 1., 1.), 1.);
+    }
+
+    #[test]
+    fn test_lerp() {
+        let a = Vector3::new(1., 2., 3.);
+        let b = Vector3::new(3., 2., 1.);
+        assert_eq!(lerp(a, b, 0.), a);
+        assert_eq!(lerp(a, b, 1.), b);
+        assert_eq!(lerp(a, b, 0.5), Vector3::new(2., 2., 2.));
+        assert_eq!(lerp(1., 3., 2.), 5.);
+    }
+
+    #[test]
+    fn test_interpolate_barycentric() {
+        let (a, b, c) = (
+            Vector4::new(1., 0., 0., 1.),
+            Vector4::new(0., 1., 0., 1.),
+            Vector4::new(0., 0., 1., 0.),
+        );
+        assert_eq!(
+            interpolate_barycentric(Vector3::new(1., 0., 0.), a, b, c),
+            a
+        );
+        assert_eq!(
+            interpolate_barycentric(Vector3::new(0.25, 0.25, 0.5), a, b, c),
+            Vector4::new(0.25, 0.25, 0.5, 0.5)
+        );
+        // Coordinates outside the triangle extrapolate
+        assert_eq!(
+            interpolate_barycentric(Vector3::new(-1., 1., 1.), 1., 2., 4.),
+            5.
+        );
+    }
+
+    #[test]
+    fn test_smooth_lerp() {
+        let a = Vector2::new(0., 0.);
+        let b = Vector2::new(1., 2.);
+        assert_eq!(smooth_lerp(a, b, -1.), a);
+        assert_eq!(smooth_lerp(a, b, 2.), b);
+        assert_eq!(smooth_lerp(a, b, 0.25), Vector2::new(0.15625, 0.3125));
+    }
+
+    #[test]
+    fn test_componentwise() {
+        let v = Vector3::smoothstep(
+            &Vector3::new(0., 0., 0.),
+            &Vector3::new(1., 2., 4.),
+            &Vector3::new(0.5, 0.5, 5.),
+        );
+        assert_eq!(v, Vector3::new(0.5, 0.15625, 1.));
+
+        let v = Vector4::smootherstep(
+            &Vector4::new(0., 0., 0., 0.),
+            &Vector4::new(1., 1., 1., 1.),
+            &Vector4::new(0., 0.5, 1., 2.),
+        );
+        assert_eq!(v, Vector4::new(0., 0.5, 1., 1.));
+    }
+
+    #[test]
+    fn test_hermite() {
+        let p0 = Vector3::new(0., 0., 0.);
+        let m0 = Vector3::new(1., 0., 0.);
+        let p1 = Vector3::new(1., 1., 0.);
+        let m1 = Vector3::new(0., 1., 0.);
+        assert_eq!(hermite(p0, m0, p1, m1, 0.), p0);
+        assert_eq!(hermite(p0, m0, p1, m1, 1.), p1);
+        assert_eq!(hermite(p0, m0, p1, m1, 0.5), Vector3::new(0.625, 0.375, 0.));
+
+        // Tangents matching the chord reduce the curve to a straight line
+        let chord = p1 - p0;
+        assert_eq!(hermite(p0, chord, p1, chord, 0.25), lerp(p0, p1, 0.25));
+    }
+
+    #[test]
+    fn test_smooth_damp() {
+        let target = Vector3::new(1., 2., 3.);
+        let mut position = Vector3::new(0., 0., 0.);
+        let mut velocity = Vector3::new(0., 0., 0.);
+        let mut last_distance = (target - position).magnitude();
+
+        for _ in 0..300 {
+            position = smooth_damp(position, target, &mut velocity, 0.3, 1. / 60.);
+            let distance = (target - position).magnitude();
+            assert!(distance <= last_distance);
+            last_distance = distance;
+        }
+        assert!(last_distance < 1e-4);
+    }
+
+    #[test]
+    fn test_smooth_damp_no_overshoot() {
+        let target = Vector2::new(1., 0.);
+        let mut velocity = Vector2::new(100., 0.);
+        let position = smooth_damp(Vector2::new(0., 0.), target, &mut velocity, 1., 1.);
+        assert_eq!(position, target);
+        assert_eq!(velocity, Vector2::new(0., 0.));
+    }
+
+    #[test]
+    fn test_smooth_damp_zero_dt() {
+        let current = Vector2::new(3., 4.);
+        let mut velocity = Vector2::new(1., 1.);
+        let position = smooth_damp(current, Vector2::new(0., 0.), &mut velocity, 0.5, 0.);
+        assert_eq!(position, current);
+        assert_eq!(velocity, Vector2::new(1., 1.));
+    }
+}
@@ -0,0 +1,203 @@
+use crate::{Vector, Vector2};
+
+/// Checks whether two directions are parallel, relative to their lengths so that the result does
+/// not depend on the scale of the input. Zero vectors are parallel to everything.
+fn nearly_parallel(a: &Vector2, b: &Vector2) -> bool {
+    a.cross(b).abs() <= f64::EPSILON * a.magnitude() * b.magnitude()
+}
+
+/// Checks whether a point lies on the segment `a`-`b`
+fn on_segment(point: &Vector2, a: &Vector2, b: &Vector2) -> bool {
+    let direction = *b - *a;
+    let length_square = direction.dot(&direction);
+    if length_square == 0. {
+        return point == a;
+    }
+    let offset = *point - *a;
+    nearly_parallel(&offset, &direction)
+        && (0. ..=1.).contains(&(offset.dot(&direction) / length_square))
+}
+
+/// Finds where two infinite lines in 2D cross
+///
+/// ## Arguments
+///
+/// * `p1` - A point on the first line
+/// * `d1` - The direction of the first line
+/// * `p2` - A point on the second line
+/// * `d2` - The direction of the second line
+///
+/// ## Returns
+///
+/// The intersection point. If the lines are the same line every point is an intersection and
+/// `p1` is returned. `None` if the lines are parallel but distinct, or if either direction is zero
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{intersect_lines, Vector2};
+///
+/// let hit = intersect_lines(
+///     &Vector2::new(0., 1.),
+///     &Vector2::new(1., 0.),
+///     &Vector2::new(3., 0.),
+///     &Vector2::new(0., 2.),
+/// );
+///
+/// assert_eq!(hit, Some(Vector2::new(3., 1.)));
+/// ```
+pub fn intersect_lines(p1: &Vector2, d1: &Vector2, p2: &Vector2, d2: &Vector2) -> Option<Vector2> {
+    if d1.dot(d1) == 0. || d2.dot(d2) == 0. {
+        return None;
+    }
+    let offset = *p2 - *p1;
+    if nearly_parallel(d1, d2) {
+        return nearly_parallel(&offset, d1).then_some(*p1);
+    }
+    let t = offset.cross(d2) / d1.cross(d2);
+    Some(*p1 + *d1 * t)
+}
+
+/// Finds where two line segments in 2D cross
+///
+/// Segments that only touch at an end point are considered to intersect. Segments of zero length
+/// are treated as single points.
+///
+/// ## Arguments
+///
+/// * `a1`, `a2` - The end points of the first segment
+/// * `b1`, `b2` - The end points of the second segment
+///
+/// ## Returns
+///
+/// The intersection point, or `None` if the segments do not touch. If the segments are collinear
+/// and overlap, the point of the overlap closest to `a1` is returned
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{intersect_segments, Vector2};
+///
+/// let a1 = Vector2::new(0., 0.);
+/// let a2 = Vector2::new(2., 2.);
+///
+/// assert_eq!(
+///     intersect_segments(&a1, &a2, &Vector2::new(0., 2.), &Vector2::new(2., 0.)),
+///     Some(Vector2::new(1., 1.))
+/// );
+/// assert_eq!(
+///     intersect_segments(&a1, &a2, &Vector2::new(3., 0.), &Vector2::new(4., 0.)),
+///     None
+/// );
+/// ```
+pub fn intersect_segments(
+    a1: &Vector2,
+    a2: &Vector2,
+    b1: &Vector2,
+    b2: &Vector2,
+) -> Option<Vector2> {
+    let d1 = *a2 - *a1;
+    let d2 = *b2 - *b1;
+    let offset = *b1 - *a1;
+
+    if !nearly_parallel(&d1, &d2) {
+        let denominator = d1.cross(&d2);
+        let t = offset.cross(&d2) / denominator;
+        let u = offset.cross(&d1) / denominator;
+        return ((0. ..=1.).contains(&t) && (0. ..=1.).contains(&u)).then(|| *a1 + d1 * t);
+    }
+
+    let length_square = d1.dot(&d1);
+    if length_square == 0. {
+        return on_segment(a1, b1, b2).then_some(*a1);
+    }
+    if !nearly_parallel(&offset, &d1) {
+        return None;
+    }
+
+    // The segments lie on the same line, so compare them as intervals along the first one
+    let start = offset.dot(&d1) / length_square;
+    let end = (*b2 - *a1).dot(&d1) / length_square;
+    let low = start.min(end).max(0.);
+    let high = start.max(end).min(1.);
+    (low <= high).then(|| *a1 + d1 * low)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines() {
+        let origin = Vector2::new(0., 0.);
+        let diagonal = Vector2::new(1., 1.);
+        assert_eq!(
+            intersect_lines(
+                &origin,
+                &diagonal,
+                &Vector2::new(4., 0.),
+                &Vector2::new(-1., 1.)
+            ),
+            Some(Vector2::new(2., 2.))
+        );
+        assert_eq!(
+            intersect_lines(&origin, &diagonal, &Vector2::new(1., 0.), &diagonal),
+            None
+        );
+        assert_eq!(
+            intersect_lines(&origin, &diagonal, &Vector2::new(3., 3.), &(diagonal * -2.)),
+            Some(origin)
+        );
+        assert_eq!(
+            intersect_lines(&origin, &Vector2::new(0., 0.), &origin, &diagonal),
+            None
+        );
+    }
+
+    #[test]
+    fn test_segments() {
+        let a1 = Vector2::new(0., 0.);
+        let a2 = Vector2::new(4., 0.);
+        // Touching at an end point
+        assert_eq!(
+            intersect_segments(&a1, &a2, &Vector2::new(4., 0.), &Vector2::new(4., 3.)),
+            Some(a2)
+        );
+        // The lines cross, but beyond the end of the second segment
+        assert_eq!(
+            intersect_segments(&a1, &a2, &Vector2::new(2., 1.), &Vector2::new(2., 3.)),
+            None
+        );
+        // Parallel
+        assert_eq!(
+            intersect_segments(&a1, &a2, &Vector2::new(0., 1.), &Vector2::new(4., 1.)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_collinear_segments() {
+        let a1 = Vector2::new(0., 0.);
+        let a2 = Vector2::new(4., 0.);
+        assert_eq!(
+            intersect_segments(&a1, &a2, &Vector2::new(6., 0.), &Vector2::new(3., 0.)),
+            Some(Vector2::new(3., 0.))
+        );
+        assert_eq!(
+            intersect_segments(&a1, &a2, &Vector2::new(-2., 0.), &Vector2::new(1., 0.)),
+            Some(a1)
+        );
+        assert_eq!(
+            intersect_segments(&a1, &a2, &Vector2::new(5., 0.), &Vector2::new(6., 0.)),
+            None
+        );
+        // Zero length segments
+        let point = Vector2::new(1., 0.);
+        assert_eq!(intersect_segments(&point, &point, &a1, &a2), Some(point));
+        assert_eq!(intersect_segments(&a1, &a2, &point, &point), Some(point));
+        assert_eq!(
+            intersect_segments(&point, &point, &a1, &Vector2::new(0., 4.)),
+            None
+        );
+    }
+}
@@ -0,0 +1,318 @@
+use crate::{Vector2, Vector3};
+use core::ops::{Add, Mul, Neg, Sub};
+
+/// A 2D vector with integer components, for grid cells, pixels and tile coordinates
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(C)]
+pub struct IVector2 {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// A 3D vector with integer components, for voxels and grid cells
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(C)]
+pub struct IVector3 {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+impl IVector2 {
+    /// Creates a new 2D integer vector
+    ///
+    /// ## Arguments
+    ///
+    /// * `x` - The x component of the vector
+    /// * `y` - The y component of the vector
+    ///
+    /// ## Returns
+    ///
+    /// A new 2D integer vector
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::IVector2;
+    ///
+    /// let cell = IVector2::new(3, -1);
+    /// ```
+    pub fn new(x: i32, y: i32) -> IVector2 {
+        IVector2 { x, y }
+    }
+
+    /// Calculates the dot product of two vectors
+    ///
+    /// ## Arguments
+    ///
+    /// * `other` - The other vector to calculate the dot product with
+    ///
+    /// ## Returns
+    ///
+    /// The dot product, widened so that it cannot overflow
+    pub fn dot(&self, other: &IVector2) -> i64 {
+        self.x as i64 * other.x as i64 + self.y as i64 * other.y as i64
+    }
+}
+
+impl IVector3 {
+    /// Creates a new 3D integer vector
+    ///
+    /// ## Arguments
+    ///
+    /// * `x` - The x component of the vector
+    /// * `y` - The y component of the vector
+    /// * `z` - The z component of the vector
+    ///
+    /// ## Returns
+    ///
+    /// A new 3D integer vector
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::IVector3;
+    ///
+    /// let voxel = IVector3::new(3, -1, 4);
+    /// ```
+    pub fn new(x: i32, y: i32, z: i32) -> IVector3 {
+        IVector3 { x, y, z }
+    }
+
+    /// Calculates the dot product of two vectors
+    ///
+    /// ## Arguments
+    ///
+    /// * `other` - The other vector to calculate the dot product with
+    ///
+    /// ## Returns
+    ///
+    /// The dot product, widened so that it cannot overflow
+    pub fn dot(&self, other: &IVector3) -> i64 {
+        self.x as i64 * other.x as i64
+            + self.y as i64 * other.y as i64
+            + self.z as i64 * other.z as i64
+    }
+}
+
+// Implements the saturating, wrapping and checked arithmetic of `i32` component by component.
+macro_rules! integer_arithmetic {
+    ($V:ident: $($f:ident),+) => {
+        impl $V {
+            /// Adds two vectors, clamping each component at the bounds of `i32` instead of
+            /// overflowing
+            pub fn saturating_add(&self, other: &$V) -> $V {
+                $V { $($f: self.$f.saturating_add(other.$f)),+ }
+            }
+
+            /// Subtracts a vector, clamping each component at the bounds of `i32` instead of
+            /// overflowing
+            pub fn saturating_sub(&self, other: &$V) -> $V {
+                $V { $($f: self.$f.saturating_sub(other.$f)),+ }
+            }
+
+            /// Scales the vector, clamping each component at the bounds of `i32` instead of
+            /// overflowing
+            pub fn saturating_mul(&self, scalar: i32) -> $V {
+                $V { $($f: self.$f.saturating_mul(scalar)),+ }
+            }
+
+            /// Adds two vectors, wrapping each component around on overflow
+            pub fn wrapping_add(&self, other: &$V) -> $V {
+                $V { $($f: self.$f.wrapping_add(other.$f)),+ }
+            }
+
+            /// Subtracts a vector, wrapping each component around on overflow
+            pub fn wrapping_sub(&self, other: &$V) -> $V {
+                $V { $($f: self.$f.wrapping_sub(other.$f)),+ }
+            }
+
+            /// Scales the vector, wrapping each component around on overflow
+            pub fn wrapping_mul(&self, scalar: i32) -> $V {
+                $V { $($f: self.$f.wrapping_mul(scalar)),+ }
+            }
+
+            /// Adds two vectors, returning `None` if any component overflows
+            pub fn checked_add(&self, other: &$V) -> Option<$V> {
+                Some($V { $($f: self.$f.checked_add(other.$f)?),+ })
+            }
+
+            /// Subtracts a vector, returning `None` if any component overflows
+            pub fn checked_sub(&self, other: &$V) -> Option<$V> {
+                Some($V { $($f: self.$f.checked_sub(other.$f)?),+ })
+            }
+
+            /// Scales the vector, returning `None` if any component overflows
+            pub fn checked_mul(&self, scalar: i32) -> Option<$V> {
+                Some($V { $($f: self.$f.checked_mul(scalar)?),+ })
+            }
+        }
+    };
+}
+
+integer_arithmetic!(IVector2: x, y);
+integer_arithmetic!(IVector3: x, y, z);
+
+impl Add for IVector2 {
+    type Output = IVector2;
+
+    fn add(self, other: IVector2) -> IVector2 {
+        IVector2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for IVector2 {
+    type Output = IVector2;
+
+    fn sub(self, other: IVector2) -> IVector2 {
+        IVector2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<i32> for IVector2 {
+    type Output = IVector2;
+
+    fn mul(self, scalar: i32) -> IVector2 {
+        IVector2::new(self.x * scalar, self.y * scalar)
+    }
+}
+
+impl Neg for IVector2 {
+    type Output = IVector2;
+
+    fn neg(self) -> IVector2 {
+        IVector2::new(-self.x, -self.y)
+    }
+}
+
+impl Add for IVector3 {
+    type Output = IVector3;
+
+    fn add(self, other: IVector3) -> IVector3 {
+        IVector3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for IVector3 {
+    type Output = IVector3;
+
+    fn sub(self, other: IVector3) -> IVector3 {
+        IVector3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl Mul<i32> for IVector3 {
+    type Output = IVector3;
+
+    fn mul(self, scalar: i32) -> IVector3 {
+        IVector3::new(self.x * scalar, self.y * scalar, self.z * scalar)
+    }
+}
+
+impl Neg for IVector3 {
+    type Output = IVector3;
+
+    fn neg(self) -> IVector3 {
+        IVector3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl From<[i32; 2]> for IVector2 {
+    fn from(v: [i32; 2]) -> Self {
+        IVector2::new(v[0], v[1])
+    }
+}
+
+impl From<IVector2> for [i32; 2] {
+    fn from(v: IVector2) -> Self {
+        [v.x, v.y]
+    }
+}
+
+impl From<[i32; 3]> for IVector3 {
+    fn from(v: [i32; 3]) -> Self {
+        IVector3::new(v[0], v[1], v[2])
+    }
+}
+
+impl From<IVector3> for [i32; 3] {
+    fn from(v: IVector3) -> Self {
+        [v.x, v.y, v.z]
+    }
+}
+
+impl From<IVector2> for Vector2 {
+    fn from(v: IVector2) -> Self {
+        Vector2::new(v.x as f64, v.y as f64)
+    }
+}
+
+impl From<IVector3> for Vector3 {
+    fn from(v: IVector3) -> Self {
+        Vector3::new(v.x as f64, v.y as f64, v.z as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ops() {
+        let a = IVector2::new(1, 2);
+        let b = IVector2::new(3, -4);
+        assert_eq!(a + b, IVector2::new(4, -2));
+        assert_eq!(a - b, IVector2::new(-2, 6));
+        assert_eq!(-(a * 2), IVector2::new(-2, -4));
+        assert_eq!(a.dot(&b), -5);
+        let c = IVector3::new(1, 2, 3);
+        assert_eq!(c + c * 2 - c, IVector3::new(2, 4, 6));
+        assert_eq!(
+            IVector3::new(i32::MAX, 0, 0).dot(&IVector3::new(2, 0, 0)),
+            2 * i32::MAX as i64
+        );
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(IVector2::from([1, 2]), IVector2::new(1, 2));
+        assert_eq!(<[i32; 3]>::from(IVector3::new(1, 2, 3)), [1, 2, 3]);
+        assert_eq!(
+            Vector3::from(IVector3::new(1, 2, 3)),
+            Vector3::new(1., 2., 3.)
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        let edge = IVector2::new(i32::MAX - 1, i32::MIN + 1);
+        let step = IVector2::new(2, 2);
+        assert_eq!(
+            edge.saturating_add(&step),
+            IVector2::new(i32::MAX, i32::MIN + 3)
+        );
+        assert_eq!(
+            edge.saturating_sub(&step),
+            IVector2::new(i32::MAX - 3, i32::MIN)
+        );
+        assert_eq!(
+            edge.wrapping_add(&step),
+            IVector2::new(i32::MIN, i32::MIN + 3)
+        );
+        assert_eq!(edge.checked_add(&step), None);
+        assert_eq!(
+            edge.checked_sub(&IVector2::new(0, 1)),
+            Some(IVector2::new(i32::MAX - 1, i32::MIN))
+        );
+
+        let v = IVector3::new(1 << 30, -3, 0);
+        assert_eq!(v.saturating_mul(4), IVector3::new(i32::MAX, -12, 0));
+        assert_eq!(v.wrapping_mul(4), IVector3::new(0, -12, 0));
+        assert_eq!(v.checked_mul(4), None);
+        assert_eq!(v.checked_mul(-1), Some(IVector3::new(-(1 << 30), 3, 0)));
+        assert_eq!(
+            IVector3::new(0, 0, i32::MIN).wrapping_sub(&IVector3::new(0, 0, 1)),
+            IVector3::new(0, 0, i32::MAX)
+        );
+    }
+}
@@ -0,0 +1,246 @@
+use crate::average::running_average;
+use crate::{DynamicVector, Metric, VectorSpace};
+use rand::Rng;
+
+/// A point that can be grouped by [`kmeans`]
+///
+/// Distances come from [`Metric`]. This is implemented for every [`VectorSpace`] type, such as
+/// [`Vector2`](crate::Vector2) and [`Vector3`](crate::Vector3), and for [`DynamicVector`].
+pub trait Clusterable: Metric + Clone {
+    /// Calculates the mean of a non-empty set of points
+    fn mean(points: &[&Self]) -> Self;
+}
+
+impl<V: VectorSpace> Clusterable for V {
+    fn mean(points: &[&V]) -> V {
+        running_average(points.iter().map(|&&point| point)).unwrap()
+    }
+}
+
+impl Clusterable for DynamicVector {
+    fn mean(points: &[&DynamicVector]) -> DynamicVector {
+        DynamicVector::average(points.iter().copied()).unwrap()
+    }
+}
+
+/// The result of clustering points with [`kmeans`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct KMeans<V> {
+    /// The center of each cluster
+    pub centroids: Vec<V>,
+    /// The index of the cluster each point belongs to, in the same order as the points
+    pub assignments: Vec<usize>,
+    /// The number of iterations run before the assignments stopped changing
+    pub iterations: usize,
+}
+
+impl<V: Clusterable> KMeans<V> {
+    /// Calculates the sum of squared distances from every point to its centroid
+    ///
+    /// Lower values mean tighter clusters, which helps choose `k` or pick the best of several
+    /// runs.
+    ///
+    /// ## Arguments
+    ///
+    /// * `points` - The points that were clustered
+    ///
+    /// ## Returns
+    ///
+    /// The within-cluster sum of squares
+    pub fn inertia(&self, points: &[V]) -> f64 {
+        points
+            .iter()
+            .zip(&self.assignments)
+            .map(|(point, &cluster)| point.distance_squared(&self.centroids[cluster]))
+            .sum()
+    }
+}
+
+/// Finds the index of the centroid closest to a point
+fn nearest<V: Clusterable>(point: &V, centroids: &[V]) -> usize {
+    centroids
+        .iter()
+        .map(|centroid| point.distance_squared(centroid))
+        .enumerate()
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map_or(0, |(index, _)| index)
+}
+
+/// Picks starting centroids with k-means++, which spreads them out by choosing each new centroid
+/// with probability proportional to its squared distance from the ones already chosen
+fn kmeans_plus_plus<V: Clusterable, R: Rng + ?Sized>(
+    points: &[V],
+    k: usize,
+    rng: &mut R,
+) -> Vec<V> {
+    let mut centroids = vec![points[rng.gen_range(0..points.len())].clone()];
+    let mut distances: Vec<f64> = points
+        .iter()
+        .map(|point| point.distance_squared(&centroids[0]))
+        .collect();
+    while centroids.len() < k {
+        let total: f64 = distances.iter().sum();
+        let index = if total > 0. {
+            let mut target = rng.gen::<f64>() * total;
+            distances
+                .iter()
+                .position(|&distance| {
+                    target -= distance;
+                    target < 0.
+                })
+                .unwrap_or(points.len() - 1)
+        } else {
+            // Every point coincides with a centroid already
+            rng.gen_range(0..points.len())
+        };
+        let centroid = points[index].clone();
+        for (distance, point) in distances.iter_mut().zip(points) {
+            *distance = distance.min(point.distance_squared(&centroid));
+        }
+        centroids.push(centroid);
+    }
+    centroids
+}
+
+/// Groups points into `k` clusters with Lloyd's algorithm
+///
+/// The starting centroids are chosen with k-means++. Each iteration assigns every point to its
+/// nearest centroid and then moves each centroid to the mean of its points, stopping once the
+/// assignments no longer change. A cluster that loses all of its points keeps its previous
+/// centroid.
+///
+/// ## Arguments
+///
+/// * `points` - The points to cluster
+/// * `k` - The number of clusters. It is capped at the number of points
+/// * `max_iters` - The largest number of iterations to run
+/// * `rng` - The random number generator used to pick the starting centroids
+///
+/// ## Returns
+///
+/// The centroids and the cluster of each point, or `None` if `points` is empty or `k` is zero
+///
+/// ## Panics
+///
+/// If the points are [`DynamicVector`]s with different lengths
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{kmeans, Vector2};
+/// use rand::SeedableRng;
+///
+/// let points = [
+///     Vector2::new(0., 0.),
+///     Vector2::new(0., 1.),
+///     Vector2::new(10., 10.),
+///     Vector2::new(10., 11.),
+/// ];
+/// let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+/// let result = kmeans(&points, 2, 100, &mut rng).unwrap();
+///
+/// assert_eq!(result.assignments[0], result.assignments[1]);
+/// assert_ne!(result.assignments[0], result.assignments[2]);
+/// ```
+pub fn kmeans<V: Clusterable, R: Rng + ?Sized>(
+    points: &[V],
+    k: usize,
+    max_iters: usize,
+    rng: &mut R,
+) -> Option<KMeans<V>> {
+    if points.is_empty() || k == 0 {
+        return None;
+    }
+    let mut centroids = kmeans_plus_plus(points, k.min(points.len()), rng);
+    let mut assignments: Vec<usize> = points
+        .iter()
+        .map(|point| nearest(point, &centroids))
+        .collect();
+
+    let mut iterations = 0;
+    while iterations < max_iters {
+        iterations += 1;
+        for (cluster, centroid) in centroids.iter_mut().enumerate() {
+            let members: Vec<&V> = points
+                .iter()
+                .zip(&assignments)
+                .filter(|(_, &assignment)| assignment == cluster)
+                .map(|(point, _)| point)
+                .collect();
+            if !members.is_empty() {
+                *centroid = V::mean(&members);
+            }
+        }
+
+        let mut changed = false;
+        for (point, assignment) in points.iter().zip(assignments.iter_mut()) {
+            let cluster = nearest(point, &centroids);
+            if cluster != *assignment {
+                *assignment = cluster;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    Some(KMeans {
+        centroids,
+        assignments,
+        iterations,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Vector3;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn blobs() -> Vec<Vector3> {
+        let mut points = Vec::new();
+        for center in [0., 100., 200.] {
+            for offset in [-1., 0., 1.] {
+                points.push(Vector3::new(center + offset, center, -center));
+            }
+        }
+        points
+    }
+
+    #[test]
+    fn test_kmeans() {
+        let points = blobs();
+        let mut rng = StdRng::seed_from_u64(3);
+        let result = kmeans(&points, 3, 100, &mut rng).unwrap();
+        assert_eq!(result.centroids.len(), 3);
+        for blob in result.assignments.chunks(3) {
+            assert!(blob.iter().all(|&cluster| cluster == blob[0]));
+        }
+        // Each centroid is the middle point of a blob
+        assert_eq!(result.inertia(&points), 6.);
+    }
+
+    #[test]
+    fn test_kmeans_dynamic() {
+        let points: Vec<DynamicVector> = blobs()
+            .into_iter()
+            .map(|v| DynamicVector::from(vec![v.x, v.y, v.z, 1.]))
+            .collect();
+        let mut rng = StdRng::seed_from_u64(5);
+        let result = kmeans(&points, 3, 100, &mut rng).unwrap();
+        assert_eq!(result.inertia(&points), 6.);
+    }
+
+    #[test]
+    fn test_degenerate() {
+        let mut rng = StdRng::seed_from_u64(1);
+        assert!(kmeans::<Vector3, _>(&[], 2, 10, &mut rng).is_none());
+        let points = [Vector3::new(1., 1., 1.); 2];
+        assert!(kmeans(&points, 0, 10, &mut rng).is_none());
+        let result = kmeans(&points, 5, 10, &mut rng).unwrap();
+        assert_eq!(result.centroids.len(), 2);
+        assert_eq!(result.inertia(&points), 0.);
+    }
+}
@@ -1,16 +1,299 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("libvector needs either the `std` feature or the `libm` feature for float math");
+
+mod aabb;
+#[cfg(feature = "alloc")]
+mod aligned;
+mod angle;
+mod approx;
+#[cfg(feature = "proptest")]
+mod arbitrary;
+#[cfg(feature = "alloc")]
+mod arc_length;
+mod average;
+mod batch;
+mod bezier;
+#[cfg(feature = "std")]
+mod binary;
+mod bvec;
+mod bytes;
+mod checked;
+mod circle;
+#[cfg(feature = "color")]
+mod color;
+mod complex;
+mod conventions;
+mod covariance;
+#[cfg(feature = "std")]
+mod csv;
+#[cfg(feature = "std")]
+mod dedup;
+#[cfg(feature = "alloc")]
+mod differentiate;
+#[cfg(feature = "alloc")]
+mod dynamic_complex_vector;
+#[cfg(feature = "alloc")]
+mod dynamic_matrix;
+#[cfg(feature = "alloc")]
 mod dynamic_vector;
+mod error;
+#[cfg(feature = "fft")]
+mod fft;
+mod fixed;
+mod frustum;
+#[cfg(feature = "ga")]
+mod ga;
+#[cfg(feature = "alloc")]
+mod histogram;
+mod homogeneous;
+mod integrate;
+mod interop;
+mod interpolation;
+mod intersection;
+mod ivector;
+#[cfg(feature = "rand")]
+mod kmeans;
+mod line_distance;
+mod low_discrepancy;
+#[cfg(not(feature = "std"))]
+mod math;
+#[cfg(feature = "alloc")]
+mod mesh;
+mod metric;
+#[cfg(feature = "mmap")]
+mod mmap;
+mod noise;
+#[cfg(feature = "std")]
+mod npy;
+mod orthonormalize;
+#[cfg(feature = "alloc")]
+mod pca;
+#[cfg(feature = "alloc")]
+mod permutation;
+mod plane;
+#[cfg(feature = "bytemuck")]
+mod pod;
+#[cfg(feature = "std")]
+mod point_cloud;
+#[cfg(feature = "alloc")]
+mod polygon;
+mod polyline;
+mod pose;
+mod quaternion;
+#[cfg(feature = "rand")]
+mod random;
+mod ray;
+mod rot2;
+mod running_stats;
+mod scalar;
+#[cfg(feature = "alloc")]
+mod signal;
+#[cfg(feature = "alloc")]
+mod sort;
+mod space_filling;
+mod sphere;
+mod spline;
+mod stack_vector;
+mod swizzle;
+mod transform;
+mod triangle;
+mod twist;
+mod unit;
 mod vector2;
 mod vector3;
 mod vector4;
+#[cfg(feature = "alloc")]
+mod vector_field;
+#[cfg(feature = "alloc")]
+mod view;
 
+pub use aabb::*;
+#[cfg(feature = "alloc")]
+pub use aligned::DYNAMIC_VECTOR_ALIGNMENT;
+pub use angle::*;
+pub use approx::*;
+#[cfg(feature = "proptest")]
+pub use arbitrary::*;
+#[cfg(feature = "alloc")]
+pub use arc_length::*;
+pub use batch::*;
+pub use bezier::*;
+pub use bvec::*;
+pub use circle::*;
+#[cfg(feature = "color")]
+pub use color::*;
+pub use complex::*;
+pub use conventions::*;
+pub use covariance::*;
+#[cfg(feature = "std")]
+pub use csv::*;
+#[cfg(feature = "std")]
+pub use dedup::*;
+#[cfg(feature = "alloc")]
+pub use differentiate::*;
+#[cfg(feature = "alloc")]
+pub use dynamic_complex_vector::*;
+#[cfg(feature = "alloc")]
+pub use dynamic_matrix::*;
+#[cfg(feature = "alloc")]
+pub use dynamic_vector::*;
+pub use error::VectorError;
+pub use fixed::*;
+pub use frustum::*;
+#[cfg(feature = "ga")]
+pub use ga::*;
+#[cfg(feature = "alloc")]
+pub use histogram::*;
+pub use integrate::*;
+pub use interpolation::*;
+pub use intersection::*;
+pub use ivector::*;
+#[cfg(feature = "rand")]
+pub use kmeans::*;
+pub use low_discrepancy::*;
+#[cfg(feature = "alloc")]
+pub use mesh::*;
+pub use metric::*;
+#[cfg(feature = "mmap")]
+pub use mmap::*;
+pub use noise::*;
+#[cfg(feature = "std")]
+pub use npy::*;
+pub use orthonormalize::*;
+#[cfg(feature = "alloc")]
+pub use pca::*;
+pub use plane::*;
+#[cfg(feature = "bytemuck")]
+pub use pod::*;
+#[cfg(feature = "std")]
+pub use point_cloud::*;
+#[cfg(feature = "alloc")]
+pub use polygon::*;
+pub use polyline::*;
+pub use pose::*;
+pub use quaternion::*;
+#[cfg(feature = "rand")]
+pub use random::*;
+pub use ray::*;
+pub use rot2::*;
+pub use running_stats::*;
+pub use scalar::*;
+#[cfg(feature = "alloc")]
+pub use signal::*;
+#[cfg(feature = "alloc")]
+pub use sort::*;
+pub use space_filling::*;
+pub use sphere::*;
+pub use spline::*;
+pub use stack_vector::*;
+pub use transform::*;
+pub use triangle::*;
+pub use twist::*;
+pub use unit::*;
 pub use vector2::*;
 pub use vector3::*;
 pub use vector4::*;
+#[cfg(feature = "alloc")]
+pub use vector_field::*;
 pub use vector_macro::vector_macro as vector;
+pub use vector_macro::Vector;
+#[cfg(feature = "alloc")]
+pub use view::*;
+
+use core::ops::{Add, Div, Mul, Neg, Sub};
 
 /// Base trait for all vector types
 pub trait Vector {
     fn dot(&self, other: &Self) -> f64;
     fn magnitude(&self) -> f64;
     fn normalize(&self) -> Self;
+
+    /// Creates the zero vector, the identity of vector addition
+    ///
+    /// Types without a fixed dimension, such as [`DynamicVector`], return an empty vector. Use
+    /// [`Vector::zero_like`] to get a zero vector with the dimension of an existing one.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Vector, Vector3, VectorSpace};
+    ///
+    /// fn sum<V: VectorSpace>(vectors: &[V]) -> V {
+    ///     vectors.iter().fold(V::zero(), |sum, &v| sum + v)
+    /// }
+    ///
+    /// assert_eq!(sum::<Vector3>(&[]), Vector3::new(0., 0., 0.));
+    /// assert_eq!(sum(&[Vector3::new(1., 2., 3.), Vector3::new(1., 0., 0.)]), Vector3::new(2., 2., 3.));
+    /// ```
+    fn zero() -> Self;
+
+    /// Creates a zero vector with the same dimension as this one
+    ///
+    /// This is the same as [`Vector::zero`] for every type with a fixed dimension.
+    fn zero_like(&self) -> Self
+    where
+        Self: Sized,
+    {
+        Self::zero()
+    }
+
+    /// Checks whether the vector is zero, up to a tolerance
+    ///
+    /// ## Arguments
+    ///
+    /// * `epsilon` - The largest magnitude that still counts as zero
+    ///
+    /// ## Returns
+    ///
+    /// `true` if the magnitude of the vector is at most `epsilon`
+    fn is_zero(&self, epsilon: f64) -> bool {
+        self.magnitude() <= epsilon
+    }
+}
+
+/// A [`Vector`] that also supports the arithmetic of a vector space
+///
+/// This bundles the operator bounds that generic code needs, so a function that adds, scales and
+/// measures vectors can be written once as `fn f<V: VectorSpace>(...)` instead of repeating
+/// `Vector + Copy + Add<Output = V> + ...`. It is implemented automatically for every type with
+/// the required operators, including [`Vector2`], [`Vector3`], [`Vector4`] and the structs
+/// generated by [`vector!`].
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{Vector2, Vector3, VectorSpace};
+///
+/// fn reflect_through<V: VectorSpace>(point: V, center: V) -> V {
+///     center * 2. - point
+/// }
+///
+/// assert_eq!(reflect_through(Vector2::new(1., 0.), Vector2::new(0., 0.)), Vector2::new(-1., 0.));
+/// assert_eq!(reflect_through(-Vector3::new(1., 1., 1.), Vector3::new(0., 0., 0.)), Vector3::new(1., 1., 1.));
+/// ```
+pub trait VectorSpace:
+    Vector
+    + Copy
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<f64, Output = Self>
+    + Div<f64, Output = Self>
+    + Neg<Output = Self>
+{
+}
+
+impl<V> VectorSpace for V where
+    V: Vector
+        + Copy
+        + Add<Output = V>
+        + Sub<Output = V>
+        + Mul<f64, Output = V>
+        + Div<f64, Output = V>
+        + Neg<Output = V>
+{
 }
@@ -0,0 +1,118 @@
+use crate::{Vector, Vector2, Vector3, VectorSpace};
+
+/// Calculates how far along `direction` from `origin` the projection of `point` lies
+///
+/// The result is in multiples of `direction`. A zero direction collapses the line to its origin.
+fn line_parameter<V: VectorSpace>(point: V, origin: V, direction: V) -> f64 {
+    let length_square = direction.dot(&direction);
+    if length_square == 0. {
+        return 0.;
+    }
+    (point - origin).dot(&direction) / length_square
+}
+
+// Implements the point to line and point to ray queries for a vector type.
+macro_rules! line_queries {
+    ($V:ident) => {
+        impl $V {
+            /// Finds the point on an infinite line closest to this point
+            ///
+            /// The direction does not need to be normalized. A zero direction is treated as a line
+            /// consisting only of its origin.
+            ///
+            /// ## Arguments
+            ///
+            /// * `origin` - Any point on the line
+            /// * `direction` - The direction of the line
+            ///
+            /// ## Returns
+            ///
+            /// The closest point on the line
+            pub fn closest_point_on_line(&self, origin: &$V, direction: &$V) -> $V {
+                *origin + *direction * line_parameter(*self, *origin, *direction)
+            }
+
+            /// Calculates the distance from this point to an infinite line
+            ///
+            /// ## Arguments
+            ///
+            /// * `origin` - Any point on the line
+            /// * `direction` - The direction of the line
+            ///
+            /// ## Returns
+            ///
+            /// The perpendicular distance to the line
+            pub fn distance_to_line(&self, origin: &$V, direction: &$V) -> f64 {
+                (*self - self.closest_point_on_line(origin, direction)).magnitude()
+            }
+
+            /// Finds the point on a ray closest to this point
+            ///
+            /// Points behind the origin of the ray are closest to the origin itself.
+            ///
+            /// ## Arguments
+            ///
+            /// * `origin` - The starting point of the ray
+            /// * `direction` - The direction the ray travels in
+            ///
+            /// ## Returns
+            ///
+            /// The closest point on the ray
+            pub fn closest_point_on_ray(&self, origin: &$V, direction: &$V) -> $V {
+                let t = line_parameter(*self, *origin, *direction).max(0.);
+                *origin + *direction * t
+            }
+
+            /// Calculates the distance from this point to a ray
+            ///
+            /// ## Arguments
+            ///
+            /// * `origin` - The starting point of the ray
+            /// * `direction` - The direction the ray travels in
+            ///
+            /// ## Returns
+            ///
+            /// The distance to the closest point on the ray
+            pub fn distance_to_ray(&self, origin: &$V, direction: &$V) -> f64 {
+                (*self - self.closest_point_on_ray(origin, direction)).magnitude()
+            }
+        }
+    };
+}
+
+line_queries!(Vector2);
+line_queries!(Vector3);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line() {
+        let origin = Vector2::new(1., 1.);
+        let direction = Vector2::new(2., 0.);
+        let point = Vector2::new(-3., 4.);
+        assert_eq!(
+            point.closest_point_on_line(&origin, &direction),
+            Vector2::new(-3., 1.)
+        );
+        assert_eq!(point.distance_to_line(&origin, &direction), 3.);
+        assert_eq!(point.distance_to_line(&origin, &Vector2::new(0., 0.)), 5.);
+    }
+
+    #[test]
+    fn test_ray() {
+        let origin = Vector3::new(0., 0., 1.);
+        let direction = Vector3::new(0., 0., 4.);
+        let ahead = Vector3::new(3., 4., 10.);
+        assert_eq!(
+            ahead.closest_point_on_ray(&origin, &direction),
+            Vector3::new(0., 0., 10.)
+        );
+        assert_eq!(ahead.distance_to_ray(&origin, &direction), 5.);
+        let behind = Vector3::new(0., 3., -3.);
+        assert_eq!(behind.closest_point_on_ray(&origin, &direction), origin);
+        assert_eq!(behind.distance_to_ray(&origin, &direction), 5.);
+        assert_eq!(behind.distance_to_line(&origin, &direction), 3.);
+    }
+}
@@ -0,0 +1,272 @@
+#[cfg(not(feature = "std"))]
+use crate::math::FloatMath;
+use crate::{Vector2, Vector3};
+use core::f64::consts::{FRAC_PI_4, TAU};
+
+/// The quasi-random sequence a [`QuasiRandom`] sampler draws from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sequence {
+    /// The Halton sequence in bases 2 and 3
+    Halton,
+    /// The Sobol sequence using the first two dimensions
+    Sobol,
+}
+
+/// Calculates the radical inverse of `index` in a given base
+///
+/// This mirrors the digits of `index` around the decimal point, so `base = 2` gives the
+/// van der Corput sequence and pairs of coprime bases give the Halton sequence.
+///
+/// ## Arguments
+///
+/// * `index` - The position in the sequence
+/// * `base` - The base to mirror the digits in, which must be at least 2
+///
+/// ## Returns
+///
+/// A value in `[0, 1)`
+///
+/// ## Example
+///
+/// ```
+/// use libvector::radical_inverse;
+///
+/// assert_eq!(radical_inverse(1, 2), 0.5);
+/// assert_eq!(radical_inverse(3, 2), 0.75);
+/// assert_eq!(radical_inverse(1, 3), 1. / 3.);
+/// ```
+pub fn radical_inverse(mut index: u64, base: u32) -> f64 {
+    assert!(base >= 2, "radical inverse base must be at least 2");
+
+    let base = base as u64;
+    let inverse_base = 1. / base as f64;
+    let mut factor = inverse_base;
+    let mut result = 0.;
+    while index > 0 {
+        result += (index % base) as f64 * factor;
+        index /= base;
+        factor *= inverse_base;
+    }
+    result
+}
+
+/// Calculates a point of the 2D Sobol sequence
+///
+/// ## Arguments
+///
+/// * `index` - The position in the sequence
+///
+/// ## Returns
+///
+/// A point in the unit square `[0, 1)²`
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{sobol2, Vector2};
+///
+/// assert_eq!(sobol2(0), Vector2::new(0., 0.));
+/// assert_eq!(sobol2(1), Vector2::new(0.5, 0.5));
+/// assert_eq!(sobol2(2), Vector2::new(0.25, 0.75));
+/// ```
+pub fn sobol2(index: u32) -> Vector2 {
+    let mut x = 0u32;
+    let mut y = 0u32;
+    // Direction numbers of the second dimension, from the primitive polynomial x + 1
+    let mut v = 1u32 << 31;
+    let mut bits = index;
+    let mut bit = 0;
+    while bits > 0 {
+        if bits & 1 == 1 {
+            x ^= 1 << (31 - bit);
+            y ^= v;
+        }
+        v ^= v >> 1;
+        bits >>= 1;
+        bit += 1;
+    }
+
+    let scale = 1. / (1u64 << 32) as f64;
+    Vector2::new(x as f64 * scale, y as f64 * scale)
+}
+
+/// A stream of quasi-random points and directions
+///
+/// Quasi-random points cover their domain more evenly than independent random samples, so
+/// Monte Carlo estimates built from them converge faster. The sequence is deterministic, and
+/// each call to a `next_*` method advances it by one point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuasiRandom {
+    pub sequence: Sequence,
+    pub index: u32,
+}
+
+impl QuasiRandom {
+    /// Creates a new sampler starting at the beginning of a sequence
+    ///
+    /// ## Arguments
+    ///
+    /// * `sequence` - The sequence to draw points from
+    ///
+    /// ## Returns
+    ///
+    /// A new sampler
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{QuasiRandom, Sequence};
+    ///
+    /// let mut sampler = QuasiRandom::new(Sequence::Halton);
+    /// ```
+    pub fn new(sequence: Sequence) -> QuasiRandom {
+        QuasiRandom { sequence, index: 0 }
+    }
+
+    /// Calculates the point in the unit square at a given position in the sequence
+    ///
+    /// ## Arguments
+    ///
+    /// * `index` - The position in the sequence
+    ///
+    /// ## Returns
+    ///
+    /// A point in `[0, 1)²`
+    pub fn square_at(&self, index: u32) -> Vector2 {
+        match self.sequence {
+            Sequence::Halton => Vector2::new(
+                radical_inverse(index as u64, 2),
+                radical_inverse(index as u64, 3),
+            ),
+            Sequence::Sobol => sobol2(index),
+        }
+    }
+
+    /// Draws the next point in the unit square
+    ///
+    /// ## Returns
+    ///
+    /// A point in `[0, 1)²`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{QuasiRandom, Sequence, Vector2};
+    ///
+    /// let mut sampler = QuasiRandom::new(Sequence::Halton);
+    /// sampler.next_square();
+    ///
+    /// assert_eq!(sampler.next_square(), Vector2::new(0.5, 1. / 3.));
+    /// ```
+    pub fn next_square(&mut self) -> Vector2 {
+        let point = self.square_at(self.index);
+        self.index = self.index.wrapping_add(1);
+        point
+    }
+
+    /// Draws the next point in the unit disk
+    ///
+    /// Points are mapped from the square with the concentric mapping, which keeps the even
+    /// spacing of the sequence.
+    ///
+    /// ## Returns
+    ///
+    /// A point with a magnitude of at most 1
+    pub fn next_disk(&mut self) -> Vector2 {
+        let square = self.next_square();
+        concentric_disk(square * 2. - Vector2::new(1., 1.))
+    }
+
+    /// Draws the next direction on the unit sphere
+    ///
+    /// ## Returns
+    ///
+    /// A unit vector
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{QuasiRandom, Sequence, Vector};
+    ///
+    /// let mut sampler = QuasiRandom::new(Sequence::Sobol);
+    ///
+    /// for _ in 0..16 {
+    ///     assert!((sampler.next_sphere().magnitude() - 1.).abs() < 1e-12);
+    /// }
+    /// ```
+    pub fn next_sphere(&mut self) -> Vector3 {
+        let square = self.next_square();
+        let z = 1. - 2. * square.x;
+        let radius = (1. - z * z).max(0.).sqrt();
+        let (sin, cos) = (square.y * TAU).sin_cos();
+        Vector3::new(radius * cos, radius * sin, z)
+    }
+}
+
+/// Maps a point in `[-1, 1]²` onto the unit disk, preserving relative areas
+fn concentric_disk(p: Vector2) -> Vector2 {
+    if p.x == 0. && p.y == 0. {
+        return p;
+    }
+
+    let (radius, angle) = if p.x.abs() > p.y.abs() {
+        (p.x, FRAC_PI_4 * (p.y / p.x))
+    } else {
+        (p.y, 2. * FRAC_PI_4 - FRAC_PI_4 * (p.x / p.y))
+    };
+    let (sin, cos) = angle.sin_cos();
+    Vector2::new(radius * cos, radius * sin)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Vector;
+
+    #[test]
+    fn test_radical_inverse() {
+        let expected = [0., 0.5, 0.25, 0.75, 0.125, 0.625];
+        for (i, e) in expected.iter().enumerate() {
+            assert_eq!(radical_inverse(i as u64, 2), *e);
+        }
+        assert!((radical_inverse(5, 3) - 7. / 9.).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_sobol_stratification() {
+        // Every block of 2^k Sobol points puts exactly one point in each 1/2^k wide column and row
+        let n = 16;
+        let mut columns = [0; 16];
+        let mut rows = [0; 16];
+        for i in 0..n {
+            let p = sobol2(i);
+            columns[(p.x * n as f64) as usize] += 1;
+            rows[(p.y * n as f64) as usize] += 1;
+        }
+        assert!(columns.iter().all(|&c| c == 1));
+        assert!(rows.iter().all(|&c| c == 1));
+    }
+
+    #[test]
+    fn test_disk_and_sphere() {
+        for sequence in [Sequence::Halton, Sequence::Sobol] {
+            let mut disk = QuasiRandom::new(sequence);
+            let mut sphere = QuasiRandom::new(sequence);
+            let mut sum = Vector3::new(0., 0., 0.);
+            for _ in 0..1024 {
+                assert!(disk.next_disk().magnitude() <= 1. + 1e-12);
+                let d = sphere.next_sphere();
+                assert!((d.magnitude() - 1.).abs() < 1e-12);
+                sum = sum + d;
+            }
+            assert!(sum.magnitude() / 1024. < 0.02);
+        }
+    }
+
+    #[test]
+    fn test_concentric_disk_edges() {
+        assert_eq!(concentric_disk(Vector2::new(0., 0.)), Vector2::new(0., 0.));
+        assert!((concentric_disk(Vector2::new(1., 1.)).magnitude() - 1.).abs() < 1e-12);
+        assert!((concentric_disk(Vector2::new(-1., 0.3)).magnitude() - 1.).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,41 @@
+/// The float functions used by the crate that `core` does not provide, implemented with `libm`
+///
+/// This module only exists without the `std` feature. Modules that call one of these functions
+/// import the trait under `#[cfg(not(feature = "std"))]`, so the same method call resolves to
+/// the inherent `f64` method when `std` is enabled and to this trait otherwise.
+pub(crate) trait FloatMath {
+    fn sqrt(self) -> Self;
+    fn sin_cos(self) -> (Self, Self)
+    where
+        Self: Sized;
+    fn floor(self) -> Self;
+    fn round(self) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+}
+
+impl FloatMath for f64 {
+    fn sqrt(self) -> f64 {
+        libm::sqrt(self)
+    }
+
+    fn sin_cos(self) -> (f64, f64) {
+        libm::sincos(self)
+    }
+
+    fn floor(self) -> f64 {
+        libm::floor(self)
+    }
+
+    fn round(self) -> f64 {
+        libm::round(self)
+    }
+
+    fn powf(self, n: f64) -> f64 {
+        libm::pow(self, n)
+    }
+
+    fn atan2(self, other: f64) -> f64 {
+        libm::atan2(self, other)
+    }
+}
@@ -0,0 +1,334 @@
+use crate::{Radians, Triangle2, Triangle3, Vector, Vector2, Vector3};
+use alloc::{vec, vec::Vec};
+
+/// Looks up the corners of an indexed triangle
+fn corners<V: Copy>(values: &[V], triangle: &[u32; 3]) -> [V; 3] {
+    triangle.map(|index| values[index as usize])
+}
+
+/// Calculates a tangent and bitangent for every vertex of an indexed triangle mesh
+///
+/// The per-triangle tangents from [`Triangle3::tangents`] are summed at each vertex, weighted by
+/// the area of the triangle. The tangent is then made perpendicular to the vertex normal, and the
+/// bitangent is rebuilt from the normal and the tangent so that the three form an orthonormal
+/// basis. The bitangent keeps the direction of the summed bitangents, so mirrored texture
+/// coordinates are handled.
+///
+/// ## Arguments
+///
+/// * `positions` - The position of each vertex
+/// * `uvs` - The texture coordinates of each vertex
+/// * `indices` - The corners of each triangle, as indices into `positions` and `uvs`
+///
+/// ## Returns
+///
+/// The unit tangent and bitangent of each vertex. Vertices that are not part of any triangle
+/// with usable texture coordinates get zero vectors
+///
+/// ## Panics
+///
+/// If `positions` and `uvs` have different lengths or an index is out of bounds
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{vertex_tangents, Vector2, Vector3};
+///
+/// let positions = [
+///     Vector3::new(0., 0., 0.),
+///     Vector3::new(1., 0., 0.),
+///     Vector3::new(1., 1., 0.),
+///     Vector3::new(0., 1., 0.),
+/// ];
+/// let uvs = [
+///     Vector2::new(0., 0.),
+///     Vector2::new(1., 0.),
+///     Vector2::new(1., 1.),
+///     Vector2::new(0., 1.),
+/// ];
+///
+/// let tangents = vertex_tangents(&positions, &uvs, &[[0, 1, 2], [0, 2, 3]]);
+///
+/// assert_eq!(tangents[3], (Vector3::new(1., 0., 0.), Vector3::new(0., 1., 0.)));
+/// ```
+pub fn vertex_tangents(
+    positions: &[Vector3],
+    uvs: &[Vector2],
+    indices: &[[u32; 3]],
+) -> Vec<(Vector3, Vector3)> {
+    assert_eq!(
+        positions.len(),
+        uvs.len(),
+        "every vertex needs texture coordinates"
+    );
+
+    let zero = Vector3::new(0., 0., 0.);
+    let mut normals = vec![zero; positions.len()];
+    let mut tangents = vec![(zero, zero); positions.len()];
+    for triangle in indices {
+        let [a, b, c] = corners(positions, triangle);
+        let [uv_a, uv_b, uv_c] = corners(uvs, triangle);
+        // Half the cross product is both the normal and, through its length, the area weight
+        let normal = (b - a).cross(&(c - a));
+        let area = normal.magnitude() / 2.;
+        let Some((tangent, bitangent)) =
+            Triangle3::new(a, b, c).tangents(&Triangle2::new(uv_a, uv_b, uv_c))
+        else {
+            continue;
+        };
+
+        for &index in triangle {
+            let index = index as usize;
+            normals[index] = normals[index] + normal;
+            tangents[index].0 = tangents[index].0 + tangent * area;
+            tangents[index].1 = tangents[index].1 + bitangent * area;
+        }
+    }
+
+    normals
+        .into_iter()
+        .zip(tangents)
+        .map(|(normal, (tangent, bitangent))| {
+            if normal.magnitude() == 0. {
+                return (zero, zero);
+            }
+            let normal = normal.normalize();
+            let tangent = tangent - normal * normal.dot(&tangent);
+            if tangent.magnitude() == 0. {
+                return (zero, zero);
+            }
+            let tangent = tangent.normalize();
+            let mut rebuilt = normal.cross(&tangent);
+            if rebuilt.dot(&bitangent) < 0. {
+                rebuilt = -rebuilt;
+            }
+            (tangent, rebuilt)
+        })
+        .collect()
+}
+
+/// Calculates the unit normal of every triangle of an indexed mesh
+///
+/// Normals follow the right-hand rule like [`Triangle3::normal`], but degenerate triangles get a
+/// zero normal instead of `NaN` so they can be skipped or summed safely.
+///
+/// ## Arguments
+///
+/// * `positions` - The position of each vertex
+/// * `indices` - The corners of each triangle, as indices into `positions`
+///
+/// ## Returns
+///
+/// One normal per triangle
+///
+/// ## Panics
+///
+/// If an index is out of bounds
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{face_normals, Vector3};
+///
+/// let positions = [
+///     Vector3::new(0., 0., 0.),
+///     Vector3::new(1., 0., 0.),
+///     Vector3::new(0., 1., 0.),
+/// ];
+///
+/// assert_eq!(face_normals(&positions, &[[0, 1, 2]]), vec![Vector3::new(0., 0., 1.)]);
+/// ```
+pub fn face_normals(positions: &[Vector3], indices: &[[u32; 3]]) -> Vec<Vector3> {
+    indices
+        .iter()
+        .map(|triangle| {
+            let [a, b, c] = corners(positions, triangle);
+            let normal = (b - a).cross(&(c - a));
+            let magnitude = normal.magnitude();
+            if magnitude == 0. {
+                normal
+            } else {
+                normal / magnitude
+            }
+        })
+        .collect()
+}
+
+/// Calculates smooth normals for the corners of an indexed mesh, keeping sharp edges sharp
+///
+/// The normal of each corner is the average of the normals of the triangles sharing its vertex,
+/// weighted by the angle each triangle has at that vertex so the result does not depend on how
+/// the surface was split into triangles. Triangles whose normal differs from the corner's own
+/// triangle by more than `angle_threshold` are left out, so a vertex on a hard edge gets a
+/// different normal in the triangles on either side of it. Because of that the result holds one
+/// normal per corner rather than per vertex.
+///
+/// ## Arguments
+///
+/// * `positions` - The position of each vertex
+/// * `indices` - The corners of each triangle, as indices into `positions`
+/// * `angle_threshold` - The largest angle between two triangles that is still smoothed over. A
+///   plain `f64` is taken to be in radians
+///
+/// ## Returns
+///
+/// One unit normal per corner, in the order of `indices`, so the normal of corner `j` of
+/// triangle `i` is at `3 * i + j`. Corners of degenerate triangles get a zero normal
+///
+/// ## Panics
+///
+/// If an index is out of bounds
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{smooth_vertex_normals, Degrees, Vector3};
+///
+/// // Two faces of a cube meeting at a right angle along the edge from vertex 0 to vertex 1
+/// let positions = [
+///     Vector3::new(0., 0., 0.),
+///     Vector3::new(1., 0., 0.),
+///     Vector3::new(0., 1., 0.),
+///     Vector3::new(0., 0., 1.),
+/// ];
+/// let indices = [[0, 2, 1], [0, 1, 3]];
+///
+/// let smooth = smooth_vertex_normals(&positions, &indices, Degrees(100.));
+/// let sharp = smooth_vertex_normals(&positions, &indices, Degrees(30.));
+///
+/// assert_eq!(sharp[0], Vector3::new(0., 0., -1.));
+/// assert_eq!(sharp[3], Vector3::new(0., -1., 0.));
+/// assert_eq!(smooth[0], smooth[3]);
+/// ```
+pub fn smooth_vertex_normals(
+    positions: &[Vector3],
+    indices: &[[u32; 3]],
+    angle_threshold: impl Into<Radians>,
+) -> Vec<Vector3> {
+    let (_, min_cos) = angle_threshold.into().sin_cos();
+    let normals = face_normals(positions, indices);
+
+    // The angle of every corner, and the triangles around every vertex
+    let mut angles = Vec::with_capacity(indices.len() * 3);
+    let mut adjacent = vec![Vec::new(); positions.len()];
+    for (face, triangle) in indices.iter().enumerate() {
+        let [a, b, c] = corners(positions, triangle);
+        for (corner, next, previous) in [(a, b, c), (b, c, a), (c, a, b)] {
+            angles.push((next - corner).angle_between(&(previous - corner)).0);
+        }
+        for &index in triangle {
+            adjacent[index as usize].push(face);
+        }
+    }
+
+    let zero = Vector3::new(0., 0., 0.);
+    let mut result = Vec::with_capacity(indices.len() * 3);
+    for (face, triangle) in indices.iter().enumerate() {
+        let own = normals[face];
+        for &index in triangle {
+            if own == zero {
+                result.push(zero);
+                continue;
+            }
+            let mut sum = zero;
+            for &other in &adjacent[index as usize] {
+                let normal = normals[other];
+                if normal == zero || own.dot(&normal) < min_cos {
+                    continue;
+                }
+                let corner = indices[other].iter().position(|&i| i == index).unwrap();
+                sum = sum + normal * angles[3 * other + corner];
+            }
+            result.push(sum.normalize());
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Degrees;
+
+    #[test]
+    fn test_vertex_tangents() {
+        // Two triangles folded along the y axis, with the texture mirrored on the second
+        let positions = [
+            Vector3::new(0., 0., 0.),
+            Vector3::new(0., 1., 0.),
+            Vector3::new(1., 0., 0.),
+            Vector3::new(0., 0., 1.),
+            Vector3::new(5., 5., 5.),
+        ];
+        let uvs = [
+            Vector2::new(0., 0.),
+            Vector2::new(0., 1.),
+            Vector2::new(1., 0.),
+            Vector2::new(-1., 0.),
+            Vector2::new(0., 0.),
+        ];
+        let tangents = vertex_tangents(&positions, &uvs, &[[0, 2, 1], [0, 1, 3]]);
+
+        assert_eq!(
+            tangents[2],
+            (Vector3::new(1., 0., 0.), Vector3::new(0., 1., 0.))
+        );
+        assert_eq!(
+            tangents[3],
+            (Vector3::new(0., 0., -1.), Vector3::new(0., 1., 0.))
+        );
+        let (tangent, bitangent) = tangents[0];
+        assert!((tangent.magnitude() - 1.).abs() < 1e-12);
+        assert!(tangent.dot(&bitangent).abs() < 1e-12);
+        assert!((bitangent - Vector3::new(0., 1., 0.)).magnitude() < 1e-12);
+        // The unused vertex
+        let zero = Vector3::new(0., 0., 0.);
+        assert_eq!(tangents[4], (zero, zero));
+    }
+
+    #[test]
+    fn test_face_normals() {
+        let positions = [
+            Vector3::new(0., 0., 0.),
+            Vector3::new(0., 2., 0.),
+            Vector3::new(0., 0., 3.),
+        ];
+        assert_eq!(
+            face_normals(&positions, &[[0, 1, 2], [0, 2, 1], [0, 0, 1]]),
+            vec![
+                Vector3::new(1., 0., 0.),
+                Vector3::new(-1., 0., 0.),
+                Vector3::new(0., 0., 0.),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_smooth_vertex_normals() {
+        // A fan of four triangles around vertex 0 forming a shallow pyramid, split unevenly so
+        // that angle weighting matters
+        let positions = [
+            Vector3::new(0., 0., 1.),
+            Vector3::new(1., 0., 0.),
+            Vector3::new(0., 1., 0.),
+            Vector3::new(-1., 0., 0.),
+            Vector3::new(0., -1., 0.),
+            Vector3::new(0.5, -0.5, 0.),
+        ];
+        let indices = [[0, 1, 2], [0, 2, 3], [0, 3, 4], [0, 4, 5], [0, 5, 1]];
+        let normals = smooth_vertex_normals(&positions, &indices, Degrees(180.));
+        assert_eq!(normals.len(), 15);
+        let apex = normals[0];
+        assert!((apex - Vector3::new(0., 0., 1.)).magnitude() < 1e-12);
+        for triangle in 0..indices.len() {
+            assert_eq!(normals[3 * triangle], apex);
+        }
+
+        // A threshold below the angle between the faces keeps every face flat
+        let flat = smooth_vertex_normals(&positions, &indices, 0.1);
+        let faces = face_normals(&positions, &indices);
+        for (i, normal) in flat.iter().enumerate() {
+            assert!((*normal - faces[i / 3]).magnitude() < 1e-12);
+        }
+    }
+}
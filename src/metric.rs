@@ -0,0 +1,154 @@
+#[cfg(not(feature = "std"))]
+use crate::math::FloatMath;
+#[cfg(feature = "alloc")]
+use crate::DynamicVector;
+use crate::{Vector, VectorSpace};
+
+/// A vector type with an inner product, which gives it lengths and angles
+///
+/// This is implemented for every [`Vector`], using its dot product. Algorithms that only need
+/// inner products can be written once against this trait and work for fixed size and dynamic
+/// vectors alike.
+pub trait InnerProduct {
+    /// Calculates the inner product of two vectors
+    fn inner_product(&self, other: &Self) -> f64;
+
+    /// Calculates the squared norm of the vector, its inner product with itself
+    fn norm_squared(&self) -> f64 {
+        self.inner_product(self)
+    }
+
+    /// Calculates the norm of the vector
+    fn norm(&self) -> f64 {
+        self.norm_squared().sqrt()
+    }
+}
+
+impl<V: Vector> InnerProduct for V {
+    fn inner_product(&self, other: &V) -> f64 {
+        self.dot(other)
+    }
+}
+
+/// A type with a distance between any two of its values
+///
+/// This is implemented for every [`VectorSpace`] type and for [`DynamicVector`] using the
+/// Euclidean distance, and is what nearest neighbour searches and clustering such as `kmeans`
+/// (with the `rand` feature) are built on.
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{Metric, Vector2};
+///
+/// let a = Vector2::new(1., 1.);
+/// let b = Vector2::new(4., 5.);
+///
+/// assert_eq!(a.distance_squared(&b), 25.);
+/// assert_eq!(a.distance(&b), 5.);
+/// ```
+pub trait Metric {
+    /// Calculates the squared distance between two values
+    ///
+    /// This avoids a square root, and sorts values in the same order as [`Metric::distance`].
+    fn distance_squared(&self, other: &Self) -> f64;
+
+    /// Calculates the distance between two values
+    fn distance(&self, other: &Self) -> f64 {
+        self.distance_squared(other).sqrt()
+    }
+}
+
+impl<V: VectorSpace> Metric for V {
+    fn distance_squared(&self, other: &V) -> f64 {
+        let difference = *self - *other;
+        difference.dot(&difference)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Metric for DynamicVector {
+    /// Calculates the squared distance between two vectors without allocating their difference
+    ///
+    /// ## Panics
+    ///
+    /// If the vectors have different lengths
+    fn distance_squared(&self, other: &DynamicVector) -> f64 {
+        assert_eq!(self.len(), other.len(), "vectors must have the same length");
+        self.as_slice()
+            .iter()
+            .zip(other.as_slice())
+            .map(|(a, b)| (a - b) * (a - b))
+            .sum()
+    }
+}
+
+/// Finds the value closest to a query point by brute force
+///
+/// ## Arguments
+///
+/// * `query` - The point to search around
+/// * `candidates` - The values to search
+///
+/// ## Returns
+///
+/// The index of the closest candidate and its distance, or `None` if there are no candidates
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{nearest_neighbour, Vector3};
+///
+/// let points = [Vector3::new(0., 0., 0.), Vector3::new(5., 0., 0.), Vector3::new(1., 1., 0.)];
+/// let (index, _) = nearest_neighbour(&Vector3::new(2., 1., 0.), &points).unwrap();
+///
+/// assert_eq!(index, 2);
+/// ```
+pub fn nearest_neighbour<M: Metric>(query: &M, candidates: &[M]) -> Option<(usize, f64)> {
+    candidates
+        .iter()
+        .map(|candidate| query.distance_squared(candidate))
+        .enumerate()
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(index, distance_squared)| (index, distance_squared.sqrt()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Vector2, Vector4};
+
+    #[test]
+    fn test_inner_product() {
+        let v = Vector4::new(1., 2., 2., 4.);
+        assert_eq!(v.inner_product(&Vector4::new(1., 0., 0., 1.)), 5.);
+        assert_eq!(v.norm_squared(), 25.);
+        assert_eq!(v.norm(), 5.);
+    }
+
+    #[test]
+    fn test_nearest_neighbour() {
+        assert_eq!(
+            nearest_neighbour::<Vector2>(&Vector2::new(0., 0.), &[]),
+            None
+        );
+        let points = [Vector2::new(3., 4.), Vector2::new(-1., 0.)];
+        assert_eq!(
+            nearest_neighbour(&Vector2::new(0., 0.), &points),
+            Some((1, 1.))
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_dynamic() {
+        let a = DynamicVector::from(alloc::vec![1., 2., 3., 4., 5.]);
+        let b = DynamicVector::from(alloc::vec![1., 2., 3., 4., 3.]);
+        assert_eq!(a.distance(&b), 2.);
+        assert_eq!(a.norm_squared(), 55.);
+        assert_eq!(
+            nearest_neighbour(&a, &[b.clone(), a.clone()]),
+            Some((1, 0.))
+        );
+    }
+}
@@ -0,0 +1,337 @@
+use crate::{DynamicVector, Float};
+use memmap2::Mmap;
+use std::fs::File;
+use std::io;
+use std::marker::PhantomData;
+use std::mem::{align_of, size_of};
+use std::path::Path;
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+}
+
+/// A scalar type that can be read in place from a memory-mapped file
+///
+/// This is implemented for `f32` and `f64` only, as every bit pattern of those types is a valid
+/// value.
+pub trait MappedScalar: Float + private::Sealed {}
+
+impl MappedScalar for f32 {}
+impl MappedScalar for f64 {}
+
+/// A file of vectors that is memory-mapped instead of read into memory
+///
+/// The file holds nothing but the components of every row, one after another, as little-endian
+/// `f32` or `f64` values. Only the pages of the file that are actually touched are loaded by the
+/// operating system, so datasets far larger than the available memory, such as embedding files,
+/// can be opened instantly and rows borrowed as [`RowView`]s without copying.
+///
+/// The file must not be modified while it is mapped, the rows would change underneath any
+/// borrowed views.
+///
+/// ## Example
+///
+/// ```no_run
+/// use libvector::VectorDataset;
+///
+/// let embeddings = VectorDataset::<f32>::open("embeddings.bin", 768).unwrap();
+/// let query = embeddings.row(0);
+///
+/// let closest = embeddings
+///     .rows()
+///     .enumerate()
+///     .skip(1)
+///     .map(|(i, row)| (i, row.dot(&query)))
+///     .max_by(|a, b| a.1.total_cmp(&b.1));
+/// ```
+#[derive(Debug)]
+pub struct VectorDataset<T: MappedScalar> {
+    map: Mmap,
+    dimension: usize,
+    scalar: PhantomData<T>,
+}
+
+impl<T: MappedScalar> VectorDataset<T> {
+    /// Memory-maps a file of vectors
+    ///
+    /// ## Arguments
+    ///
+    /// * `path` - The path of the file
+    /// * `dimension` - The number of components in every row
+    ///
+    /// ## Returns
+    ///
+    /// The dataset, or an error if the file cannot be opened or mapped.
+    /// [`io::ErrorKind::InvalidInput`] if `dimension` is zero or a row would not fit in memory,
+    /// [`io::ErrorKind::InvalidData`]
+    /// if the file size is not a whole number of rows, and [`io::ErrorKind::Unsupported`] on
+    /// big-endian platforms, where the values cannot be used in place
+    pub fn open<P: AsRef<Path>>(path: P, dimension: usize) -> io::Result<VectorDataset<T>> {
+        if dimension == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "a dataset needs at least one component per row",
+            ));
+        }
+        let row_bytes = dimension.checked_mul(size_of::<T>()).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidInput, "the dimension is too large")
+        })?;
+        if cfg!(target_endian = "big") {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "memory-mapped datasets are little-endian and need a little-endian platform",
+            ));
+        }
+
+        let file = File::open(path)?;
+        // SAFETY: the mapping is only ever read, and the documentation of `VectorDataset` asks
+        // that the file is not modified while it is mapped
+        let map = unsafe { Mmap::map(&file)? };
+        if map.len() % row_bytes != 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "the file size is not a whole number of rows",
+            ));
+        }
+        // Mappings start on a page boundary, so this only fails for empty files, whose pointer
+        // may be dangling but is never read
+        if map.as_ptr().align_offset(align_of::<T>()) != 0 && !map.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "the mapped file is not aligned for its scalar type",
+            ));
+        }
+
+        Ok(VectorDataset {
+            map,
+            dimension,
+            scalar: PhantomData,
+        })
+    }
+
+    /// Gets the number of components in every row
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    /// Gets the number of rows
+    pub fn len(&self) -> usize {
+        // `open` checked that the size of a row does not overflow
+        self.map.len() / (self.dimension * size_of::<T>())
+    }
+
+    /// Checks whether the dataset has no rows
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Gets every value of the dataset as one slice
+    pub fn as_slice(&self) -> &[T] {
+        if self.map.is_empty() {
+            return &[];
+        }
+        // SAFETY: `open` checked that the mapping is aligned for `T` and a whole number of `T`s
+        // long, `T` is `f32` or `f64` for which every bit pattern is valid, and the platform is
+        // little-endian like the file
+        unsafe {
+            std::slice::from_raw_parts(
+                self.map.as_ptr() as *const T,
+                self.map.len() / size_of::<T>(),
+            )
+        }
+    }
+
+    /// Borrows a row of the dataset
+    ///
+    /// ## Arguments
+    ///
+    /// * `index` - The index of the row
+    ///
+    /// ## Returns
+    ///
+    /// A view of the row, read straight from the mapped file
+    ///
+    /// ## Panics
+    ///
+    /// If `index` is out of bounds
+    pub fn row(&self, index: usize) -> RowView<'_, T> {
+        assert!(index < self.len(), "index out of bounds");
+        let start = index * self.dimension;
+        RowView {
+            values: &self.as_slice()[start..start + self.dimension],
+        }
+    }
+
+    /// Iterates over views of the rows of the dataset
+    pub fn rows(&self) -> impl Iterator<Item = RowView<'_, T>> {
+        self.as_slice()
+            .chunks_exact(self.dimension)
+            .map(|values| RowView { values })
+    }
+
+    /// Copies a row of the dataset into a new vector
+    ///
+    /// ## Arguments
+    ///
+    /// * `index` - The index of the row
+    ///
+    /// ## Returns
+    ///
+    /// The row, converted to `f64`
+    ///
+    /// ## Panics
+    ///
+    /// If `index` is out of bounds
+    pub fn row_vector(&self, index: usize) -> DynamicVector {
+        self.row(index).to_vector()
+    }
+}
+
+/// A read-only view of one row of a [`VectorDataset`]
+///
+/// Created with [`VectorDataset::row`] and [`VectorDataset::rows`]. Like
+/// [`StridedView`](crate::StridedView), it can be measured and multiplied without copying the
+/// row out of the mapped file.
+#[derive(Debug, Clone, Copy)]
+pub struct RowView<'a, T: MappedScalar> {
+    values: &'a [T],
+}
+
+impl<'a, T: MappedScalar> RowView<'a, T> {
+    /// Gets the number of elements in the row
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Checks whether the row has no elements
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Gets a value from the row
+    ///
+    /// ## Arguments
+    ///
+    /// * `index` - The index of the value within the row
+    ///
+    /// ## Returns
+    ///
+    /// The value at the specified index
+    ///
+    /// ## Panics
+    ///
+    /// If `index` is out of bounds
+    pub fn get(&self, index: usize) -> T {
+        self.values[index]
+    }
+
+    /// Iterates over the elements of the row
+    pub fn iter(&self) -> impl Iterator<Item = T> + 'a {
+        self.values.iter().copied()
+    }
+
+    /// Borrows the elements of the row as a slice
+    pub fn as_slice(&self) -> &'a [T] {
+        self.values
+    }
+
+    /// Calculates the dot product of two rows
+    ///
+    /// ## Arguments
+    ///
+    /// * `other` - The other row
+    ///
+    /// ## Returns
+    ///
+    /// The dot product of the two rows
+    ///
+    /// ## Panics
+    ///
+    /// If the rows have different lengths
+    pub fn dot(&self, other: &RowView<T>) -> f64 {
+        assert_eq!(self.len(), other.len(), "rows must have the same length");
+        self.iter()
+            .zip(other.iter())
+            .fold(T::ZERO, |sum, (a, b)| sum + a * b)
+            .to_f64()
+    }
+
+    /// Calculates the magnitude of the row
+    pub fn magnitude(&self) -> f64 {
+        self.iter()
+            .fold(T::ZERO, |sum, a| sum + a * a)
+            .sqrt()
+            .to_f64()
+    }
+
+    /// Calculates the sum of the elements of the row
+    pub fn sum(&self) -> f64 {
+        self.iter().fold(T::ZERO, |sum, a| sum + a).to_f64()
+    }
+
+    /// Copies the elements of the row into a new `f64` vector
+    pub fn to_vector(&self) -> DynamicVector {
+        self.iter().map(|value| value.to_f64()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::path::PathBuf;
+
+    fn write_temp(name: &str, bytes: &[u8]) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("libvector_{}_{}", std::process::id(), name));
+        fs::write(&path, bytes).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_open() {
+        let values = [1_f32, 2., 3., 4., 5., 6.];
+        let bytes: Vec<u8> = values.iter().flat_map(|v| v.to_le_bytes()).collect();
+        let path = write_temp("f32.bin", &bytes);
+
+        let dataset = VectorDataset::<f32>::open(&path, 3).unwrap();
+        assert_eq!(dataset.len(), 2);
+        assert_eq!(dataset.dimension(), 3);
+        assert_eq!(dataset.row(1).as_slice(), &[4., 5., 6.]);
+        assert_eq!(dataset.row(0).dot(&dataset.row(1)), 32.);
+        assert_eq!(dataset.row(1).get(2), 6.);
+        assert_eq!(dataset.rows().map(|row| row.sum()).sum::<f64>(), 21.);
+        assert_eq!(dataset.row_vector(0), [1., 2., 3.]);
+
+        // The same 24 bytes are three f64 values, which do not split into rows of two
+        let error = VectorDataset::<f64>::open(&path, 2).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(VectorDataset::<f64>::open(&path, 3).unwrap().len(), 1);
+        let error = VectorDataset::<f32>::open(&path, 0).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        let error = VectorDataset::<f64>::open(&path, usize::MAX).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_empty() {
+        let path = write_temp("empty.bin", &[]);
+        let dataset = VectorDataset::<f64>::open(&path, 4).unwrap();
+        assert!(dataset.is_empty());
+        assert_eq!(dataset.rows().count(), 0);
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_row_out_of_bounds() {
+        let path = write_temp("bounds.bin", &[0; 16]);
+        let dataset = VectorDataset::<f64>::open(&path, 2).unwrap();
+        fs::remove_file(&path).unwrap();
+        // The start of this row wraps around to zero
+        dataset.row(usize::MAX / 2 + 1);
+    }
+}
@@ -0,0 +1,311 @@
+#[cfg(not(feature = "std"))]
+use crate::math::FloatMath;
+use crate::{lerp, Vector, Vector2, Vector3};
+
+/// The gradients at the corners of the 2D lattice, picked by the low bits of a corner's hash
+const GRADIENTS2: [[f64; 2]; 8] = [
+    [1., 1.],
+    [-1., 1.],
+    [1., -1.],
+    [-1., -1.],
+    [1., 0.],
+    [-1., 0.],
+    [0., 1.],
+    [0., -1.],
+];
+
+/// The gradients at the corners of the 3D lattice, the midpoints of the edges of a cube with four
+/// of them repeated so that the table can be indexed with four bits
+const GRADIENTS3: [[f64; 3]; 16] = [
+    [1., 1., 0.],
+    [-1., 1., 0.],
+    [1., -1., 0.],
+    [-1., -1., 0.],
+    [1., 0., 1.],
+    [-1., 0., 1.],
+    [1., 0., -1.],
+    [-1., 0., -1.],
+    [0., 1., 1.],
+    [0., -1., 1.],
+    [0., 1., -1.],
+    [0., -1., -1.],
+    [1., 1., 0.],
+    [-1., 1., 0.],
+    [0., -1., 1.],
+    [0., -1., -1.],
+];
+
+/// The quintic curve `6t^5 - 15t^4 + 10t^3` used to blend between lattice corners, and its
+/// derivative
+fn fade(t: f64) -> (f64, f64) {
+    let value = t * t * t * (t * (t * 6. - 15.) + 10.);
+    let derivative = 30. * t * t * (t * (t - 2.) + 1.);
+    (value, derivative)
+}
+
+/// Splits a coordinate into the index of its lattice cell, wrapped to the size of the
+/// permutation table, and the position inside that cell
+fn cell(coordinate: f64) -> (usize, f64) {
+    let floor = coordinate.floor();
+    ((floor as i64).rem_euclid(256) as usize, coordinate - floor)
+}
+
+/// Seedable Perlin gradient noise in two and three dimensions
+///
+/// This is Ken Perlin's improved noise. It is zero at every integer lattice point, smooth
+/// everywhere else and repeats every 256 units. Alongside the value, the `_with_gradient`
+/// methods return the exact derivative, which is useful for surface normals of noise terrain or
+/// for curl noise, without having to sample the noise several more times.
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{Perlin, Vector2};
+///
+/// let perlin = Perlin::new(42);
+///
+/// assert_eq!(perlin.noise2(&Vector2::new(3., 7.)), 0.);
+///
+/// let height = perlin.noise2(&Vector2::new(3.25, 7.5));
+/// assert!(height.abs() <= 1.);
+/// assert_eq!(height, Perlin::new(42).noise2(&Vector2::new(3.25, 7.5)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Perlin {
+    /// A permutation of `0..256`, stored twice so that hashes of neighbouring cells do not need
+    /// to wrap
+    permutation: [u8; 512],
+}
+
+impl Perlin {
+    /// Creates a noise generator with a permutation table shuffled by a seed
+    ///
+    /// ## Arguments
+    ///
+    /// * `seed` - The seed, the same seed always produces the same noise
+    ///
+    /// ## Returns
+    ///
+    /// A new noise generator
+    pub fn new(seed: u64) -> Perlin {
+        // SplitMix64, which is plenty for shuffling 256 values and needs no dependencies
+        let mut state = seed;
+        let mut next = || {
+            state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            z ^ (z >> 31)
+        };
+
+        let mut table = [0_u8; 256];
+        for (i, value) in table.iter_mut().enumerate() {
+            *value = i as u8;
+        }
+        for i in (1..table.len()).rev() {
+            let j = (next() % (i as u64 + 1)) as usize;
+            table.swap(i, j);
+        }
+
+        let mut permutation = [0; 512];
+        for (i, value) in permutation.iter_mut().enumerate() {
+            *value = table[i % 256];
+        }
+        Perlin { permutation }
+    }
+
+    /// Hashes a lattice cell index together with the hash of the dimensions before it
+    fn hash(&self, previous: usize, index: usize) -> usize {
+        self.permutation[previous + index] as usize
+    }
+
+    /// Evaluates 2D noise at a point
+    ///
+    /// ## Arguments
+    ///
+    /// * `point` - The point to sample
+    ///
+    /// ## Returns
+    ///
+    /// The noise value, in the range `[-1, 1]`
+    pub fn noise2(&self, point: &Vector2) -> f64 {
+        self.noise2_with_gradient(point).0
+    }
+
+    /// Evaluates 2D noise and its gradient at a point
+    ///
+    /// ## Arguments
+    ///
+    /// * `point` - The point to sample
+    ///
+    /// ## Returns
+    ///
+    /// The noise value and the vector of its partial derivatives
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Perlin, Vector2};
+    ///
+    /// let perlin = Perlin::new(7);
+    /// let p = Vector2::new(1.3, -2.6);
+    /// let (value, gradient) = perlin.noise2_with_gradient(&p);
+    ///
+    /// // Stepping along the gradient raises the value
+    /// assert!(perlin.noise2(&(p + gradient * 1e-3)) > value);
+    /// ```
+    pub fn noise2_with_gradient(&self, point: &Vector2) -> (f64, Vector2) {
+        let (i, fx) = cell(point.x);
+        let (j, fy) = cell(point.y);
+        let (u, du) = fade(fx);
+        let (v, dv) = fade(fy);
+
+        let corner = |di: usize, dj: usize| {
+            let hash = self.hash(self.hash(0, i + di), j + dj);
+            let gradient = Vector2::from(GRADIENTS2[hash & 7]);
+            let offset = Vector2::new(fx - di as f64, fy - dj as f64);
+            (gradient.dot(&offset), gradient)
+        };
+        let (n00, g00) = corner(0, 0);
+        let (n10, g10) = corner(1, 0);
+        let (n01, g01) = corner(0, 1);
+        let (n11, g11) = corner(1, 1);
+
+        // The bilinear blend written as a polynomial in u and v, whose coefficients also give
+        // the derivative of the blending weights
+        let k1 = n10 - n00;
+        let k2 = n01 - n00;
+        let k3 = n00 - n10 - n01 + n11;
+        let value = n00 + k1 * u + k2 * v + k3 * u * v;
+
+        let blended = lerp(lerp(g00, g10, u), lerp(g01, g11, u), v);
+        let gradient = blended + Vector2::new(du * (k1 + k3 * v), dv * (k2 + k3 * u));
+        (value, gradient)
+    }
+
+    /// Evaluates 3D noise at a point
+    ///
+    /// ## Arguments
+    ///
+    /// * `point` - The point to sample
+    ///
+    /// ## Returns
+    ///
+    /// The noise value, roughly in the range `[-1, 1]`
+    pub fn noise3(&self, point: &Vector3) -> f64 {
+        self.noise3_with_gradient(point).0
+    }
+
+    /// Evaluates 3D noise and its gradient at a point
+    ///
+    /// ## Arguments
+    ///
+    /// * `point` - The point to sample
+    ///
+    /// ## Returns
+    ///
+    /// The noise value and the vector of its partial derivatives
+    pub fn noise3_with_gradient(&self, point: &Vector3) -> (f64, Vector3) {
+        let (i, fx) = cell(point.x);
+        let (j, fy) = cell(point.y);
+        let (k, fz) = cell(point.z);
+        let (u, du) = fade(fx);
+        let (v, dv) = fade(fy);
+        let (w, dw) = fade(fz);
+
+        let corner = |di: usize, dj: usize, dk: usize| {
+            let hash = self.hash(self.hash(self.hash(0, i + di), j + dj), k + dk);
+            let gradient = Vector3::from(GRADIENTS3[hash & 15]);
+            let offset = Vector3::new(fx - di as f64, fy - dj as f64, fz - dk as f64);
+            (gradient.dot(&offset), gradient)
+        };
+        let (n000, g000) = corner(0, 0, 0);
+        let (n100, g100) = corner(1, 0, 0);
+        let (n010, g010) = corner(0, 1, 0);
+        let (n110, g110) = corner(1, 1, 0);
+        let (n001, g001) = corner(0, 0, 1);
+        let (n101, g101) = corner(1, 0, 1);
+        let (n011, g011) = corner(0, 1, 1);
+        let (n111, g111) = corner(1, 1, 1);
+
+        let k1 = n100 - n000;
+        let k2 = n010 - n000;
+        let k3 = n001 - n000;
+        let k4 = n000 - n100 - n010 + n110;
+        let k5 = n000 - n010 - n001 + n011;
+        let k6 = n000 - n100 - n001 + n101;
+        let k7 = n100 + n010 + n001 + n111 - n000 - n110 - n101 - n011;
+        let value =
+            n000 + k1 * u + k2 * v + k3 * w + k4 * u * v + k5 * v * w + k6 * w * u + k7 * u * v * w;
+
+        let blended = lerp(
+            lerp(lerp(g000, g100, u), lerp(g010, g110, u), v),
+            lerp(lerp(g001, g101, u), lerp(g011, g111, u), v),
+            w,
+        );
+        let gradient = blended
+            + Vector3::new(
+                du * (k1 + k4 * v + k6 * w + k7 * v * w),
+                dv * (k2 + k5 * w + k4 * u + k7 * w * u),
+                dw * (k3 + k6 * u + k5 * v + k7 * u * v),
+            );
+        (value, gradient)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seeds() {
+        let point = Vector3::new(0.5, 1.25, -3.75);
+        assert_eq!(Perlin::new(1), Perlin::new(1));
+        assert_ne!(Perlin::new(1).noise3(&point), Perlin::new(2).noise3(&point));
+    }
+
+    #[test]
+    fn test_lattice_and_range() {
+        let perlin = Perlin::new(3);
+        assert_eq!(perlin.noise2(&Vector2::new(-4., 9.)), 0.);
+        assert_eq!(perlin.noise3(&Vector3::new(2., -1., 300.)), 0.);
+        for i in 0..40 {
+            let t = i as f64 * 0.37;
+            assert!(perlin.noise2(&Vector2::new(t, -t * 0.5)).abs() <= 1.);
+            assert!(perlin.noise3(&Vector3::new(t, t * 0.7, -t)).abs() <= 1.1);
+        }
+        // The noise repeats every 256 units
+        let p = Vector2::new(0.3, 0.6);
+        let shifted = p + Vector2::new(256., -512.);
+        assert!((perlin.noise2(&p) - perlin.noise2(&shifted)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_gradients() {
+        let perlin = Perlin::new(11);
+        let h = 1e-6;
+        for i in 0..20 {
+            let t = i as f64 * 0.61 - 5.;
+            let p = Vector2::new(t, t * 1.3 + 0.2);
+            let (_, gradient) = perlin.noise2_with_gradient(&p);
+            let dx = (perlin.noise2(&(p + Vector2::new(h, 0.)))
+                - perlin.noise2(&(p - Vector2::new(h, 0.))))
+                / (2. * h);
+            let dy = (perlin.noise2(&(p + Vector2::new(0., h)))
+                - perlin.noise2(&(p - Vector2::new(0., h))))
+                / (2. * h);
+            assert!((gradient - Vector2::new(dx, dy)).magnitude() < 1e-6);
+
+            let p = Vector3::new(t, 0.4 - t, t * 0.8);
+            let (_, gradient) = perlin.noise3_with_gradient(&p);
+            let axis =
+                |a: Vector3| (perlin.noise3(&(p + a * h)) - perlin.noise3(&(p - a * h))) / (2. * h);
+            let numeric = Vector3::new(
+                axis(Vector3::new(1., 0., 0.)),
+                axis(Vector3::new(0., 1., 0.)),
+                axis(Vector3::new(0., 0., 1.)),
+            );
+            assert!((gradient - numeric).magnitude() < 1e-6);
+        }
+    }
+}
@@ -0,0 +1,381 @@
+use crate::{DynamicVector, Vector2, Vector3};
+use std::io::{self, Read, Write};
+
+/// The bytes every `.npy` file starts with
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Writes a header describing a C-ordered array of little-endian `f64`s, followed by the values
+fn write_array<W: Write + ?Sized>(
+    shape: &[usize],
+    values: &[f64],
+    writer: &mut W,
+) -> io::Result<()> {
+    let shape = match shape {
+        [length] => format!("({},)", length),
+        _ => format!(
+            "({})",
+            shape
+                .iter()
+                .map(|s| s.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    };
+    let mut header = format!(
+        "{{'descr': '<f8', 'fortran_order': False, 'shape': {}, }}",
+        shape
+    );
+    // The header is padded with spaces and ends in a newline so that the data starts on a
+    // multiple of 64 bytes, counting the 10 bytes of magic, version and header length
+    let padding = (64 - (10 + header.len() + 1) % 64) % 64;
+    header.push_str(&" ".repeat(padding));
+    header.push('\n');
+
+    let mut bytes = Vec::with_capacity(10 + header.len() + values.len() * 8);
+    bytes.extend_from_slice(MAGIC);
+    bytes.extend_from_slice(&[1, 0]);
+    let length =
+        u16::try_from(header.len()).map_err(|_| invalid("array has too many dimensions"))?;
+    bytes.extend_from_slice(&length.to_le_bytes());
+    bytes.extend_from_slice(header.as_bytes());
+    for value in values {
+        bytes.extend_from_slice(&value.to_le_bytes());
+    }
+    writer.write_all(&bytes)
+}
+
+/// Finds the text of a value in the Python dictionary literal of a header
+fn header_value<'a>(header: &'a str, key: &str) -> Option<&'a str> {
+    let start = header.find(&format!("'{}'", key))? + key.len() + 2;
+    let rest = header[start..].trim_start().strip_prefix(':')?.trim_start();
+    let end = match rest.chars().next()? {
+        '(' => rest.find(')')? + 1,
+        quote @ ('\'' | '"') => rest[1..].find(quote)? + 2,
+        _ => rest.find([',', '}'])?,
+    };
+    Some(rest[..end].trim())
+}
+
+/// Reads an array of floats, returning its shape and its values in C order
+fn read_array<R: Read + ?Sized>(reader: &mut R) -> io::Result<(Vec<usize>, Vec<f64>)> {
+    let mut preamble = [0; 8];
+    reader.read_exact(&mut preamble)?;
+    if &preamble[..6] != MAGIC {
+        return Err(invalid("not a .npy file"));
+    }
+    let header_length = match preamble[6] {
+        1 => {
+            let mut length = [0; 2];
+            reader.read_exact(&mut length)?;
+            u16::from_le_bytes(length) as usize
+        }
+        2 | 3 => {
+            let mut length = [0; 4];
+            reader.read_exact(&mut length)?;
+            u32::from_le_bytes(length) as usize
+        }
+        _ => return Err(invalid("unsupported .npy version")),
+    };
+    let mut header = Vec::new();
+    reader.take(header_length as u64).read_to_end(&mut header)?;
+    if header.len() < header_length {
+        return Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "not enough bytes for the .npy header",
+        ));
+    }
+    let header = String::from_utf8(header).map_err(|_| invalid("invalid .npy header"))?;
+
+    let descr = header_value(&header, "descr").ok_or_else(|| invalid("missing dtype"))?;
+    let (little_endian, size) = match descr.trim_matches(['\'', '"']) {
+        "<f8" => (true, 8),
+        ">f8" => (false, 8),
+        "<f4" => (true, 4),
+        ">f4" => (false, 4),
+        _ => return Err(invalid("only float32 and float64 arrays are supported")),
+    };
+    let fortran_order = match header_value(&header, "fortran_order") {
+        Some("False") => false,
+        Some("True") => true,
+        _ => return Err(invalid("missing fortran_order")),
+    };
+    let shape = header_value(&header, "shape").ok_or_else(|| invalid("missing shape"))?;
+    let shape = shape
+        .trim_start_matches('(')
+        .trim_end_matches(')')
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| s.parse::<usize>().map_err(|_| invalid("invalid shape")))
+        .collect::<io::Result<Vec<_>>>()?;
+
+    let count = shape
+        .iter()
+        .try_fold(1_usize, |count, &s| count.checked_mul(s))
+        .filter(|count| count.checked_mul(size).is_some())
+        .ok_or_else(|| invalid("array is too large"))?;
+    // Grows the buffer as data arrives, so a corrupt shape cannot trigger a huge allocation
+    let mut bytes = Vec::new();
+    reader.take((count * size) as u64).read_to_end(&mut bytes)?;
+    if bytes.len() < count * size {
+        return Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "not enough bytes for the array",
+        ));
+    }
+    let values: Vec<f64> = bytes
+        .chunks_exact(size)
+        .map(|chunk| match (size, little_endian) {
+            (8, true) => f64::from_le_bytes(chunk.try_into().unwrap()),
+            (8, false) => f64::from_be_bytes(chunk.try_into().unwrap()),
+            (_, true) => f32::from_le_bytes(chunk.try_into().unwrap()) as f64,
+            (_, false) => f32::from_be_bytes(chunk.try_into().unwrap()) as f64,
+        })
+        .collect();
+
+    if fortran_order && shape.len() == 2 {
+        let (rows, columns) = (shape[0], shape[1]);
+        let transposed = (0..count)
+            .map(|i| values[(i % columns) * rows + i / columns])
+            .collect();
+        return Ok((shape, transposed));
+    }
+    if fortran_order && shape.len() > 2 {
+        return Err(invalid(
+            "Fortran ordered arrays with more than two dimensions are not supported",
+        ));
+    }
+    Ok((shape, values))
+}
+
+/// Reads an `N x width` array of floats
+fn read_rows<R: Read + ?Sized>(reader: &mut R, width: usize) -> io::Result<Vec<f64>> {
+    let (shape, values) = read_array(reader)?;
+    if shape.len() != 2 || shape[1] != width {
+        return Err(invalid(&format!(
+            "expected an array of shape (N, {}), found {:?}",
+            width, shape
+        )));
+    }
+    Ok(values)
+}
+
+impl DynamicVector {
+    /// Writes the vector as a one-dimensional NumPy `.npy` array of `float64`
+    ///
+    /// ## Arguments
+    ///
+    /// * `writer` - The destination to write the file to
+    ///
+    /// ## Returns
+    ///
+    /// An error if the writer fails
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::DynamicVector;
+    ///
+    /// let v = DynamicVector::from(vec![1., 2., 3.]);
+    ///
+    /// let mut file = Vec::new();
+    /// v.write_npy(&mut file).unwrap();
+    /// // In Python, `numpy.load` gives `array([1., 2., 3.])`
+    ///
+    /// assert_eq!(DynamicVector::read_npy(&mut file.as_slice()).unwrap(), v);
+    /// ```
+    pub fn write_npy<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+        write_array(&[self.len()], self.as_slice(), writer)
+    }
+
+    /// Reads a one-dimensional NumPy `.npy` array
+    ///
+    /// Arrays of `float32` or `float64` in either byte order are supported.
+    ///
+    /// ## Arguments
+    ///
+    /// * `reader` - The source to read the file from
+    ///
+    /// ## Returns
+    ///
+    /// The vector, or an error if the reader fails, the data is not a `.npy` file, or the array
+    /// is not one-dimensional or not of floats
+    pub fn read_npy<R: Read + ?Sized>(reader: &mut R) -> io::Result<DynamicVector> {
+        let (shape, values) = read_array(reader)?;
+        if shape.len() != 1 {
+            return Err(invalid(&format!(
+                "expected a one-dimensional array, found shape {:?}",
+                shape
+            )));
+        }
+        Ok(DynamicVector::from(values))
+    }
+}
+
+/// Writes a list of [`Vector2`]s as an `N x 2` NumPy `.npy` array of `float64`
+///
+/// ## Arguments
+///
+/// * `points` - The vectors to write, one per row
+/// * `writer` - The destination to write the file to
+///
+/// ## Returns
+///
+/// An error if the writer fails
+pub fn write_npy_vector2<W: Write + ?Sized>(points: &[Vector2], writer: &mut W) -> io::Result<()> {
+    let values: Vec<f64> = points.iter().flat_map(|p| [p.x, p.y]).collect();
+    write_array(&[points.len(), 2], &values, writer)
+}
+
+/// Writes a list of [`Vector3`]s as an `N x 3` NumPy `.npy` array of `float64`
+///
+/// ## Arguments
+///
+/// * `points` - The vectors to write, one per row
+/// * `writer` - The destination to write the file to
+///
+/// ## Returns
+///
+/// An error if the writer fails
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{read_npy_vector3, write_npy_vector3, Vector3};
+///
+/// let points = vec![Vector3::new(1., 2., 3.), Vector3::new(4., 5., 6.)];
+///
+/// let mut file = Vec::new();
+/// write_npy_vector3(&points, &mut file).unwrap();
+///
+/// assert_eq!(read_npy_vector3(&mut file.as_slice()).unwrap(), points);
+/// ```
+pub fn write_npy_vector3<W: Write + ?Sized>(points: &[Vector3], writer: &mut W) -> io::Result<()> {
+    let values: Vec<f64> = points.iter().flat_map(|p| [p.x, p.y, p.z]).collect();
+    write_array(&[points.len(), 3], &values, writer)
+}
+
+/// Reads an `N x 2` NumPy `.npy` array into a list of [`Vector2`]s
+///
+/// Arrays of `float32` or `float64` in either byte order and in C or Fortran order are
+/// supported.
+///
+/// ## Arguments
+///
+/// * `reader` - The source to read the file from
+///
+/// ## Returns
+///
+/// One vector per row, or an error if the reader fails, the data is not a `.npy` file, or the
+/// array does not have two columns of floats
+pub fn read_npy_vector2<R: Read + ?Sized>(reader: &mut R) -> io::Result<Vec<Vector2>> {
+    Ok(read_rows(reader, 2)?
+        .chunks_exact(2)
+        .map(|c| Vector2::new(c[0], c[1]))
+        .collect())
+}
+
+/// Reads an `N x 3` NumPy `.npy` array into a list of [`Vector3`]s
+///
+/// Arrays of `float32` or `float64` in either byte order and in C or Fortran order are
+/// supported.
+///
+/// ## Arguments
+///
+/// * `reader` - The source to read the file from
+///
+/// ## Returns
+///
+/// One vector per row, or an error if the reader fails, the data is not a `.npy` file, or the
+/// array does not have three columns of floats
+pub fn read_npy_vector3<R: Read + ?Sized>(reader: &mut R) -> io::Result<Vec<Vector3>> {
+    Ok(read_rows(reader, 3)?
+        .chunks_exact(3)
+        .map(|c| Vector3::new(c[0], c[1], c[2]))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a `.npy` file by hand, the way NumPy would write it
+    fn npy(header: &str, data: &[u8]) -> Vec<u8> {
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend_from_slice(&[1, 0]);
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+        bytes.extend_from_slice(data);
+        bytes
+    }
+
+    #[test]
+    fn test_header_layout() {
+        let mut file = Vec::new();
+        DynamicVector::from(vec![0.5]).write_npy(&mut file).unwrap();
+        let header_length = u16::from_le_bytes([file[8], file[9]]) as usize;
+        assert_eq!((10 + header_length) % 64, 0);
+        let header = std::str::from_utf8(&file[10..10 + header_length]).unwrap();
+        assert!(header.starts_with("{'descr': '<f8', 'fortran_order': False, 'shape': (1,), }"));
+        assert!(header.ends_with(" \n"));
+        assert_eq!(&file[10 + header_length..], &0.5_f64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let points = vec![Vector2::new(1., -2.), Vector2::new(0.25, 8.)];
+        let mut file = Vec::new();
+        write_npy_vector2(&points, &mut file).unwrap();
+        assert_eq!(read_npy_vector2(&mut file.as_slice()).unwrap(), points);
+        // The wrong number of columns
+        assert_eq!(
+            read_npy_vector3(&mut file.as_slice()).unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+        assert!(DynamicVector::read_npy(&mut file.as_slice()).is_err());
+
+        let empty: Vec<Vector3> = Vec::new();
+        let mut file = Vec::new();
+        write_npy_vector3(&empty, &mut file).unwrap();
+        assert_eq!(read_npy_vector3(&mut file.as_slice()).unwrap(), empty);
+    }
+
+    #[test]
+    fn test_read_other_layouts() {
+        // Big-endian float32 in Fortran order, the columns stored one after another
+        let data: Vec<u8> = [1_f32, 2., 3., 4., 5., 6.]
+            .iter()
+            .flat_map(|v| v.to_be_bytes())
+            .collect();
+        let file = npy(
+            "{'descr': '>f4', 'fortran_order': True, 'shape': (2, 3), }\n",
+            &data,
+        );
+        assert_eq!(
+            read_npy_vector3(&mut file.as_slice()).unwrap(),
+            vec![Vector3::new(1., 3., 5.), Vector3::new(2., 4., 6.)]
+        );
+
+        let file = npy(
+            "{'descr': '<i8', 'fortran_order': False, 'shape': (1,), }\n",
+            &[0; 8],
+        );
+        assert!(DynamicVector::read_npy(&mut file.as_slice()).is_err());
+        // Truncated data
+        let file = npy(
+            "{'descr': '<f8', 'fortran_order': False, 'shape': (2,), }\n",
+            &[0; 8],
+        );
+        assert_eq!(
+            DynamicVector::read_npy(&mut file.as_slice())
+                .unwrap_err()
+                .kind(),
+            io::ErrorKind::UnexpectedEof
+        );
+        assert!(DynamicVector::read_npy(&mut &b"not numpy"[..]).is_err());
+    }
+}
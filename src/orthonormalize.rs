@@ -0,0 +1,150 @@
+use crate::VectorSpace;
+#[cfg(feature = "alloc")]
+use crate::{DynamicVector, Vector};
+
+/// A vector is treated as dependent on the ones before it once Gram-Schmidt leaves it shorter
+/// than this fraction of the longest input vector
+const DEPENDENCE_TOLERANCE: f64 = 1e-10;
+
+/// Makes a set of vectors orthonormal in place with the modified Gram-Schmidt process
+///
+/// Each vector is normalized and then removed from every later vector, which is much more stable
+/// than the classic process when the vectors are nearly parallel. The first vector keeps its
+/// direction, so this can re-orthogonalize a rotation frame that has drifted from accumulated
+/// rounding errors.
+///
+/// ## Arguments
+///
+/// * `vectors` - The vectors to orthonormalize. A vector that is a combination of the ones
+///   before it has no direction left and is set to zero
+///
+/// ## Returns
+///
+/// The number of linearly independent vectors, which is the number of non-zero results
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{orthonormalize, Vector3};
+///
+/// let mut frame = [Vector3::new(2., 0., 0.), Vector3::new(1., 1., 0.), Vector3::new(1., 1., 1.)];
+///
+/// assert_eq!(orthonormalize(&mut frame), 3);
+/// assert_eq!(frame, [Vector3::new(1., 0., 0.), Vector3::new(0., 1., 0.), Vector3::new(0., 0., 1.)]);
+/// ```
+pub fn orthonormalize<V>(vectors: &mut [V]) -> usize
+where
+    V: VectorSpace,
+{
+    let tolerance = vectors.iter().map(|v| v.magnitude()).fold(0., f64::max) * DEPENDENCE_TOLERANCE;
+    let mut rank = 0;
+    for i in 0..vectors.len() {
+        let (done, rest) = vectors.split_at_mut(i + 1);
+        let v = &mut done[i];
+        let magnitude = v.magnitude();
+        if magnitude <= tolerance {
+            *v = *v * 0.;
+            continue;
+        }
+        *v = *v * (1. / magnitude);
+        rank += 1;
+        for other in rest {
+            *other = *other - *v * other.dot(v);
+        }
+    }
+    rank
+}
+
+/// Makes a set of [`DynamicVector`]s orthonormal in place with the modified Gram-Schmidt process
+///
+/// This behaves like [`orthonormalize`], and can build an orthonormal basis for the span of a
+/// set of high-dimensional vectors before projecting onto it.
+///
+/// ## Arguments
+///
+/// * `vectors` - The vectors to orthonormalize. A vector that is a combination of the ones
+///   before it has no direction left and is set to zero
+///
+/// ## Returns
+///
+/// The number of linearly independent vectors, which is the number of non-zero results
+///
+/// ## Panics
+///
+/// If the vectors have different lengths
+#[cfg(feature = "alloc")]
+pub fn orthonormalize_dynamic(vectors: &mut [DynamicVector]) -> usize {
+    let tolerance = vectors.iter().map(|v| v.magnitude()).fold(0., f64::max) * DEPENDENCE_TOLERANCE;
+    let mut rank = 0;
+    for i in 0..vectors.len() {
+        let (done, rest) = vectors.split_at_mut(i + 1);
+        let v = &mut done[i];
+        let magnitude = v.magnitude();
+        if magnitude <= tolerance {
+            v.scale_mut(0.);
+            continue;
+        }
+        v.scale_mut(1. / magnitude);
+        rank += 1;
+        for other in rest {
+            other.axpy(-other.dot(v), v);
+        }
+    }
+    rank
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Vector2, Vector3};
+
+    fn assert_orthonormal(vectors: &[Vector3]) {
+        for (i, a) in vectors.iter().enumerate() {
+            assert!((a.magnitude() - 1.).abs() < 1e-12);
+            for b in &vectors[i + 1..] {
+                assert!(a.dot(b).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_orthonormalize() {
+        let mut frame = [
+            Vector3::new(1., 0.001, 0.),
+            Vector3::new(0.002, 1., 0.),
+            Vector3::new(0., 0.001, 1.),
+        ];
+        assert_eq!(orthonormalize(&mut frame), 3);
+        assert_orthonormal(&frame);
+        // The first vector keeps its direction
+        assert_eq!(frame[0], Vector3::new(1., 0.001, 0.).normalize());
+    }
+
+    #[test]
+    fn test_dependent_vectors() {
+        let mut vectors = [
+            Vector2::new(1., 1.),
+            Vector2::new(2., 2.),
+            Vector2::new(0., 3.),
+        ];
+        assert_eq!(orthonormalize(&mut vectors), 2);
+        assert_eq!(vectors[1], Vector2::new(0., 0.));
+        assert!(vectors[0].dot(&vectors[2]).abs() < 1e-12);
+        assert_eq!(orthonormalize::<Vector3>(&mut []), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_orthonormalize_dynamic() {
+        let mut vectors = [
+            DynamicVector::from(alloc::vec![0., 2., 0., 0.]),
+            DynamicVector::from(alloc::vec![1., 1., 1., 1.]),
+            DynamicVector::from(alloc::vec![0., -5., 0., 0.]),
+        ];
+        assert_eq!(orthonormalize_dynamic(&mut vectors), 2);
+        assert_eq!(vectors[0].as_slice(), &[0., 1., 0., 0.]);
+        assert!(vectors[0].dot(&vectors[1]).abs() < 1e-12);
+        assert!((vectors[1].magnitude() - 1.).abs() < 1e-12);
+        assert_eq!(vectors[2].magnitude(), 0.);
+    }
+}
@@ -0,0 +1,256 @@
+use crate::{DynamicVector, Vector};
+use alloc::vec::Vec;
+
+/// The largest number of power iterations used to find each principal component
+const MAX_POWER_ITERATIONS: usize = 1000;
+
+/// Power iteration stops once a component moves less than this between iterations
+const POWER_ITERATION_TOLERANCE: f64 = 1e-12;
+
+/// The principal components of a dataset, as computed by [`pca`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pca {
+    /// The mean of the dataset
+    pub mean: DynamicVector,
+    /// Unit principal components, ordered from the largest to the smallest variance
+    pub components: Vec<DynamicVector>,
+    /// The variance of the dataset along each component
+    pub explained_variance: Vec<f64>,
+    /// The variance of the dataset summed over every dimension
+    pub total_variance: f64,
+}
+
+impl Pca {
+    /// Calculates the fraction of the total variance explained by each component
+    ///
+    /// ## Returns
+    ///
+    /// The fractions, in the same order as the components. They are all zero if the dataset has
+    /// no variance
+    pub fn explained_variance_ratio(&self) -> Vec<f64> {
+        self.explained_variance
+            .iter()
+            .map(|variance| {
+                if self.total_variance > 0. {
+                    variance / self.total_variance
+                } else {
+                    0.
+                }
+            })
+            .collect()
+    }
+
+    /// Projects a vector onto the principal components
+    ///
+    /// ## Arguments
+    ///
+    /// * `v` - A vector with the same length as the dataset's vectors
+    ///
+    /// ## Returns
+    ///
+    /// The coordinates of `v - mean` along each component
+    ///
+    /// ## Panics
+    ///
+    /// If `v` has a different length to the dataset's vectors
+    pub fn project(&self, v: &DynamicVector) -> DynamicVector {
+        let centered = v.add_scaled(&self.mean, -1.);
+        self.components
+            .iter()
+            .map(|component| component.dot(&centered))
+            .collect()
+    }
+
+    /// Maps coordinates along the principal components back into the original space
+    ///
+    /// ## Arguments
+    ///
+    /// * `coordinates` - One coordinate per component, such as those returned by `project`
+    ///
+    /// ## Returns
+    ///
+    /// The reconstructed vector, which is the closest point to the original that the kept
+    /// components can represent
+    ///
+    /// ## Panics
+    ///
+    /// If there is not exactly one coordinate per component
+    pub fn reconstruct(&self, coordinates: &DynamicVector) -> DynamicVector {
+        assert_eq!(
+            coordinates.len(),
+            self.components.len(),
+            "expected one coordinate per component"
+        );
+        let mut v = self.mean.clone();
+        for (component, coordinate) in self.components.iter().zip(coordinates.as_slice()) {
+            v.axpy(*coordinate, component);
+        }
+        v
+    }
+}
+
+/// Finds the top principal components of a dataset
+///
+/// Components are found one at a time by power iteration on the covariance matrix, deflating
+/// the matrix after each one. The covariance matrix is never built, so this works for
+/// high-dimensional data such as embeddings as long as only a few components are needed.
+///
+/// ## Arguments
+///
+/// * `data` - The vectors of the dataset, which must all have the same length
+/// * `k` - The number of components to find
+///
+/// ## Returns
+///
+/// The mean, components and their variances, or `None` if `data` is empty. Fewer than `k`
+/// components are returned if the data has no variance left in other directions
+///
+/// ## Panics
+///
+/// If the vectors have different lengths
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{pca, DynamicVector};
+///
+/// // Points spread along the line y = 2x
+/// let data: Vec<DynamicVector> = (0..5)
+///     .map(|i| DynamicVector::from(vec![i as f64, 2. * i as f64]))
+///     .collect();
+/// let result = pca(&data, 2).unwrap();
+///
+/// assert_eq!(result.components.len(), 1);
+/// assert!((result.explained_variance_ratio()[0] - 1.).abs() < 1e-12);
+/// ```
+pub fn pca(data: &[DynamicVector], k: usize) -> Option<Pca> {
+    let first = data.first()?;
+    let dimension = first.len();
+    assert!(
+        data.iter().all(|v| v.len() == dimension),
+        "vectors must have the same length"
+    );
+    let n = data.len() as f64;
+
+    let mut mean = DynamicVector::new(dimension);
+    for v in data {
+        mean.axpy(1. / n, v);
+    }
+    let centered: Vec<DynamicVector> = data.iter().map(|v| v.add_scaled(&mean, -1.)).collect();
+    let total_variance = centered.iter().map(|v| v.dot(v)).sum::<f64>() / n;
+
+    let mut components: Vec<DynamicVector> = Vec::new();
+    let mut explained_variance = Vec::new();
+    // Multiplies by the covariance matrix with the components found so far deflated out
+    let covariance_times = |v: &DynamicVector, components: &[DynamicVector], variances: &[f64]| {
+        let mut result = DynamicVector::new(dimension);
+        for x in &centered {
+            result.axpy(x.dot(v) / n, x);
+        }
+        for (component, variance) in components.iter().zip(variances) {
+            result.axpy(-variance * component.dot(v), component);
+        }
+        result
+    };
+
+    while components.len() < k.min(dimension) {
+        // Start from the largest residual of a sample once the components found so far are
+        // removed, which is a far better guess than a fixed vector
+        let remaining = |x: &DynamicVector| {
+            let mut x = x.clone();
+            for component in &components {
+                x.axpy(-component.dot(&x), component);
+            }
+            x
+        };
+        let Some(mut v) = centered
+            .iter()
+            .map(remaining)
+            .max_by(|a, b| a.dot(a).total_cmp(&b.dot(b)))
+            .and_then(|start| start.with_magnitude(1.))
+        else {
+            break;
+        };
+
+        for _ in 0..MAX_POWER_ITERATIONS {
+            let Some(next) =
+                covariance_times(&v, &components, &explained_variance).with_magnitude(1.)
+            else {
+                break;
+            };
+            let change = next.add_scaled(&v, -1.).magnitude();
+            v = next;
+            if change < POWER_ITERATION_TOLERANCE {
+                break;
+            }
+        }
+
+        let variance = v.dot(&covariance_times(&v, &components, &explained_variance));
+        if variance <= total_variance * POWER_ITERATION_TOLERANCE {
+            break;
+        }
+        components.push(v);
+        explained_variance.push(variance);
+    }
+
+    Some(Pca {
+        mean,
+        components,
+        explained_variance,
+        total_variance,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn dataset() -> Vec<DynamicVector> {
+        // Variance 8 along x, 2 along y and none along z, with a mean of (1, 2, 3)
+        let mut data = Vec::new();
+        for (x, y) in [(-4., 0.), (4., 0.), (0., -2.), (0., 2.)] {
+            data.push(DynamicVector::from(vec![1. + x, 2. + y, 3.]));
+        }
+        data
+    }
+
+    #[test]
+    fn test_pca() {
+        let result = pca(&dataset(), 3).unwrap();
+        assert_eq!(result.mean, DynamicVector::from(vec![1., 2., 3.]));
+        assert_eq!(result.total_variance, 10.);
+        assert_eq!(result.components.len(), 2);
+        assert!((result.explained_variance[0] - 8.).abs() < 1e-9);
+        assert!((result.explained_variance[1] - 2.).abs() < 1e-9);
+        assert!((result.components[0].get(0).abs() - 1.).abs() < 1e-9);
+        assert!((result.components[1].get(1).abs() - 1.).abs() < 1e-9);
+        let ratio = result.explained_variance_ratio();
+        assert!((ratio[0] - 0.8).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_project_reconstruct() {
+        let result = pca(&dataset(), 2).unwrap();
+        let v = DynamicVector::from(vec![2., 5., 3.]);
+        let projected = result.project(&v);
+        assert_eq!(projected.len(), 2);
+        let reconstructed = result.reconstruct(&projected);
+        assert!(reconstructed.add_scaled(&v, -1.).magnitude() < 1e-9);
+    }
+
+    #[test]
+    fn test_degenerate() {
+        assert_eq!(pca(&[], 2), None);
+        let same = vec![DynamicVector::from(vec![1., 1.]); 3];
+        let result = pca(&same, 2).unwrap();
+        assert!(result.components.is_empty());
+        assert!(result.explained_variance_ratio().is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mismatched_lengths() {
+        pca(&[DynamicVector::new(2), DynamicVector::new(3)], 1);
+    }
+}
@@ -0,0 +1,108 @@
+use crate::DynamicVector;
+use alloc::vec::Vec;
+
+impl DynamicVector {
+    /// Finds the order that would sort the vector in ascending order
+    ///
+    /// Elements are compared with [`f64::total_cmp`], so `NaN` values sort after every number
+    /// (and negative `NaN` before). The sort is stable, so equal elements keep their order.
+    ///
+    /// ## Returns
+    ///
+    /// The indices of the elements from smallest to largest, suitable for
+    /// [`DynamicVector::permute`]
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::DynamicVector;
+    ///
+    /// let scores = DynamicVector::from(vec![0.3, 0.9, 0.1, 0.7]);
+    /// let order = scores.argsort();
+    ///
+    /// assert_eq!(order, vec![2, 0, 3, 1]);
+    ///
+    /// // The indices of the two highest scores
+    /// assert_eq!(&order[order.len() - 2..], &[3, 1]);
+    /// ```
+    pub fn argsort(&self) -> Vec<usize> {
+        let values = self.as_slice();
+        let mut order: Vec<usize> = (0..values.len()).collect();
+        order.sort_by(|&a, &b| values[a].total_cmp(&values[b]));
+        order
+    }
+
+    /// Creates a sorted copy of the vector
+    ///
+    /// Elements are compared with [`f64::total_cmp`] like [`DynamicVector::argsort`].
+    ///
+    /// ## Returns
+    ///
+    /// The elements in ascending order
+    pub fn sorted(&self) -> DynamicVector {
+        let mut sorted = self.clone();
+        sorted.as_mut_slice().sort_by(f64::total_cmp);
+        sorted
+    }
+
+    /// Gathers elements of the vector in a given order
+    ///
+    /// ## Arguments
+    ///
+    /// * `indices` - The index of the element to take for each position of the result. Indices
+    ///   may be left out or repeated, so a prefix of [`DynamicVector::argsort`] selects the
+    ///   smallest elements
+    ///
+    /// ## Returns
+    ///
+    /// A vector with the same length as `indices`, whose element `i` is `self[indices[i]]`
+    ///
+    /// ## Panics
+    ///
+    /// If an index is out of bounds
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::DynamicVector;
+    ///
+    /// let v = DynamicVector::from(vec![10., 20., 30.]);
+    ///
+    /// assert_eq!(v.permute(&[2, 0, 1]), [30., 10., 20.]);
+    /// assert_eq!(v.permute(&v.argsort()), v.sorted());
+    /// ```
+    pub fn permute(&self, indices: &[usize]) -> DynamicVector {
+        let values = self.as_slice();
+        indices.iter().map(|&i| values[i]).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_argsort() {
+        let v = DynamicVector::from(vec![3., f64::NAN, -1., 3., 0.]);
+        // Stable for the repeated 3, with NaN last
+        assert_eq!(v.argsort(), vec![2, 4, 0, 3, 1]);
+        let sorted = v.sorted();
+        assert_eq!(&sorted.as_slice()[..4], &[-1., 0., 3., 3.]);
+        assert!(sorted.get(4).is_nan());
+        assert_eq!(DynamicVector::new(0).argsort(), vec![]);
+    }
+
+    #[test]
+    fn test_permute() {
+        let v = DynamicVector::from(vec![5., 6., 7.]);
+        assert_eq!(v.permute(&[1, 1, 0]), [6., 6., 5.]);
+        assert_eq!(v.permute(&[]), []);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_permute_out_of_bounds() {
+        DynamicVector::from(vec![1.]).permute(&[1]);
+    }
+}
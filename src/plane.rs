@@ -0,0 +1,135 @@
+use crate::{UnitVector3, Vector, Vector3};
+
+/// An infinite plane in 3D space, the points `p` for which `normal.dot(p) == distance`
+///
+/// The normal points towards the positive side of the plane. It is a [`UnitVector3`], so
+/// [`Plane::signed_distance`] is always a true distance, and [`Plane::from_point_normal`] and
+/// [`Plane::from_coefficients`] normalize it for you.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Plane {
+    pub normal: UnitVector3,
+    pub distance: f64,
+}
+
+impl Plane {
+    /// Creates a new plane
+    ///
+    /// ## Arguments
+    ///
+    /// * `normal` - The normal of the plane
+    /// * `distance` - The signed distance of the plane from the origin along its normal
+    ///
+    /// ## Returns
+    ///
+    /// A new plane
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Plane, UnitVector3, Vector3};
+    ///
+    /// let up = UnitVector3::new_checked(Vector3::new(0., 1., 0.)).unwrap();
+    /// let ground = Plane::new(up, 0.);
+    /// ```
+    pub fn new(normal: UnitVector3, distance: f64) -> Plane {
+        Plane { normal, distance }
+    }
+
+    /// Creates a plane through a point
+    ///
+    /// ## Arguments
+    ///
+    /// * `point` - Any point on the plane
+    /// * `normal` - The direction the plane faces, which does not need to be normalized
+    ///
+    /// ## Returns
+    ///
+    /// The plane, or `None` if the normal is zero or not finite
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Plane, Vector3};
+    ///
+    /// let p = Plane::from_point_normal(&Vector3::new(0., 2., 0.), &Vector3::new(0., 3., 0.)).unwrap();
+    ///
+    /// assert_eq!(*p.normal, Vector3::new(0., 1., 0.));
+    /// assert_eq!(p.distance, 2.);
+    /// ```
+    pub fn from_point_normal(point: &Vector3, normal: &Vector3) -> Option<Plane> {
+        let normal = UnitVector3::new_normalize(*normal)?;
+        Some(Plane::new(normal, normal.dot(point)))
+    }
+
+    /// Creates a plane from the coefficients of its equation `ax + by + cz + d = 0`
+    ///
+    /// ## Returns
+    ///
+    /// The plane, or `None` if `a`, `b` and `c` are all zero or not finite
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Plane, Vector3};
+    ///
+    /// let p = Plane::from_coefficients(0., 0., 2., -4.).unwrap();
+    ///
+    /// assert_eq!(*p.normal, Vector3::new(0., 0., 1.));
+    /// assert_eq!(p.distance, 2.);
+    /// ```
+    pub fn from_coefficients(a: f64, b: f64, c: f64, d: f64) -> Option<Plane> {
+        let normal = Vector3::new(a, b, c);
+        let unit = UnitVector3::new_normalize(normal)?;
+        Some(Plane::new(unit, -d / normal.magnitude()))
+    }
+
+    /// Calculates the signed distance from the plane to a point
+    ///
+    /// ## Arguments
+    ///
+    /// * `point` - The point to measure
+    ///
+    /// ## Returns
+    ///
+    /// The distance, positive on the side the normal points to and negative on the other
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Plane, UnitVector3, Vector3};
+    ///
+    /// let up = UnitVector3::new_checked(Vector3::new(0., 1., 0.)).unwrap();
+    /// let ground = Plane::new(up, 0.);
+    ///
+    /// assert_eq!(ground.signed_distance(&Vector3::new(4., 2., 1.)), 2.);
+    /// assert_eq!(ground.signed_distance(&Vector3::new(4., -3., 1.)), -3.);
+    /// ```
+    pub fn signed_distance(&self, point: &Vector3) -> f64 {
+        self.normal.dot(point) - self.distance
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_constructors() {
+        let p = Plane::from_point_normal(&Vector3::new(1., 1., 1.), &Vector3::new(-2., 0., 0.));
+        let normal = UnitVector3::new_checked(Vector3::new(-1., 0., 0.)).unwrap();
+        assert_eq!(p, Some(Plane::new(normal, -1.)));
+        assert_eq!(
+            Plane::from_point_normal(&Vector3::new(1., 1., 1.), &Vector3::new(0., 0., 0.)),
+            None
+        );
+        assert_eq!(Plane::from_coefficients(0., 0., 0., 1.), None);
+        assert_eq!(Plane::from_coefficients(f64::NAN, 0., 1., 1.), None);
+    }
+
+    #[test]
+    fn test_signed_distance() {
+        let p = Plane::from_coefficients(3., 0., 4., -10.).unwrap();
+        assert!((p.signed_distance(&Vector3::new(0., 0., 0.)) + 2.).abs() < 1e-12);
+        assert!((p.signed_distance(&Vector3::new(3., 5., 4.)) - 3.).abs() < 1e-12);
+    }
+}
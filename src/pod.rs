@@ -0,0 +1,293 @@
+use crate::{Vector2, Vector3, Vector4};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use bytemuck::{Pod, PodCastError, Zeroable};
+#[cfg(feature = "alloc")]
+use core::mem::size_of;
+
+// SAFETY: the vector types are `#[repr(C)]` structs made only of `f64`s, so they have no padding
+// and every bit pattern is a valid value
+unsafe impl Zeroable for Vector2 {}
+unsafe impl Pod for Vector2 {}
+unsafe impl Zeroable for Vector3 {}
+unsafe impl Pod for Vector3 {}
+unsafe impl Zeroable for Vector4 {}
+unsafe impl Pod for Vector4 {}
+
+impl Vector2 {
+    /// Views the vector as its 16 raw bytes without copying
+    ///
+    /// The components are in the native endianness of the machine. Use
+    /// [`Vector2::to_le_bytes`] when the bytes leave the machine.
+    ///
+    /// ## Returns
+    ///
+    /// The bytes of the vector, starting with the first component
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::Vector2;
+    ///
+    /// let v = Vector2::new(1., 2.);
+    ///
+    /// assert_eq!(Vector2::from_bytes(v.as_bytes()), Ok(&v));
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        bytemuck::bytes_of(self)
+    }
+
+    /// Views 16 raw bytes as a vector without copying
+    ///
+    /// ## Arguments
+    ///
+    /// * `bytes` - The bytes of the vector in native endianness
+    ///
+    /// ## Returns
+    ///
+    /// The vector, or an error if `bytes` has the wrong length or is not aligned for `f64`
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Vector2, PodCastError> {
+        bytemuck::try_from_bytes(bytes)
+    }
+}
+
+impl Vector3 {
+    /// Views the vector as its 24 raw bytes without copying
+    ///
+    /// The components are in the native endianness of the machine. Use
+    /// [`Vector3::to_le_bytes`] when the bytes leave the machine.
+    ///
+    /// ## Returns
+    ///
+    /// The bytes of the vector, starting with the first component
+    pub fn as_bytes(&self) -> &[u8] {
+        bytemuck::bytes_of(self)
+    }
+
+    /// Views 24 raw bytes as a vector without copying
+    ///
+    /// ## Arguments
+    ///
+    /// * `bytes` - The bytes of the vector in native endianness
+    ///
+    /// ## Returns
+    ///
+    /// The vector, or an error if `bytes` has the wrong length or is not aligned for `f64`
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Vector3, PodCastError> {
+        bytemuck::try_from_bytes(bytes)
+    }
+}
+
+impl Vector4 {
+    /// Views the vector as its 32 raw bytes without copying
+    ///
+    /// The components are in the native endianness of the machine. Use
+    /// [`Vector4::to_le_bytes`] when the bytes leave the machine.
+    ///
+    /// ## Returns
+    ///
+    /// The bytes of the vector, starting with the first component
+    pub fn as_bytes(&self) -> &[u8] {
+        bytemuck::bytes_of(self)
+    }
+
+    /// Views 32 raw bytes as a vector without copying
+    ///
+    /// ## Arguments
+    ///
+    /// * `bytes` - The bytes of the vector in native endianness
+    ///
+    /// ## Returns
+    ///
+    /// The vector, or an error if `bytes` has the wrong length or is not aligned for `f64`
+    pub fn from_bytes(bytes: &[u8]) -> Result<&Vector4, PodCastError> {
+        bytemuck::try_from_bytes(bytes)
+    }
+}
+
+/// Views a slice of vectors as raw bytes without copying
+///
+/// The bytes use the native endianness of the machine, which is what GPU APIs expect.
+///
+/// ## Arguments
+///
+/// * `vectors` - The vectors to view
+///
+/// ## Returns
+///
+/// The bytes of every vector, back to back
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{as_byte_slice, Vector3};
+///
+/// let positions = [Vector3::new(0., 0., 0.), Vector3::new(1., 0., 0.)];
+///
+/// assert_eq!(as_byte_slice(&positions).len(), 48);
+/// ```
+pub fn as_byte_slice<V: Pod>(vectors: &[V]) -> &[u8] {
+    bytemuck::cast_slice(vectors)
+}
+
+/// Views raw bytes as a slice of vectors without copying
+///
+/// ## Arguments
+///
+/// * `bytes` - The bytes to view, in native endianness
+///
+/// ## Returns
+///
+/// The vectors, or an error if the bytes are not aligned for `f64` or their length is not a
+/// multiple of the vector size
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{as_byte_slice, try_from_byte_slice, Vector2};
+///
+/// let points = [Vector2::new(1., 2.), Vector2::new(3., 4.)];
+/// let bytes = as_byte_slice(&points);
+///
+/// assert_eq!(try_from_byte_slice::<Vector2>(bytes), Ok(&points[..]));
+/// assert!(try_from_byte_slice::<Vector2>(&bytes[..20]).is_err());
+/// ```
+pub fn try_from_byte_slice<V: Pod>(bytes: &[u8]) -> Result<&[V], PodCastError> {
+    bytemuck::try_cast_slice(bytes)
+}
+
+/// A single field of an interleaved vertex, such as a position or a normal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VertexAttribute {
+    /// The distance in bytes from the start of the vertex to this field
+    pub offset: usize,
+    /// The size of the field in bytes
+    pub size: usize,
+    /// The number of `f64` components in the field
+    pub components: usize,
+}
+
+/// Describes how the fields of an interleaved vertex are laid out in memory
+///
+/// Mirror a `#[repr(C)]` vertex struct by adding its fields in order. As every vector type is
+/// made of `f64`s the fields are packed without padding, so the layout can be passed directly to
+/// wgpu or OpenGL as double precision attributes.
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{Vector2, Vector3, VertexLayout};
+///
+/// #[repr(C)]
+/// struct Vertex {
+///     position: Vector3,
+///     normal: Vector3,
+///     uv: Vector2,
+/// }
+///
+/// let layout = VertexLayout::new()
+///     .with::<Vector3>()
+///     .with::<Vector3>()
+///     .with::<Vector2>();
+///
+/// assert_eq!(layout.stride, core::mem::size_of::<Vertex>());
+/// assert_eq!(layout.attributes[2].offset, 48);
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct VertexLayout {
+    /// The size in bytes of one whole vertex
+    pub stride: usize,
+    pub attributes: Vec<VertexAttribute>,
+}
+
+#[cfg(feature = "alloc")]
+impl VertexLayout {
+    /// Creates an empty layout
+    ///
+    /// ## Returns
+    ///
+    /// A layout without any attributes
+    pub fn new() -> VertexLayout {
+        VertexLayout::default()
+    }
+
+    /// Adds a field after the existing ones
+    ///
+    /// ## Returns
+    ///
+    /// The layout with the new attribute appended
+    pub fn with<V: Pod>(mut self) -> VertexLayout {
+        let size = size_of::<V>();
+        self.attributes.push(VertexAttribute {
+            offset: self.stride,
+            size,
+            components: size / size_of::<f64>(),
+        });
+        self.stride += size;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_byte_slice_round_trip() {
+        let points = vec![Vector4::new(1., 2., 3., 4.), Vector4::new(5., 6., 7., 8.)];
+        let bytes = as_byte_slice(&points);
+        assert_eq!(bytes.len(), 64);
+        assert_eq!(&bytes[8..16], &2_f64.to_ne_bytes());
+        assert_eq!(try_from_byte_slice::<Vector4>(bytes).unwrap(), &points[..]);
+
+        // The same bytes can be reinterpreted as a different vector type
+        let as_pairs = try_from_byte_slice::<Vector2>(bytes).unwrap();
+        assert_eq!(as_pairs[3], Vector2::new(7., 8.));
+    }
+
+    #[test]
+    fn test_try_from_byte_slice_errors() {
+        let points = [Vector3::new(1., 2., 3.); 2];
+        let bytes = as_byte_slice(&points);
+        assert!(try_from_byte_slice::<Vector3>(&bytes[..40]).is_err());
+        assert!(try_from_byte_slice::<Vector3>(&bytes[1..25]).is_err());
+    }
+
+    #[test]
+    fn test_as_from_bytes() {
+        let v = Vector3::new(1., 2., 3.);
+        let expected: Vec<u8> = [1_f64, 2., 3.]
+            .iter()
+            .flat_map(|c| c.to_ne_bytes())
+            .collect();
+        assert_eq!(v.as_bytes(), &expected[..]);
+        assert_eq!(Vector3::from_bytes(v.as_bytes()), Ok(&v));
+        assert!(Vector3::from_bytes(&v.as_bytes()[..16]).is_err());
+
+        let v4 = Vector4::new(1., 2., 3., 4.);
+        assert_eq!(Vector4::from_bytes(v4.as_bytes()), Ok(&v4));
+    }
+
+    #[test]
+    fn test_vertex_layout() {
+        let layout = VertexLayout::new().with::<Vector3>().with::<Vector4>();
+        assert_eq!(layout.stride, 56);
+        assert_eq!(
+            layout.attributes,
+            vec![
+                VertexAttribute {
+                    offset: 0,
+                    size: 24,
+                    components: 3
+                },
+                VertexAttribute {
+                    offset: 24,
+                    size: 32,
+                    components: 4
+                },
+            ]
+        );
+    }
+}
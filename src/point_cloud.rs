@@ -0,0 +1,548 @@
+use crate::Vector3;
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Write};
+
+/// An error produced while parsing a point cloud file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PointCloudError {
+    /// A field could not be parsed as a number
+    InvalidNumber { line: usize, field: String },
+    /// A row had a different number of fields than expected
+    WrongFieldCount {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// The PLY header is missing, malformed or describes data that is not supported
+    InvalidHeader { line: usize, message: String },
+    /// The file ended before every point described by the header was read
+    MissingPoints { expected: usize, found: usize },
+}
+
+impl fmt::Display for PointCloudError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PointCloudError::InvalidNumber { line, field } => {
+                write!(f, "line {}: invalid number {:?}", line, field)
+            }
+            PointCloudError::WrongFieldCount {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {}: expected {} fields, found {}",
+                line, expected, found
+            ),
+            PointCloudError::InvalidHeader { line, message } => {
+                write!(f, "line {}: {}", line, message)
+            }
+            PointCloudError::MissingPoints { expected, found } => {
+                write!(f, "expected {} points, found {}", expected, found)
+            }
+        }
+    }
+}
+
+impl Error for PointCloudError {}
+
+/// A set of points with optional per-point normals and colors
+///
+/// When present, `normals` and `colors` hold one entry per position. Colors are RGB with each
+/// channel in `[0, 1]`, like the helpers of the `color` feature.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PointCloud {
+    pub positions: Vec<Vector3>,
+    pub normals: Option<Vec<Vector3>>,
+    pub colors: Option<Vec<Vector3>>,
+}
+
+impl PointCloud {
+    /// Creates a point cloud with only positions
+    ///
+    /// ## Arguments
+    ///
+    /// * `positions` - The points
+    ///
+    /// ## Returns
+    ///
+    /// A new point cloud without normals or colors
+    pub fn new(positions: Vec<Vector3>) -> PointCloud {
+        PointCloud {
+            positions,
+            normals: None,
+            colors: None,
+        }
+    }
+
+    /// Panics if the normals or colors do not match the positions
+    fn check_lengths(&self) {
+        for attribute in [&self.normals, &self.colors].into_iter().flatten() {
+            assert_eq!(
+                attribute.len(),
+                self.positions.len(),
+                "normals and colors need one entry per position"
+            );
+        }
+    }
+}
+
+/// Parses whitespace separated fields, reporting errors against the given line number
+fn parse_fields(row: &str, line: usize) -> Result<Vec<f64>, PointCloudError> {
+    row.split_whitespace()
+        .map(|field| {
+            field.parse().map_err(|_| PointCloudError::InvalidNumber {
+                line,
+                field: field.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Formats a row of whitespace separated numbers
+fn write_row<W: Write + ?Sized>(writer: &mut W, values: &[f64]) -> io::Result<()> {
+    let row: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+    writeln!(writer, "{}", row.join(" "))
+}
+
+/// Parses lines of an XYZ point file
+///
+/// Every non-empty line holds the `x y z` coordinates of one point separated by whitespace,
+/// optionally followed by the `nx ny nz` components of its normal. Whether normals are present is
+/// decided by the first point. Lines starting with `#` are skipped as comments.
+///
+/// ## Arguments
+///
+/// * `lines` - The lines of the file
+///
+/// ## Returns
+///
+/// The point cloud, or an error naming the first invalid line
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{read_xyz, Vector3};
+///
+/// let cloud = read_xyz("# scan\n1 2 3\n4 5 6\n".lines()).unwrap();
+///
+/// assert_eq!(cloud.positions, vec![Vector3::new(1., 2., 3.), Vector3::new(4., 5., 6.)]);
+/// assert_eq!(cloud.normals, None);
+/// ```
+pub fn read_xyz<'a, I>(lines: I) -> Result<PointCloud, PointCloudError>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let mut cloud = PointCloud::default();
+    let mut normals = Vec::new();
+    let mut width = None;
+    for (i, row) in lines.into_iter().enumerate() {
+        let row = row.trim();
+        if row.is_empty() || row.starts_with('#') {
+            continue;
+        }
+        let values = parse_fields(row, i + 1)?;
+        let expected = *width.get_or_insert(if values.len() >= 6 { 6 } else { 3 });
+        if values.len() != expected {
+            return Err(PointCloudError::WrongFieldCount {
+                line: i + 1,
+                expected,
+                found: values.len(),
+            });
+        }
+        cloud
+            .positions
+            .push(Vector3::new(values[0], values[1], values[2]));
+        if expected == 6 {
+            normals.push(Vector3::new(values[3], values[4], values[5]));
+        }
+    }
+    if width == Some(6) {
+        cloud.normals = Some(normals);
+    }
+    Ok(cloud)
+}
+
+/// Writes a point cloud as an XYZ point file
+///
+/// Each point is written on its own line, followed by its normal if the cloud has normals.
+/// Colors are not part of the format and are left out.
+///
+/// ## Arguments
+///
+/// * `cloud` - The points to write
+/// * `writer` - The destination to write the lines to
+///
+/// ## Returns
+///
+/// An error if the writer fails
+///
+/// ## Panics
+///
+/// If the cloud has normals but not one per position
+pub fn write_xyz<W: Write + ?Sized>(cloud: &PointCloud, writer: &mut W) -> io::Result<()> {
+    cloud.check_lengths();
+    for (i, p) in cloud.positions.iter().enumerate() {
+        match &cloud.normals {
+            Some(normals) => {
+                let n = normals[i];
+                write_row(writer, &[p.x, p.y, p.z, n.x, n.y, n.z])?;
+            }
+            None => write_row(writer, &[p.x, p.y, p.z])?,
+        }
+    }
+    Ok(())
+}
+
+/// An element declared in a PLY header, with the names and types of its properties
+struct PlyElement {
+    name: String,
+    count: usize,
+    properties: Vec<(String, String)>,
+}
+
+/// Parses lines of an ASCII PLY file
+///
+/// The `x`, `y` and `z` properties of the `vertex` element are read as positions, `nx`, `ny` and
+/// `nz` as normals and `red`, `green` and `blue` as colors, each only if all three are present.
+/// Integer colors are taken to range from 0 to 255 and are scaled to `[0, 1]`. Other properties
+/// and other elements, such as faces, are skipped.
+///
+/// ## Arguments
+///
+/// * `lines` - The lines of the file, including the header
+///
+/// ## Returns
+///
+/// The point cloud, or an error if the header is invalid, the file is not ASCII or a line of
+/// vertex data is invalid
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{read_ply, Vector3};
+///
+/// let text = "ply
+/// format ascii 1.0
+/// element vertex 2
+/// property float x
+/// property float y
+/// property float z
+/// property uchar red
+/// property uchar green
+/// property uchar blue
+/// end_header
+/// 0 0 1 255 0 0
+/// 1 0 0 0 0 255
+/// ";
+/// let cloud = read_ply(text.lines()).unwrap();
+///
+/// assert_eq!(cloud.positions[1], Vector3::new(1., 0., 0.));
+/// assert_eq!(cloud.colors.unwrap()[0], Vector3::new(1., 0., 0.));
+/// ```
+pub fn read_ply<'a, I>(lines: I) -> Result<PointCloud, PointCloudError>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let mut lines = lines
+        .into_iter()
+        .enumerate()
+        .map(|(i, row)| (i + 1, row.trim()));
+    let header_error = |line: usize, message: &str| PointCloudError::InvalidHeader {
+        line,
+        message: message.to_string(),
+    };
+
+    match lines.next() {
+        Some((_, "ply")) => {}
+        _ => return Err(header_error(1, "missing \"ply\" magic line")),
+    }
+    let mut elements: Vec<PlyElement> = Vec::new();
+    let mut ascii = false;
+    let mut last_line = 1;
+    loop {
+        let Some((line, row)) = lines.next() else {
+            return Err(header_error(last_line, "missing end_header"));
+        };
+        last_line = line;
+        let words: Vec<&str> = row.split_whitespace().collect();
+        match words.as_slice() {
+            ["end_header"] => break,
+            ["format", "ascii", _] => ascii = true,
+            ["format", ..] => return Err(header_error(line, "only ASCII PLY files are supported")),
+            ["comment", ..] | ["obj_info", ..] | [] => {}
+            ["element", name, count] => elements.push(PlyElement {
+                name: name.to_string(),
+                count: count
+                    .parse()
+                    .map_err(|_| header_error(line, "invalid element count"))?,
+                properties: Vec::new(),
+            }),
+            ["property", "list", ..] => match elements.last_mut() {
+                Some(element) if element.name != "vertex" => {
+                    element.properties.push(("list".to_string(), String::new()))
+                }
+                _ => {
+                    return Err(header_error(
+                        line,
+                        "list properties of vertices are not supported",
+                    ))
+                }
+            },
+            ["property", kind, name] => match elements.last_mut() {
+                Some(element) => element
+                    .properties
+                    .push((name.to_string(), kind.to_string())),
+                None => return Err(header_error(line, "property outside of an element")),
+            },
+            _ => return Err(header_error(line, "unrecognized header line")),
+        }
+    }
+    if !ascii {
+        return Err(header_error(last_line, "missing format line"));
+    }
+
+    let mut cloud = PointCloud::default();
+    for element in &elements {
+        if element.name != "vertex" {
+            // Every element is stored one per line, so the lines can be skipped without parsing
+            for _ in 0..element.count {
+                lines.next();
+            }
+            continue;
+        }
+
+        let find = |names: [&str; 3]| -> Option<[(usize, bool); 3]> {
+            let mut found = [(0, false); 3];
+            for (slot, name) in found.iter_mut().zip(names) {
+                let index = element.properties.iter().position(|(n, _)| n == name)?;
+                let integer = !element.properties[index].1.starts_with("float")
+                    && element.properties[index].1 != "double";
+                *slot = (index, integer);
+            }
+            Some(found)
+        };
+        let Some(position) = find(["x", "y", "z"]) else {
+            return Err(header_error(
+                last_line,
+                "vertices need x, y and z properties",
+            ));
+        };
+        let normal = find(["nx", "ny", "nz"]);
+        let color = find(["red", "green", "blue"]);
+        let get = |values: &[f64], [a, b, c]: [(usize, bool); 3], scale_integers: bool| {
+            let scale = |(index, integer): (usize, bool)| {
+                if integer && scale_integers {
+                    values[index] / 255.
+                } else {
+                    values[index]
+                }
+            };
+            Vector3::new(scale(a), scale(b), scale(c))
+        };
+
+        let mut normals = Vec::new();
+        let mut colors = Vec::new();
+        for found in 0..element.count {
+            let Some((line, row)) = lines.next() else {
+                return Err(PointCloudError::MissingPoints {
+                    expected: element.count,
+                    found,
+                });
+            };
+            let values = parse_fields(row, line)?;
+            if values.len() != element.properties.len() {
+                return Err(PointCloudError::WrongFieldCount {
+                    line,
+                    expected: element.properties.len(),
+                    found: values.len(),
+                });
+            }
+            cloud.positions.push(get(&values, position, false));
+            if let Some(normal) = normal {
+                normals.push(get(&values, normal, false));
+            }
+            if let Some(color) = color {
+                colors.push(get(&values, color, true));
+            }
+        }
+        cloud.normals = normal.map(|_| normals);
+        cloud.colors = color.map(|_| colors);
+        break;
+    }
+    Ok(cloud)
+}
+
+/// Writes a point cloud as an ASCII PLY file
+///
+/// Positions and normals are written as `double` properties and colors as `uchar` properties
+/// from 0 to 255.
+///
+/// ## Arguments
+///
+/// * `cloud` - The points to write
+/// * `writer` - The destination to write the file to
+///
+/// ## Returns
+///
+/// An error if the writer fails
+///
+/// ## Panics
+///
+/// If the cloud has normals or colors but not one per position
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{read_ply, write_ply, PointCloud, Vector3};
+///
+/// let mut cloud = PointCloud::new(vec![Vector3::new(1., 2., 3.)]);
+/// cloud.normals = Some(vec![Vector3::new(0., 0., 1.)]);
+///
+/// let mut out = Vec::new();
+/// write_ply(&cloud, &mut out).unwrap();
+/// let text = String::from_utf8(out).unwrap();
+///
+/// assert_eq!(read_ply(text.lines()).unwrap(), cloud);
+/// ```
+pub fn write_ply<W: Write + ?Sized>(cloud: &PointCloud, writer: &mut W) -> io::Result<()> {
+    cloud.check_lengths();
+    let mut header = format!(
+        "ply\nformat ascii 1.0\nelement vertex {}\n",
+        cloud.positions.len()
+    );
+    for name in ["x", "y", "z"] {
+        header += &format!("property double {}\n", name);
+    }
+    if cloud.normals.is_some() {
+        for name in ["nx", "ny", "nz"] {
+            header += &format!("property double {}\n", name);
+        }
+    }
+    if cloud.colors.is_some() {
+        for name in ["red", "green", "blue"] {
+            header += &format!("property uchar {}\n", name);
+        }
+    }
+    header += "end_header\n";
+    writer.write_all(header.as_bytes())?;
+
+    let channel = |c: f64| (c.clamp(0., 1.) * 255.).round();
+    for (i, p) in cloud.positions.iter().enumerate() {
+        let mut values = vec![p.x, p.y, p.z];
+        if let Some(normals) = &cloud.normals {
+            values.extend([normals[i].x, normals[i].y, normals[i].z]);
+        }
+        if let Some(colors) = &cloud.colors {
+            let c = colors[i];
+            values.extend([channel(c.x), channel(c.y), channel(c.z)]);
+        }
+        write_row(writer, &values)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xyz() {
+        let cloud = read_xyz("1 2 3 0 0 1\n\n4 5 6 0 1 0\n".lines()).unwrap();
+        assert_eq!(
+            cloud.normals,
+            Some(vec![Vector3::new(0., 0., 1.), Vector3::new(0., 1., 0.)])
+        );
+        let mut out = Vec::new();
+        write_xyz(&cloud, &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "1 2 3 0 0 1\n4 5 6 0 1 0\n"
+        );
+
+        assert_eq!(
+            read_xyz("1 2 3\n4 5".lines()),
+            Err(PointCloudError::WrongFieldCount {
+                line: 2,
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(
+            read_xyz("1 2 z".lines()),
+            Err(PointCloudError::InvalidNumber {
+                line: 1,
+                field: "z".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_ply_with_other_elements() {
+        let text = "ply
+format ascii 1.0
+comment made by hand
+element material 1
+property float shininess
+element vertex 3
+property float x
+property float y
+property float z
+property float intensity
+element face 1
+property list uchar int vertex_indices
+end_header
+0.5
+0 0 0 0.1
+1 0 0 0.2
+0 1 0 0.3
+3 0 1 2
+";
+        let cloud = read_ply(text.lines()).unwrap();
+        assert_eq!(
+            cloud,
+            PointCloud::new(vec![
+                Vector3::new(0., 0., 0.),
+                Vector3::new(1., 0., 0.),
+                Vector3::new(0., 1., 0.),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_ply_round_trip() {
+        let cloud = PointCloud {
+            positions: vec![Vector3::new(0.25, -1., 3.), Vector3::new(1e-3, 2., 0.)],
+            normals: Some(vec![Vector3::new(0., 1., 0.), Vector3::new(1., 0., 0.)]),
+            colors: Some(vec![Vector3::new(1., 0., 0.2), Vector3::new(0., 1., 1.)]),
+        };
+        let mut out = Vec::new();
+        write_ply(&cloud, &mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        let read = read_ply(text.lines()).unwrap();
+        assert_eq!(read.positions, cloud.positions);
+        assert_eq!(read.normals, cloud.normals);
+        // Colors are stored with 8 bits per channel
+        assert_eq!(read.colors.unwrap()[0], Vector3::new(1., 0., 51. / 255.));
+    }
+
+    #[test]
+    fn test_ply_errors() {
+        let header = "ply\nformat ascii 1.0\nelement vertex 2\nproperty float x\nproperty float y\nproperty float z\nend_header\n";
+        assert_eq!(
+            read_ply(format!("{}1 2 3\n", header).lines()),
+            Err(PointCloudError::MissingPoints {
+                expected: 2,
+                found: 1
+            })
+        );
+        let binary = "ply\nformat binary_little_endian 1.0\nend_header\n";
+        assert!(matches!(
+            read_ply(binary.lines()),
+            Err(PointCloudError::InvalidHeader { line: 2, .. })
+        ));
+        assert!(read_ply("1 2 3".lines()).is_err());
+        assert!(read_ply("ply\nformat ascii 1.0\n".lines()).is_err());
+    }
+}
@@ -0,0 +1,461 @@
+use crate::Vector2;
+use alloc::vec::Vec;
+
+/// The order in which the vertices of a polygon are listed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Winding {
+    Clockwise,
+    CounterClockwise,
+}
+
+/// A simple polygon in 2D space described by its vertices
+///
+/// The polygon is implicitly closed, so the last vertex connects back to the first one. Vertices
+/// should not be repeated at the end.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polygon2 {
+    pub points: Vec<Vector2>,
+}
+
+impl Polygon2 {
+    /// Creates a new polygon
+    ///
+    /// ## Arguments
+    ///
+    /// * `points` - The vertices of the polygon, in order
+    ///
+    /// ## Returns
+    ///
+    /// A new polygon
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Polygon2, Vector2};
+    ///
+    /// let p = Polygon2::new(vec![
+    ///     Vector2::new(0., 0.),
+    ///     Vector2::new(1., 0.),
+    ///     Vector2::new(1., 1.),
+    ///     Vector2::new(0., 1.),
+    /// ]);
+    /// ```
+    pub fn new(points: Vec<Vector2>) -> Polygon2 {
+        Polygon2 { points }
+    }
+
+    /// Iterates over the edges of the polygon as pairs of start and end points
+    fn edges(&self) -> impl Iterator<Item = (Vector2, Vector2)> + '_ {
+        let next = self.points.iter().cycle().skip(1);
+        self.points.iter().copied().zip(next.copied())
+    }
+
+    /// Calculates the signed area of the polygon using the shoelace formula
+    ///
+    /// ## Returns
+    ///
+    /// The area of the polygon, positive if the vertices are in counter-clockwise order and
+    /// negative if they are in clockwise order
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Polygon2, Vector2};
+    ///
+    /// let p = Polygon2::new(vec![
+    ///     Vector2::new(0., 0.),
+    ///     Vector2::new(2., 0.),
+    ///     Vector2::new(2., 2.),
+    ///     Vector2::new(0., 2.),
+    /// ]);
+    ///
+    /// assert_eq!(p.signed_area(), 4.);
+    /// ```
+    pub fn signed_area(&self) -> f64 {
+        self.edges().map(|(a, b)| a.cross(&b)).sum::<f64>() / 2.
+    }
+
+    /// Calculates the area of the polygon
+    ///
+    /// ## Returns
+    ///
+    /// The area of the polygon
+    pub fn area(&self) -> f64 {
+        self.signed_area().abs()
+    }
+
+    /// Calculates the centroid (center of mass) of the polygon
+    ///
+    /// ## Returns
+    ///
+    /// The centroid of the polygon, or `None` if the polygon has no area
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Polygon2, Vector2};
+    ///
+    /// let p = Polygon2::new(vec![
+    ///     Vector2::new(0., 0.),
+    ///     Vector2::new(2., 0.),
+    ///     Vector2::new(2., 2.),
+    ///     Vector2::new(0., 2.),
+    /// ]);
+    ///
+    /// assert_eq!(p.centroid(), Some(Vector2::new(1., 1.)));
+    /// ```
+    pub fn centroid(&self) -> Option<Vector2> {
+        let area = self.signed_area();
+        if area == 0. {
+            return None;
+        }
+
+        let sum = self.edges().fold(Vector2::new(0., 0.), |sum, (a, b)| {
+            sum + (a + b) * a.cross(&b)
+        });
+        Some(sum / (6. * area))
+    }
+
+    /// Determines the winding order of the polygon
+    ///
+    /// ## Returns
+    ///
+    /// The winding order, or `None` if the polygon has no area
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Polygon2, Vector2, Winding};
+    ///
+    /// let p = Polygon2::new(vec![
+    ///     Vector2::new(0., 0.),
+    ///     Vector2::new(0., 1.),
+    ///     Vector2::new(1., 0.),
+    /// ]);
+    ///
+    /// assert_eq!(p.winding(), Some(Winding::Clockwise));
+    /// ```
+    pub fn winding(&self) -> Option<Winding> {
+        let area = self.signed_area();
+        if area > 0. {
+            Some(Winding::CounterClockwise)
+        } else if area < 0. {
+            Some(Winding::Clockwise)
+        } else {
+            None
+        }
+    }
+
+    /// Checks whether the polygon is convex
+    ///
+    /// Collinear vertices are allowed. Polygons with fewer than three vertices are not convex.
+    ///
+    /// ## Returns
+    ///
+    /// `true` if every turn along the boundary goes in the same direction
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Polygon2, Vector2};
+    ///
+    /// let square = Polygon2::new(vec![
+    ///     Vector2::new(0., 0.),
+    ///     Vector2::new(1., 0.),
+    ///     Vector2::new(1., 1.),
+    ///     Vector2::new(0., 1.),
+    /// ]);
+    /// let arrow = Polygon2::new(vec![
+    ///     Vector2::new(0., 0.),
+    ///     Vector2::new(2., 1.),
+    ///     Vector2::new(0., 2.),
+    ///     Vector2::new(1., 1.),
+    /// ]);
+    ///
+    /// assert!(square.is_convex());
+    /// assert!(!arrow.is_convex());
+    /// ```
+    pub fn is_convex(&self) -> bool {
+        if self.points.len() < 3 {
+            return false;
+        }
+
+        let mut sign = 0.;
+        let n = self.points.len();
+        for i in 0..n {
+            let a = self.points[i];
+            let b = self.points[(i + 1) % n];
+            let c = self.points[(i + 2) % n];
+            let turn = (b - a).cross(&(c - b));
+
+            if turn == 0. {
+                continue;
+            }
+            if sign == 0. {
+                sign = turn.signum();
+            } else if turn.signum() != sign {
+                return false;
+            }
+        }
+
+        sign != 0.
+    }
+
+    /// Checks whether a point lies inside the polygon
+    ///
+    /// Uses the even-odd rule, so this works for concave polygons as well. Points exactly on the
+    /// boundary may be reported as either inside or outside.
+    ///
+    /// ## Arguments
+    ///
+    /// * `point` - The point to test
+    ///
+    /// ## Returns
+    ///
+    /// `true` if the point is inside the polygon
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Polygon2, Vector2};
+    ///
+    /// let p = Polygon2::new(vec![
+    ///     Vector2::new(0., 0.),
+    ///     Vector2::new(2., 0.),
+    ///     Vector2::new(2., 2.),
+    ///     Vector2::new(0., 2.),
+    /// ]);
+    ///
+    /// assert!(p.contains(&Vector2::new(1., 1.)));
+    /// assert!(!p.contains(&Vector2::new(3., 1.)));
+    /// ```
+    pub fn contains(&self, point: &Vector2) -> bool {
+        let mut inside = false;
+        for (a, b) in self.edges() {
+            if (a.y > point.y) != (b.y > point.y) {
+                let x = a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x);
+                if point.x < x {
+                    inside = !inside;
+                }
+            }
+        }
+        inside
+    }
+}
+
+impl From<Vec<Vector2>> for Polygon2 {
+    fn from(points: Vec<Vector2>) -> Self {
+        Polygon2::new(points)
+    }
+}
+
+/// Computes the convex hull of a set of points using Andrew's monotone chain algorithm
+///
+/// Duplicate points and points containing `NaN` are ignored. Collinear points along the hull's
+/// edges are not included in the result. If all points are collinear only the two extreme
+/// points are returned.
+///
+/// ## Arguments
+///
+/// * `points` - The points to wrap
+///
+/// ## Returns
+///
+/// The vertices of the hull in counter-clockwise order, starting at the point with the smallest
+/// `x` (and then `y`) component
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{convex_hull, Vector2};
+///
+/// let points = [
+///     Vector2::new(0., 0.),
+///     Vector2::new(2., 0.),
+///     Vector2::new(1., 1.),
+///     Vector2::new(2., 2.),
+///     Vector2::new(0., 2.),
+/// ];
+///
+/// let hull = convex_hull(&points);
+///
+/// assert_eq!(
+///     hull,
+///     vec![
+///         Vector2::new(0., 0.),
+///         Vector2::new(2., 0.),
+///         Vector2::new(2., 2.),
+///         Vector2::new(0., 2.),
+///     ]
+/// );
+/// ```
+pub fn convex_hull(points: &[Vector2]) -> Vec<Vector2> {
+    let mut sorted: Vec<Vector2> = points
+        .iter()
+        .copied()
+        .filter(|p| !p.x.is_nan() && !p.y.is_nan())
+        .collect();
+    sorted.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    sorted.dedup();
+
+    if sorted.len() < 3 {
+        return sorted;
+    }
+
+    let half_hull = |points: &mut dyn Iterator<Item = &Vector2>| {
+        let mut hull: Vec<Vector2> = Vec::new();
+        for p in points {
+            while hull.len() >= 2 {
+                let a = hull[hull.len() - 2];
+                let b = hull[hull.len() - 1];
+                if (b - a).cross(&(*p - b)) > 0. {
+                    break;
+                }
+                hull.pop();
+            }
+            hull.push(*p);
+        }
+        hull.pop();
+        hull
+    };
+
+    let mut hull = half_hull(&mut sorted.iter());
+    hull.extend(half_hull(&mut sorted.iter().rev()));
+    hull
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn l_shape() -> Polygon2 {
+        Polygon2::new(vec![
+            Vector2::new(0., 0.),
+            Vector2::new(2., 0.),
+            Vector2::new(2., 1.),
+            Vector2::new(1., 1.),
+            Vector2::new(1., 2.),
+            Vector2::new(0., 2.),
+        ])
+    }
+
+    #[test]
+    fn test_area() {
+        let p = l_shape();
+        assert_eq!(p.signed_area(), 3.);
+        assert_eq!(p.area(), 3.);
+
+        let mut reversed = p.points.clone();
+        reversed.reverse();
+        let p = Polygon2::new(reversed);
+        assert_eq!(p.signed_area(), -3.);
+        assert_eq!(p.area(), 3.);
+    }
+
+    #[test]
+    fn test_centroid() {
+        let p = Polygon2::new(vec![
+            Vector2::new(0., 0.),
+            Vector2::new(3., 0.),
+            Vector2::new(0., 3.),
+        ]);
+        assert_eq!(p.centroid(), Some(Vector2::new(1., 1.)));
+
+        let line = Polygon2::new(vec![Vector2::new(0., 0.), Vector2::new(1., 1.)]);
+        assert_eq!(line.centroid(), None);
+    }
+
+    #[test]
+    fn test_winding() {
+        let p = l_shape();
+        assert_eq!(p.winding(), Some(Winding::CounterClockwise));
+
+        let mut reversed = p.points.clone();
+        reversed.reverse();
+        assert_eq!(Polygon2::new(reversed).winding(), Some(Winding::Clockwise));
+
+        assert_eq!(Polygon2::new(vec![]).winding(), None);
+    }
+
+    #[test]
+    fn test_is_convex() {
+        assert!(!l_shape().is_convex());
+
+        let with_collinear = Polygon2::new(vec![
+            Vector2::new(0., 0.),
+            Vector2::new(1., 0.),
+            Vector2::new(2., 0.),
+            Vector2::new(2., 2.),
+            Vector2::new(0., 2.),
+        ]);
+        assert!(with_collinear.is_convex());
+
+        assert!(!Polygon2::new(vec![Vector2::new(0., 0.), Vector2::new(1., 0.)]).is_convex());
+    }
+
+    #[test]
+    fn test_contains() {
+        let p = l_shape();
+        assert!(p.contains(&Vector2::new(0.5, 0.5)));
+        assert!(p.contains(&Vector2::new(0.5, 1.5)));
+        assert!(!p.contains(&Vector2::new(1.5, 1.5)));
+        assert!(!p.contains(&Vector2::new(-1., 0.5)));
+    }
+
+    #[test]
+    fn test_convex_hull() {
+        let points = [
+            Vector2::new(1., 1.),
+            Vector2::new(2., 2.),
+            Vector2::new(0., 2.),
+            Vector2::new(1., 0.),
+            Vector2::new(0., 0.),
+            Vector2::new(2., 0.),
+            Vector2::new(0.5, 1.5),
+        ];
+        let hull = convex_hull(&points);
+        assert_eq!(
+            hull,
+            vec![
+                Vector2::new(0., 0.),
+                Vector2::new(2., 0.),
+                Vector2::new(2., 2.),
+                Vector2::new(0., 2.),
+            ]
+        );
+        assert_eq!(
+            Polygon2::new(hull).winding(),
+            Some(Winding::CounterClockwise)
+        );
+    }
+
+    #[test]
+    fn test_convex_hull_degenerate() {
+        assert_eq!(convex_hull(&[]), Vec::<Vector2>::new());
+
+        let single = [Vector2::new(1., 1.), Vector2::new(1., 1.)];
+        assert_eq!(convex_hull(&single), vec![Vector2::new(1., 1.)]);
+
+        let collinear = [
+            Vector2::new(1., 1.),
+            Vector2::new(0., 0.),
+            Vector2::new(3., 3.),
+            Vector2::new(2., 2.),
+        ];
+        assert_eq!(
+            convex_hull(&collinear),
+            vec![Vector2::new(0., 0.), Vector2::new(3., 3.)]
+        );
+
+        let with_nan = [
+            Vector2::new(0., 0.),
+            Vector2::new(f64::NAN, 1.),
+            Vector2::new(1., 0.),
+        ];
+        assert_eq!(
+            convex_hull(&with_nan),
+            vec![Vector2::new(0., 0.), Vector2::new(1., 0.)]
+        );
+    }
+}
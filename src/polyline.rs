@@ -0,0 +1,387 @@
+use crate::VectorSpace;
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
+/// A read-only view of a list of points treated as connected line segments
+///
+/// Works with any of the fixed size vector types, so the same helpers are available for 2D and
+/// 3D paths.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Polyline<'a, V> {
+    pub points: &'a [V],
+}
+
+/// Finds the point on the segment `a`-`b` closest to `p`
+fn closest_on_segment<V>(a: V, b: V, p: V) -> V
+where
+    V: VectorSpace,
+{
+    let ab = b - a;
+    let length_square = ab.dot(&ab);
+    if length_square == 0. {
+        return a;
+    }
+    let t = ((p - a).dot(&ab) / length_square).clamp(0., 1.);
+    a + ab * t
+}
+
+impl<'a, V> Polyline<'a, V>
+where
+    V: VectorSpace,
+{
+    /// Creates a new polyline over a list of points
+    ///
+    /// ## Arguments
+    ///
+    /// * `points` - The points of the polyline, in order
+    ///
+    /// ## Returns
+    ///
+    /// A new polyline borrowing the points
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Polyline, Vector2};
+    ///
+    /// let points = [Vector2::new(0., 0.), Vector2::new(1., 0.), Vector2::new(1., 1.)];
+    ///
+    /// let line = Polyline::new(&points);
+    /// ```
+    pub fn new(points: &'a [V]) -> Polyline<'a, V> {
+        Polyline { points }
+    }
+
+    /// Calculates the total length of the polyline
+    ///
+    /// ## Returns
+    ///
+    /// The sum of the lengths of all segments
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Polyline, Vector2};
+    ///
+    /// let points = [Vector2::new(0., 0.), Vector2::new(3., 4.), Vector2::new(3., 5.)];
+    ///
+    /// assert_eq!(Polyline::new(&points).length(), 6.);
+    /// ```
+    pub fn length(&self) -> f64 {
+        self.points
+            .windows(2)
+            .map(|w| (w[1] - w[0]).magnitude())
+            .sum()
+    }
+
+    /// Finds the point at a given distance along the polyline
+    ///
+    /// ## Arguments
+    ///
+    /// * `distance` - The distance from the first point, clamped to the length of the polyline
+    ///
+    /// ## Returns
+    ///
+    /// The point at that distance, or `None` if the polyline has no points
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Polyline, Vector2};
+    ///
+    /// let points = [Vector2::new(0., 0.), Vector2::new(2., 0.), Vector2::new(2., 2.)];
+    /// let line = Polyline::new(&points);
+    ///
+    /// assert_eq!(line.point_at_distance(3.), Some(Vector2::new(2., 1.)));
+    /// ```
+    pub fn point_at_distance(&self, distance: f64) -> Option<V> {
+        let mut remaining = distance.max(0.);
+
+        for w in self.points.windows(2) {
+            let segment = (w[1] - w[0]).magnitude();
+            if remaining <= segment {
+                if segment == 0. {
+                    return Some(w[0]);
+                }
+                return Some(w[0] + (w[1] - w[0]) * (remaining / segment));
+            }
+            remaining -= segment;
+        }
+
+        self.points.last().copied()
+    }
+
+    /// Resamples the polyline into evenly spaced points
+    ///
+    /// The first and last points are always kept, so the final segment may be shorter than
+    /// `spacing`. Distances are measured along the polyline, so corners are cut.
+    ///
+    /// ## Arguments
+    ///
+    /// * `spacing` - The distance along the polyline between consecutive points
+    ///
+    /// ## Returns
+    ///
+    /// The resampled points. If `spacing` is not positive the original points are returned
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Polyline, Vector2};
+    ///
+    /// let points = [Vector2::new(0., 0.), Vector2::new(2.5, 0.)];
+    ///
+    /// let resampled = Polyline::new(&points).resample(1.);
+    ///
+    /// assert_eq!(
+    ///     resampled,
+    ///     vec![
+    ///         Vector2::new(0., 0.),
+    ///         Vector2::new(1., 0.),
+    ///         Vector2::new(2., 0.),
+    ///         Vector2::new(2.5, 0.),
+    ///     ]
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn resample(&self, spacing: f64) -> Vec<V> {
+        if spacing.is_nan() || spacing <= 0. || self.points.len() < 2 {
+            return self.points.to_vec();
+        }
+
+        let mut result = vec![self.points[0]];
+        // Distance along the current segment at which the next sample is placed
+        let mut next = spacing;
+
+        for w in self.points.windows(2) {
+            let segment = (w[1] - w[0]).magnitude();
+            while next < segment {
+                result.push(w[0] + (w[1] - w[0]) * (next / segment));
+                next += spacing;
+            }
+            next -= segment;
+        }
+
+        // Samples are only placed strictly inside segments, so the end is never a duplicate
+        result.push(self.points[self.points.len() - 1]);
+        result
+    }
+
+    /// Finds the point on the polyline closest to a given point
+    ///
+    /// ## Arguments
+    ///
+    /// * `point` - The point to search from
+    ///
+    /// ## Returns
+    ///
+    /// The closest point on the polyline, or `None` if the polyline has no points
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Polyline, Vector2};
+    ///
+    /// let points = [Vector2::new(0., 0.), Vector2::new(2., 0.), Vector2::new(2., 2.)];
+    /// let line = Polyline::new(&points);
+    ///
+    /// assert_eq!(line.closest_point(&Vector2::new(1., 1.)), Some(Vector2::new(1., 0.)));
+    /// ```
+    pub fn closest_point(&self, point: &V) -> Option<V> {
+        if self.points.len() == 1 {
+            return Some(self.points[0]);
+        }
+
+        let mut best: Option<(V, f64)> = None;
+        for w in self.points.windows(2) {
+            let candidate = closest_on_segment(w[0], w[1], *point);
+            let offset = candidate - *point;
+            let distance_square = offset.dot(&offset);
+            match best {
+                Some((_, best_distance)) if best_distance <= distance_square => {}
+                _ => best = Some((candidate, distance_square)),
+            }
+        }
+        best.map(|(p, _)| p)
+    }
+
+    /// Simplifies the polyline, see [`simplify`]
+    #[cfg(feature = "alloc")]
+    pub fn simplify(&self, epsilon: f64) -> Vec<V> {
+        simplify(self.points, epsilon)
+    }
+}
+
+/// Simplifies a polyline with the Ramer–Douglas–Peucker algorithm
+///
+/// Points are removed as long as the simplified line stays within `epsilon` of every original
+/// point. The first and last points are always kept.
+///
+/// ## Arguments
+///
+/// * `points` - The points of the polyline, in order
+/// * `epsilon` - The largest distance a removed point may have from the simplified line
+///
+/// ## Returns
+///
+/// The points that were kept, in their original order
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{simplify, Vector2};
+///
+/// let trace = [
+///     Vector2::new(0., 0.),
+///     Vector2::new(1., 0.1),
+///     Vector2::new(2., -0.1),
+///     Vector2::new(3., 0.),
+///     Vector2::new(3., 2.),
+/// ];
+///
+/// assert_eq!(
+///     simplify(&trace, 0.5),
+///     vec![Vector2::new(0., 0.), Vector2::new(3., 0.), Vector2::new(3., 2.)]
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+pub fn simplify<V: VectorSpace>(points: &[V], epsilon: f64) -> Vec<V> {
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[points.len() - 1] = true;
+
+    // Ranges still to be simplified, handled with an explicit stack so long traces cannot
+    // overflow the call stack
+    let mut ranges = vec![(0, points.len() - 1)];
+    while let Some((start, end)) = ranges.pop() {
+        let mut farthest = (start, 0.);
+        for (i, point) in points.iter().enumerate().take(end).skip(start + 1) {
+            let offset = *point - closest_on_segment(points[start], points[end], *point);
+            let distance_square = offset.dot(&offset);
+            if distance_square > farthest.1 {
+                farthest = (i, distance_square);
+            }
+        }
+
+        let (index, distance_square) = farthest;
+        if index != start && distance_square > epsilon * epsilon {
+            keep[index] = true;
+            ranges.push((start, index));
+            ranges.push((index, end));
+        }
+    }
+
+    points
+        .iter()
+        .zip(keep)
+        .filter_map(|(point, kept)| kept.then_some(*point))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Vector2, Vector3};
+
+    fn path() -> Vec<Vector3> {
+        vec![
+            Vector3::new(0., 0., 0.),
+            Vector3::new(0., 0., 2.),
+            Vector3::new(0., 2., 2.),
+            Vector3::new(2., 2., 2.),
+        ]
+    }
+
+    #[test]
+    fn test_length() {
+        let points = path();
+        assert_eq!(Polyline::new(&points).length(), 6.);
+        assert_eq!(Polyline::<Vector3>::new(&[]).length(), 0.);
+    }
+
+    #[test]
+    fn test_point_at_distance() {
+        let points = path();
+        let line = Polyline::new(&points);
+        assert_eq!(line.point_at_distance(-1.), Some(points[0]));
+        assert_eq!(line.point_at_distance(1.), Some(Vector3::new(0., 0., 1.)));
+        assert_eq!(line.point_at_distance(2.), Some(points[1]));
+        assert_eq!(line.point_at_distance(5.), Some(Vector3::new(1., 2., 2.)));
+        assert_eq!(line.point_at_distance(10.), Some(points[3]));
+        assert_eq!(Polyline::<Vector3>::new(&[]).point_at_distance(1.), None);
+    }
+
+    #[test]
+    fn test_resample() {
+        let points = path();
+        let resampled = Polyline::new(&points).resample(1.5);
+        assert_eq!(resampled.len(), 5);
+        assert_eq!(resampled[0], points[0]);
+        assert_eq!(resampled[1], Vector3::new(0., 0., 1.5));
+        assert_eq!(resampled[2], Vector3::new(0., 1., 2.));
+        assert_eq!(resampled[3], Vector3::new(0.5, 2., 2.));
+        assert_eq!(resampled[4], points[3]);
+    }
+
+    #[test]
+    fn test_simplify() {
+        let points = path();
+        assert_eq!(Polyline::new(&points).simplify(0.1), points);
+        let zigzag = [
+            Vector2::new(0., 0.),
+            Vector2::new(1., 0.2),
+            Vector2::new(2., 0.),
+            Vector2::new(3., 1.),
+            Vector2::new(4., 0.),
+        ];
+        assert_eq!(
+            simplify(&zigzag, 0.5),
+            vec![zigzag[0], zigzag[2], zigzag[3], zigzag[4]]
+        );
+        assert_eq!(simplify(&zigzag, 2.), vec![zigzag[0], zigzag[4]]);
+        assert_eq!(simplify(&zigzag[..2], 2.), zigzag[..2].to_vec());
+        let collinear = [
+            Vector3::new(0., 0., 0.),
+            Vector3::new(1., 1., 1.),
+            Vector3::new(2., 2., 2.),
+        ];
+        assert_eq!(simplify(&collinear, 0.), vec![collinear[0], collinear[2]]);
+    }
+
+    #[test]
+    fn test_resample_invalid_spacing() {
+        let points = path();
+        assert_eq!(Polyline::new(&points).resample(0.), points);
+        assert_eq!(Polyline::new(&points).resample(f64::NAN), points);
+    }
+
+    #[test]
+    fn test_closest_point() {
+        let points = [
+            Vector2::new(0., 0.),
+            Vector2::new(4., 0.),
+            Vector2::new(4., 4.),
+        ];
+        let line = Polyline::new(&points);
+        assert_eq!(
+            line.closest_point(&Vector2::new(2., -1.)),
+            Some(Vector2::new(2., 0.))
+        );
+        assert_eq!(
+            line.closest_point(&Vector2::new(5., 3.)),
+            Some(Vector2::new(4., 3.))
+        );
+        assert_eq!(
+            line.closest_point(&Vector2::new(6., -2.)),
+            Some(Vector2::new(4., 0.))
+        );
+        assert_eq!(
+            Polyline::<Vector2>::new(&[]).closest_point(&points[0]),
+            None
+        );
+    }
+}
@@ -0,0 +1,406 @@
+#[cfg(not(feature = "std"))]
+use crate::math::FloatMath;
+use crate::{Quaternion, Radians, Vector2, Vector3};
+use core::ops::Mul;
+
+/// A position and heading in the plane, a rigid transform of SE(2)
+///
+/// As a transform a pose maps points from its local frame into the parent frame, rotating them
+/// and then translating them. Unlike [`Transform2`](crate::Transform2) there is no scale, so
+/// composing and inverting poses is always exact.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pose2 {
+    pub position: Vector2,
+    /// Counter-clockwise heading in radians
+    pub angle: f64,
+}
+
+/// A position and orientation in space, a rigid transform of SE(3)
+///
+/// As a transform a pose maps points from its local frame into the parent frame, rotating them
+/// and then translating them. Unlike [`Transform3`](crate::Transform3) there is no scale, so
+/// composing and inverting poses is always exact.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pose3 {
+    pub position: Vector3,
+    /// The orientation, which should be a unit quaternion
+    pub rotation: Quaternion,
+}
+
+impl Pose2 {
+    /// Creates a new 2D pose
+    ///
+    /// ## Arguments
+    ///
+    /// * `position` - The position of the origin of the local frame
+    /// * `angle` - The counter-clockwise heading. A plain `f64` is taken to be in radians
+    ///
+    /// ## Returns
+    ///
+    /// A new 2D pose
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Degrees, Pose2, Vector2};
+    ///
+    /// let pose = Pose2::new(Vector2::new(1., 2.), Degrees(90.));
+    /// ```
+    pub fn new(position: Vector2, angle: impl Into<Radians>) -> Pose2 {
+        Pose2 {
+            position,
+            angle: angle.into().0,
+        }
+    }
+
+    /// Creates the pose at the origin facing along the X axis
+    ///
+    /// ## Returns
+    ///
+    /// The identity pose
+    pub fn identity() -> Pose2 {
+        Pose2::new(Vector2::new(0., 0.), 0.)
+    }
+
+    /// Rotates a direction from the local frame into the parent frame, ignoring the position
+    ///
+    /// ## Arguments
+    ///
+    /// * `v` - The vector to transform
+    ///
+    /// ## Returns
+    ///
+    /// The rotated vector
+    pub fn transform_vector(&self, v: &Vector2) -> Vector2 {
+        let (sin, cos) = self.angle.sin_cos();
+        Vector2::new(v.x * cos - v.y * sin, v.x * sin + v.y * cos)
+    }
+
+    /// Maps a point from the local frame into the parent frame
+    ///
+    /// ## Arguments
+    ///
+    /// * `p` - The point to transform
+    ///
+    /// ## Returns
+    ///
+    /// The rotated and translated point
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Pose2, Vector, Vector2};
+    /// use core::f64::consts::FRAC_PI_2;
+    ///
+    /// let robot = Pose2::new(Vector2::new(1., 1.), FRAC_PI_2);
+    /// // A point one unit in front of the robot
+    /// let ahead = robot.transform_point(&Vector2::new(1., 0.));
+    ///
+    /// assert!((ahead - Vector2::new(1., 2.)).magnitude() < 1e-12);
+    /// ```
+    pub fn transform_point(&self, p: &Vector2) -> Vector2 {
+        self.transform_vector(p) + self.position
+    }
+
+    /// Calculates the pose that undoes this one
+    ///
+    /// ## Returns
+    ///
+    /// The inverse pose, which maps points from the parent frame into the local frame
+    pub fn inverse(&self) -> Pose2 {
+        let (sin, cos) = (-self.angle).sin_cos();
+        let p = self.position;
+        Pose2 {
+            position: Vector2::new(-(p.x * cos - p.y * sin), -(p.x * sin + p.y * cos)),
+            angle: -self.angle,
+        }
+    }
+
+    /// Composes two poses, applying `other` first and then `self`
+    ///
+    /// This is the same as `self * other`. If `other` is given relative to `self`, such as a
+    /// sensor mounted on a robot, the result is `other` in the frame of `self`'s parent. The
+    /// angle of the result is wrapped into `[-PI, PI)`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `other` - The pose to apply first
+    ///
+    /// ## Returns
+    ///
+    /// The combined pose
+    pub fn compose(&self, other: &Pose2) -> Pose2 {
+        Pose2 {
+            position: self.transform_point(&other.position),
+            angle: Radians(self.angle + other.angle).wrapped().0,
+        }
+    }
+
+    /// Interpolates between two poses
+    ///
+    /// The position moves in a straight line and the heading turns the shorter way round, so
+    /// interpolating from `170°` to `-170°` passes through `180°` rather than `0°`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `other` - The pose at `t = 1`
+    /// * `t` - How far to go from `self` towards `other`
+    ///
+    /// ## Returns
+    ///
+    /// The interpolated pose
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Degrees, Pose2, Vector2};
+    ///
+    /// let a = Pose2::new(Vector2::new(0., 0.), Degrees(170.));
+    /// let b = Pose2::new(Vector2::new(2., 4.), Degrees(-170.));
+    /// let halfway = a.interpolate(&b, 0.5);
+    ///
+    /// assert_eq!(halfway.position, Vector2::new(1., 2.));
+    /// assert!((halfway.angle.abs() - core::f64::consts::PI).abs() < 1e-12);
+    /// ```
+    pub fn interpolate(&self, other: &Pose2, t: f64) -> Pose2 {
+        let turn = Radians(other.angle - self.angle).wrapped().0;
+        Pose2 {
+            position: self.position + (other.position - self.position) * t,
+            angle: self.angle + turn * t,
+        }
+    }
+}
+
+impl Mul for Pose2 {
+    type Output = Pose2;
+
+    /// Composes two poses, applying `other` first and then `self`
+    fn mul(self, other: Pose2) -> Pose2 {
+        self.compose(&other)
+    }
+}
+
+impl Pose3 {
+    /// Creates a new 3D pose
+    ///
+    /// ## Arguments
+    ///
+    /// * `position` - The position of the origin of the local frame
+    /// * `rotation` - The orientation, which should be a unit quaternion
+    ///
+    /// ## Returns
+    ///
+    /// A new 3D pose
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Pose3, Quaternion, Vector3};
+    ///
+    /// let pose = Pose3::new(
+    ///     Vector3::new(1., 2., 3.),
+    ///     Quaternion::from_axis_angle(&Vector3::new(0., 0., 1.), 0.5),
+    /// );
+    /// ```
+    pub fn new(position: Vector3, rotation: Quaternion) -> Pose3 {
+        Pose3 { position, rotation }
+    }
+
+    /// Creates the pose at the origin with no rotation
+    ///
+    /// ## Returns
+    ///
+    /// The identity pose
+    pub fn identity() -> Pose3 {
+        Pose3::new(Vector3::new(0., 0., 0.), Quaternion::identity())
+    }
+
+    /// Rotates a direction from the local frame into the parent frame, ignoring the position
+    ///
+    /// ## Arguments
+    ///
+    /// * `v` - The vector to transform
+    ///
+    /// ## Returns
+    ///
+    /// The rotated vector
+    pub fn transform_vector(&self, v: &Vector3) -> Vector3 {
+        self.rotation.rotate(v)
+    }
+
+    /// Maps a point from the local frame into the parent frame
+    ///
+    /// ## Arguments
+    ///
+    /// * `p` - The point to transform
+    ///
+    /// ## Returns
+    ///
+    /// The rotated and translated point
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Pose3, Quaternion, Vector, Vector3};
+    /// use core::f64::consts::FRAC_PI_2;
+    ///
+    /// let camera = Pose3::new(
+    ///     Vector3::new(0., 0., 1.),
+    ///     Quaternion::from_axis_angle(&Vector3::new(0., 0., 1.), FRAC_PI_2),
+    /// );
+    /// let p = camera.transform_point(&Vector3::new(2., 0., 0.));
+    ///
+    /// assert!((p - Vector3::new(0., 2., 1.)).magnitude() < 1e-12);
+    /// ```
+    pub fn transform_point(&self, p: &Vector3) -> Vector3 {
+        self.rotation.rotate(p) + self.position
+    }
+
+    /// Calculates the pose that undoes this one
+    ///
+    /// ## Returns
+    ///
+    /// The inverse pose, which maps points from the parent frame into the local frame
+    pub fn inverse(&self) -> Pose3 {
+        let rotation = self.rotation.conjugate();
+        Pose3 {
+            position: rotation.rotate(&self.position) * -1.,
+            rotation,
+        }
+    }
+
+    /// Composes two poses, applying `other` first and then `self`
+    ///
+    /// This is the same as `self * other`. If `other` is given relative to `self`, such as a
+    /// sensor mounted on a robot, the result is `other` in the frame of `self`'s parent.
+    ///
+    /// ## Arguments
+    ///
+    /// * `other` - The pose to apply first
+    ///
+    /// ## Returns
+    ///
+    /// The combined pose
+    pub fn compose(&self, other: &Pose3) -> Pose3 {
+        Pose3 {
+            position: self.transform_point(&other.position),
+            rotation: self.rotation * other.rotation,
+        }
+    }
+
+    /// Interpolates between two poses
+    ///
+    /// The position moves in a straight line and the rotation is interpolated with
+    /// [`Quaternion::slerp`], so it turns the shorter way round at a constant speed.
+    ///
+    /// ## Arguments
+    ///
+    /// * `other` - The pose at `t = 1`
+    /// * `t` - How far to go from `self` towards `other`
+    ///
+    /// ## Returns
+    ///
+    /// The interpolated pose
+    pub fn interpolate(&self, other: &Pose3, t: f64) -> Pose3 {
+        Pose3 {
+            position: self.position + (other.position - self.position) * t,
+            rotation: self.rotation.slerp(&other.rotation, t),
+        }
+    }
+}
+
+impl Mul for Pose3 {
+    type Output = Pose3;
+
+    /// Composes two poses, applying `other` first and then `self`
+    fn mul(self, other: Pose3) -> Pose3 {
+        self.compose(&other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_vec_approx_eq, Transform2, Transform3};
+    use core::f64::consts::{FRAC_PI_2, PI};
+
+    #[test]
+    fn test_pose2_compose_and_inverse() {
+        let a = Pose2::new(Vector2::new(1., 2.), 0.3);
+        let b = Pose2::new(Vector2::new(-1., 0.5), 3.);
+        let p = Vector2::new(0.7, -1.1);
+
+        assert_vec_approx_eq!(
+            (a * b).transform_point(&p),
+            a.transform_point(&b.transform_point(&p))
+        );
+        assert!((a * b).angle < PI && (a * b).angle >= -PI);
+        assert_vec_approx_eq!(a.inverse().transform_point(&a.transform_point(&p)), p);
+        assert_vec_approx_eq!((a * a.inverse()).position, [0., 0.]);
+
+        // Matches the scale-free transform
+        let t = Transform2::new(a.position, a.angle, Vector2::new(1., 1.));
+        assert_vec_approx_eq!(a.transform_point(&p), t.transform_point(&p));
+    }
+
+    #[test]
+    fn test_pose2_interpolate() {
+        let a = Pose2::new(Vector2::new(0., 0.), 0.5);
+        let b = Pose2::new(Vector2::new(4., 0.), 1.5);
+        assert_eq!(a.interpolate(&b, 0.), a);
+        let quarter = a.interpolate(&b, 0.25);
+        assert_eq!(quarter.position, Vector2::new(1., 0.));
+        assert!((quarter.angle - 0.75).abs() < 1e-12);
+
+        // Turns the short way across the discontinuity
+        let a = Pose2::new(Vector2::new(0., 0.), PI - 0.1);
+        let b = Pose2::new(Vector2::new(0., 0.), -PI + 0.1);
+        let end = a.interpolate(&b, 1.);
+        assert!((Radians(end.angle - b.angle).wrapped().0).abs() < 1e-12);
+        assert!((a.interpolate(&b, 0.5).angle - PI).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_pose3_compose_and_inverse() {
+        let a = Pose3::new(
+            Vector3::new(1., 2., 3.),
+            Quaternion::from_axis_angle(&Vector3::new(1., 1., 0.), 0.7),
+        );
+        let b = Pose3::new(
+            Vector3::new(-2., 0., 1.),
+            Quaternion::from_axis_angle(&Vector3::new(0., 1., 1.), -1.3),
+        );
+        let p = Vector3::new(0.5, -1., 2.);
+
+        assert_vec_approx_eq!(
+            (a * b).transform_point(&p),
+            a.transform_point(&b.transform_point(&p))
+        );
+        assert_vec_approx_eq!(a.inverse().transform_point(&a.transform_point(&p)), p);
+        assert_vec_approx_eq!((a.inverse() * a).transform_point(&p), p);
+
+        let t = Transform3::new(a.position, a.rotation, Vector3::new(1., 1., 1.));
+        assert_vec_approx_eq!(a.transform_point(&p), t.transform_point(&p));
+    }
+
+    #[test]
+    fn test_pose3_interpolate() {
+        let axis = Vector3::new(0., 0., 1.);
+        let a = Pose3::new(Vector3::new(0., 0., 0.), Quaternion::identity());
+        let b = Pose3::new(
+            Vector3::new(2., 2., 0.),
+            Quaternion::from_axis_angle(&axis, PI),
+        );
+        let halfway = a.interpolate(&b, 0.5);
+        assert_eq!(halfway.position, Vector3::new(1., 1., 0.));
+        assert_vec_approx_eq!(
+            halfway.transform_vector(&Vector3::new(1., 0., 0.)),
+            Quaternion::from_axis_angle(&axis, FRAC_PI_2).rotate(&Vector3::new(1., 0., 0.))
+        );
+        assert_vec_approx_eq!(
+            a.interpolate(&b, 1.)
+                .transform_point(&Vector3::new(1., 0., 0.)),
+            b.transform_point(&Vector3::new(1., 0., 0.))
+        );
+    }
+}
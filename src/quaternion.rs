@@ -0,0 +1,485 @@
+#[cfg(not(feature = "std"))]
+use crate::math::FloatMath;
+use crate::{Radians, Vector, Vector3};
+use core::ops::Mul;
+
+/// A quaternion used to represent rotations in 3D space
+///
+/// Only unit quaternions represent rotations. Use [`Quaternion::normalize`] to correct any drift
+/// after composing many rotations.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quaternion {
+    pub w: f64,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl Quaternion {
+    /// Creates a new quaternion
+    ///
+    /// ## Arguments
+    ///
+    /// * `w` - The scalar (real) part of the quaternion
+    /// * `x` - The i component of the quaternion
+    /// * `y` - The j component of the quaternion
+    /// * `z` - The k component of the quaternion
+    ///
+    /// ## Returns
+    ///
+    /// A new quaternion
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::Quaternion;
+    ///
+    /// let q = Quaternion::new(1., 0., 0., 0.);
+    /// ```
+    pub fn new(w: f64, x: f64, y: f64, z: f64) -> Quaternion {
+        Quaternion { w, x, y, z }
+    }
+
+    /// Creates the quaternion representing no rotation
+    ///
+    /// ## Returns
+    ///
+    /// The identity quaternion
+    pub fn identity() -> Quaternion {
+        Quaternion::new(1., 0., 0., 0.)
+    }
+
+    /// Creates a quaternion rotating around an axis
+    ///
+    /// ## Arguments
+    ///
+    /// * `axis` - The axis to rotate around. It does not need to be normalized
+    /// * `angle` - The angle to rotate by. A plain `f64` is taken to be in radians
+    ///
+    /// ## Returns
+    ///
+    /// A unit quaternion representing the rotation
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Degrees, Quaternion, Vector3};
+    /// use core::f64::consts::PI;
+    ///
+    /// let q = Quaternion::from_axis_angle(&Vector3::new(0., 0., 1.), PI);
+    /// let same = Quaternion::from_axis_angle(&Vector3::new(0., 0., 1.), Degrees(180.));
+    /// ```
+    pub fn from_axis_angle(axis: &Vector3, angle: impl Into<Radians>) -> Quaternion {
+        let axis = axis.normalize();
+        let (sin, cos) = (angle.into() / 2.).sin_cos();
+        Quaternion::new(cos, axis.x * sin, axis.y * sin, axis.z * sin)
+    }
+
+    /// Creates the rotation that maps the X, Y and Z axes onto an orthonormal basis
+    ///
+    /// ## Arguments
+    ///
+    /// * `right` - Where the X axis ends up
+    /// * `up` - Where the Y axis ends up
+    /// * `forward` - Where the Z axis ends up
+    ///
+    /// ## Returns
+    ///
+    /// A unit quaternion representing the rotation. The result is meaningless if the vectors are
+    /// not orthonormal and right-handed, such as those returned by [`face_towards_basis`]
+    pub fn from_basis(right: &Vector3, up: &Vector3, forward: &Vector3) -> Quaternion {
+        // Shepperd's method, dividing by the largest of the four possible denominators
+        let (m00, m11, m22) = (right.x, up.y, forward.z);
+        let trace = m00 + m11 + m22;
+        let q = if trace > 0. {
+            let s = (trace + 1.).sqrt() * 2.;
+            Quaternion::new(
+                0.25 * s,
+                (up.z - forward.y) / s,
+                (forward.x - right.z) / s,
+                (right.y - up.x) / s,
+            )
+        } else if m00 > m11 && m00 > m22 {
+            let s = (1. + m00 - m11 - m22).sqrt() * 2.;
+            Quaternion::new(
+                (up.z - forward.y) / s,
+                0.25 * s,
+                (up.x + right.y) / s,
+                (forward.x + right.z) / s,
+            )
+        } else if m11 > m22 {
+            let s = (1. + m11 - m00 - m22).sqrt() * 2.;
+            Quaternion::new(
+                (forward.x - right.z) / s,
+                (up.x + right.y) / s,
+                0.25 * s,
+                (forward.y + up.z) / s,
+            )
+        } else {
+            let s = (1. + m22 - m00 - m11).sqrt() * 2.;
+            Quaternion::new(
+                (right.y - up.x) / s,
+                (forward.x + right.z) / s,
+                (forward.y + up.z) / s,
+                0.25 * s,
+            )
+        };
+        q.normalize()
+    }
+
+    /// Creates the rotation that turns the Z axis towards a direction while keeping the Y axis
+    /// as close to an up vector as possible
+    ///
+    /// This follows the Y-up, Z-forward convention used by Unity and Direct3D. For cameras that
+    /// look down -Z, as in OpenGL, pass the negated direction.
+    ///
+    /// ## Arguments
+    ///
+    /// * `forward` - The direction to face. It does not need to be normalized
+    /// * `up` - The preferred up direction. If it is parallel to `forward` another axis is used
+    ///
+    /// ## Returns
+    ///
+    /// The rotation, or `None` if `forward` is zero
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Quaternion, Vector, Vector3};
+    ///
+    /// let q = Quaternion::face_towards(&Vector3::new(1., 0., 0.), &Vector3::new(0., 1., 0.)).unwrap();
+    ///
+    /// let forward = q.rotate(&Vector3::new(0., 0., 1.));
+    /// assert!((forward - Vector3::new(1., 0., 0.)).magnitude() < 1e-12);
+    /// ```
+    pub fn face_towards(forward: &Vector3, up: &Vector3) -> Option<Quaternion> {
+        let [right, up, forward] = face_towards_basis(forward, up)?;
+        Some(Quaternion::from_basis(&right, &up, &forward))
+    }
+
+    /// Creates the rotation that turns an object at one point to face another
+    ///
+    /// ## Arguments
+    ///
+    /// * `eye` - The position of the object
+    /// * `target` - The point to look at
+    /// * `up` - The preferred up direction
+    ///
+    /// ## Returns
+    ///
+    /// The rotation, or `None` if `eye` and `target` are the same point
+    pub fn look_at(eye: &Vector3, target: &Vector3, up: &Vector3) -> Option<Quaternion> {
+        Quaternion::face_towards(&(*target - *eye), up)
+    }
+
+    /// Calculates the length of the quaternion
+    ///
+    /// ## Returns
+    ///
+    /// The length of the quaternion, which is `1` for rotations
+    pub fn magnitude(&self) -> f64 {
+        (self.w * self.w + self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+    }
+
+    /// Scales the quaternion to unit length
+    ///
+    /// ## Returns
+    ///
+    /// The normalized quaternion
+    pub fn normalize(&self) -> Quaternion {
+        let mag = self.magnitude();
+        Quaternion::new(self.w / mag, self.x / mag, self.y / mag, self.z / mag)
+    }
+
+    /// Calculates the conjugate of the quaternion
+    ///
+    /// For unit quaternions this is the inverse rotation.
+    ///
+    /// ## Returns
+    ///
+    /// The quaternion with its vector part negated
+    pub fn conjugate(&self) -> Quaternion {
+        Quaternion::new(self.w, -self.x, -self.y, -self.z)
+    }
+
+    /// Calculates the inverse of the quaternion
+    ///
+    /// ## Returns
+    ///
+    /// The inverse quaternion, such that `q * q.inverse()` is the identity
+    pub fn inverse(&self) -> Quaternion {
+        let norm_square = self.w * self.w + self.x * self.x + self.y * self.y + self.z * self.z;
+        let c = self.conjugate();
+        Quaternion::new(
+            c.w / norm_square,
+            c.x / norm_square,
+            c.y / norm_square,
+            c.z / norm_square,
+        )
+    }
+
+    /// Rotates a vector by the quaternion
+    ///
+    /// The quaternion is assumed to be normalized.
+    ///
+    /// ## Arguments
+    ///
+    /// * `v` - The vector to rotate
+    ///
+    /// ## Returns
+    ///
+    /// The rotated vector
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Quaternion, Vector, Vector3};
+    /// use core::f64::consts::FRAC_PI_2;
+    ///
+    /// let q = Quaternion::from_axis_angle(&Vector3::new(0., 0., 1.), FRAC_PI_2);
+    ///
+    /// let v = q.rotate(&Vector3::new(1., 0., 0.));
+    ///
+    /// assert!((v - Vector3::new(0., 1., 0.)).magnitude() < 1e-12);
+    /// ```
+    pub fn rotate(&self, v: &Vector3) -> Vector3 {
+        // v' = v + 2w(u x v) + 2u x (u x v), where u is the vector part
+        let u = Vector3::new(self.x, self.y, self.z);
+        let t = u.cross(v) * 2.;
+        *v + t * self.w + u.cross(&t)
+    }
+
+    /// Interpolates between two rotations at a constant angular speed
+    ///
+    /// Both quaternions are assumed to be normalized. `q` and `-q` are the same rotation, so the
+    /// sign of `other` is chosen to take the shorter way round.
+    ///
+    /// ## Arguments
+    ///
+    /// * `other` - The rotation at `t = 1`
+    /// * `t` - How far to go from `self` towards `other`
+    ///
+    /// ## Returns
+    ///
+    /// The interpolated rotation
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Quaternion, Vector, Vector3};
+    ///
+    /// let axis = Vector3::new(0., 0., 1.);
+    /// let halfway = Quaternion::identity().slerp(&Quaternion::from_axis_angle(&axis, 2.), 0.5);
+    ///
+    /// let expected = Quaternion::from_axis_angle(&axis, 1.);
+    /// let v = Vector3::new(1., 0., 0.);
+    /// assert!((halfway.rotate(&v) - expected.rotate(&v)).magnitude() < 1e-12);
+    /// ```
+    pub fn slerp(&self, other: &Quaternion, t: f64) -> Quaternion {
+        let mut cos = self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z;
+        let mut other = *other;
+        if cos < 0. {
+            cos = -cos;
+            other = Quaternion::new(-other.w, -other.x, -other.y, -other.z);
+        }
+
+        let (a, b) = if cos > 1. - 1e-9 {
+            // The rotations are almost equal, where a straight line is as good and avoids
+            // dividing by a tiny sine
+            (1. - t, t)
+        } else {
+            let sin = (1. - cos * cos).sqrt();
+            let angle = sin.atan2(cos);
+            (
+                (angle * (1. - t)).sin_cos().0 / sin,
+                (angle * t).sin_cos().0 / sin,
+            )
+        };
+        Quaternion::new(
+            a * self.w + b * other.w,
+            a * self.x + b * other.x,
+            a * self.y + b * other.y,
+            a * self.z + b * other.z,
+        )
+        .normalize()
+    }
+}
+
+/// Builds an orthonormal basis facing a direction, keeping its up axis as close to an up vector
+/// as possible
+///
+/// If `up` is zero or parallel to `forward`, the coordinate axis least aligned with `forward` is
+/// used instead, so looking straight up or down still gives a valid basis.
+///
+/// ## Arguments
+///
+/// * `forward` - The direction to face. It does not need to be normalized
+/// * `up` - The preferred up direction
+///
+/// ## Returns
+///
+/// The unit `[right, up, forward]` vectors of a right-handed basis, or `None` if `forward` is zero
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{face_towards_basis, Vector3};
+///
+/// let [right, up, forward] =
+///     face_towards_basis(&Vector3::new(0., 0., 2.), &Vector3::new(0., 1., 0.)).unwrap();
+///
+/// assert_eq!(right, Vector3::new(1., 0., 0.));
+/// assert_eq!(up, Vector3::new(0., 1., 0.));
+/// assert_eq!(forward, Vector3::new(0., 0., 1.));
+/// ```
+pub fn face_towards_basis(forward: &Vector3, up: &Vector3) -> Option<[Vector3; 3]> {
+    let forward = forward.with_magnitude(1.)?;
+    let mut right = up.cross(&forward);
+    if right.magnitude() <= 1e-12 * up.magnitude() {
+        let (x, y, z) = (forward.x.abs(), forward.y.abs(), forward.z.abs());
+        let fallback = if x <= y && x <= z {
+            Vector3::new(1., 0., 0.)
+        } else if y <= z {
+            Vector3::new(0., 1., 0.)
+        } else {
+            Vector3::new(0., 0., 1.)
+        };
+        right = fallback.cross(&forward);
+    }
+    let right = right.normalize();
+    Some([right, forward.cross(&right), forward])
+}
+
+impl Mul for Quaternion {
+    type Output = Quaternion;
+
+    /// Composes two rotations, applying `other` first and then `self`
+    fn mul(self, other: Quaternion) -> Quaternion {
+        Quaternion::new(
+            self.w * other.w - self.x * other.x - self.y * other.y - self.z * other.z,
+            self.w * other.x + self.x * other.w + self.y * other.z - self.z * other.y,
+            self.w * other.y - self.x * other.z + self.y * other.w + self.z * other.x,
+            self.w * other.z + self.x * other.y - self.y * other.x + self.z * other.w,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::consts::FRAC_PI_2;
+
+    fn assert_close(a: Vector3, b: Vector3) {
+        assert!((a - b).magnitude() < 1e-12, "{:?} != {:?}", a, b);
+    }
+
+    #[test]
+    fn test_from_axis_angle() {
+        let q = Quaternion::from_axis_angle(&Vector3::new(0., 2., 0.), 0.);
+        assert_eq!(q, Quaternion::identity());
+
+        let q = Quaternion::from_axis_angle(&Vector3::new(1., 0., 0.), FRAC_PI_2);
+        assert!((q.magnitude() - 1.).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_rotate() {
+        let q = Quaternion::from_axis_angle(&Vector3::new(1., 0., 0.), FRAC_PI_2);
+        assert_close(
+            q.rotate(&Vector3::new(0., 1., 0.)),
+            Vector3::new(0., 0., 1.),
+        );
+        assert_close(
+            q.rotate(&Vector3::new(1., 2., 0.)),
+            Vector3::new(1., 0., 2.),
+        );
+    }
+
+    #[test]
+    fn test_mul() {
+        let a = Quaternion::from_axis_angle(&Vector3::new(0., 0., 1.), FRAC_PI_2);
+        let b = Quaternion::from_axis_angle(&Vector3::new(1., 0., 0.), FRAC_PI_2);
+        let v = Vector3::new(0., 1., 0.);
+        assert_close((a * b).rotate(&v), a.rotate(&b.rotate(&v)));
+    }
+
+    #[test]
+    fn test_from_basis() {
+        // Rotations near each of the four branches of the conversion
+        let axes = [
+            Vector3::new(0., 0., 1.),
+            Vector3::new(1., 0., 0.),
+            Vector3::new(0., 1., 0.),
+            Vector3::new(1., 2., 3.),
+        ];
+        for axis in axes {
+            for angle in [0.5, 3.] {
+                let q = Quaternion::from_axis_angle(&axis, angle);
+                let r = Quaternion::from_basis(
+                    &q.rotate(&Vector3::new(1., 0., 0.)),
+                    &q.rotate(&Vector3::new(0., 1., 0.)),
+                    &q.rotate(&Vector3::new(0., 0., 1.)),
+                );
+                let v = Vector3::new(3., -1., 2.);
+                assert_close(r.rotate(&v), q.rotate(&v));
+            }
+        }
+    }
+
+    #[test]
+    fn test_face_towards() {
+        let up = Vector3::new(0., 1., 0.);
+        let direction = Vector3::new(1., 1., -1.);
+        let q = Quaternion::face_towards(&direction, &up).unwrap();
+        assert_close(q.rotate(&Vector3::new(0., 0., 1.)), direction.normalize());
+        // The rotated up axis stays in the plane of up and forward, pointing upwards
+        let rotated_up = q.rotate(&up);
+        assert!(rotated_up.y > 0.);
+        assert!(rotated_up.dot(&up.cross(&direction)).abs() < 1e-12);
+
+        let q = Quaternion::look_at(&Vector3::new(1., 1., 1.), &Vector3::new(1., 1., 5.), &up);
+        assert_close(q.unwrap().rotate(&up), up);
+        assert_eq!(Quaternion::look_at(&up, &up, &up), None);
+    }
+
+    #[test]
+    fn test_face_towards_degenerate_up() {
+        for up in [Vector3::new(0., 2., 0.), Vector3::new(0., 0., 0.)] {
+            let [right, up, forward] = face_towards_basis(&Vector3::new(0., 3., 0.), &up).unwrap();
+            assert_eq!(forward, Vector3::new(0., 1., 0.));
+            assert!((right.magnitude() - 1.).abs() < 1e-12);
+            assert!((up.magnitude() - 1.).abs() < 1e-12);
+            assert_eq!(right.dot(&forward), 0.);
+            assert_close(right.cross(&up), forward);
+        }
+    }
+
+    #[test]
+    fn test_inverse() {
+        let q = Quaternion::new(1., 2., 3., 4.);
+        let identity = q * q.inverse();
+        assert!((identity.w - 1.).abs() < 1e-12);
+        assert!(identity.x.abs() < 1e-12 && identity.y.abs() < 1e-12 && identity.z.abs() < 1e-12);
+
+        let r = Quaternion::from_axis_angle(&Vector3::new(1., 1., 0.), 1.);
+        let v = Vector3::new(3., -1., 2.);
+        assert_close(r.conjugate().rotate(&r.rotate(&v)), v);
+    }
+
+    #[test]
+    fn test_slerp() {
+        let axis = Vector3::new(1., 2., -1.);
+        let a = Quaternion::from_axis_angle(&axis, 0.2);
+        let b = Quaternion::from_axis_angle(&axis, 1.4);
+        let v = Vector3::new(3., -1., 2.);
+        assert_close(a.slerp(&b, 0.).rotate(&v), a.rotate(&v));
+        assert_close(a.slerp(&b, 1.).rotate(&v), b.rotate(&v));
+
+        let quarter = Quaternion::from_axis_angle(&axis, 0.5);
+        assert_close(a.slerp(&b, 0.25).rotate(&v), quarter.rotate(&v));
+        // The negated quaternion is the same rotation and gives the same path
+        let negated = Quaternion::new(-b.w, -b.x, -b.y, -b.z);
+        assert_close(a.slerp(&negated, 0.25).rotate(&v), quarter.rotate(&v));
+        assert_eq!(a.slerp(&a, 0.5), a.normalize());
+    }
+}
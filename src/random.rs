@@ -0,0 +1,576 @@
+use crate::{DynamicVector, Vector, Vector2, Vector3, Vector4};
+use rand::distributions::uniform::{SampleBorrow, SampleUniform, UniformFloat, UniformSampler};
+use rand::distributions::{Distribution, Standard, Uniform};
+use rand::Rng;
+use std::f64::consts::{PI, TAU};
+
+impl Distribution<Vector2> for Standard {
+    /// Samples a vector with every component uniformly distributed in `[0, 1)`
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector2 {
+        Vector2::new(rng.gen(), rng.gen())
+    }
+}
+
+impl Distribution<Vector3> for Standard {
+    /// Samples a vector with every component uniformly distributed in `[0, 1)`
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector3 {
+        Vector3::new(rng.gen(), rng.gen(), rng.gen())
+    }
+}
+
+impl Distribution<Vector4> for Standard {
+    /// Samples a vector with every component uniformly distributed in `[0, 1)`
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector4 {
+        Vector4::new(rng.gen(), rng.gen(), rng.gen(), rng.gen())
+    }
+}
+
+/// Samples a [`Vector2`] uniformly from the box spanned by two corners
+///
+/// Each component is sampled independently, so `Uniform::new(low, high)` requires every component
+/// of `low` to be less than the matching component of `high`.
+#[derive(Debug, Clone, Copy)]
+pub struct UniformVector2 {
+    x: UniformFloat<f64>,
+    y: UniformFloat<f64>,
+}
+
+/// Samples a [`Vector3`] uniformly from the box spanned by two corners
+///
+/// Each component is sampled independently, so `Uniform::new(low, high)` requires every component
+/// of `low` to be less than the matching component of `high`.
+#[derive(Debug, Clone, Copy)]
+pub struct UniformVector3 {
+    x: UniformFloat<f64>,
+    y: UniformFloat<f64>,
+    z: UniformFloat<f64>,
+}
+
+/// Samples a [`Vector4`] uniformly from the box spanned by two corners
+///
+/// Each component is sampled independently, so `Uniform::new(low, high)` requires every component
+/// of `low` to be less than the matching component of `high`.
+#[derive(Debug, Clone, Copy)]
+pub struct UniformVector4 {
+    a: UniformFloat<f64>,
+    b: UniformFloat<f64>,
+    c: UniformFloat<f64>,
+    d: UniformFloat<f64>,
+}
+
+impl UniformSampler for UniformVector2 {
+    type X = Vector2;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Vector2> + Sized,
+        B2: SampleBorrow<Vector2> + Sized,
+    {
+        let (low, high) = (low.borrow(), high.borrow());
+        UniformVector2 {
+            x: UniformFloat::new(low.x, high.x),
+            y: UniformFloat::new(low.y, high.y),
+        }
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Vector2> + Sized,
+        B2: SampleBorrow<Vector2> + Sized,
+    {
+        let (low, high) = (low.borrow(), high.borrow());
+        UniformVector2 {
+            x: UniformFloat::new_inclusive(low.x, high.x),
+            y: UniformFloat::new_inclusive(low.y, high.y),
+        }
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector2 {
+        Vector2::new(self.x.sample(rng), self.y.sample(rng))
+    }
+}
+
+impl UniformSampler for UniformVector3 {
+    type X = Vector3;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Vector3> + Sized,
+        B2: SampleBorrow<Vector3> + Sized,
+    {
+        let (low, high) = (low.borrow(), high.borrow());
+        UniformVector3 {
+            x: UniformFloat::new(low.x, high.x),
+            y: UniformFloat::new(low.y, high.y),
+            z: UniformFloat::new(low.z, high.z),
+        }
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Vector3> + Sized,
+        B2: SampleBorrow<Vector3> + Sized,
+    {
+        let (low, high) = (low.borrow(), high.borrow());
+        UniformVector3 {
+            x: UniformFloat::new_inclusive(low.x, high.x),
+            y: UniformFloat::new_inclusive(low.y, high.y),
+            z: UniformFloat::new_inclusive(low.z, high.z),
+        }
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector3 {
+        Vector3::new(self.x.sample(rng), self.y.sample(rng), self.z.sample(rng))
+    }
+}
+
+impl UniformSampler for UniformVector4 {
+    type X = Vector4;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Vector4> + Sized,
+        B2: SampleBorrow<Vector4> + Sized,
+    {
+        let (low, high) = (low.borrow(), high.borrow());
+        UniformVector4 {
+            a: UniformFloat::new(low.a, high.a),
+            b: UniformFloat::new(low.b, high.b),
+            c: UniformFloat::new(low.c, high.c),
+            d: UniformFloat::new(low.d, high.d),
+        }
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Vector4> + Sized,
+        B2: SampleBorrow<Vector4> + Sized,
+    {
+        let (low, high) = (low.borrow(), high.borrow());
+        UniformVector4 {
+            a: UniformFloat::new_inclusive(low.a, high.a),
+            b: UniformFloat::new_inclusive(low.b, high.b),
+            c: UniformFloat::new_inclusive(low.c, high.c),
+            d: UniformFloat::new_inclusive(low.d, high.d),
+        }
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector4 {
+        Vector4::new(
+            self.a.sample(rng),
+            self.b.sample(rng),
+            self.c.sample(rng),
+            self.d.sample(rng),
+        )
+    }
+}
+
+impl SampleUniform for Vector2 {
+    type Sampler = UniformVector2;
+}
+
+impl SampleUniform for Vector3 {
+    type Sampler = UniformVector3;
+}
+
+impl SampleUniform for Vector4 {
+    type Sampler = UniformVector4;
+}
+
+impl Vector2 {
+    /// Samples a random direction with a magnitude of 1
+    ///
+    /// Directions are uniformly distributed around the unit circle.
+    ///
+    /// ## Arguments
+    ///
+    /// * `rng` - The random number generator to sample with
+    ///
+    /// ## Returns
+    ///
+    /// A random unit vector
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Vector, Vector2};
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    ///
+    /// let mut rng = StdRng::seed_from_u64(0);
+    /// let v = Vector2::random_unit(&mut rng);
+    ///
+    /// assert!((v.magnitude() - 1.).abs() < 1e-12);
+    /// ```
+    pub fn random_unit<R: Rng + ?Sized>(rng: &mut R) -> Vector2 {
+        let (sin, cos) = (rng.gen::<f64>() * TAU).sin_cos();
+        Vector2::new(cos, sin)
+    }
+
+    /// Samples a random point inside the unit disk
+    ///
+    /// Points are uniformly distributed over the area of the disk, rather than bunched up at
+    /// the center.
+    ///
+    /// ## Arguments
+    ///
+    /// * `rng` - The random number generator to sample with
+    ///
+    /// ## Returns
+    ///
+    /// A random vector with a magnitude of at most 1
+    pub fn random_in_unit_disk<R: Rng + ?Sized>(rng: &mut R) -> Vector2 {
+        // The area within radius r grows with r^2, so the radius is the square root of a
+        // uniform sample
+        Vector2::random_unit(rng) * rng.gen::<f64>().sqrt()
+    }
+}
+
+impl Vector3 {
+    /// Samples a random direction with a magnitude of 1
+    ///
+    /// Directions are uniformly distributed over the surface of the unit sphere.
+    ///
+    /// ## Arguments
+    ///
+    /// * `rng` - The random number generator to sample with
+    ///
+    /// ## Returns
+    ///
+    /// A random unit vector
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Vector, Vector3};
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    ///
+    /// let mut rng = StdRng::seed_from_u64(0);
+    /// let v = Vector3::random_unit(&mut rng);
+    ///
+    /// assert!((v.magnitude() - 1.).abs() < 1e-12);
+    /// ```
+    pub fn random_unit<R: Rng + ?Sized>(rng: &mut R) -> Vector3 {
+        // By Archimedes' hat-box theorem, a uniform height gives a uniform area on the sphere
+        let z = rng.gen::<f64>() * 2. - 1.;
+        let radius = (1. - z * z).max(0.).sqrt();
+        let (sin, cos) = (rng.gen::<f64>() * TAU).sin_cos();
+        Vector3::new(radius * cos, radius * sin, z)
+    }
+
+    /// Samples a random point inside the unit sphere
+    ///
+    /// Points are uniformly distributed over the volume of the ball, rather than bunched up at
+    /// the center.
+    ///
+    /// ## Arguments
+    ///
+    /// * `rng` - The random number generator to sample with
+    ///
+    /// ## Returns
+    ///
+    /// A random vector with a magnitude of at most 1
+    pub fn random_in_unit_sphere<R: Rng + ?Sized>(rng: &mut R) -> Vector3 {
+        // The volume within radius r grows with r^3
+        Vector3::random_unit(rng) * rng.gen::<f64>().cbrt()
+    }
+
+    /// Samples a random direction within a cone
+    ///
+    /// Directions are uniformly distributed over the solid angle of the cone, which makes this
+    /// suitable for weapon spread or sampling a spherical light source.
+    ///
+    /// ## Arguments
+    ///
+    /// * `axis` - The center line of the cone. This does not need to be normalized
+    /// * `half_angle` - The angle in radians between the axis and the edge of the cone, clamped
+    ///   to `[0, π]`
+    /// * `rng` - The random number generator to sample with
+    ///
+    /// ## Returns
+    ///
+    /// A random unit vector at most `half_angle` away from `axis`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Vector, Vector3};
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    ///
+    /// let mut rng = StdRng::seed_from_u64(0);
+    /// let axis = Vector3::new(0., 0., 1.);
+    /// let v = Vector3::random_in_cone(&axis, 0.1, &mut rng);
+    ///
+    /// assert!(v.dot(&axis) >= 0.1_f64.cos() - 1e-12);
+    /// ```
+    pub fn random_in_cone<R: Rng + ?Sized>(
+        axis: &Vector3,
+        half_angle: f64,
+        rng: &mut R,
+    ) -> Vector3 {
+        // A uniform cosine gives a uniform solid angle, just like the height in `random_unit`
+        let min_cos = half_angle.clamp(0., PI).cos();
+        let cos_theta = 1. - rng.gen::<f64>() * (1. - min_cos);
+        let sin_theta = (1. - cos_theta * cos_theta).max(0.).sqrt();
+        let (sin, cos) = (rng.gen::<f64>() * TAU).sin_cos();
+        to_basis(
+            &axis.normalize(),
+            Vector3::new(sin_theta * cos, sin_theta * sin, cos_theta),
+        )
+    }
+
+    /// Samples a random direction in a hemisphere, weighted by the cosine of its angle to the
+    /// normal
+    ///
+    /// Directions close to the normal are more likely than grazing ones, matching the light
+    /// reflected by a perfectly diffuse surface.
+    ///
+    /// ## Arguments
+    ///
+    /// * `normal` - The direction the hemisphere faces. This does not need to be normalized
+    /// * `rng` - The random number generator to sample with
+    ///
+    /// ## Returns
+    ///
+    /// A random unit vector on the same side as `normal`
+    pub fn random_cosine_hemisphere<R: Rng + ?Sized>(normal: &Vector3, rng: &mut R) -> Vector3 {
+        // Malley's method: project uniform points of the disk up onto the hemisphere
+        let disk = Vector2::random_in_unit_disk(rng);
+        let z = (1. - disk.dot(&disk)).max(0.).sqrt();
+        to_basis(&normal.normalize(), Vector3::new(disk.x, disk.y, z))
+    }
+}
+
+impl DynamicVector {
+    /// Creates a vector of normally distributed values
+    ///
+    /// Every element is sampled independently from the standard normal distribution, with a
+    /// mean of 0 and a standard deviation of 1.
+    ///
+    /// ## Arguments
+    ///
+    /// * `length` - The length of the vector
+    /// * `rng` - The random number generator to sample with
+    ///
+    /// ## Returns
+    ///
+    /// A new dynamic vector of the specified length
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::DynamicVector;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    ///
+    /// let mut rng = StdRng::seed_from_u64(0);
+    /// let weights = DynamicVector::randn(128, &mut rng);
+    /// ```
+    pub fn randn<R: Rng + ?Sized>(length: usize, rng: &mut R) -> DynamicVector {
+        let mut data = Vec::with_capacity(length);
+        while data.len() < length {
+            // The Box-Muller transform turns two uniform samples into two normal samples
+            let u1 = 1. - rng.gen::<f64>();
+            let u2 = rng.gen::<f64>();
+            let radius = (-2. * u1.ln()).sqrt();
+            let (sin, cos) = (u2 * TAU).sin_cos();
+            data.push(radius * cos);
+            if data.len() < length {
+                data.push(radius * sin);
+            }
+        }
+        DynamicVector { data: data.into() }
+    }
+
+    /// Creates a vector of uniformly distributed values
+    ///
+    /// ## Arguments
+    ///
+    /// * `length` - The length of the vector
+    /// * `low` - The smallest value to generate
+    /// * `high` - The upper bound of the values, which is never generated
+    /// * `rng` - The random number generator to sample with
+    ///
+    /// ## Returns
+    ///
+    /// A new dynamic vector of the specified length
+    ///
+    /// ## Panics
+    ///
+    /// If `low` is not less than `high`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::DynamicVector;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    ///
+    /// let mut rng = StdRng::seed_from_u64(0);
+    /// let v = DynamicVector::random_uniform(4, -1., 1., &mut rng);
+    ///
+    /// assert!((-1. ..1.).contains(&v.get(3)));
+    /// ```
+    pub fn random_uniform<R: Rng + ?Sized>(
+        length: usize,
+        low: f64,
+        high: f64,
+        rng: &mut R,
+    ) -> DynamicVector {
+        let range = Uniform::new(low, high);
+        DynamicVector {
+            data: (0..length).map(|_| range.sample(rng)).collect(),
+        }
+    }
+}
+
+/// Rotates `v` from a frame where the z axis is `n` into world space
+///
+/// Uses the branchless orthonormal basis of Duff et al., which is stable for every unit `n`.
+fn to_basis(n: &Vector3, v: Vector3) -> Vector3 {
+    let sign = 1_f64.copysign(n.z);
+    let a = -1. / (sign + n.z);
+    let b = n.x * n.y * a;
+    let tangent = Vector3::new(1. + sign * n.x * n.x * a, sign * b, -sign * n.x);
+    let bitangent = Vector3::new(b, sign + n.y * n.y * a, -n.y);
+    tangent * v.x + bitangent * v.y + *n * v.z
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Vector;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_standard() {
+        let mut rng = StdRng::seed_from_u64(1);
+        for _ in 0..100 {
+            let v: Vector3 = rng.gen();
+            assert!((0. ..1.).contains(&v.x));
+            assert!((0. ..1.).contains(&v.y));
+            assert!((0. ..1.).contains(&v.z));
+        }
+    }
+
+    #[test]
+    fn test_uniform() {
+        let mut rng = StdRng::seed_from_u64(2);
+        let low = Vector2::new(-1., 10.);
+        let high = Vector2::new(1., 20.);
+        let dist = Uniform::new(low, high);
+        for _ in 0..100 {
+            let v = dist.sample(&mut rng);
+            assert!((-1. ..1.).contains(&v.x));
+            assert!((10. ..20.).contains(&v.y));
+        }
+
+        let inclusive =
+            Uniform::new_inclusive(Vector4::new(0., 0., 0., 0.), Vector4::new(0., 1., 2., 3.));
+        let v = inclusive.sample(&mut rng);
+        assert_eq!(v.a, 0.);
+        assert!(v.d <= 3.);
+    }
+
+    #[test]
+    fn test_random_unit() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut sum2 = Vector2::new(0., 0.);
+        let mut sum3 = Vector3::new(0., 0., 0.);
+        for _ in 0..10000 {
+            let v2 = Vector2::random_unit(&mut rng);
+            let v3 = Vector3::random_unit(&mut rng);
+            assert!((v2.magnitude() - 1.).abs() < 1e-12);
+            assert!((v3.magnitude() - 1.).abs() < 1e-12);
+            sum2 = sum2 + v2;
+            sum3 = sum3 + v3;
+        }
+        // An unbiased distribution averages out to the origin
+        assert!(sum2.magnitude() / 10000. < 0.05);
+        assert!(sum3.magnitude() / 10000. < 0.05);
+    }
+
+    #[test]
+    fn test_random_in_unit_ball() {
+        let mut rng = StdRng::seed_from_u64(4);
+        let mut inner2 = 0;
+        let mut inner3 = 0;
+        for _ in 0..10000 {
+            let v2 = Vector2::random_in_unit_disk(&mut rng);
+            let v3 = Vector3::random_in_unit_sphere(&mut rng);
+            assert!(v2.magnitude() <= 1.);
+            assert!(v3.magnitude() <= 1.);
+            if v2.magnitude() < 0.5 {
+                inner2 += 1;
+            }
+            if v3.magnitude() < 0.5 {
+                inner3 += 1;
+            }
+        }
+        // A uniform distribution puts 1/4 of the disk and 1/8 of the ball within half the radius
+        assert!((inner2 as f64 / 10000. - 0.25).abs() < 0.02);
+        assert!((inner3 as f64 / 10000. - 0.125).abs() < 0.02);
+    }
+
+    #[test]
+    fn test_random_in_cone() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let axis = Vector3::new(1., -2., 0.5);
+        let unit_axis = axis.normalize();
+        for half_angle in [0., 0.3, 2.] {
+            for _ in 0..1000 {
+                let v = Vector3::random_in_cone(&axis, half_angle, &mut rng);
+                assert!((v.magnitude() - 1.).abs() < 1e-12);
+                assert!(v.dot(&unit_axis) >= half_angle.cos() - 1e-12);
+            }
+        }
+
+        // The full sphere is covered evenly when the cone is opened all the way
+        let mut sum = Vector3::new(0., 0., 0.);
+        for _ in 0..10000 {
+            sum = sum + Vector3::random_in_cone(&axis, PI, &mut rng);
+        }
+        assert!(sum.magnitude() / 10000. < 0.05);
+    }
+
+    #[test]
+    fn test_random_cosine_hemisphere() {
+        let mut rng = StdRng::seed_from_u64(6);
+        for normal in [Vector3::new(0., 0., -1.), Vector3::new(0., 3., 4.)] {
+            let unit_normal = normal.normalize();
+            let mut mean_cos = 0.;
+            for _ in 0..10000 {
+                let v = Vector3::random_cosine_hemisphere(&normal, &mut rng);
+                assert!((v.magnitude() - 1.).abs() < 1e-12);
+                let cos = v.dot(&unit_normal);
+                assert!(cos >= -1e-12);
+                mean_cos += cos / 10000.;
+            }
+            // E[cos θ] is 2/3 for a cosine-weighted hemisphere, compared to 1/2 for a uniform one
+            assert!((mean_cos - 2. / 3.).abs() < 0.02);
+        }
+    }
+
+    #[test]
+    fn test_randn() {
+        let mut rng = StdRng::seed_from_u64(7);
+        assert_eq!(DynamicVector::randn(7, &mut rng).data.len(), 7);
+
+        let v = DynamicVector::randn(10000, &mut rng);
+        let mean = v.data.iter().sum::<f64>() / 10000.;
+        let variance = v.data.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / 10000.;
+        assert!(mean.abs() < 0.05);
+        assert!((variance - 1.).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_random_uniform() {
+        let mut rng = StdRng::seed_from_u64(8);
+        let v = DynamicVector::random_uniform(1000, 2., 3., &mut rng);
+        assert_eq!(v.data.len(), 1000);
+        assert!(v.data.iter().all(|x| (2. ..3.).contains(x)));
+    }
+}
@@ -0,0 +1,327 @@
+use crate::{Triangle3, Vector, Vector3, AABB3};
+
+/// A ray in 3D space starting at an origin and extending infinitely along a direction
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ray3 {
+    pub origin: Vector3,
+    pub direction: Vector3,
+}
+
+/// The result of a successful ray intersection query
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hit {
+    /// Distance along the ray to the hit point, measured in multiples of the ray's direction
+    pub distance: f64,
+    /// Barycentric coordinates of the hit point relative to the corners of the triangle
+    pub barycentric: Vector3,
+    /// Whether the ray hit the side of the triangle its normal points out of
+    pub front_facing: bool,
+}
+
+impl Ray3 {
+    /// Creates a new 3D ray
+    ///
+    /// The direction is not normalized, so distances reported by intersection queries are in
+    /// multiples of its length.
+    ///
+    /// ## Arguments
+    ///
+    /// * `origin` - The starting point of the ray
+    /// * `direction` - The direction the ray travels in
+    ///
+    /// ## Returns
+    ///
+    /// A new 3D ray
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Ray3, Vector3};
+    ///
+    /// let r = Ray3::new(Vector3::new(0., 0., 0.), Vector3::new(0., 0., 1.));
+    /// ```
+    pub fn new(origin: Vector3, direction: Vector3) -> Ray3 {
+        Ray3 { origin, direction }
+    }
+
+    /// Calculates the point at a given distance along the ray
+    ///
+    /// ## Arguments
+    ///
+    /// * `t` - The distance along the ray, in multiples of the ray's direction
+    ///
+    /// ## Returns
+    ///
+    /// The point `origin + direction * t`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Ray3, Vector3};
+    ///
+    /// let r = Ray3::new(Vector3::new(1., 0., 0.), Vector3::new(0., 0., 2.));
+    ///
+    /// assert_eq!(r.at(1.5), Vector3::new(1., 0., 3.));
+    /// ```
+    pub fn at(&self, t: f64) -> Vector3 {
+        self.origin + self.direction * t
+    }
+
+    /// Finds the point on the ray closest to a given point, see [`Vector3::closest_point_on_ray`]
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Ray3, Vector3};
+    ///
+    /// let r = Ray3::new(Vector3::new(0., 0., 0.), Vector3::new(0., 0., 1.));
+    ///
+    /// assert_eq!(r.closest_point(&Vector3::new(1., 0., 2.)), Vector3::new(0., 0., 2.));
+    /// assert_eq!(r.closest_point(&Vector3::new(1., 0., -2.)), Vector3::new(0., 0., 0.));
+    /// ```
+    pub fn closest_point(&self, point: &Vector3) -> Vector3 {
+        point.closest_point_on_ray(&self.origin, &self.direction)
+    }
+
+    /// Calculates where the ray hits a triangle using the Möller–Trumbore algorithm
+    ///
+    /// Rays that run exactly parallel to the triangle's plane or that only hit it behind their
+    /// origin are not considered hits. No fixed epsilon is involved, so triangles and distances
+    /// of any scale work. Both sides of the triangle can be hit, use [`Hit::front_facing`] to
+    /// cull back faces.
+    ///
+    /// ## Arguments
+    ///
+    /// * `triangle` - The triangle to test against
+    ///
+    /// ## Returns
+    ///
+    /// The hit information, or `None` if the ray misses the triangle
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Ray3, Triangle3, Vector3};
+    ///
+    /// let t = Triangle3::new(
+    ///     Vector3::new(0., 0., 0.),
+    ///     Vector3::new(1., 0., 0.),
+    ///     Vector3::new(0., 1., 0.),
+    /// );
+    /// let r = Ray3::new(Vector3::new(0.25, 0.25, 1.), Vector3::new(0., 0., -1.));
+    ///
+    /// let hit = r.intersect_triangle(&t).unwrap();
+    ///
+    /// assert_eq!(hit.distance, 1.);
+    /// assert_eq!(hit.barycentric, Vector3::new(0.5, 0.25, 0.25));
+    /// assert!(hit.front_facing);
+    /// ```
+    pub fn intersect_triangle(&self, triangle: &Triangle3) -> Option<Hit> {
+        let edge1 = triangle.b - triangle.a;
+        let edge2 = triangle.c - triangle.a;
+
+        let p = self.direction.cross(&edge2);
+        let det = edge1.dot(&p);
+        if det == 0. {
+            return None;
+        }
+        let inv_det = 1. / det;
+
+        let s = self.origin - triangle.a;
+        let u = s.dot(&p) * inv_det;
+        if !(0. ..=1.).contains(&u) {
+            return None;
+        }
+
+        let q = s.cross(&edge1);
+        let v = self.direction.dot(&q) * inv_det;
+        if v < 0. || u + v > 1. {
+            return None;
+        }
+
+        let distance = edge2.dot(&q) * inv_det;
+        if distance < 0. {
+            return None;
+        }
+
+        Some(Hit {
+            distance,
+            barycentric: Vector3::new(1. - u - v, u, v),
+            front_facing: det > 0.,
+        })
+    }
+
+    /// Calculates where the ray enters and exits an axis-aligned bounding box
+    ///
+    /// Uses the slab method. Direction components that are zero are handled explicitly, so rays
+    /// running parallel to a face only hit the box if their origin lies between its planes.
+    /// Any `NaN` in the ray or the box results in a miss.
+    ///
+    /// ## Arguments
+    ///
+    /// * `aabb` - The bounding box to test against
+    ///
+    /// ## Returns
+    ///
+    /// The distances along the ray at which it enters and exits the box, or `None` if the ray
+    /// misses it. If the origin is inside the box the entry distance is `0`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Ray3, Vector3, AABB3};
+    ///
+    /// let b = AABB3::new(Vector3::new(-1., -1., -1.), Vector3::new(1., 1., 1.));
+    /// let r = Ray3::new(Vector3::new(-3., 0., 0.), Vector3::new(1., 0., 0.));
+    ///
+    /// assert_eq!(r.intersect_aabb(&b), Some((2., 4.)));
+    /// ```
+    pub fn intersect_aabb(&self, aabb: &AABB3) -> Option<(f64, f64)> {
+        let axes = [
+            (self.origin.x, self.direction.x, aabb.min.x, aabb.max.x),
+            (self.origin.y, self.direction.y, aabb.min.y, aabb.max.y),
+            (self.origin.z, self.direction.z, aabb.min.z, aabb.max.z),
+        ];
+
+        let mut t_min: f64 = 0.;
+        let mut t_max = f64::INFINITY;
+
+        for (origin, direction, min, max) in axes {
+            if origin.is_nan() || direction.is_nan() || min.is_nan() || max.is_nan() {
+                return None;
+            }
+
+            if direction == 0. {
+                if origin < min || origin > max {
+                    return None;
+                }
+                continue;
+            }
+
+            let inv_direction = 1. / direction;
+            let mut t0 = (min - origin) * inv_direction;
+            let mut t1 = (max - origin) * inv_direction;
+            if t0 > t1 {
+                core::mem::swap(&mut t0, &mut t1);
+            }
+
+            t_min = t_min.max(t0);
+            t_max = t_max.min(t1);
+            if t_min > t_max {
+                return None;
+            }
+        }
+
+        Some((t_min, t_max))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn triangle() -> Triangle3 {
+        Triangle3::new(
+            Vector3::new(0., 0., 0.),
+            Vector3::new(2., 0., 0.),
+            Vector3::new(0., 2., 0.),
+        )
+    }
+
+    #[test]
+    fn test_at() {
+        let r = Ray3::new(Vector3::new(1., 2., 3.), Vector3::new(1., 0., 0.));
+        assert_eq!(r.at(0.), r.origin);
+        assert_eq!(r.at(2.), Vector3::new(3., 2., 3.));
+    }
+
+    #[test]
+    fn test_intersect_triangle_front() {
+        let r = Ray3::new(Vector3::new(0.5, 0.5, 2.), Vector3::new(0., 0., -1.));
+        let hit = r.intersect_triangle(&triangle()).unwrap();
+        assert_eq!(hit.distance, 2.);
+        assert_eq!(hit.barycentric, Vector3::new(0.5, 0.25, 0.25));
+        assert!(hit.front_facing);
+        assert_eq!(r.at(hit.distance), Vector3::new(0.5, 0.5, 0.));
+    }
+
+    #[test]
+    fn test_intersect_triangle_back() {
+        let r = Ray3::new(Vector3::new(0.5, 0.5, -2.), Vector3::new(0., 0., 1.));
+        let hit = r.intersect_triangle(&triangle()).unwrap();
+        assert_eq!(hit.distance, 2.);
+        assert!(!hit.front_facing);
+    }
+
+    #[test]
+    fn test_intersect_triangle_scale() {
+        // A triangle far smaller than f64::EPSILON, hit from a distance of the same size
+        let tiny = Triangle3::new(
+            Vector3::new(0., 0., 0.),
+            Vector3::new(1e-20, 0., 0.),
+            Vector3::new(0., 1e-20, 0.),
+        );
+        let r = Ray3::new(
+            Vector3::new(2.5e-21, 2.5e-21, 1e-20),
+            Vector3::new(0., 0., -1.),
+        );
+        let hit = r.intersect_triangle(&tiny).unwrap();
+        assert!((hit.distance - 1e-20).abs() < 1e-32);
+        assert!(hit.front_facing);
+    }
+
+    #[test]
+    fn test_intersect_triangle_miss() {
+        let outside = Ray3::new(Vector3::new(2., 2., 1.), Vector3::new(0., 0., -1.));
+        assert_eq!(outside.intersect_triangle(&triangle()), None);
+
+        let behind = Ray3::new(Vector3::new(0.5, 0.5, 1.), Vector3::new(0., 0., 1.));
+        assert_eq!(behind.intersect_triangle(&triangle()), None);
+
+        let parallel = Ray3::new(Vector3::new(0.5, 0.5, 1.), Vector3::new(1., 0., 0.));
+        assert_eq!(parallel.intersect_triangle(&triangle()), None);
+    }
+
+    #[test]
+    fn test_intersect_aabb() {
+        let b = AABB3::new(Vector3::new(0., 0., 0.), Vector3::new(2., 2., 2.));
+
+        let r = Ray3::new(Vector3::new(-1., 1., 1.), Vector3::new(2., 0., 0.));
+        assert_eq!(r.intersect_aabb(&b), Some((0.5, 1.5)));
+
+        let inside = Ray3::new(Vector3::new(1., 1., 1.), Vector3::new(0., 0., -1.));
+        assert_eq!(inside.intersect_aabb(&b), Some((0., 1.)));
+
+        let diagonal = Ray3::new(Vector3::new(-1., -1., -1.), Vector3::new(1., 1., 1.));
+        assert_eq!(diagonal.intersect_aabb(&b), Some((1., 3.)));
+    }
+
+    #[test]
+    fn test_intersect_aabb_miss() {
+        let b = AABB3::new(Vector3::new(0., 0., 0.), Vector3::new(2., 2., 2.));
+
+        let away = Ray3::new(Vector3::new(-1., 1., 1.), Vector3::new(-1., 0., 0.));
+        assert_eq!(away.intersect_aabb(&b), None);
+
+        let beside = Ray3::new(Vector3::new(-1., 3., 1.), Vector3::new(1., 0., 0.));
+        assert_eq!(beside.intersect_aabb(&b), None);
+    }
+
+    #[test]
+    fn test_intersect_aabb_zero_direction() {
+        let b = AABB3::new(Vector3::new(0., 0., 0.), Vector3::new(2., 2., 2.));
+
+        let on_face = Ray3::new(Vector3::new(-1., 0., 2.), Vector3::new(1., 0., 0.));
+        assert_eq!(on_face.intersect_aabb(&b), Some((1., 3.)));
+
+        let outside = Ray3::new(Vector3::new(-1., -0.5, 1.), Vector3::new(1., 0., 0.));
+        assert_eq!(outside.intersect_aabb(&b), None);
+    }
+
+    #[test]
+    fn test_intersect_aabb_nan() {
+        let b = AABB3::new(Vector3::new(0., 0., 0.), Vector3::new(2., 2., 2.));
+        let r = Ray3::new(Vector3::new(-1., 1., 1.), Vector3::new(1., f64::NAN, 0.));
+        assert_eq!(r.intersect_aabb(&b), None);
+    }
+}
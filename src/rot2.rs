@@ -0,0 +1,198 @@
+#[cfg(not(feature = "std"))]
+use crate::math::FloatMath;
+use crate::{Radians, Vector, Vector2};
+use core::ops::Mul;
+
+/// A rotation in 2D space, stored as the cosine and sine of its angle
+///
+/// This is the 2D counterpart of [`Quaternion`](crate::Quaternion). Storing the cosine and sine
+/// instead of the angle means rotating vectors and composing rotations need no trigonometry, and
+/// there is no wrapping around at a full turn to worry about. Only rotations with
+/// `cos² + sin² = 1` are valid, use [`Rot2::normalize`] to correct any drift after composing
+/// many rotations.
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{Degrees, Rot2, Vector2};
+///
+/// let quarter = Rot2::from_angle(Degrees(90.));
+/// let v = quarter.rotate(&Vector2::new(2., 0.));
+///
+/// assert!((v.x - 0.).abs() < 1e-12 && (v.y - 2.).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rot2 {
+    pub cos: f64,
+    pub sin: f64,
+}
+
+impl Rot2 {
+    /// Creates the rotation that does nothing
+    ///
+    /// ## Returns
+    ///
+    /// The identity rotation
+    pub fn identity() -> Rot2 {
+        Rot2 { cos: 1., sin: 0. }
+    }
+
+    /// Creates a counter-clockwise rotation by an angle
+    ///
+    /// ## Arguments
+    ///
+    /// * `angle` - The angle to rotate by. A plain `f64` is taken to be in radians
+    ///
+    /// ## Returns
+    ///
+    /// The rotation
+    pub fn from_angle(angle: impl Into<Radians>) -> Rot2 {
+        let (sin, cos) = angle.into().sin_cos();
+        Rot2 { cos, sin }
+    }
+
+    /// Creates the rotation that turns one direction into another
+    ///
+    /// ## Arguments
+    ///
+    /// * `from` - The starting direction, which does not need to be normalized
+    /// * `to` - The target direction, which does not need to be normalized
+    ///
+    /// ## Returns
+    ///
+    /// The rotation, or `None` if either direction is zero
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Rot2, Vector2};
+    ///
+    /// let r = Rot2::between(&Vector2::new(1., 0.), &Vector2::new(0., 3.)).unwrap();
+    ///
+    /// assert_eq!(r, Rot2 { cos: 0., sin: 1. });
+    /// ```
+    pub fn between(from: &Vector2, to: &Vector2) -> Option<Rot2> {
+        let lengths = from.magnitude() * to.magnitude();
+        if lengths == 0. {
+            return None;
+        }
+        Some(Rot2 {
+            cos: from.dot(to) / lengths,
+            sin: from.cross(to) / lengths,
+        })
+    }
+
+    /// Gets the angle of the rotation
+    ///
+    /// ## Returns
+    ///
+    /// The counter-clockwise angle, in the range `[-PI, PI]`
+    pub fn to_angle(&self) -> Radians {
+        Radians(self.sin.atan2(self.cos))
+    }
+
+    /// Scales the rotation back to unit length
+    ///
+    /// ## Returns
+    ///
+    /// The normalized rotation
+    pub fn normalize(&self) -> Rot2 {
+        let magnitude = (self.cos * self.cos + self.sin * self.sin).sqrt();
+        Rot2 {
+            cos: self.cos / magnitude,
+            sin: self.sin / magnitude,
+        }
+    }
+
+    /// Calculates the inverse of the rotation
+    ///
+    /// ## Returns
+    ///
+    /// The rotation by the opposite angle, such that `r * r.inverse()` is the identity
+    pub fn inverse(&self) -> Rot2 {
+        Rot2 {
+            cos: self.cos,
+            sin: -self.sin,
+        }
+    }
+
+    /// Rotates a vector
+    ///
+    /// ## Arguments
+    ///
+    /// * `v` - The vector to rotate
+    ///
+    /// ## Returns
+    ///
+    /// The rotated vector
+    pub fn rotate(&self, v: &Vector2) -> Vector2 {
+        Vector2::new(
+            self.cos * v.x - self.sin * v.y,
+            self.sin * v.x + self.cos * v.y,
+        )
+    }
+}
+
+impl Default for Rot2 {
+    fn default() -> Self {
+        Rot2::identity()
+    }
+}
+
+impl Mul for Rot2 {
+    type Output = Rot2;
+
+    /// Composes two rotations, applying `other` first and then `self`
+    fn mul(self, other: Rot2) -> Rot2 {
+        Rot2 {
+            cos: self.cos * other.cos - self.sin * other.sin,
+            sin: self.sin * other.cos + self.cos * other.sin,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Degrees;
+    use core::f64::consts::{FRAC_PI_2, PI};
+
+    #[test]
+    fn test_angles() {
+        assert_eq!(Rot2::from_angle(0.), Rot2::identity());
+        assert_eq!(Rot2::default(), Rot2::identity());
+        assert!((Rot2::from_angle(2.).to_angle().0 - 2.).abs() < 1e-12);
+        // Angles are reported in [-PI, PI]
+        let turned = Rot2::from_angle(Degrees(270.)).to_angle();
+        assert!((turned.0 + FRAC_PI_2).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_composition() {
+        let a = Rot2::from_angle(0.5);
+        let b = Rot2::from_angle(1.25);
+        assert!(((a * b).to_angle().0 - 1.75).abs() < 1e-12);
+        let identity = a * a.inverse();
+        assert!((identity.cos - 1.).abs() < 1e-12 && identity.sin.abs() < 1e-12);
+
+        let half = Rot2::from_angle(PI / 2.);
+        let v = (half * half).rotate(&Vector2::new(1., 2.));
+        assert!((v - Vector2::new(-1., -2.)).magnitude() < 1e-12);
+    }
+
+    #[test]
+    fn test_between() {
+        let from = Vector2::new(2., 1.);
+        let to = Vector2::new(-3., 4.);
+        let r = Rot2::between(&from, &to).unwrap();
+        let rotated = r.rotate(&from);
+        assert!((rotated.normalize() - to.normalize()).magnitude() < 1e-12);
+        assert_eq!(Rot2::between(&from, &Vector2::new(0., 0.)), None);
+    }
+
+    #[test]
+    fn test_normalize() {
+        let r = Rot2 { cos: 3., sin: 4. }.normalize();
+        assert_eq!(r, Rot2 { cos: 0.6, sin: 0.8 });
+    }
+}
@@ -0,0 +1,234 @@
+use crate::{Vector2, Vector3, Vector4, VectorSpace};
+
+/// Vectors that can be multiplied component by component
+pub trait ComponentMul {
+    /// Multiplies each component by the matching component of another vector
+    fn component_mul(&self, other: &Self) -> Self;
+}
+
+impl ComponentMul for Vector2 {
+    fn component_mul(&self, other: &Vector2) -> Vector2 {
+        Vector2::new(self.x * other.x, self.y * other.y)
+    }
+}
+
+impl ComponentMul for Vector3 {
+    fn component_mul(&self, other: &Vector3) -> Vector3 {
+        Vector3::new(self.x * other.x, self.y * other.y, self.z * other.z)
+    }
+}
+
+impl ComponentMul for Vector4 {
+    fn component_mul(&self, other: &Vector4) -> Vector4 {
+        Vector4::new(
+            self.a * other.a,
+            self.b * other.b,
+            self.c * other.c,
+            self.d * other.d,
+        )
+    }
+}
+
+/// An accumulator for the mean and variance of a stream of vectors
+///
+/// Samples are folded in one at a time with Welford's algorithm, so memory use is constant and
+/// the result stays accurate even when the variance is tiny compared to the mean. Two
+/// accumulators can be combined with `merge`, for example after processing chunks in parallel.
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{RunningStats, Vector2};
+///
+/// let mut stats = RunningStats::new();
+/// stats.push(Vector2::new(1., 10.));
+/// stats.push(Vector2::new(3., 10.));
+///
+/// assert_eq!(stats.count(), 2);
+/// assert_eq!(stats.mean(), Some(Vector2::new(2., 10.)));
+/// assert_eq!(stats.variance(), Some(Vector2::new(1., 0.)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RunningStats<V> {
+    count: u64,
+    /// The running mean and the sum of squared differences from it, once a sample has arrived
+    moments: Option<(V, V)>,
+}
+
+impl<V> Default for RunningStats<V> {
+    fn default() -> Self {
+        RunningStats {
+            count: 0,
+            moments: None,
+        }
+    }
+}
+
+impl<V> RunningStats<V>
+where
+    V: VectorSpace + ComponentMul,
+{
+    /// Creates an empty accumulator
+    ///
+    /// ## Returns
+    ///
+    /// An accumulator that has not seen any samples
+    pub fn new() -> RunningStats<V> {
+        RunningStats::default()
+    }
+
+    /// Adds a sample
+    ///
+    /// ## Arguments
+    ///
+    /// * `sample` - The vector to add
+    pub fn push(&mut self, sample: V) {
+        self.count += 1;
+        match &mut self.moments {
+            None => self.moments = Some((sample, V::zero())),
+            Some((mean, m2)) => {
+                let delta = sample - *mean;
+                *mean = *mean + delta * (1. / self.count as f64);
+                *m2 = *m2 + delta.component_mul(&(sample - *mean));
+            }
+        }
+    }
+
+    /// Combines the samples of another accumulator into this one
+    ///
+    /// ## Arguments
+    ///
+    /// * `other` - The accumulator to merge in
+    pub fn merge(&mut self, other: &RunningStats<V>) {
+        let Some((other_mean, other_m2)) = other.moments else {
+            return;
+        };
+        let Some((mean, m2)) = &mut self.moments else {
+            *self = *other;
+            return;
+        };
+        let (n_a, n_b) = (self.count as f64, other.count as f64);
+        let total = n_a + n_b;
+        let delta = other_mean - *mean;
+        *mean = *mean + delta * (n_b / total);
+        *m2 = *m2 + other_m2 + delta.component_mul(&delta) * (n_a * n_b / total);
+        self.count += other.count;
+    }
+
+    /// Gets the number of samples seen
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Gets the mean of the samples
+    ///
+    /// ## Returns
+    ///
+    /// The mean, or `None` if there are no samples
+    pub fn mean(&self) -> Option<V> {
+        self.moments.map(|(mean, _)| mean)
+    }
+
+    /// Gets the population variance of each component, dividing by the number of samples
+    ///
+    /// ## Returns
+    ///
+    /// The variance, or `None` if there are no samples
+    pub fn variance(&self) -> Option<V> {
+        self.moments.map(|(_, m2)| m2 * (1. / self.count as f64))
+    }
+
+    /// Gets the unbiased sample variance of each component, dividing by one less than the number
+    /// of samples
+    ///
+    /// ## Returns
+    ///
+    /// The variance, or `None` if there are fewer than two samples
+    pub fn sample_variance(&self) -> Option<V> {
+        if self.count < 2 {
+            return None;
+        }
+        self.moments
+            .map(|(_, m2)| m2 * (1. / (self.count - 1) as f64))
+    }
+}
+
+impl<V> Extend<V> for RunningStats<V>
+where
+    V: VectorSpace + ComponentMul,
+{
+    fn extend<I: IntoIterator<Item = V>>(&mut self, samples: I) {
+        for sample in samples {
+            self.push(sample);
+        }
+    }
+}
+
+impl<V> FromIterator<V> for RunningStats<V>
+where
+    V: VectorSpace + ComponentMul,
+{
+    fn from_iter<I: IntoIterator<Item = V>>(samples: I) -> Self {
+        let mut stats = RunningStats::new();
+        stats.extend(samples);
+        stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Vector;
+
+    #[test]
+    fn test_empty() {
+        let stats = RunningStats::<Vector3>::new();
+        assert_eq!(stats.count(), 0);
+        assert_eq!(stats.mean(), None);
+        assert_eq!(stats.variance(), None);
+        let one: RunningStats<Vector3> = [Vector3::new(1., 2., 3.)].into_iter().collect();
+        assert_eq!(one.variance(), Some(Vector3::new(0., 0., 0.)));
+        assert_eq!(one.sample_variance(), None);
+    }
+
+    #[test]
+    fn test_variance() {
+        let samples = [2., 4., 4., 4., 5., 5., 7., 9.].map(|x| Vector2::new(x, -x));
+        let stats: RunningStats<Vector2> = samples.into_iter().collect();
+        assert_eq!(stats.mean(), Some(Vector2::new(5., -5.)));
+        assert_eq!(stats.variance(), Some(Vector2::new(4., 4.)));
+        assert_eq!(
+            stats.sample_variance(),
+            Some(Vector2::new(32. / 7., 32. / 7.))
+        );
+    }
+
+    #[test]
+    fn test_large_offset() {
+        // A naive sum of squares loses every digit of the variance here
+        let offset = 1e9;
+        let stats: RunningStats<Vector4> = [4., 7., 13., 16.]
+            .map(|x| Vector4::new(offset + x, 0., 0., 0.))
+            .into_iter()
+            .collect();
+        assert_eq!(stats.variance().unwrap().a, 22.5);
+    }
+
+    #[test]
+    fn test_merge() {
+        let samples = [1., 2., 3., 4., 10.].map(|x| Vector2::new(x, x * x));
+        let all: RunningStats<Vector2> = samples.into_iter().collect();
+        let mut left: RunningStats<Vector2> = samples[..2].iter().copied().collect();
+        let right: RunningStats<Vector2> = samples[2..].iter().copied().collect();
+        left.merge(&right);
+        assert_eq!(left.count(), 5);
+        let (a, b) = (left.variance().unwrap(), all.variance().unwrap());
+        assert!((a - b).magnitude() < 1e-9);
+
+        let mut empty = RunningStats::new();
+        empty.merge(&all);
+        assert_eq!(empty, all);
+        empty.merge(&RunningStats::new());
+        assert_eq!(empty, all);
+    }
+}
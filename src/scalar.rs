@@ -0,0 +1,141 @@
+use core::fmt::{Debug, Display};
+use core::iter::Sum;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// A primitive number type that can be stored in a [`DynamicVector`](crate::DynamicVector)
+///
+/// This is implemented for `f32`, `f64` and the primitive integer types, so a dynamic vector can
+/// hold `f32` values at half the memory of `f64`, or integer counts. It is sealed, as the aligned
+/// storage of a dynamic vector relies on every value being plain data whose all-zero bytes mean
+/// zero, no larger than [`DYNAMIC_VECTOR_ALIGNMENT`](crate::DYNAMIC_VECTOR_ALIGNMENT).
+pub trait Scalar:
+    Copy
+    + Default
+    + PartialOrd
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Sum
+    + private::Sealed
+{
+}
+
+macro_rules! impl_scalar {
+    ($($t:ty),*) => {
+        $(
+            impl private::Sealed for $t {}
+            impl Scalar for $t {}
+        )*
+    };
+}
+
+impl_scalar!(f32, f64, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// A floating point type that can be used as the components of a vector
+///
+/// This is implemented for `f32` and `f64`, and is the bound used by vector types that are
+/// generic over their scalar, such as those generated by `vector!(Name<T: Float> { ... })`.
+pub trait Float:
+    Copy
+    + PartialOrd
+    + Debug
+    + Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    /// The additive identity
+    const ZERO: Self;
+    /// The multiplicative identity
+    const ONE: Self;
+
+    /// Calculates the square root of the value
+    fn sqrt(self) -> Self;
+
+    /// Converts the value to an `f64`
+    fn to_f64(self) -> f64;
+
+    /// Converts an `f64` to this type, rounding to the nearest value if needed
+    fn from_f64(value: f64) -> Self;
+}
+
+impl Float for f32 {
+    const ZERO: f32 = 0.;
+    const ONE: f32 = 1.;
+
+    #[cfg(feature = "std")]
+    fn sqrt(self) -> f32 {
+        f32::sqrt(self)
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn sqrt(self) -> f32 {
+        libm::sqrtf(self)
+    }
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    fn from_f64(value: f64) -> f32 {
+        value as f32
+    }
+}
+
+impl Float for f64 {
+    const ZERO: f64 = 0.;
+    const ONE: f64 = 1.;
+
+    #[cfg(feature = "std")]
+    fn sqrt(self) -> f64 {
+        f64::sqrt(self)
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn sqrt(self) -> f64 {
+        libm::sqrt(self)
+    }
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn from_f64(value: f64) -> f64 {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hypot<T: Float>(a: T, b: T) -> T {
+        Float::sqrt(a * a + b * b)
+    }
+
+    #[test]
+    fn test_float() {
+        assert_eq!(hypot(3_f32, 4.), 5.);
+        assert_eq!(hypot(3_f64, 4.), 5.);
+        assert_eq!(f32::from_f64(0.5).to_f64(), 0.5);
+        assert_eq!(<f64 as Float>::ZERO + <f64 as Float>::ONE, 1.);
+    }
+
+    fn total<T: Scalar>(values: &[T]) -> T {
+        values.iter().copied().sum()
+    }
+
+    #[test]
+    fn test_scalar() {
+        assert_eq!(total(&[1_u32, 2, 3]), 6);
+        assert_eq!(total(&[0.5_f32, 0.25]), 0.75);
+        assert_eq!(i64::default(), 0);
+    }
+}
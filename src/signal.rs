@@ -0,0 +1,247 @@
+use crate::DynamicVector;
+
+/// How values beyond the ends of a signal are filled in when a kernel overlaps them
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EdgeMode {
+    /// Values outside the signal are zero
+    Zero,
+    /// Values outside the signal repeat the nearest end value
+    Clamp,
+    /// The signal repeats, so it is treated as periodic
+    Wrap,
+}
+
+impl EdgeMode {
+    /// Looks up an element of a signal at an index that may be outside of it
+    fn sample(&self, signal: &[f64], index: isize) -> f64 {
+        let length = signal.len() as isize;
+        match self {
+            EdgeMode::Zero if index < 0 || index >= length => 0.,
+            EdgeMode::Zero => signal[index as usize],
+            EdgeMode::Clamp => signal[index.clamp(0, length - 1) as usize],
+            EdgeMode::Wrap => signal[index.rem_euclid(length) as usize],
+        }
+    }
+}
+
+impl DynamicVector {
+    /// Calculates the cross-correlation of the vector with a kernel
+    ///
+    /// Element `i` of the result is the sum of `kernel[j] * self[i + j - (kernel.len() - 1) / 2]`,
+    /// so the kernel is slid along the vector without being flipped, centred on its middle
+    /// element. Correlating with a pattern gives the largest values where the vector looks most
+    /// like the pattern.
+    ///
+    /// ## Arguments
+    ///
+    /// * `kernel` - The weights to slide along the vector
+    /// * `edges` - How to treat elements beyond the ends of the vector
+    ///
+    /// ## Returns
+    ///
+    /// A vector of the same length as this one
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{DynamicVector, EdgeMode};
+    ///
+    /// let v = DynamicVector::from(vec![1., 2., 3., 4.]);
+    ///
+    /// // A central difference
+    /// let slope = v.correlate(&[-0.5, 0., 0.5], EdgeMode::Clamp);
+    ///
+    /// assert_eq!(slope, [0.5, 1., 1., 0.5]);
+    /// ```
+    pub fn correlate(&self, kernel: &[f64], edges: EdgeMode) -> DynamicVector {
+        let center = (kernel.len().saturating_sub(1) / 2) as isize;
+        self.slide(kernel, edges, |i, j| i + j - center)
+    }
+
+    /// Calculates the convolution of the vector with a kernel
+    ///
+    /// Element `i` of the result is the sum of `kernel[j] * self[i + (kernel.len() - 1) / 2 - j]`,
+    /// which matches the "same" mode of NumPy. For kernels of odd length this is
+    /// [`DynamicVector::correlate`] with the kernel reversed, so for a symmetric kernel both give
+    /// the same result. Convolving with a kernel that sums to one, such as
+    /// `[0.25, 0.5, 0.25]`, smooths the vector.
+    ///
+    /// ## Arguments
+    ///
+    /// * `kernel` - The weights to slide along the vector
+    /// * `edges` - How to treat elements beyond the ends of the vector
+    ///
+    /// ## Returns
+    ///
+    /// A vector of the same length as this one
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{DynamicVector, EdgeMode};
+    ///
+    /// let v = DynamicVector::from(vec![0., 0., 4., 0.]);
+    ///
+    /// let smooth = v.convolve(&[0.25, 0.5, 0.25], EdgeMode::Zero);
+    ///
+    /// assert_eq!(smooth, [0., 1., 2., 1.]);
+    /// ```
+    pub fn convolve(&self, kernel: &[f64], edges: EdgeMode) -> DynamicVector {
+        let center = (kernel.len().saturating_sub(1) / 2) as isize;
+        self.slide(kernel, edges, |i, j| i + center - j)
+    }
+
+    /// Calculates the running total of the vector
+    ///
+    /// ## Returns
+    ///
+    /// A vector of the same length whose element `i` is the sum of elements `0..=i`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::DynamicVector;
+    ///
+    /// let v = DynamicVector::from(vec![1., 2., 3., 4.]);
+    ///
+    /// assert_eq!(v.cumsum(), [1., 3., 6., 10.]);
+    /// ```
+    pub fn cumsum(&self) -> DynamicVector {
+        self.as_slice()
+            .iter()
+            .scan(0., |total, value| {
+                *total += value;
+                Some(*total)
+            })
+            .collect()
+    }
+
+    /// Calculates the differences between neighbouring elements
+    ///
+    /// ## Returns
+    ///
+    /// A vector one element shorter whose element `i` is `self[i + 1] - self[i]`, or an empty
+    /// vector if this one has fewer than two elements
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::DynamicVector;
+    ///
+    /// let v = DynamicVector::from(vec![1., 3., 6., 10.]);
+    ///
+    /// assert_eq!(v.diff(), [2., 3., 4.]);
+    /// ```
+    pub fn diff(&self) -> DynamicVector {
+        self.as_slice().windows(2).map(|w| w[1] - w[0]).collect()
+    }
+
+    /// Estimates the derivative of evenly spaced samples
+    ///
+    /// Interior elements use central differences, which are second order accurate, and the two
+    /// end elements use one-sided differences. This is the same as `numpy.gradient`, so for
+    /// example the velocity along one axis can be found from positions sampled every `dx`
+    /// seconds.
+    ///
+    /// ## Arguments
+    ///
+    /// * `dx` - The spacing between samples
+    ///
+    /// ## Returns
+    ///
+    /// A vector of the same length. Vectors with fewer than two elements give zeros, as there is
+    /// nothing to compare against
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::DynamicVector;
+    ///
+    /// // Positions of x = t^2, sampled every half second
+    /// let positions = DynamicVector::from(vec![0., 0.25, 1., 2.25]);
+    ///
+    /// assert_eq!(positions.gradient(0.5), [0.5, 1., 2., 2.5]);
+    /// ```
+    pub fn gradient(&self, dx: f64) -> DynamicVector {
+        let values = self.as_slice();
+        let n = values.len();
+        if n < 2 {
+            return DynamicVector::new(n);
+        }
+        (0..n)
+            .map(|i| {
+                if i == 0 {
+                    (values[1] - values[0]) / dx
+                } else if i == n - 1 {
+                    (values[n - 1] - values[n - 2]) / dx
+                } else {
+                    (values[i + 1] - values[i - 1]) / (2. * dx)
+                }
+            })
+            .collect()
+    }
+
+    /// Sums the kernel weights times the elements picked by `index` for every output element
+    fn slide<F>(&self, kernel: &[f64], edges: EdgeMode, index: F) -> DynamicVector
+    where
+        F: Fn(isize, isize) -> isize,
+    {
+        let signal = self.as_slice();
+        (0..signal.len() as isize)
+            .map(|i| {
+                kernel
+                    .iter()
+                    .zip(0..)
+                    .map(|(k, j)| k * edges.sample(signal, index(i, j)))
+                    .sum()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_edge_modes() {
+        let v = DynamicVector::from(vec![1., 2., 3.]);
+        let sum = [1., 1., 1.];
+        assert_eq!(v.correlate(&sum, EdgeMode::Zero), [3., 6., 5.]);
+        assert_eq!(v.correlate(&sum, EdgeMode::Clamp), [4., 6., 8.]);
+        assert_eq!(v.correlate(&sum, EdgeMode::Wrap), [6., 6., 6.]);
+        // A kernel wider than the signal wraps around it more than once
+        let wide = [1., 1., 1., 1., 1.];
+        assert_eq!(v.correlate(&wide, EdgeMode::Wrap), [11., 10., 9.]);
+    }
+
+    #[test]
+    fn test_convolve() {
+        let v = DynamicVector::from(vec![1., 2., 3., 4.]);
+        let kernel = [1., 0., -1.];
+        assert_eq!(v.convolve(&kernel, EdgeMode::Clamp), [1., 2., 2., 1.]);
+        assert_eq!(v.correlate(&kernel, EdgeMode::Clamp), [-1., -2., -2., -1.]);
+        // An even kernel is centred on the first of its two middle elements
+        assert_eq!(v.correlate(&[0., 1.], EdgeMode::Zero), [2., 3., 4., 0.]);
+        assert_eq!(v.convolve(&[0., 1.], EdgeMode::Zero), [0., 1., 2., 3.]);
+        assert_eq!(v.convolve(&[], EdgeMode::Zero), [0., 0., 0., 0.]);
+        assert_eq!(DynamicVector::new(0).convolve(&kernel, EdgeMode::Wrap), []);
+    }
+
+    #[test]
+    fn test_differences() {
+        let positions = DynamicVector::from(vec![2., 3., 5., 8.]);
+        assert_eq!(positions.diff().cumsum(), [1., 3., 6.]);
+        assert_eq!(positions.gradient(2.), [0.5, 0.75, 1.25, 1.5]);
+        // A straight line has the same slope everywhere, ends included
+        let line: DynamicVector = (0..5).map(|i| 3. * i as f64 + 1.).collect();
+        assert_eq!(line.gradient(1.), [3., 3., 3., 3., 3.]);
+
+        let single = DynamicVector::from(vec![4.]);
+        assert_eq!(single.cumsum(), [4.]);
+        assert_eq!(single.diff(), []);
+        assert_eq!(single.gradient(1.), [0.]);
+        assert_eq!(DynamicVector::new(0).cumsum(), []);
+    }
+}
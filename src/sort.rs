@@ -0,0 +1,136 @@
+use crate::Vector;
+use core::cmp::Ordering;
+
+/// Sorts vectors by one of their components
+///
+/// Components are compared with [`f64::total_cmp`], so `NaN` components sort after every number
+/// (and negative `NaN` before). The sort is stable, so vectors with equal components keep their
+/// order.
+///
+/// ## Arguments
+///
+/// * `points` - The vectors to sort in place
+/// * `axis` - The index of the component to sort by, such as 0 for `x`
+///
+/// ## Panics
+///
+/// If `axis` is not less than the number of components
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{sort_by_component, Vector2};
+///
+/// let mut points = [Vector2::new(3., 1.), Vector2::new(1., 2.), Vector2::new(2., 0.)];
+/// sort_by_component(&mut points, 1);
+///
+/// assert_eq!(points, [Vector2::new(2., 0.), Vector2::new(3., 1.), Vector2::new(1., 2.)]);
+/// ```
+pub fn sort_by_component<V, const N: usize>(points: &mut [V], axis: usize)
+where
+    V: Copy + Into<[f64; N]>,
+{
+    assert!(axis < N, "axis out of bounds");
+    points.sort_by(|&a, &b| a.into()[axis].total_cmp(&b.into()[axis]));
+}
+
+/// Sorts vectors from shortest to longest
+///
+/// Magnitudes are compared with [`f64::total_cmp`] and the sort is stable, like
+/// [`sort_by_component`].
+///
+/// ## Arguments
+///
+/// * `points` - The vectors to sort in place
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{sort_by_magnitude, Vector3};
+///
+/// let mut points = [Vector3::new(0., 3., 0.), Vector3::new(-1., 0., 0.), Vector3::new(0., 0., 2.)];
+/// sort_by_magnitude(&mut points);
+///
+/// assert_eq!(points[0], Vector3::new(-1., 0., 0.));
+/// assert_eq!(points[2], Vector3::new(0., 3., 0.));
+/// ```
+pub fn sort_by_magnitude<V: Vector>(points: &mut [V]) {
+    points.sort_by(|a, b| a.magnitude().total_cmp(&b.magnitude()));
+}
+
+/// Sorts vectors by their first component, then their second, and so on
+///
+/// Components are compared with [`f64::total_cmp`], so this is a total order even with `NaN`
+/// components, and only vectors with bitwise equal components compare as equal.
+///
+/// ## Arguments
+///
+/// * `points` - The vectors to sort in place
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{sort_lexicographic, Vector2};
+///
+/// let mut points = [Vector2::new(1., 5.), Vector2::new(0., 9.), Vector2::new(1., -2.)];
+/// sort_lexicographic(&mut points);
+///
+/// assert_eq!(points, [Vector2::new(0., 9.), Vector2::new(1., -2.), Vector2::new(1., 5.)]);
+/// ```
+pub fn sort_lexicographic<V, const N: usize>(points: &mut [V])
+where
+    V: Copy + Into<[f64; N]>,
+{
+    points.sort_by(|&a, &b| {
+        let (a, b): ([f64; N], [f64; N]) = (a.into(), b.into());
+        a.iter()
+            .zip(&b)
+            .map(|(x, y)| x.total_cmp(y))
+            .find(|order| order.is_ne())
+            .unwrap_or(Ordering::Equal)
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Vector3, Vector4};
+
+    #[test]
+    fn test_sort_by_component() {
+        let mut points = [
+            Vector3::new(0., f64::NAN, 0.),
+            Vector3::new(1., 2., 0.),
+            Vector3::new(2., -1., 0.),
+            Vector3::new(3., 2., 0.),
+        ];
+        sort_by_component(&mut points, 1);
+        let xs: [f64; 4] = points.map(|p| p.x);
+        // Stable for the repeated 2, with NaN last
+        assert_eq!(xs, [2., 1., 3., 0.]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sort_by_component_out_of_bounds() {
+        sort_by_component(&mut [Vector3::new(0., 0., 0.)], 3);
+    }
+
+    #[test]
+    fn test_sort_lexicographic() {
+        let mut points = [
+            Vector4::new(1., 2., 3., 4.),
+            Vector4::new(1., 2., 3., -4.),
+            Vector4::new(-0., 0., 0., 0.),
+            Vector4::new(0., 0., 0., 0.),
+        ];
+        sort_lexicographic(&mut points);
+        // total_cmp orders -0 before 0
+        assert!(points[0].a.is_sign_negative());
+        assert_eq!(points[2], Vector4::new(1., 2., 3., -4.));
+
+        let mut magnitudes = [Vector3::new(2., 0., 0.), Vector3::new(0., 1., 0.)];
+        sort_by_magnitude(&mut magnitudes);
+        assert_eq!(magnitudes[0], Vector3::new(0., 1., 0.));
+    }
+}
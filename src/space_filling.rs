@@ -0,0 +1,338 @@
+use crate::{IVector2, IVector3, Vector2, Vector3, AABB2, AABB3};
+
+/// The number of bits per axis used by [`morton_encode_in_aabb3`], the most that fit in a `u64`
+const MORTON_AABB3_BITS: u32 = 21;
+
+/// Maps a signed coordinate onto an unsigned one that sorts in the same order
+fn to_ordered(value: i32) -> u32 {
+    (value as u32) ^ 0x8000_0000
+}
+
+/// Undoes [`to_ordered`]
+fn from_ordered(value: u32) -> i32 {
+    (value ^ 0x8000_0000) as i32
+}
+
+/// Spreads the bits of a value out so that `stride - 1` zero bits separate each one
+fn spread_bits(value: u32, stride: u32) -> u128 {
+    let mut spread = 0;
+    for bit in 0..32 {
+        spread |= (((value >> bit) & 1) as u128) << (bit * stride);
+    }
+    spread
+}
+
+/// Gathers every `stride`th bit of a value, undoing [`spread_bits`]
+fn compact_bits(code: u128, stride: u32) -> u32 {
+    let mut value = 0;
+    for bit in 0..32 {
+        value |= (((code >> (bit * stride)) & 1) as u32) << bit;
+    }
+    value
+}
+
+/// Interleaves the bits of a 2D integer vector into its Morton (Z-order) code
+///
+/// Sorting points by Morton code keeps points that are close in space mostly close in memory,
+/// which speeds up neighbour queries and is the first step in building linear quadtrees.
+/// Coordinates are offset by `2^31` so that the codes of negative and positive coordinates sort
+/// in the same order as the coordinates themselves.
+///
+/// ## Arguments
+///
+/// * `v` - The vector to encode
+///
+/// ## Returns
+///
+/// The Morton code, with the x bits in the even positions
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{morton_decode2, morton_encode2, IVector2};
+///
+/// let code = morton_encode2(IVector2::new(-3, 7));
+///
+/// assert_eq!(morton_decode2(code), IVector2::new(-3, 7));
+/// assert!(morton_encode2(IVector2::new(0, 0)) < morton_encode2(IVector2::new(1, 1)));
+/// ```
+pub fn morton_encode2(v: IVector2) -> u64 {
+    (spread_bits(to_ordered(v.x), 2) | spread_bits(to_ordered(v.y), 2) << 1) as u64
+}
+
+/// Recovers a 2D integer vector from its Morton code
+///
+/// ## Arguments
+///
+/// * `code` - A code produced by [`morton_encode2`]
+///
+/// ## Returns
+///
+/// The encoded vector
+pub fn morton_decode2(code: u64) -> IVector2 {
+    let code = code as u128;
+    IVector2::new(
+        from_ordered(compact_bits(code, 2)),
+        from_ordered(compact_bits(code >> 1, 2)),
+    )
+}
+
+/// Interleaves the bits of a 3D integer vector into its Morton (Z-order) code
+///
+/// Coordinates are offset by `2^31` like in [`morton_encode2`]. Every bit of the three
+/// coordinates is kept, so the code needs 96 bits.
+///
+/// ## Arguments
+///
+/// * `v` - The vector to encode
+///
+/// ## Returns
+///
+/// The Morton code, with the x bits at positions that are multiples of three
+pub fn morton_encode3(v: IVector3) -> u128 {
+    spread_bits(to_ordered(v.x), 3)
+        | spread_bits(to_ordered(v.y), 3) << 1
+        | spread_bits(to_ordered(v.z), 3) << 2
+}
+
+/// Recovers a 3D integer vector from its Morton code
+///
+/// ## Arguments
+///
+/// * `code` - A code produced by [`morton_encode3`]
+///
+/// ## Returns
+///
+/// The encoded vector
+pub fn morton_decode3(code: u128) -> IVector3 {
+    IVector3::new(
+        from_ordered(compact_bits(code, 3)),
+        from_ordered(compact_bits(code >> 1, 3)),
+        from_ordered(compact_bits(code >> 2, 3)),
+    )
+}
+
+/// Quantizes a coordinate to `bits` bits across a range, clamping values outside it
+fn quantize(value: f64, min: f64, max: f64, bits: u32) -> u32 {
+    let cells = ((1_u64 << bits) - 1) as f64;
+    let t = if max > min {
+        ((value - min) / (max - min)).clamp(0., 1.)
+    } else {
+        0.
+    };
+    (t * cells) as u32
+}
+
+/// Calculates the Morton code of a point quantized to a 32 bit grid across a bounding box
+///
+/// ## Arguments
+///
+/// * `point` - The point to encode. Points outside the box are clamped onto it
+/// * `bounds` - The box covered by the grid, usually the bounds of every point being sorted
+///
+/// ## Returns
+///
+/// The Morton code of the grid cell containing the point
+pub fn morton_encode_in_aabb2(point: &Vector2, bounds: &AABB2) -> u64 {
+    let x = quantize(point.x, bounds.min.x, bounds.max.x, 32);
+    let y = quantize(point.y, bounds.min.y, bounds.max.y, 32);
+    (spread_bits(x, 2) | spread_bits(y, 2) << 1) as u64
+}
+
+/// Calculates the Morton code of a point quantized to a 21 bit grid across a bounding box
+///
+/// This is the usual key for building linear BVHs and octrees, as it fits in a `u64`.
+///
+/// ## Arguments
+///
+/// * `point` - The point to encode. Points outside the box are clamped onto it
+/// * `bounds` - The box covered by the grid, usually the bounds of every point being sorted
+///
+/// ## Returns
+///
+/// The Morton code of the grid cell containing the point
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{morton_encode_in_aabb3, Vector3, AABB3};
+///
+/// let bounds = AABB3::new(Vector3::new(0., 0., 0.), Vector3::new(1., 1., 1.));
+/// let mut points = vec![Vector3::new(0.9, 0.9, 0.9), Vector3::new(0.1, 0.2, 0.1)];
+/// points.sort_by_key(|p| morton_encode_in_aabb3(p, &bounds));
+///
+/// assert_eq!(points[0], Vector3::new(0.1, 0.2, 0.1));
+/// ```
+pub fn morton_encode_in_aabb3(point: &Vector3, bounds: &AABB3) -> u64 {
+    let x = quantize(point.x, bounds.min.x, bounds.max.x, MORTON_AABB3_BITS);
+    let y = quantize(point.y, bounds.min.y, bounds.max.y, MORTON_AABB3_BITS);
+    let z = quantize(point.z, bounds.min.z, bounds.max.z, MORTON_AABB3_BITS);
+    (spread_bits(x, 3) | spread_bits(y, 3) << 1 | spread_bits(z, 3) << 2) as u64
+}
+
+/// Calculates the distance of a cell along a 2D Hilbert curve
+///
+/// Unlike the Morton curve, consecutive cells on a Hilbert curve are always neighbours, which
+/// gives better locality at a slightly higher cost.
+///
+/// ## Arguments
+///
+/// * `v` - The cell, with both components in `0..2^order`
+/// * `order` - The number of bits per axis of the grid, at most 31
+///
+/// ## Returns
+///
+/// The distance along the curve, in `0..4^order`
+///
+/// ## Panics
+///
+/// If `order` is greater than 31 or `v` lies outside the grid
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{hilbert_decode2, hilbert_encode2, IVector2};
+///
+/// // The first cells of an order 1 curve
+/// assert_eq!(hilbert_decode2(0, 1), IVector2::new(0, 0));
+/// assert_eq!(hilbert_decode2(1, 1), IVector2::new(0, 1));
+/// assert_eq!(hilbert_encode2(IVector2::new(1, 0), 1), 3);
+/// ```
+pub fn hilbert_encode2(v: IVector2, order: u32) -> u64 {
+    assert!(order <= 31, "order must be at most 31");
+    let n = 1_i64 << order;
+    assert!(
+        (0..n).contains(&(v.x as i64)) && (0..n).contains(&(v.y as i64)),
+        "cell lies outside the grid"
+    );
+    let (mut x, mut y) = (v.x as i64, v.y as i64);
+    let mut d = 0;
+    let mut s = n / 2;
+    while s > 0 {
+        let rx = (x & s != 0) as i64;
+        let ry = (y & s != 0) as i64;
+        d += s * s * ((3 * rx) ^ ry);
+        (x, y) = hilbert_rotate(n, x, y, rx, ry);
+        s /= 2;
+    }
+    d as u64
+}
+
+/// Recovers a cell from its distance along a 2D Hilbert curve
+///
+/// ## Arguments
+///
+/// * `d` - The distance along the curve, in `0..4^order`
+/// * `order` - The number of bits per axis of the grid, at most 31
+///
+/// ## Returns
+///
+/// The cell at that distance
+///
+/// ## Panics
+///
+/// If `order` is greater than 31 or `d` lies beyond the end of the curve
+pub fn hilbert_decode2(d: u64, order: u32) -> IVector2 {
+    assert!(order <= 31, "order must be at most 31");
+    let n = 1_i64 << order;
+    assert!(
+        (d as u128) < (n as u128 * n as u128),
+        "distance lies beyond the curve"
+    );
+    let (mut x, mut y) = (0, 0);
+    let mut t = d as i64;
+    let mut s = 1;
+    while s < n {
+        let rx = 1 & (t / 2);
+        let ry = 1 & (t ^ rx);
+        (x, y) = hilbert_rotate(s, x, y, rx, ry);
+        x += s * rx;
+        y += s * ry;
+        t /= 4;
+        s *= 2;
+    }
+    IVector2::new(x as i32, y as i32)
+}
+
+/// Rotates and flips a quadrant so the sub-curve inside it has the right orientation
+fn hilbert_rotate(n: i64, x: i64, y: i64, rx: i64, ry: i64) -> (i64, i64) {
+    if ry != 0 {
+        return (x, y);
+    }
+    if rx == 1 {
+        (n - 1 - y, n - 1 - x)
+    } else {
+        (y, x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_morton2() {
+        // With the offset removed, x = 0b11 and y = 0b01 interleave to 0b0111
+        let offset = morton_encode2(IVector2::new(0, 0));
+        assert_eq!(morton_encode2(IVector2::new(3, 1)) - offset, 0b0111);
+        for v in [
+            IVector2::new(i32::MIN, i32::MAX),
+            IVector2::new(-1, 0),
+            IVector2::new(12345, -678),
+        ] {
+            assert_eq!(morton_decode2(morton_encode2(v)), v);
+        }
+        assert!(morton_encode2(IVector2::new(-1, -1)) < morton_encode2(IVector2::new(0, 0)));
+    }
+
+    #[test]
+    fn test_morton3() {
+        let offset = morton_encode3(IVector3::new(0, 0, 0));
+        assert_eq!(morton_encode3(IVector3::new(1, 0, 1)) - offset, 0b101);
+        assert_eq!(morton_encode3(IVector3::new(2, 0, 0)) - offset, 0b1000);
+        for v in [
+            IVector3::new(i32::MIN, 0, i32::MAX),
+            IVector3::new(-5, 6, -7),
+        ] {
+            assert_eq!(morton_decode3(morton_encode3(v)), v);
+        }
+    }
+
+    #[test]
+    fn test_morton_in_aabb() {
+        let bounds = AABB3::new(Vector3::new(0., 0., 0.), Vector3::new(1., 1., 1.));
+        assert_eq!(
+            morton_encode_in_aabb3(&Vector3::new(-1., 0., 0.), &bounds),
+            0
+        );
+        assert_eq!(
+            morton_encode_in_aabb3(&Vector3::new(1., 1., 2.), &bounds),
+            (1 << 63) - 1
+        );
+        let bounds = AABB2::new(Vector2::new(0., 0.), Vector2::new(0., 1.));
+        assert_eq!(
+            morton_encode_in_aabb2(&Vector2::new(0., 1.), &bounds),
+            0xAAAA_AAAA_AAAA_AAAA
+        );
+    }
+
+    #[test]
+    fn test_hilbert() {
+        let order = 4;
+        let mut previous = hilbert_decode2(0, order);
+        for d in 1..256 {
+            let v = hilbert_decode2(d, order);
+            assert_eq!(hilbert_encode2(v, order), d);
+            // Consecutive cells are neighbours
+            let step = v - previous;
+            assert_eq!(step.x.abs() + step.y.abs(), 1);
+            previous = v;
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_hilbert_outside_grid() {
+        hilbert_encode2(IVector2::new(4, 0), 2);
+    }
+}
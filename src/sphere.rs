@@ -0,0 +1,180 @@
+use crate::{Vector, Vector3};
+
+/// A sphere in 3D space described by a center point and a radius
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sphere {
+    pub center: Vector3,
+    pub radius: f64,
+}
+
+impl Sphere {
+    /// Creates a new sphere
+    ///
+    /// ## Arguments
+    ///
+    /// * `center` - The center of the sphere
+    /// * `radius` - The radius of the sphere
+    ///
+    /// ## Returns
+    ///
+    /// A new sphere
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Sphere, Vector3};
+    ///
+    /// let c = Sphere::new(Vector3::new(0., 0., 0.), 1.);
+    /// ```
+    pub fn new(center: Vector3, radius: f64) -> Sphere {
+        Sphere { center, radius }
+    }
+
+    /// Checks whether a point lies inside (or on the surface of) the sphere
+    ///
+    /// ## Arguments
+    ///
+    /// * `point` - The point to test
+    ///
+    /// ## Returns
+    ///
+    /// `true` if the point is inside the sphere
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Sphere, Vector3};
+    ///
+    /// let c = Sphere::new(Vector3::new(0., 0., 0.), 1.);
+    ///
+    /// assert!(c.contains(&Vector3::new(0.5, 0.5, 0.)));
+    /// assert!(!c.contains(&Vector3::new(1., 1., 0.)));
+    /// ```
+    pub fn contains(&self, point: &Vector3) -> bool {
+        let offset = *point - self.center;
+        offset.dot(&offset) <= self.radius * self.radius
+    }
+
+    /// Checks whether two spheres overlap
+    ///
+    /// Spheres that only touch at a single point are considered to be intersecting.
+    ///
+    /// ## Arguments
+    ///
+    /// * `other` - The other sphere to test against
+    ///
+    /// ## Returns
+    ///
+    /// `true` if the spheres intersect
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Sphere, Vector3};
+    ///
+    /// let a = Sphere::new(Vector3::new(0., 0., 0.), 1.);
+    /// let b = Sphere::new(Vector3::new(1.5, 0., 0.), 1.);
+    ///
+    /// assert!(a.intersects(&b));
+    /// ```
+    pub fn intersects(&self, other: &Sphere) -> bool {
+        let offset = other.center - self.center;
+        let radii = self.radius + other.radius;
+        offset.dot(&offset) <= radii * radii
+    }
+
+    /// Computes a sphere enclosing all of the given points
+    ///
+    /// This uses Ritter's algorithm, which is fast but only approximate. The resulting sphere
+    /// always contains every point, however it may be slightly larger than the minimal one.
+    ///
+    /// ## Arguments
+    ///
+    /// * `points` - The points to enclose
+    ///
+    /// ## Returns
+    ///
+    /// The bounding sphere, or `None` if `points` is empty
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Sphere, Vector3};
+    ///
+    /// let points = [Vector3::new(-1., 0., 0.), Vector3::new(1., 0., 0.), Vector3::new(0., 0.5, 0.)];
+    ///
+    /// let c = Sphere::from_points(&points).unwrap();
+    ///
+    /// assert_eq!(c, Sphere::new(Vector3::new(0., 0., 0.), 1.));
+    /// ```
+    pub fn from_points(points: &[Vector3]) -> Option<Sphere> {
+        let first = *points.first()?;
+        let farthest_from = |from: Vector3| {
+            points.iter().copied().fold(from, |best, p| {
+                if (p - from).magnitude() > (best - from).magnitude() {
+                    p
+                } else {
+                    best
+                }
+            })
+        };
+
+        let a = farthest_from(first);
+        let b = farthest_from(a);
+
+        let mut center = (a + b) / 2.;
+        let mut radius = (b - a).magnitude() / 2.;
+
+        for p in points {
+            let distance = (*p - center).magnitude();
+            if distance > radius {
+                let new_radius = (radius + distance) / 2.;
+                center = center + (*p - center) * ((new_radius - radius) / distance);
+                radius = new_radius;
+            }
+        }
+
+        Some(Sphere { center, radius })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contains() {
+        let c = Sphere::new(Vector3::new(1., 1., 0.), 2.);
+        assert!(c.contains(&Vector3::new(1., 1., 0.)));
+        assert!(c.contains(&Vector3::new(3., 1., 0.)));
+        assert!(!c.contains(&Vector3::new(3., 3., 0.)));
+    }
+
+    #[test]
+    fn test_intersects() {
+        let a = Sphere::new(Vector3::new(0., 0., 0.), 1.);
+        let b = Sphere::new(Vector3::new(2., 0., 0.), 1.);
+        let c = Sphere::new(Vector3::new(3., 0., 0.), 1.);
+        assert!(a.intersects(&b));
+        assert!(!a.intersects(&c));
+    }
+
+    #[test]
+    fn test_from_points() {
+        let points = [
+            Vector3::new(0., 0., 0.),
+            Vector3::new(4., 0., 0.),
+            Vector3::new(2., 3., 1.),
+            Vector3::new(1., -1., -2.),
+        ];
+        let c = Sphere::from_points(&points).unwrap();
+        for p in &points {
+            assert!((*p - c.center).magnitude() <= c.radius + 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_from_points_empty() {
+        assert_eq!(Sphere::from_points(&[]), None);
+    }
+}
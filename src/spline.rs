@@ -0,0 +1,269 @@
+#[cfg(not(feature = "std"))]
+use crate::math::FloatMath;
+use crate::VectorSpace;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// How the knots of a Catmull-Rom spline are spaced
+///
+/// Centripetal parameterization is the usual choice, as it never forms cusps or self-intersections
+/// within a segment. Uniform parameterization is cheaper and matches the classic formulation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Parameterization {
+    Uniform,
+    Centripetal,
+    Chordal,
+}
+
+impl Parameterization {
+    fn alpha(&self) -> f64 {
+        match self {
+            Parameterization::Uniform => 0.,
+            Parameterization::Centripetal => 0.5,
+            Parameterization::Chordal => 1.,
+        }
+    }
+}
+
+/// A Catmull-Rom spline passing through a list of control points
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Spline<V> {
+    pub points: Vec<V>,
+    pub parameterization: Parameterization,
+}
+
+#[cfg(feature = "alloc")]
+impl<V> Spline<V>
+where
+    V: VectorSpace,
+{
+    /// Creates a new spline
+    ///
+    /// ## Arguments
+    ///
+    /// * `points` - The points the spline passes through, in order
+    /// * `parameterization` - How the knots of the spline are spaced
+    ///
+    /// ## Returns
+    ///
+    /// A new spline
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Parameterization, Spline, Vector2};
+    ///
+    /// let s = Spline::new(
+    ///     vec![Vector2::new(0., 0.), Vector2::new(1., 1.), Vector2::new(2., 0.)],
+    ///     Parameterization::Centripetal,
+    /// );
+    /// ```
+    pub fn new(points: Vec<V>, parameterization: Parameterization) -> Spline<V> {
+        Spline {
+            points,
+            parameterization,
+        }
+    }
+
+    /// Evaluates the spline
+    ///
+    /// Each segment between two consecutive points covers an equal share of the range of `t`.
+    /// The ends of the spline are extended by mirroring the neighbouring points, so the curve
+    /// also passes through the first and last point.
+    ///
+    /// ## Arguments
+    ///
+    /// * `t` - The curve parameter, from `0` at the first point to `1` at the last point. Values
+    ///   outside of this range are clamped
+    ///
+    /// ## Returns
+    ///
+    /// The point on the spline at `t`, or `None` if the spline has no points
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Parameterization, Spline, Vector2};
+    ///
+    /// let s = Spline::new(
+    ///     vec![Vector2::new(0., 0.), Vector2::new(1., 1.), Vector2::new(2., 0.)],
+    ///     Parameterization::Uniform,
+    /// );
+    ///
+    /// assert_eq!(s.sample(0.5), Some(Vector2::new(1., 1.)));
+    /// assert_eq!(s.sample(1.), Some(Vector2::new(2., 0.)));
+    /// ```
+    pub fn sample(&self, t: f64) -> Option<V> {
+        sample_points(&self.points, t, self.parameterization.alpha())
+    }
+}
+
+/// Evaluates a uniform Catmull-Rom spline through a list of points
+///
+/// This is a shorthand for [`Spline::sample`] with [`Parameterization::Uniform`] that does not
+/// require copying the points into a [`Spline`].
+///
+/// ## Arguments
+///
+/// * `points` - The points the spline passes through, in order
+/// * `t` - The curve parameter, from `0` at the first point to `1` at the last point
+///
+/// ## Returns
+///
+/// The point on the spline at `t`, or `None` if `points` is empty
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{catmull_rom, Vector2};
+///
+/// let points = [Vector2::new(0., 0.), Vector2::new(1., 0.), Vector2::new(2., 0.)];
+///
+/// assert_eq!(catmull_rom(&points, 0.25), Some(Vector2::new(0.5, 0.)));
+/// ```
+pub fn catmull_rom<V>(points: &[V], t: f64) -> Option<V>
+where
+    V: VectorSpace,
+{
+    sample_points(points, t, Parameterization::Uniform.alpha())
+}
+
+/// Finds the segment containing `t` and evaluates it, mirroring the end points as needed
+fn sample_points<V>(points: &[V], t: f64, alpha: f64) -> Option<V>
+where
+    V: VectorSpace,
+{
+    let n = points.len();
+    match n {
+        0 => return None,
+        1 => return Some(points[0]),
+        _ => {}
+    }
+
+    let segments = (n - 1) as f64;
+    let scaled = t.clamp(0., 1.) * segments;
+    let index = (scaled.floor() as usize).min(n - 2);
+    let local = scaled - index as f64;
+
+    let p1 = points[index];
+    let p2 = points[index + 1];
+    let p0 = if index > 0 {
+        points[index - 1]
+    } else {
+        p1 * 2. - p2
+    };
+    let p3 = if index + 2 < n {
+        points[index + 2]
+    } else {
+        p2 * 2. - p1
+    };
+
+    Some(catmull_rom_segment(p0, p1, p2, p3, local, alpha))
+}
+
+/// Evaluates a single Catmull-Rom segment between `p1` and `p2` using the Barry-Goldman
+/// pyramidal formulation
+fn catmull_rom_segment<V>(p0: V, p1: V, p2: V, p3: V, u: f64, alpha: f64) -> V
+where
+    V: VectorSpace,
+{
+    // Coincident points would give zero-length knot intervals, which the blends divide by
+    let interval = |a: V, b: V| (b - a).magnitude().powf(alpha).max(1e-8);
+
+    let t0 = 0.;
+    let t1 = t0 + interval(p0, p1);
+    let t2 = t1 + interval(p1, p2);
+    let t3 = t2 + interval(p2, p3);
+    let t = t1 + (t2 - t1) * u;
+
+    let blend =
+        |a: V, b: V, ta: f64, tb: f64| a * ((tb - t) / (tb - ta)) + b * ((t - ta) / (tb - ta));
+
+    let a1 = blend(p0, p1, t0, t1);
+    let a2 = blend(p1, p2, t1, t2);
+    let a3 = blend(p2, p3, t2, t3);
+    let b1 = blend(a1, a2, t0, t2);
+    let b2 = blend(a2, a3, t1, t3);
+    blend(b1, b2, t1, t2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Vector, Vector2, Vector3};
+    use alloc::vec;
+
+    fn waypoints() -> Vec<Vector2> {
+        vec![
+            Vector2::new(0., 0.),
+            Vector2::new(1., 2.),
+            Vector2::new(3., 2.),
+            Vector2::new(4., 0.),
+        ]
+    }
+
+    #[test]
+    fn test_passes_through_points() {
+        for parameterization in [
+            Parameterization::Uniform,
+            Parameterization::Centripetal,
+            Parameterization::Chordal,
+        ] {
+            let s = Spline::new(waypoints(), parameterization);
+            for (i, p) in waypoints().iter().enumerate() {
+                let sampled = s.sample(i as f64 / 3.).unwrap();
+                assert!((sampled - *p).magnitude() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_uniform_matches_classic_formula() {
+        let p = waypoints();
+        let u: f64 = 0.3;
+        let expected = (p[1] * 2.
+            + (p[2] - p[0]) * u
+            + (p[0] * 2. - p[1] * 5. + p[2] * 4. - p[3]) * (u * u)
+            + (p[1] * 3. - p[0] - p[2] * 3. + p[3]) * (u * u * u))
+            * 0.5;
+        let sampled = catmull_rom(&p, (1. + u) / 3.).unwrap();
+        assert!((sampled - expected).magnitude() < 1e-9);
+    }
+
+    #[test]
+    fn test_centripetal_differs_from_uniform() {
+        let uneven = vec![
+            Vector2::new(0., 0.),
+            Vector2::new(0.1, 0.),
+            Vector2::new(5., 3.),
+            Vector2::new(6., 0.),
+        ];
+        let uniform = Spline::new(uneven.clone(), Parameterization::Uniform);
+        let centripetal = Spline::new(uneven, Parameterization::Centripetal);
+        let a = uniform.sample(0.5).unwrap();
+        let b = centripetal.sample(0.5).unwrap();
+        assert!((a - b).magnitude() > 1e-3);
+    }
+
+    #[test]
+    fn test_degenerate_inputs() {
+        assert_eq!(catmull_rom::<Vector3>(&[], 0.5), None);
+
+        let single = [Vector3::new(1., 2., 3.)];
+        assert_eq!(catmull_rom(&single, 0.5), Some(single[0]));
+
+        let repeated = Spline::new(
+            vec![Vector2::new(1., 1.), Vector2::new(1., 1.)],
+            Parameterization::Centripetal,
+        );
+        assert_eq!(repeated.sample(0.5), Some(Vector2::new(1., 1.)));
+    }
+
+    #[test]
+    fn test_clamps_parameter() {
+        let s = Spline::new(waypoints(), Parameterization::Centripetal);
+        assert_eq!(s.sample(-1.), Some(waypoints()[0]));
+        assert_eq!(s.sample(2.), Some(waypoints()[3]));
+    }
+}
@@ -0,0 +1,439 @@
+#[cfg(not(feature = "std"))]
+use crate::math::FloatMath;
+use crate::Vector;
+use core::fmt;
+
+/// A Vector with a length chosen at runtime, stored inline without allocating
+///
+/// This offers the same operations as [`DynamicVector`](crate::DynamicVector), but its elements
+/// live in a fixed `[f64; CAP]` array, so it can be used without `alloc` and inside real-time
+/// loops that must not touch the heap. The length can be anything up to `CAP`.
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{StackVector, Vector};
+///
+/// let mut v = StackVector::<8>::new(2);
+/// v.set(0, 3.);
+/// v.set(1, 4.);
+///
+/// assert_eq!(v.magnitude(), 5.);
+/// assert_eq!(v.len(), 2);
+/// ```
+#[derive(Clone, Copy)]
+pub struct StackVector<const CAP: usize> {
+    data: [f64; CAP],
+    len: usize,
+}
+
+impl<const CAP: usize> StackVector<CAP> {
+    /// Create a new stack vector filled with zeros
+    ///
+    /// ## Arguments
+    ///
+    /// * `length` - The length of the vector
+    ///
+    /// ## Returns
+    ///
+    /// A new stack vector of the specified length
+    ///
+    /// ## Panics
+    ///
+    /// If `length` is greater than `CAP`
+    pub fn new(length: usize) -> Self {
+        assert!(length <= CAP, "length {} exceeds capacity {}", length, CAP);
+        StackVector {
+            data: [0.; CAP],
+            len: length,
+        }
+    }
+
+    /// Create a new stack vector holding a copy of some values
+    ///
+    /// ## Arguments
+    ///
+    /// * `values` - The elements of the vector
+    ///
+    /// ## Returns
+    ///
+    /// The vector, or `None` if there are more than `CAP` values
+    pub fn from_slice(values: &[f64]) -> Option<Self> {
+        if values.len() > CAP {
+            return None;
+        }
+        let mut vector = StackVector::new(values.len());
+        vector.data[..values.len()].copy_from_slice(values);
+        Some(vector)
+    }
+
+    /// Gets the number of elements in the Vector
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks whether the Vector has no elements
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Gets the largest number of elements the Vector can hold
+    pub fn capacity(&self) -> usize {
+        CAP
+    }
+
+    /// Gets a value from the Vector
+    ///
+    /// ## Arguments
+    ///
+    /// * `index` - The index of the value to get
+    ///
+    /// ## Returns
+    ///
+    /// The value at the specified index
+    pub fn get(&self, index: usize) -> f64 {
+        self.as_slice()[index]
+    }
+
+    /// Sets a value in the Vector
+    ///
+    /// ## Arguments
+    ///
+    /// * `index` - The index of the value to set
+    pub fn set(&mut self, index: usize, value: f64) {
+        self.as_mut_slice()[index] = value;
+    }
+
+    /// Adds a value to the end of the Vector
+    ///
+    /// ## Arguments
+    ///
+    /// * `value` - The value to add
+    ///
+    /// ## Returns
+    ///
+    /// `Err` with the value if the Vector is already full
+    pub fn push(&mut self, value: f64) -> Result<(), f64> {
+        if self.len == CAP {
+            return Err(value);
+        }
+        self.data[self.len] = value;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Removes the last value of the Vector
+    ///
+    /// ## Returns
+    ///
+    /// The removed value, or `None` if the Vector is empty
+    pub fn pop(&mut self) -> Option<f64> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        Some(self.data[self.len])
+    }
+
+    /// Gets the elements of the Vector
+    pub fn as_slice(&self) -> &[f64] {
+        &self.data[..self.len]
+    }
+
+    /// Gets the elements of the Vector mutably
+    pub fn as_mut_slice(&mut self) -> &mut [f64] {
+        &mut self.data[..self.len]
+    }
+
+    /// Rescales the vector to an exact length, keeping its direction
+    ///
+    /// ## Arguments
+    ///
+    /// * `length` - The magnitude of the new vector. A negative length reverses the direction
+    ///
+    /// ## Returns
+    ///
+    /// The rescaled vector, or `None` if the vector is zero and so has no direction
+    pub fn with_magnitude(&self, length: f64) -> Option<StackVector<CAP>> {
+        let magnitude = self.magnitude();
+        if magnitude == 0. {
+            return None;
+        }
+        let mut rescaled = *self;
+        rescaled.scale_mut(length / magnitude);
+        Some(rescaled)
+    }
+
+    /// Rescales the vector in place to an exact length, keeping its direction
+    ///
+    /// A zero vector has no direction, so it is left unchanged. Use `with_magnitude` to detect
+    /// that case.
+    ///
+    /// ## Arguments
+    ///
+    /// * `length` - The new magnitude of the vector
+    pub fn set_magnitude(&mut self, length: f64) {
+        let magnitude = self.magnitude();
+        if magnitude != 0. {
+            self.scale_mut(length / magnitude);
+        }
+    }
+
+    /// Shortens the vector if it is longer than a maximum length
+    ///
+    /// ## Arguments
+    ///
+    /// * `max` - The largest allowed magnitude
+    ///
+    /// ## Returns
+    ///
+    /// A vector in the same direction with a magnitude of at most `max`
+    pub fn clamp_length(&self, max: f64) -> StackVector<CAP> {
+        self.clamp_length_between(0., max)
+    }
+
+    /// Rescales the vector so its length lies within a range
+    ///
+    /// A zero vector has no direction, so it is returned unchanged even if `min` is positive.
+    ///
+    /// ## Arguments
+    ///
+    /// * `min` - The smallest allowed magnitude
+    /// * `max` - The largest allowed magnitude
+    ///
+    /// ## Returns
+    ///
+    /// A vector in the same direction with a magnitude between `min` and `max`
+    ///
+    /// ## Panics
+    ///
+    /// If `min` is greater than `max`
+    pub fn clamp_length_between(&self, min: f64, max: f64) -> StackVector<CAP> {
+        assert!(min <= max, "min must not be greater than max");
+        let magnitude = self.magnitude();
+        let mut clamped = *self;
+        if magnitude > max {
+            clamped.scale_mut(max / magnitude);
+        } else if magnitude < min && magnitude > 0. {
+            clamped.scale_mut(min / magnitude);
+        }
+        clamped
+    }
+
+    /// Normalizes the vector in place
+    pub fn normalize_mut(&mut self) {
+        let magnitude = self.magnitude();
+        for a in self.as_mut_slice() {
+            *a /= magnitude;
+        }
+    }
+
+    /// Multiplies every element by a scalar in place
+    ///
+    /// ## Arguments
+    ///
+    /// * `scalar` - The value to multiply each element by
+    pub fn scale_mut(&mut self, scalar: f64) {
+        for a in self.as_mut_slice() {
+            *a *= scalar;
+        }
+    }
+
+    /// Adds another vector to this one in place
+    ///
+    /// ## Arguments
+    ///
+    /// * `other` - The vector to add
+    ///
+    /// ## Panics
+    ///
+    /// If the vectors have different lengths
+    pub fn add_mut(&mut self, other: &StackVector<CAP>) {
+        self.axpy(1., other);
+    }
+
+    /// Clamps every element to a range in place
+    ///
+    /// ## Arguments
+    ///
+    /// * `min` - The smallest allowed value
+    /// * `max` - The largest allowed value
+    ///
+    /// ## Panics
+    ///
+    /// If `min` is greater than `max`, or either is `NaN`
+    pub fn clamp_mut(&mut self, min: f64, max: f64) {
+        assert!(min <= max, "min must not be greater than max");
+        for a in self.as_mut_slice() {
+            *a = a.clamp(min, max);
+        }
+    }
+
+    /// Adds a multiple of another vector to this one in place, in a single pass
+    ///
+    /// ## Arguments
+    ///
+    /// * `alpha` - The amount to scale `x` by
+    /// * `x` - The vector to add
+    ///
+    /// ## Panics
+    ///
+    /// If the vectors have different lengths
+    pub fn axpy(&mut self, alpha: f64, x: &StackVector<CAP>) {
+        assert_eq!(self.len, x.len, "vectors must have the same length");
+        for (a, b) in self.as_mut_slice().iter_mut().zip(x.as_slice()) {
+            *a += alpha * b;
+        }
+    }
+}
+
+impl<const CAP: usize> Vector for StackVector<CAP> {
+    /// Calculate the dot product of two vectors
+    ///
+    /// ## Arguments
+    ///
+    /// * `other` - The other vector to calculate the dot product with
+    ///
+    /// ## Returns
+    ///
+    /// The dot product of the two vectors
+    fn dot(&self, other: &Self) -> f64 {
+        self.as_slice()
+            .iter()
+            .zip(other.as_slice())
+            .map(|(a, b)| a * b)
+            .sum()
+    }
+
+    /// Calculate the magnitude of the vector
+    ///
+    /// ## Returns
+    ///
+    /// The magnitude of the vector
+    fn magnitude(&self) -> f64 {
+        self.dot(self).sqrt()
+    }
+
+    /// Normalize the vector
+    ///
+    /// ## Returns
+    ///
+    /// A new vector that is the normalized version of the original vector
+    fn normalize(&self) -> Self {
+        let mut normalized = *self;
+        normalized.normalize_mut();
+        normalized
+    }
+
+    /// Creates an empty vector, as a stack vector has no fixed dimension
+    fn zero() -> Self {
+        StackVector::new(0)
+    }
+
+    /// Creates a vector of zeros with the same length as this one
+    fn zero_like(&self) -> Self {
+        StackVector::new(self.len)
+    }
+}
+
+impl<const CAP: usize> PartialEq for StackVector<CAP> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<const CAP: usize> fmt::Debug for StackVector<CAP> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn test_new() {
+        let vector = StackVector::<4>::new(3);
+        assert_eq!(vector.len(), 3);
+        assert_eq!(vector.capacity(), 4);
+        assert_eq!(vector.as_slice(), &[0., 0., 0.]);
+        assert!(StackVector::<4>::new(0).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_over_capacity() {
+        StackVector::<2>::new(3);
+    }
+
+    #[test]
+    fn test_push_pop() {
+        let mut vector = StackVector::<2>::new(0);
+        assert_eq!(vector.push(1.), Ok(()));
+        assert_eq!(vector.push(2.), Ok(()));
+        assert_eq!(vector.push(3.), Err(3.));
+        assert_eq!(vector.pop(), Some(2.));
+        assert_eq!(vector.as_slice(), &[1.]);
+        vector.pop();
+        assert_eq!(vector.pop(), None);
+    }
+
+    #[test]
+    fn test_eq_ignores_unused_storage() {
+        let mut a = StackVector::<4>::from_slice(&[1., 2., 9.]).unwrap();
+        a.pop();
+        let b = StackVector::<4>::from_slice(&[1., 2.]).unwrap();
+        assert_eq!(a, b);
+        assert_eq!(format!("{:?}", a), "[1.0, 2.0]");
+        assert!(StackVector::<1>::from_slice(&[1., 2.]).is_none());
+    }
+
+    #[test]
+    fn test_vector() {
+        let a = StackVector::<3>::from_slice(&[1., 2., 3.]).unwrap();
+        let b = StackVector::<3>::from_slice(&[4., 5., 6.]).unwrap();
+        assert_eq!(a.dot(&b), 32.);
+        let v = StackVector::<3>::from_slice(&[3., 4.]).unwrap();
+        assert_eq!(v.magnitude(), 5.);
+        assert_eq!(v.normalize().as_slice(), &[0.6, 0.8]);
+    }
+
+    #[test]
+    fn test_in_place() {
+        let mut v = StackVector::<4>::from_slice(&[1., -2., 3.]).unwrap();
+        let ones = StackVector::<4>::from_slice(&[1., 1., 1.]).unwrap();
+        v.add_mut(&ones);
+        v.scale_mut(2.);
+        assert_eq!(v.as_slice(), &[4., -2., 8.]);
+        v.clamp_mut(-1., 5.);
+        v.axpy(-1., &ones);
+        assert_eq!(v.as_slice(), &[3., -2., 4.]);
+    }
+
+    #[test]
+    fn test_clamp_length() {
+        let v = StackVector::<4>::from_slice(&[3., 4.]).unwrap();
+        assert_eq!(v.clamp_length(2.5).as_slice(), &[1.5, 2.]);
+        assert_eq!(v.clamp_length_between(10., 20.).as_slice(), &[6., 8.]);
+    }
+
+    #[test]
+    fn test_with_magnitude() {
+        let mut v = StackVector::<4>::from_slice(&[3., 4.]).unwrap();
+        assert_eq!(v.with_magnitude(10.).unwrap().as_slice(), &[6., 8.]);
+        assert_eq!(StackVector::<4>::new(2).with_magnitude(1.), None);
+        v.set_magnitude(2.5);
+        assert_eq!(v.as_slice(), &[1.5, 2.]);
+    }
+
+    #[test]
+    fn test_zero() {
+        let v = StackVector::<4>::from_slice(&[1., 2., 3.]).unwrap();
+        assert_eq!(v.zero_like().as_slice(), &[0., 0., 0.]);
+        assert!(StackVector::<4>::zero().is_empty());
+        assert!(StackVector::<4>::zero().is_zero(0.));
+        assert!(!v.is_zero(1.));
+    }
+}
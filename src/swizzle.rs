@@ -0,0 +1,102 @@
+use crate::{Vector2, Vector3, Vector4};
+use paste::paste;
+
+// Generates every GLSL style swizzle of two, three and four components for a vector type.
+//
+// The components are given as `name = field` pairs, where `name` is the letter used in the
+// method names and `field` is the struct field it reads. The cartesian products are built by
+// recursing once per component, carrying the full component list along in brackets.
+macro_rules! swizzles {
+    ($V:ident: $($name:ident = $field:ident),+) => {
+        impl $V {
+            swizzles!(@two [$($name = $field),+] [$($name = $field),+]);
+            swizzles!(@three [$($name = $field),+] [$($name = $field),+]);
+            swizzles!(@four [$($name = $field),+] [$($name = $field),+]);
+        }
+    };
+
+    (@two [$($a:ident = $fa:ident),+] $all:tt) => {
+        $(swizzles!(@two_leaf $a = $fa, $all);)+
+    };
+    (@two_leaf $a:ident = $fa:ident, [$($b:ident = $fb:ident),+]) => {
+        paste! {
+            $(
+                /// Creates a new vector from the components named by the method, as in GLSL
+                pub fn [<$a $b>](&self) -> Vector2 {
+                    Vector2::new(self.$fa, self.$fb)
+                }
+            )+
+        }
+    };
+
+    (@three [$($a:ident = $fa:ident),+] $all:tt) => {
+        $(swizzles!(@three_with $a = $fa, $all, $all);)+
+    };
+    (@three_with $a:ident = $fa:ident, [$($b:ident = $fb:ident),+], $all:tt) => {
+        $(swizzles!(@three_leaf $a = $fa, $b = $fb, $all);)+
+    };
+    (@three_leaf $a:ident = $fa:ident, $b:ident = $fb:ident, [$($c:ident = $fc:ident),+]) => {
+        paste! {
+            $(
+                /// Creates a new vector from the components named by the method, as in GLSL
+                pub fn [<$a $b $c>](&self) -> Vector3 {
+                    Vector3::new(self.$fa, self.$fb, self.$fc)
+                }
+            )+
+        }
+    };
+
+    (@four [$($a:ident = $fa:ident),+] $all:tt) => {
+        $(swizzles!(@four_with $a = $fa, $all, $all);)+
+    };
+    (@four_with $a:ident = $fa:ident, [$($b:ident = $fb:ident),+], $all:tt) => {
+        $(swizzles!(@four_with_two $a = $fa, $b = $fb, $all, $all);)+
+    };
+    (@four_with_two $a:ident = $fa:ident, $b:ident = $fb:ident, [$($c:ident = $fc:ident),+], $all:tt) => {
+        $(swizzles!(@four_leaf $a = $fa, $b = $fb, $c = $fc, $all);)+
+    };
+    (@four_leaf $a:ident = $fa:ident, $b:ident = $fb:ident, $c:ident = $fc:ident, [$($d:ident = $fd:ident),+]) => {
+        paste! {
+            $(
+                /// Creates a new vector from the components named by the method, as in GLSL
+                pub fn [<$a $b $c $d>](&self) -> Vector4 {
+                    Vector4::new(self.$fa, self.$fb, self.$fc, self.$fd)
+                }
+            )+
+        }
+    };
+}
+
+swizzles!(Vector2: x = x, y = y);
+swizzles!(Vector3: x = x, y = y, z = z);
+swizzles!(Vector4: x = a, y = b, z = c, w = d);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vector2_swizzles() {
+        let v = Vector2::new(1., 2.);
+        assert_eq!(v.yx(), Vector2::new(2., 1.));
+        assert_eq!(v.xxy(), Vector3::new(1., 1., 2.));
+        assert_eq!(v.xxyy(), Vector4::new(1., 1., 2., 2.));
+    }
+
+    #[test]
+    fn test_vector3_swizzles() {
+        let v = Vector3::new(1., 2., 3.);
+        assert_eq!(v.xy(), Vector2::new(1., 2.));
+        assert_eq!(v.xz(), Vector2::new(1., 3.));
+        assert_eq!(v.zyx(), Vector3::new(3., 2., 1.));
+        assert_eq!(v.xyzz(), Vector4::new(1., 2., 3., 3.));
+    }
+
+    #[test]
+    fn test_vector4_swizzles() {
+        let v = Vector4::new(1., 2., 3., 4.);
+        assert_eq!(v.wz(), Vector2::new(4., 3.));
+        assert_eq!(v.xyz(), Vector3::new(1., 2., 3.));
+        assert_eq!(v.wzyx(), Vector4::new(4., 3., 2., 1.));
+    }
+}
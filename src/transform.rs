@@ -0,0 +1,466 @@
+#[cfg(not(feature = "std"))]
+use crate::math::FloatMath;
+use crate::{Quaternion, Radians, Vector2, Vector3};
+use core::ops::Mul;
+
+/// A 2D transform made of a scale, followed by a rotation, followed by a translation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform2 {
+    pub translation: Vector2,
+    /// Counter-clockwise rotation in radians
+    pub rotation: f64,
+    pub scale: Vector2,
+}
+
+/// A 3D transform made of a scale, followed by a rotation, followed by a translation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform3 {
+    pub translation: Vector3,
+    pub rotation: Quaternion,
+    pub scale: Vector3,
+}
+
+impl Transform2 {
+    /// Creates a new 2D transform
+    ///
+    /// ## Arguments
+    ///
+    /// * `translation` - The offset applied last
+    /// * `rotation` - The counter-clockwise rotation. A plain `f64` is taken to be in radians
+    /// * `scale` - The per-axis scale applied first
+    ///
+    /// ## Returns
+    ///
+    /// A new 2D transform
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Transform2, Vector2};
+    ///
+    /// let t = Transform2::new(Vector2::new(1., 0.), 0., Vector2::new(2., 2.));
+    /// ```
+    pub fn new(translation: Vector2, rotation: impl Into<Radians>, scale: Vector2) -> Transform2 {
+        Transform2 {
+            translation,
+            rotation: rotation.into().0,
+            scale,
+        }
+    }
+
+    /// Creates the transform that leaves every point unchanged
+    ///
+    /// ## Returns
+    ///
+    /// The identity transform
+    pub fn identity() -> Transform2 {
+        Transform2::new(Vector2::new(0., 0.), 0., Vector2::new(1., 1.))
+    }
+
+    /// Applies the transform to a direction, ignoring the translation
+    ///
+    /// ## Arguments
+    ///
+    /// * `v` - The vector to transform
+    ///
+    /// ## Returns
+    ///
+    /// The scaled and rotated vector
+    pub fn transform_vector(&self, v: &Vector2) -> Vector2 {
+        let (sin, cos) = self.rotation.sin_cos();
+        let scaled = Vector2::new(v.x * self.scale.x, v.y * self.scale.y);
+        Vector2::new(
+            scaled.x * cos - scaled.y * sin,
+            scaled.x * sin + scaled.y * cos,
+        )
+    }
+
+    /// Applies the transform to a point
+    ///
+    /// ## Arguments
+    ///
+    /// * `p` - The point to transform
+    ///
+    /// ## Returns
+    ///
+    /// The scaled, rotated and translated point
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Transform2, Vector2};
+    ///
+    /// let t = Transform2::new(Vector2::new(1., 0.), 0., Vector2::new(2., 3.));
+    ///
+    /// assert_eq!(t.transform_point(&Vector2::new(1., 1.)), Vector2::new(3., 3.));
+    /// ```
+    pub fn transform_point(&self, p: &Vector2) -> Vector2 {
+        self.transform_vector(p) + self.translation
+    }
+
+    /// Converts the transform to a homogeneous 3x3 matrix
+    ///
+    /// ## Returns
+    ///
+    /// The matrix in row-major order, for use with [`Vector2::transform_point`] and
+    /// [`Vector3::transform`]
+    pub fn to_matrix(&self) -> [[f64; 3]; 3] {
+        let (sin, cos) = self.rotation.sin_cos();
+        let (sx, sy) = (self.scale.x, self.scale.y);
+        [
+            [cos * sx, -sin * sy, self.translation.x],
+            [sin * sx, cos * sy, self.translation.y],
+            [0., 0., 1.],
+        ]
+    }
+
+    /// Calculates the transform that undoes this one
+    ///
+    /// The result is only correct when the scale is uniform or there is no rotation. Otherwise the
+    /// true inverse rotates before it scales, which this type cannot represent, and the result is
+    /// incorrect: it scales first and then rotates, as every transform of this type does.
+    ///
+    /// ## Returns
+    ///
+    /// The inverse transform, or `None` if any scale component is zero
+    pub fn inverse(&self) -> Option<Transform2> {
+        if self.scale.x == 0. || self.scale.y == 0. {
+            return None;
+        }
+
+        let scale = Vector2::new(1. / self.scale.x, 1. / self.scale.y);
+        let (sin, cos) = (-self.rotation).sin_cos();
+        let t = self.translation;
+        let rotated = Vector2::new(t.x * cos - t.y * sin, t.x * sin + t.y * cos);
+
+        Some(Transform2 {
+            translation: Vector2::new(-rotated.x * scale.x, -rotated.y * scale.y),
+            rotation: -self.rotation,
+            scale,
+        })
+    }
+}
+
+impl Mul for Transform2 {
+    type Output = Transform2;
+
+    /// Composes two transforms, applying `other` first and then `self`
+    ///
+    /// The result is only correct when `self` has a uniform scale or `other` has no rotation.
+    /// Otherwise the true composition contains a shear, which a `Transform2` cannot represent, so
+    /// the shear is dropped and the result is incorrect. Multiply the [`Transform2::to_matrix`]
+    /// matrices instead when that matters.
+    fn mul(self, other: Transform2) -> Transform2 {
+        Transform2 {
+            translation: self.transform_point(&other.translation),
+            rotation: self.rotation + other.rotation,
+            scale: Vector2::new(self.scale.x * other.scale.x, self.scale.y * other.scale.y),
+        }
+    }
+}
+
+impl Transform3 {
+    /// Creates a new 3D transform
+    ///
+    /// ## Arguments
+    ///
+    /// * `translation` - The offset applied last
+    /// * `rotation` - The rotation, which should be a unit quaternion
+    /// * `scale` - The per-axis scale applied first
+    ///
+    /// ## Returns
+    ///
+    /// A new 3D transform
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Quaternion, Transform3, Vector3};
+    ///
+    /// let t = Transform3::new(
+    ///     Vector3::new(1., 0., 0.),
+    ///     Quaternion::identity(),
+    ///     Vector3::new(2., 2., 2.),
+    /// );
+    /// ```
+    pub fn new(translation: Vector3, rotation: Quaternion, scale: Vector3) -> Transform3 {
+        Transform3 {
+            translation,
+            rotation,
+            scale,
+        }
+    }
+
+    /// Creates the transform that leaves every point unchanged
+    ///
+    /// ## Returns
+    ///
+    /// The identity transform
+    pub fn identity() -> Transform3 {
+        Transform3::new(
+            Vector3::new(0., 0., 0.),
+            Quaternion::identity(),
+            Vector3::new(1., 1., 1.),
+        )
+    }
+
+    /// Applies the transform to a direction, ignoring the translation
+    ///
+    /// ## Arguments
+    ///
+    /// * `v` - The vector to transform
+    ///
+    /// ## Returns
+    ///
+    /// The scaled and rotated vector
+    pub fn transform_vector(&self, v: &Vector3) -> Vector3 {
+        let scaled = Vector3::new(v.x * self.scale.x, v.y * self.scale.y, v.z * self.scale.z);
+        self.rotation.rotate(&scaled)
+    }
+
+    /// Applies the transform to a point
+    ///
+    /// ## Arguments
+    ///
+    /// * `p` - The point to transform
+    ///
+    /// ## Returns
+    ///
+    /// The scaled, rotated and translated point
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Quaternion, Transform3, Vector3};
+    ///
+    /// let t = Transform3::new(
+    ///     Vector3::new(1., 0., 0.),
+    ///     Quaternion::identity(),
+    ///     Vector3::new(2., 3., 4.),
+    /// );
+    ///
+    /// assert_eq!(t.transform_point(&Vector3::new(1., 1., 1.)), Vector3::new(3., 3., 4.));
+    /// ```
+    pub fn transform_point(&self, p: &Vector3) -> Vector3 {
+        self.transform_vector(p) + self.translation
+    }
+
+    /// Calculates the transform that undoes this one
+    ///
+    /// The result is only correct when the scale is uniform or there is no rotation. Otherwise the
+    /// true inverse rotates before it scales, which this type cannot represent, and the result is
+    /// incorrect: it scales first and then rotates, as every transform of this type does.
+    ///
+    /// ## Returns
+    ///
+    /// The inverse transform, or `None` if any scale component is zero
+    pub fn inverse(&self) -> Option<Transform3> {
+        if self.scale.x == 0. || self.scale.y == 0. || self.scale.z == 0. {
+            return None;
+        }
+
+        let scale = Vector3::new(1. / self.scale.x, 1. / self.scale.y, 1. / self.scale.z);
+        let rotation = self.rotation.conjugate();
+        let rotated = rotation.rotate(&self.translation);
+
+        Some(Transform3 {
+            translation: Vector3::new(
+                -rotated.x * scale.x,
+                -rotated.y * scale.y,
+                -rotated.z * scale.z,
+            ),
+            rotation,
+            scale,
+        })
+    }
+}
+
+impl Mul for Transform3 {
+    type Output = Transform3;
+
+    /// Composes two transforms, applying `other` first and then `self`
+    ///
+    /// The result is only correct when `self` has a uniform scale or `other` has no rotation.
+    /// Otherwise the true composition contains a shear, which a `Transform3` cannot represent, so
+    /// the shear is dropped and the result is incorrect. Apply the transforms one after the other
+    /// instead when that matters.
+    fn mul(self, other: Transform3) -> Transform3 {
+        Transform3 {
+            translation: self.transform_point(&other.translation),
+            rotation: self.rotation * other.rotation,
+            scale: Vector3::new(
+                self.scale.x * other.scale.x,
+                self.scale.y * other.scale.y,
+                self.scale.z * other.scale.z,
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Vector;
+    use core::f64::consts::FRAC_PI_2;
+
+    fn assert_close2(a: Vector2, b: Vector2) {
+        assert!((a - b).magnitude() < 1e-12, "{:?} != {:?}", a, b);
+    }
+
+    fn assert_close3(a: Vector3, b: Vector3) {
+        assert!((a - b).magnitude() < 1e-12, "{:?} != {:?}", a, b);
+    }
+
+    #[test]
+    fn test_transform2_point() {
+        let t = Transform2::new(Vector2::new(1., 1.), FRAC_PI_2, Vector2::new(2., 2.));
+        assert_close2(
+            t.transform_point(&Vector2::new(1., 0.)),
+            Vector2::new(1., 3.),
+        );
+        assert_close2(
+            t.transform_vector(&Vector2::new(1., 0.)),
+            Vector2::new(0., 2.),
+        );
+    }
+
+    #[test]
+    fn test_transform2_compose_and_inverse() {
+        let a = Transform2::new(Vector2::new(1., 2.), 0.3, Vector2::new(2., 2.));
+        let b = Transform2::new(Vector2::new(-1., 0.5), 1.2, Vector2::new(0.5, 3.));
+        let p = Vector2::new(0.7, -1.1);
+
+        assert_close2(
+            (a * b).transform_point(&p),
+            a.transform_point(&b.transform_point(&p)),
+        );
+
+        let inverse = a.inverse().unwrap();
+        assert_close2(inverse.transform_point(&a.transform_point(&p)), p);
+
+        let flat = Transform2::new(Vector2::new(0., 0.), 0., Vector2::new(0., 1.));
+        assert_eq!(flat.inverse(), None);
+    }
+
+    #[test]
+    fn test_transform2_compose_uniform() {
+        let a = Transform2::new(Vector2::new(-3., 1.), -2.1, Vector2::new(0.5, 0.5));
+        let others = [
+            Transform2::new(Vector2::new(2., 0.), 0.9, Vector2::new(4., 0.25)),
+            Transform2::new(Vector2::new(0., -1.), -0.4, Vector2::new(-1., 2.)),
+        ];
+        for b in others {
+            for p in [Vector2::new(1., 0.), Vector2::new(-2.5, 3.)] {
+                assert_close2(
+                    (a * b).transform_point(&p),
+                    a.transform_point(&b.transform_point(&p)),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_transform2_non_uniform_drops_shear() {
+        let stretch = Transform2::new(Vector2::new(0., 0.), 0., Vector2::new(2., 1.));
+        let turn = Transform2::new(Vector2::new(0., 0.), FRAC_PI_2, Vector2::new(1., 1.));
+        let p = Vector2::new(1., 0.);
+
+        assert_close2(
+            stretch.transform_point(&turn.transform_point(&p)),
+            Vector2::new(0., 1.),
+        );
+        assert_close2((stretch * turn).transform_point(&p), Vector2::new(0., 2.));
+        assert_close2(
+            (turn * stretch).transform_point(&p),
+            turn.transform_point(&stretch.transform_point(&p)),
+        );
+
+        let a = Transform2::new(Vector2::new(0., 0.), FRAC_PI_2, Vector2::new(2., 1.));
+        let inverse = a.inverse().unwrap();
+        assert_close2(a.transform_point(&p), Vector2::new(0., 2.));
+        assert_close2(
+            inverse.transform_point(&Vector2::new(0., 2.)),
+            Vector2::new(2., 0.),
+        );
+    }
+
+    #[test]
+    fn test_transform3_point() {
+        let t = Transform3::new(
+            Vector3::new(0., 0., 1.),
+            Quaternion::from_axis_angle(&Vector3::new(0., 0., 1.), FRAC_PI_2),
+            Vector3::new(2., 1., 1.),
+        );
+        assert_close3(
+            t.transform_point(&Vector3::new(1., 0., 0.)),
+            Vector3::new(0., 2., 1.),
+        );
+        assert_eq!(
+            Transform3::identity().transform_point(&t.translation),
+            t.translation
+        );
+    }
+
+    #[test]
+    fn test_transform3_compose_and_inverse() {
+        let a = Transform3::new(
+            Vector3::new(1., 2., 3.),
+            Quaternion::from_axis_angle(&Vector3::new(1., 1., 0.), 0.7),
+            Vector3::new(3., 3., 3.),
+        );
+        let b = Transform3::new(
+            Vector3::new(-2., 0., 1.),
+            Quaternion::from_axis_angle(&Vector3::new(0., 1., 1.), -1.3),
+            Vector3::new(1., 2., 0.5),
+        );
+        let p = Vector3::new(0.5, -1., 2.);
+
+        assert_close3(
+            (a * b).transform_point(&p),
+            a.transform_point(&b.transform_point(&p)),
+        );
+
+        let inverse = a.inverse().unwrap();
+        assert_close3(inverse.transform_point(&a.transform_point(&p)), p);
+        assert_close3(
+            (a * inverse).transform_point(&p),
+            Transform3::identity().transform_point(&p),
+        );
+    }
+
+    #[test]
+    fn test_transform3_non_uniform_drops_shear() {
+        let stretch = Transform3::new(
+            Vector3::new(0., 0., 0.),
+            Quaternion::identity(),
+            Vector3::new(2., 1., 1.),
+        );
+        let turn = Transform3::new(
+            Vector3::new(0., 0., 0.),
+            Quaternion::from_axis_angle(&Vector3::new(0., 0., 1.), FRAC_PI_2),
+            Vector3::new(1., 1., 1.),
+        );
+        let p = Vector3::new(1., 0., 0.);
+
+        assert_close3(
+            stretch.transform_point(&turn.transform_point(&p)),
+            Vector3::new(0., 1., 0.),
+        );
+        assert_close3(
+            (stretch * turn).transform_point(&p),
+            Vector3::new(0., 2., 0.),
+        );
+        assert_close3(
+            (turn * stretch).transform_point(&p),
+            turn.transform_point(&stretch.transform_point(&p)),
+        );
+
+        let a = Transform3::new(Vector3::new(0., 0., 0.), turn.rotation, stretch.scale);
+        let inverse = a.inverse().unwrap();
+        assert_close3(a.transform_point(&p), Vector3::new(0., 2., 0.));
+        assert_close3(
+            inverse.transform_point(&Vector3::new(0., 2., 0.)),
+            Vector3::new(2., 0., 0.),
+        );
+    }
+}
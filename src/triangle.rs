@@ -0,0 +1,480 @@
+use crate::{Vector, Vector2, Vector3};
+
+/// A triangle in 2D space described by its three corners
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Triangle2 {
+    pub a: Vector2,
+    pub b: Vector2,
+    pub c: Vector2,
+}
+
+/// A triangle in 3D space described by its three corners
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Triangle3 {
+    pub a: Vector3,
+    pub b: Vector3,
+    pub c: Vector3,
+}
+
+/// Calculates barycentric coordinates from the edge vectors of a triangle
+///
+/// Shared by both triangle types as it only relies on dot products. Returns `None` if the
+/// triangle is degenerate.
+fn barycentric_from_dots(d00: f64, d01: f64, d11: f64, d20: f64, d21: f64) -> Option<Vector3> {
+    let denom = d00 * d11 - d01 * d01;
+    if denom == 0. {
+        return None;
+    }
+
+    let v = (d11 * d20 - d01 * d21) / denom;
+    let w = (d00 * d21 - d01 * d20) / denom;
+    Some(Vector3::new(1. - v - w, v, w))
+}
+
+impl Triangle2 {
+    /// Creates a new 2D triangle
+    ///
+    /// ## Arguments
+    ///
+    /// * `a` - The first corner of the triangle
+    /// * `b` - The second corner of the triangle
+    /// * `c` - The third corner of the triangle
+    ///
+    /// ## Returns
+    ///
+    /// A new 2D triangle
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Triangle2, Vector2};
+    ///
+    /// let t = Triangle2::new(Vector2::new(0., 0.), Vector2::new(1., 0.), Vector2::new(0., 1.));
+    /// ```
+    pub fn new(a: Vector2, b: Vector2, c: Vector2) -> Triangle2 {
+        Triangle2 { a, b, c }
+    }
+
+    /// Calculates the area of the triangle
+    ///
+    /// ## Returns
+    ///
+    /// The area of the triangle
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Triangle2, Vector2};
+    ///
+    /// let t = Triangle2::new(Vector2::new(0., 0.), Vector2::new(2., 0.), Vector2::new(0., 2.));
+    ///
+    /// assert_eq!(t.area(), 2.);
+    /// ```
+    pub fn area(&self) -> f64 {
+        self.signed_area().abs()
+    }
+
+    /// Calculates the signed area of the triangle
+    ///
+    /// The area is positive if the corners are in counter-clockwise order and negative if they
+    /// are in clockwise order.
+    ///
+    /// ## Returns
+    ///
+    /// The signed area of the triangle
+    pub fn signed_area(&self) -> f64 {
+        (self.b - self.a).cross(&(self.c - self.a)) / 2.
+    }
+
+    /// Calculates the centroid of the triangle
+    ///
+    /// ## Returns
+    ///
+    /// The average of the three corners
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Triangle2, Vector2};
+    ///
+    /// let t = Triangle2::new(Vector2::new(0., 0.), Vector2::new(3., 0.), Vector2::new(0., 3.));
+    ///
+    /// assert_eq!(t.centroid(), Vector2::new(1., 1.));
+    /// ```
+    pub fn centroid(&self) -> Vector2 {
+        (self.a + self.b + self.c) / 3.
+    }
+
+    /// Calculates the barycentric coordinates of a point relative to the triangle
+    ///
+    /// ## Arguments
+    ///
+    /// * `point` - The point to calculate the coordinates of
+    ///
+    /// ## Returns
+    ///
+    /// The weights of the corners `a`, `b` and `c` stored in the `x`, `y` and `z` components
+    /// respectively, or `None` if the triangle is degenerate
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Triangle2, Vector2, Vector3};
+    ///
+    /// let t = Triangle2::new(Vector2::new(0., 0.), Vector2::new(1., 0.), Vector2::new(0., 1.));
+    ///
+    /// assert_eq!(t.barycentric(&Vector2::new(1., 0.)), Some(Vector3::new(0., 1., 0.)));
+    /// ```
+    pub fn barycentric(&self, point: &Vector2) -> Option<Vector3> {
+        let v0 = self.b - self.a;
+        let v1 = self.c - self.a;
+        let v2 = *point - self.a;
+        barycentric_from_dots(
+            v0.dot(&v0),
+            v0.dot(&v1),
+            v1.dot(&v1),
+            v2.dot(&v0),
+            v2.dot(&v1),
+        )
+    }
+
+    /// Checks whether a point lies inside (or on the edge of) the triangle
+    ///
+    /// ## Arguments
+    ///
+    /// * `point` - The point to test
+    ///
+    /// ## Returns
+    ///
+    /// `true` if the point is inside the triangle. Degenerate triangles contain no points
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Triangle2, Vector2};
+    ///
+    /// let t = Triangle2::new(Vector2::new(0., 0.), Vector2::new(1., 0.), Vector2::new(0., 1.));
+    ///
+    /// assert!(t.contains(&Vector2::new(0.25, 0.25)));
+    /// assert!(!t.contains(&Vector2::new(1., 1.)));
+    /// ```
+    pub fn contains(&self, point: &Vector2) -> bool {
+        match self.barycentric(point) {
+            Some(bary) => bary.x >= 0. && bary.y >= 0. && bary.z >= 0.,
+            None => false,
+        }
+    }
+}
+
+impl Triangle3 {
+    /// Creates a new 3D triangle
+    ///
+    /// ## Arguments
+    ///
+    /// * `a` - The first corner of the triangle
+    /// * `b` - The second corner of the triangle
+    /// * `c` - The third corner of the triangle
+    ///
+    /// ## Returns
+    ///
+    /// A new 3D triangle
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Triangle3, Vector3};
+    ///
+    /// let t = Triangle3::new(
+    ///     Vector3::new(0., 0., 0.),
+    ///     Vector3::new(1., 0., 0.),
+    ///     Vector3::new(0., 1., 0.),
+    /// );
+    /// ```
+    pub fn new(a: Vector3, b: Vector3, c: Vector3) -> Triangle3 {
+        Triangle3 { a, b, c }
+    }
+
+    /// Calculates the area of the triangle
+    ///
+    /// ## Returns
+    ///
+    /// The area of the triangle
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Triangle3, Vector3};
+    ///
+    /// let t = Triangle3::new(
+    ///     Vector3::new(0., 0., 0.),
+    ///     Vector3::new(2., 0., 0.),
+    ///     Vector3::new(0., 2., 0.),
+    /// );
+    ///
+    /// assert_eq!(t.area(), 2.);
+    /// ```
+    pub fn area(&self) -> f64 {
+        (self.b - self.a).cross(&(self.c - self.a)).magnitude() / 2.
+    }
+
+    /// Calculates the unit normal of the triangle
+    ///
+    /// The normal follows the right-hand rule, so it points towards the viewer when the corners
+    /// appear in counter-clockwise order.
+    ///
+    /// ## Returns
+    ///
+    /// The unit normal of the triangle. Degenerate triangles produce a `NaN` normal
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Triangle3, Vector3};
+    ///
+    /// let t = Triangle3::new(
+    ///     Vector3::new(0., 0., 0.),
+    ///     Vector3::new(1., 0., 0.),
+    ///     Vector3::new(0., 1., 0.),
+    /// );
+    ///
+    /// assert_eq!(t.normal(), Vector3::new(0., 0., 1.));
+    /// ```
+    pub fn normal(&self) -> Vector3 {
+        (self.b - self.a).cross(&(self.c - self.a)).normalize()
+    }
+
+    /// Calculates the centroid of the triangle
+    ///
+    /// ## Returns
+    ///
+    /// The average of the three corners
+    pub fn centroid(&self) -> Vector3 {
+        (self.a + self.b + self.c) / 3.
+    }
+
+    /// Calculates the barycentric coordinates of a point relative to the triangle
+    ///
+    /// Points that do not lie on the plane of the triangle are projected onto it first.
+    ///
+    /// ## Arguments
+    ///
+    /// * `point` - The point to calculate the coordinates of
+    ///
+    /// ## Returns
+    ///
+    /// The weights of the corners `a`, `b` and `c` stored in the `x`, `y` and `z` components
+    /// respectively, or `None` if the triangle is degenerate
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Triangle3, Vector3};
+    ///
+    /// let t = Triangle3::new(
+    ///     Vector3::new(0., 0., 0.),
+    ///     Vector3::new(1., 0., 0.),
+    ///     Vector3::new(0., 1., 0.),
+    /// );
+    ///
+    /// assert_eq!(t.barycentric(&Vector3::new(0., 1., 0.)), Some(Vector3::new(0., 0., 1.)));
+    /// ```
+    pub fn barycentric(&self, point: &Vector3) -> Option<Vector3> {
+        let v0 = self.b - self.a;
+        let v1 = self.c - self.a;
+        let v2 = *point - self.a;
+        barycentric_from_dots(
+            v0.dot(&v0),
+            v0.dot(&v1),
+            v1.dot(&v1),
+            v2.dot(&v0),
+            v2.dot(&v1),
+        )
+    }
+
+    /// Checks whether a point lies inside (or on the edge of) the triangle
+    ///
+    /// Points that do not lie on the plane of the triangle are projected onto it first.
+    ///
+    /// ## Arguments
+    ///
+    /// * `point` - The point to test
+    ///
+    /// ## Returns
+    ///
+    /// `true` if the point is inside the triangle. Degenerate triangles contain no points
+    pub fn contains(&self, point: &Vector3) -> bool {
+        match self.barycentric(point) {
+            Some(bary) => bary.x >= 0. && bary.y >= 0. && bary.z >= 0.,
+            None => false,
+        }
+    }
+
+    /// Calculates the tangent and bitangent of the triangle from its texture coordinates
+    ///
+    /// The tangent points in the direction the `u` texture coordinate increases across the
+    /// surface and the bitangent the direction `v` increases, which together with the normal
+    /// form the basis normal maps are stored in.
+    ///
+    /// ## Arguments
+    ///
+    /// * `uvs` - The texture coordinates of the corners `a`, `b` and `c`
+    ///
+    /// ## Returns
+    ///
+    /// The unit tangent and bitangent, or `None` if the triangle or its texture coordinates are
+    /// degenerate
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Triangle2, Triangle3, Vector2, Vector3};
+    ///
+    /// let t = Triangle3::new(
+    ///     Vector3::new(0., 0., 0.),
+    ///     Vector3::new(2., 0., 0.),
+    ///     Vector3::new(0., 0., -2.),
+    /// );
+    /// let uvs = Triangle2::new(Vector2::new(0., 0.), Vector2::new(1., 0.), Vector2::new(0., 1.));
+    ///
+    /// assert_eq!(
+    ///     t.tangents(&uvs),
+    ///     Some((Vector3::new(1., 0., 0.), Vector3::new(0., 0., -1.)))
+    /// );
+    /// ```
+    pub fn tangents(&self, uvs: &Triangle2) -> Option<(Vector3, Vector3)> {
+        let edge1 = self.b - self.a;
+        let edge2 = self.c - self.a;
+        let uv1 = uvs.b - uvs.a;
+        let uv2 = uvs.c - uvs.a;
+
+        let det = uv1.cross(&uv2);
+        if det == 0. || !det.is_finite() {
+            return None;
+        }
+        let tangent = (edge1 * uv2.y - edge2 * uv1.y) / det;
+        let bitangent = (edge2 * uv1.x - edge1 * uv2.x) / det;
+        if tangent.magnitude() == 0. || bitangent.magnitude() == 0. {
+            return None;
+        }
+        Some((tangent.normalize(), bitangent.normalize()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_area_2d() {
+        let t = Triangle2::new(
+            Vector2::new(0., 0.),
+            Vector2::new(4., 0.),
+            Vector2::new(0., 3.),
+        );
+        assert_eq!(t.area(), 6.);
+        assert_eq!(t.signed_area(), 6.);
+
+        let t = Triangle2::new(t.a, t.c, t.b);
+        assert_eq!(t.area(), 6.);
+        assert_eq!(t.signed_area(), -6.);
+    }
+
+    #[test]
+    fn test_barycentric_2d() {
+        let t = Triangle2::new(
+            Vector2::new(0., 0.),
+            Vector2::new(2., 0.),
+            Vector2::new(0., 2.),
+        );
+        let bary = t.barycentric(&Vector2::new(1., 0.5)).unwrap();
+        assert_eq!(bary, Vector3::new(0.25, 0.5, 0.25));
+    }
+
+    #[test]
+    fn test_contains_2d() {
+        let t = Triangle2::new(
+            Vector2::new(0., 0.),
+            Vector2::new(2., 0.),
+            Vector2::new(0., 2.),
+        );
+        assert!(t.contains(&Vector2::new(0.5, 0.5)));
+        assert!(t.contains(&Vector2::new(1., 0.)));
+        assert!(!t.contains(&Vector2::new(2., 2.)));
+        assert!(!t.contains(&Vector2::new(-0.1, 0.5)));
+    }
+
+    #[test]
+    fn test_degenerate() {
+        let t = Triangle2::new(
+            Vector2::new(0., 0.),
+            Vector2::new(1., 1.),
+            Vector2::new(2., 2.),
+        );
+        assert_eq!(t.barycentric(&Vector2::new(1., 1.)), None);
+        assert!(!t.contains(&Vector2::new(1., 1.)));
+    }
+
+    #[test]
+    fn test_area_3d() {
+        let t = Triangle3::new(
+            Vector3::new(0., 0., 0.),
+            Vector3::new(0., 4., 0.),
+            Vector3::new(0., 0., 3.),
+        );
+        assert_eq!(t.area(), 6.);
+    }
+
+    #[test]
+    fn test_normal() {
+        let t = Triangle3::new(
+            Vector3::new(0., 0., 0.),
+            Vector3::new(0., 1., 0.),
+            Vector3::new(1., 0., 0.),
+        );
+        assert_eq!(t.normal(), Vector3::new(0., 0., -1.));
+    }
+
+    #[test]
+    fn test_centroid_3d() {
+        let t = Triangle3::new(
+            Vector3::new(0., 0., 0.),
+            Vector3::new(3., 0., 0.),
+            Vector3::new(0., 3., 3.),
+        );
+        assert_eq!(t.centroid(), Vector3::new(1., 1., 1.));
+    }
+
+    #[test]
+    fn test_contains_3d() {
+        let t = Triangle3::new(
+            Vector3::new(0., 0., 0.),
+            Vector3::new(2., 0., 0.),
+            Vector3::new(0., 2., 0.),
+        );
+        assert!(t.contains(&Vector3::new(0.5, 0.5, 0.)));
+        assert!(t.contains(&Vector3::new(0.5, 0.5, 1.)));
+        assert!(!t.contains(&Vector3::new(2., 2., 0.)));
+    }
+
+    #[test]
+    fn test_tangents() {
+        let t = Triangle3::new(
+            Vector3::new(1., 1., 1.),
+            Vector3::new(1., 3., 1.),
+            Vector3::new(1., 1., 5.),
+        );
+        let uvs = Triangle2::new(
+            Vector2::new(0.5, 0.5),
+            Vector2::new(0.5, 0.),
+            Vector2::new(1., 0.5),
+        );
+        assert_eq!(
+            t.tangents(&uvs),
+            Some((Vector3::new(0., 0., 1.), Vector3::new(0., -1., 0.)))
+        );
+        let flat = Triangle2::new(uvs.a, uvs.a, uvs.c);
+        assert_eq!(t.tangents(&flat), None);
+        let line = Triangle3::new(t.a, t.a, t.a);
+        assert_eq!(line.tangents(&uvs), None);
+    }
+}
@@ -0,0 +1,329 @@
+use crate::{Pose3, Vector, Vector3};
+use core::ops::{Add, Mul, Neg, Sub};
+
+/// The velocity of a rigid body, made of an angular and a linear velocity
+///
+/// The linear part is the velocity of the body point that is currently at the origin of the
+/// frame the twist is expressed in, so a twist describes the motion of every point of the body
+/// at once, see [`Twist::velocity_at`]. Use [`Twist::adjoint`] to express it in another frame.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Twist {
+    /// The angular velocity, whose direction is the axis and whose length is the rate in radians
+    pub angular: Vector3,
+    pub linear: Vector3,
+}
+
+/// A force and torque acting on a rigid body
+///
+/// The torque is taken about the origin of the frame the wrench is expressed in. Use
+/// [`Wrench::adjoint`] to express it in another frame.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Wrench {
+    pub torque: Vector3,
+    pub force: Vector3,
+}
+
+impl Twist {
+    /// Creates a new twist
+    ///
+    /// ## Arguments
+    ///
+    /// * `angular` - The angular velocity
+    /// * `linear` - The linear velocity of the point at the origin
+    ///
+    /// ## Returns
+    ///
+    /// A new twist
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Twist, Vector3};
+    ///
+    /// let spin = Twist::new(Vector3::new(0., 0., 1.), Vector3::new(0., 0., 0.));
+    /// ```
+    pub fn new(angular: Vector3, linear: Vector3) -> Twist {
+        Twist { angular, linear }
+    }
+
+    /// Creates the twist of a body at rest
+    ///
+    /// ## Returns
+    ///
+    /// A twist with both parts zero
+    pub fn zero() -> Twist {
+        Twist::new(Vector3::new(0., 0., 0.), Vector3::new(0., 0., 0.))
+    }
+
+    /// Calculates the velocity of a point moving with the body
+    ///
+    /// ## Arguments
+    ///
+    /// * `point` - The current position of the point, in the frame of the twist
+    ///
+    /// ## Returns
+    ///
+    /// The linear velocity of the point, `linear + angular × point`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Twist, Vector3};
+    ///
+    /// let spin = Twist::new(Vector3::new(0., 0., 2.), Vector3::new(0., 0., 0.));
+    ///
+    /// assert_eq!(spin.velocity_at(&Vector3::new(1., 0., 0.)), Vector3::new(0., 2., 0.));
+    /// ```
+    pub fn velocity_at(&self, point: &Vector3) -> Vector3 {
+        self.linear + self.angular.cross(point)
+    }
+
+    /// Expresses the twist in the parent frame of a pose
+    ///
+    /// This applies the adjoint of the pose: if the twist is given in the local frame of `pose`,
+    /// the result describes the same motion in the frame `pose` is expressed in.
+    ///
+    /// ## Arguments
+    ///
+    /// * `pose` - The pose of the twist's frame
+    ///
+    /// ## Returns
+    ///
+    /// The twist `(R ω, R v + p × R ω)`, for the rotation `R` and position `p` of the pose
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Pose3, Quaternion, Twist, Vector3};
+    ///
+    /// // A wheel spinning about its own axis, mounted one unit along X
+    /// let wheel = Pose3::new(Vector3::new(1., 0., 0.), Quaternion::identity());
+    /// let spin = Twist::new(Vector3::new(0., 0., 1.), Vector3::new(0., 0., 0.));
+    /// let world = spin.adjoint(&wheel);
+    ///
+    /// // The point at the world origin moves as if on the rim of the wheel
+    /// assert_eq!(world.linear, Vector3::new(0., -1., 0.));
+    /// ```
+    pub fn adjoint(&self, pose: &Pose3) -> Twist {
+        let angular = pose.rotation.rotate(&self.angular);
+        Twist {
+            angular,
+            linear: pose.rotation.rotate(&self.linear) + pose.position.cross(&angular),
+        }
+    }
+}
+
+impl Wrench {
+    /// Creates a new wrench
+    ///
+    /// ## Arguments
+    ///
+    /// * `torque` - The torque about the origin
+    /// * `force` - The force
+    ///
+    /// ## Returns
+    ///
+    /// A new wrench
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Vector3, Wrench};
+    ///
+    /// let gravity = Wrench::new(Vector3::new(0., 0., 0.), Vector3::new(0., 0., -9.81));
+    /// ```
+    pub fn new(torque: Vector3, force: Vector3) -> Wrench {
+        Wrench { torque, force }
+    }
+
+    /// Creates the wrench of no load
+    ///
+    /// ## Returns
+    ///
+    /// A wrench with both parts zero
+    pub fn zero() -> Wrench {
+        Wrench::new(Vector3::new(0., 0., 0.), Vector3::new(0., 0., 0.))
+    }
+
+    /// Creates the wrench of a force applied at a point
+    ///
+    /// ## Arguments
+    ///
+    /// * `force` - The force
+    /// * `point` - Where the force is applied
+    ///
+    /// ## Returns
+    ///
+    /// The wrench `(point × force, force)`
+    pub fn from_force_at(force: &Vector3, point: &Vector3) -> Wrench {
+        Wrench::new(point.cross(force), *force)
+    }
+
+    /// Expresses the wrench in the parent frame of a pose
+    ///
+    /// This applies the dual of [`Twist::adjoint`], so the power of a twist against a wrench is
+    /// the same in either frame.
+    ///
+    /// ## Arguments
+    ///
+    /// * `pose` - The pose of the wrench's frame
+    ///
+    /// ## Returns
+    ///
+    /// The wrench `(R τ + p × R f, R f)`, for the rotation `R` and position `p` of the pose
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Pose3, Quaternion, Vector3, Wrench};
+    ///
+    /// // A sensor one unit along X measuring a pure upwards force
+    /// let sensor = Pose3::new(Vector3::new(1., 0., 0.), Quaternion::identity());
+    /// let measured = Wrench::new(Vector3::new(0., 0., 0.), Vector3::new(0., 0., 1.));
+    ///
+    /// // About the world origin the force also has a torque
+    /// assert_eq!(measured.adjoint(&sensor).torque, Vector3::new(0., -1., 0.));
+    /// ```
+    pub fn adjoint(&self, pose: &Pose3) -> Wrench {
+        let force = pose.rotation.rotate(&self.force);
+        Wrench {
+            torque: pose.rotation.rotate(&self.torque) + pose.position.cross(&force),
+            force,
+        }
+    }
+
+    /// Calculates the rate at which the wrench does work on a body moving with a twist
+    ///
+    /// Both must be expressed in the same frame.
+    ///
+    /// ## Arguments
+    ///
+    /// * `twist` - The velocity of the body
+    ///
+    /// ## Returns
+    ///
+    /// The power, `τ · ω + f · v`
+    pub fn power(&self, twist: &Twist) -> f64 {
+        self.torque.dot(&twist.angular) + self.force.dot(&twist.linear)
+    }
+}
+
+/// Implements the linear operations and array conversions shared by twists and wrenches
+macro_rules! impl_spatial_ops {
+    ($T:ident, $first:ident, $second:ident) => {
+        impl Add for $T {
+            type Output = $T;
+
+            fn add(self, other: $T) -> $T {
+                $T::new(self.$first + other.$first, self.$second + other.$second)
+            }
+        }
+
+        impl Sub for $T {
+            type Output = $T;
+
+            fn sub(self, other: $T) -> $T {
+                $T::new(self.$first - other.$first, self.$second - other.$second)
+            }
+        }
+
+        impl Mul<f64> for $T {
+            type Output = $T;
+
+            fn mul(self, scalar: f64) -> $T {
+                $T::new(self.$first * scalar, self.$second * scalar)
+            }
+        }
+
+        impl Neg for $T {
+            type Output = $T;
+
+            fn neg(self) -> $T {
+                $T::new(-self.$first, -self.$second)
+            }
+        }
+
+        impl From<[f64; 6]> for $T {
+            /// Takes the rotational part from the first three values and the translational part
+            /// from the last three
+            fn from(values: [f64; 6]) -> $T {
+                $T::new(
+                    Vector3::new(values[0], values[1], values[2]),
+                    Vector3::new(values[3], values[4], values[5]),
+                )
+            }
+        }
+
+        impl From<$T> for [f64; 6] {
+            fn from(value: $T) -> [f64; 6] {
+                let (a, b) = (value.$first, value.$second);
+                [a.x, a.y, a.z, b.x, b.y, b.z]
+            }
+        }
+    };
+}
+
+impl_spatial_ops!(Twist, angular, linear);
+impl_spatial_ops!(Wrench, torque, force);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{assert_vec_approx_eq, Quaternion};
+
+    fn pose() -> Pose3 {
+        Pose3::new(
+            Vector3::new(1., -2., 0.5),
+            Quaternion::from_axis_angle(&Vector3::new(1., 2., 3.), 0.8),
+        )
+    }
+
+    #[test]
+    fn test_twist_adjoint() {
+        let pose = pose();
+        let twist = Twist::new(Vector3::new(0.3, -1., 2.), Vector3::new(1., 0.5, -0.2));
+        let world = twist.adjoint(&pose);
+
+        // Every body point moves with the same velocity seen from either frame
+        let local = Vector3::new(0.4, 1.5, -2.);
+        assert_vec_approx_eq!(
+            world.velocity_at(&pose.transform_point(&local)),
+            pose.transform_vector(&twist.velocity_at(&local))
+        );
+        assert_vec_approx_eq!(
+            <[f64; 6]>::from(world.adjoint(&pose.inverse())),
+            <[f64; 6]>::from(twist)
+        );
+        assert_eq!(Twist::zero().adjoint(&pose), Twist::zero());
+    }
+
+    #[test]
+    fn test_wrench_adjoint() {
+        let pose = pose();
+        let twist = Twist::new(Vector3::new(0.3, -1., 2.), Vector3::new(1., 0.5, -0.2));
+        let wrench = Wrench::new(Vector3::new(-1., 0., 0.7), Vector3::new(2., 1., -3.));
+        let (world_twist, world_wrench) = (twist.adjoint(&pose), wrench.adjoint(&pose));
+        assert!((world_wrench.power(&world_twist) - wrench.power(&twist)).abs() < 1e-12);
+
+        let force = Vector3::new(0., 1., 0.);
+        let point = Vector3::new(2., 0., 0.);
+        let applied = Wrench::from_force_at(&force, &point);
+        assert_eq!(applied.torque, Vector3::new(0., 0., 2.));
+        assert_vec_approx_eq!(
+            Wrench::from_force_at(&force, &Vector3::new(0., 0., 0.))
+                .adjoint(&Pose3::new(point, Quaternion::identity()))
+                .torque,
+            applied.torque
+        );
+    }
+
+    #[test]
+    fn test_ops() {
+        let a = Twist::from([1., 2., 3., 4., 5., 6.]);
+        let b = Twist::new(Vector3::new(1., 1., 1.), Vector3::new(0., 0., 0.));
+        assert_eq!(<[f64; 6]>::from(a + b), [2., 3., 4., 4., 5., 6.]);
+        assert_eq!(a - a, Twist::zero());
+        assert_eq!(-b * 2., b * -2.);
+        assert_eq!(Wrench::from([0.; 6]), Wrench::zero());
+    }
+}
@@ -0,0 +1,203 @@
+use crate::{Vector, Vector2, Vector3};
+use core::ops::{Deref, Mul};
+
+/// How far the squared magnitude of a vector may be from one for [`Unit::new_checked`] to
+/// accept it
+const UNIT_TOLERANCE: f64 = 1e-6;
+
+/// A vector that is known to have a magnitude of one
+///
+/// The only ways to build one check or normalize the vector, so functions that need a direction,
+/// such as [`Vector3::reflect`], can take a `Unit` instead of documenting that their argument
+/// must be normalized. It dereferences to the wrapped vector for reading.
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{UnitVector2, Vector2};
+///
+/// let normal = UnitVector2::new_normalize(Vector2::new(0., 5.)).unwrap();
+///
+/// assert_eq!(*normal, Vector2::new(0., 1.));
+/// assert_eq!(normal.y, 1.);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Unit<V>(V);
+
+/// A 2D vector with a magnitude of one
+pub type UnitVector2 = Unit<Vector2>;
+
+/// A 3D vector with a magnitude of one
+pub type UnitVector3 = Unit<Vector3>;
+
+impl<V: Vector> Unit<V> {
+    /// Normalizes a vector into a unit vector
+    ///
+    /// ## Arguments
+    ///
+    /// * `v` - The vector to normalize
+    ///
+    /// ## Returns
+    ///
+    /// The unit vector in the same direction, or `None` if the vector is zero or not finite
+    pub fn new_normalize(v: V) -> Option<Unit<V>> {
+        let magnitude = v.magnitude();
+        if magnitude == 0. || !magnitude.is_finite() {
+            return None;
+        }
+        Some(Unit(v.normalize()))
+    }
+
+    /// Wraps a vector that should already have a magnitude of one, without changing it
+    ///
+    /// ## Arguments
+    ///
+    /// * `v` - The vector to wrap
+    ///
+    /// ## Returns
+    ///
+    /// The unit vector, or `None` if the magnitude of `v` is not within rounding error of one
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{UnitVector2, Vector2};
+    ///
+    /// assert!(UnitVector2::new_checked(Vector2::new(0.6, 0.8)).is_some());
+    /// assert!(UnitVector2::new_checked(Vector2::new(1., 1.)).is_none());
+    /// ```
+    pub fn new_checked(v: V) -> Option<Unit<V>> {
+        // Written so that a NaN magnitude fails the check
+        if (v.dot(&v) - 1.).abs() <= UNIT_TOLERANCE {
+            Some(Unit(v))
+        } else {
+            None
+        }
+    }
+
+    /// Wraps a vector without checking its magnitude
+    ///
+    /// Use this only when the vector is known to be normalized, for example because it was just
+    /// built from a sine and cosine. Functions taking a `Unit` give wrong results otherwise.
+    ///
+    /// ## Arguments
+    ///
+    /// * `v` - The vector to wrap
+    ///
+    /// ## Returns
+    ///
+    /// The unit vector
+    pub fn new_unchecked(v: V) -> Unit<V> {
+        Unit(v)
+    }
+}
+
+impl<V> Unit<V> {
+    /// Unwraps the vector
+    ///
+    /// ## Returns
+    ///
+    /// The vector, which has a magnitude of one
+    pub fn into_inner(self) -> V {
+        self.0
+    }
+}
+
+impl<V: Copy + Mul<f64, Output = V>> Unit<V> {
+    /// Reverses the direction of the vector
+    ///
+    /// ## Returns
+    ///
+    /// The unit vector pointing the opposite way
+    pub fn flipped(&self) -> Unit<V> {
+        Unit(self.0 * -1.)
+    }
+}
+
+impl<V> Deref for Unit<V> {
+    type Target = V;
+
+    fn deref(&self) -> &V {
+        &self.0
+    }
+}
+
+impl<V> AsRef<V> for Unit<V> {
+    fn as_ref(&self) -> &V {
+        &self.0
+    }
+}
+
+impl Vector2 {
+    /// Reflects the vector off a surface with the given normal
+    ///
+    /// ## Arguments
+    ///
+    /// * `normal` - The normal of the surface
+    ///
+    /// ## Returns
+    ///
+    /// The reflected vector, `self - 2 * (self . normal) * normal`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{UnitVector2, Vector2};
+    ///
+    /// let floor = UnitVector2::new_normalize(Vector2::new(0., 1.)).unwrap();
+    ///
+    /// assert_eq!(Vector2::new(1., -1.).reflect(&floor), Vector2::new(1., 1.));
+    /// ```
+    pub fn reflect(&self, normal: &UnitVector2) -> Vector2 {
+        *self - **normal * (2. * self.dot(normal))
+    }
+}
+
+impl Vector3 {
+    /// Reflects the vector off a surface with the given normal
+    ///
+    /// ## Arguments
+    ///
+    /// * `normal` - The normal of the surface
+    ///
+    /// ## Returns
+    ///
+    /// The reflected vector, `self - 2 * (self . normal) * normal`
+    pub fn reflect(&self, normal: &UnitVector3) -> Vector3 {
+        *self - **normal * (2. * self.dot(normal))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_constructors() {
+        let unit = UnitVector3::new_normalize(Vector3::new(0., 3., 4.)).unwrap();
+        assert_eq!(unit.into_inner(), Vector3::new(0., 0.6, 0.8));
+        assert!(UnitVector3::new_normalize(Vector3::new(0., 0., 0.)).is_none());
+        assert!(UnitVector3::new_normalize(Vector3::new(f64::INFINITY, 0., 0.)).is_none());
+        assert!(UnitVector3::new_checked(Vector3::new(0., 0., -1.)).is_some());
+        assert!(UnitVector3::new_checked(Vector3::new(0., 0., 2.)).is_none());
+        assert!(UnitVector3::new_checked(Vector3::new(f64::NAN, 0., 0.)).is_none());
+        assert!(UnitVector3::new_checked(Vector3::new(f64::INFINITY, 0., 0.)).is_none());
+        assert_eq!(
+            *UnitVector2::new_unchecked(Vector2::new(1., 0.)).flipped(),
+            Vector2::new(-1., 0.)
+        );
+    }
+
+    #[test]
+    fn test_reflect() {
+        let normal = UnitVector3::new_normalize(Vector3::new(0., 0., -2.)).unwrap();
+        assert_eq!(
+            Vector3::new(1., 2., 3.).reflect(&normal),
+            Vector3::new(1., 2., -3.)
+        );
+        // Reflecting twice gets back the original vector
+        let normal = UnitVector2::new_normalize(Vector2::new(1., 1.)).unwrap();
+        let v = Vector2::new(3., -1.);
+        assert!((v.reflect(&normal).reflect(&normal) - v).magnitude() < 1e-12);
+    }
+}
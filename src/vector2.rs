@@ -1,8 +1,13 @@
+use crate::error::{parse_array, slice_to_array, VectorError};
+#[cfg(not(feature = "std"))]
+use crate::math::FloatMath;
 /// A 2D vector struct
-use crate::Vector;
-use std::ops::{Add, Div, Mul, Sub};
+use crate::{Radians, Vector};
+use core::ops::{Add, Div, Mul, Neg, Sub};
+use core::str::FromStr;
 
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[repr(C)]
 pub struct Vector2 {
     pub x: f64,
     pub y: f64,
@@ -63,6 +68,317 @@ impl Vector2 {
     pub fn cross(&self, other: &Self) -> f64 {
         self.x * other.y - self.y * other.x
     }
+
+    /// Creates a unit vector pointing at an angle counter-clockwise from the positive x axis
+    ///
+    /// ## Arguments
+    ///
+    /// * `angle` - The angle of the vector. A plain `f64` is taken to be in radians
+    ///
+    /// ## Returns
+    ///
+    /// The unit vector `(cos(angle), sin(angle))`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Degrees, Vector2};
+    ///
+    /// assert_eq!(Vector2::from_angle(Degrees(0.)), Vector2::new(1., 0.));
+    /// ```
+    pub fn from_angle(angle: impl Into<Radians>) -> Vector2 {
+        let (sin, cos) = angle.into().sin_cos();
+        Vector2::new(cos, sin)
+    }
+
+    /// Calculates the angle of the vector counter-clockwise from the positive x axis
+    ///
+    /// ## Returns
+    ///
+    /// The angle, in the range `[-PI, PI]`
+    pub fn angle(&self) -> Radians {
+        Radians(self.y.atan2(self.x))
+    }
+
+    /// Calculates the unsigned angle between two vectors
+    ///
+    /// ## Arguments
+    ///
+    /// * `other` - The other vector
+    ///
+    /// ## Returns
+    ///
+    /// The angle, in the range `[0, PI]`. It is zero if either vector is zero
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Degrees, Vector2};
+    ///
+    /// let angle = Vector2::new(1., 0.).angle_between(&Vector2::new(0., -2.));
+    ///
+    /// assert_eq!(Degrees::from(angle), Degrees(90.));
+    /// ```
+    pub fn angle_between(&self, other: &Vector2) -> Radians {
+        Radians(self.cross(other).abs().atan2(self.dot(other)))
+    }
+
+    /// Rotates the vector counter-clockwise around the origin
+    ///
+    /// ## Arguments
+    ///
+    /// * `angle` - The angle to rotate by. A plain `f64` is taken to be in radians
+    ///
+    /// ## Returns
+    ///
+    /// The rotated vector
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Degrees, Vector2};
+    ///
+    /// let rotated = Vector2::new(1., 0.).rotate(Degrees(90.));
+    ///
+    /// assert!((rotated - Vector2::new(0., 1.)).x.abs() < 1e-12);
+    /// ```
+    pub fn rotate(&self, angle: impl Into<Radians>) -> Vector2 {
+        let (sin, cos) = angle.into().sin_cos();
+        Vector2::new(self.x * cos - self.y * sin, self.x * sin + self.y * cos)
+    }
+
+    /// Rescales the vector to an exact length, keeping its direction
+    ///
+    /// ## Arguments
+    ///
+    /// * `length` - The magnitude of the new vector. A negative length reverses the direction
+    ///
+    /// ## Returns
+    ///
+    /// The rescaled vector, or `None` if the vector is zero and so has no direction
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::Vector2;
+    ///
+    /// let towards_target = Vector2::new(3., 4.);
+    ///
+    /// assert_eq!(towards_target.with_magnitude(10.), Some(Vector2::new(6., 8.)));
+    /// assert_eq!(Vector2::new(0., 0.).with_magnitude(10.), None);
+    /// ```
+    pub fn with_magnitude(&self, length: f64) -> Option<Self> {
+        let magnitude = self.magnitude();
+        if magnitude == 0. {
+            return None;
+        }
+        Some(*self * (length / magnitude))
+    }
+
+    /// Rescales the vector in place to an exact length, keeping its direction
+    ///
+    /// A zero vector has no direction, so it is left unchanged. Use `with_magnitude` to detect
+    /// that case.
+    ///
+    /// ## Arguments
+    ///
+    /// * `length` - The new magnitude of the vector
+    pub fn set_magnitude(&mut self, length: f64) {
+        if let Some(rescaled) = self.with_magnitude(length) {
+            *self = rescaled;
+        }
+    }
+
+    /// Shortens the vector if it is longer than a maximum length
+    ///
+    /// ## Arguments
+    ///
+    /// * `max` - The largest allowed magnitude
+    ///
+    /// ## Returns
+    ///
+    /// A vector in the same direction with a magnitude of at most `max`
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Vector, Vector2};
+    ///
+    /// let velocity = Vector2::new(3., 4.);
+    ///
+    /// assert_eq!(velocity.clamp_length(2.).magnitude(), 2.);
+    /// assert_eq!(velocity.clamp_length(10.), velocity);
+    /// ```
+    pub fn clamp_length(&self, max: f64) -> Self {
+        self.clamp_length_between(0., max)
+    }
+
+    /// Rescales the vector so its length lies within a range
+    ///
+    /// A zero vector has no direction, so it is returned unchanged even if `min` is positive.
+    ///
+    /// ## Arguments
+    ///
+    /// * `min` - The smallest allowed magnitude
+    /// * `max` - The largest allowed magnitude
+    ///
+    /// ## Returns
+    ///
+    /// A vector in the same direction with a magnitude between `min` and `max`
+    ///
+    /// ## Panics
+    ///
+    /// If `min` is greater than `max`
+    pub fn clamp_length_between(&self, min: f64, max: f64) -> Self {
+        assert!(min <= max, "min must not be greater than max");
+        let magnitude = self.magnitude();
+        if magnitude > max {
+            *self * (max / magnitude)
+        } else if magnitude < min && magnitude > 0. {
+            *self * (min / magnitude)
+        } else {
+            *self
+        }
+    }
+
+    /// Normalizes the vector, failing instead of producing NaNs for a zero vector
+    ///
+    /// ## Returns
+    ///
+    /// The unit vector in the same direction, or [`VectorError::ZeroMagnitude`]
+    pub fn try_normalize(&self) -> Result<Vector2, VectorError> {
+        if self.magnitude() == 0. {
+            return Err(VectorError::ZeroMagnitude);
+        }
+        Ok(self.normalize())
+    }
+
+    /// Normalizes the vector in place
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::Vector2;
+    ///
+    /// let mut a = Vector2::new(3., 4.);
+    /// a.normalize_mut();
+    ///
+    /// assert_eq!(a, Vector2::new(0.6, 0.8));
+    /// ```
+    pub fn normalize_mut(&mut self) {
+        *self = self.normalize();
+    }
+
+    /// Multiplies the vector by a scalar in place
+    ///
+    /// ## Arguments
+    ///
+    /// * `scalar` - The value to multiply each component by
+    pub fn scale_mut(&mut self, scalar: f64) {
+        self.x *= scalar;
+        self.y *= scalar;
+    }
+
+    /// Adds another vector to this one in place
+    ///
+    /// ## Arguments
+    ///
+    /// * `other` - The vector to add
+    pub fn add_mut(&mut self, other: &Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+
+    /// Moves the vector to the nearest point of a square grid
+    ///
+    /// ## Arguments
+    ///
+    /// * `cell_size` - The distance between grid lines
+    ///
+    /// ## Returns
+    ///
+    /// The vector with every component rounded to the nearest multiple of `cell_size`
+    ///
+    /// ## Panics
+    ///
+    /// If `cell_size` is not positive
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::Vector2;
+    ///
+    /// let snapped = Vector2::new(1.3, -0.2).snap_to_grid(0.5);
+    ///
+    /// assert_eq!(snapped, Vector2::new(1.5, 0.));
+    /// ```
+    pub fn snap_to_grid(&self, cell_size: f64) -> Vector2 {
+        assert!(cell_size > 0., "cell size must be positive");
+        Vector2::new(
+            (self.x / cell_size).round() * cell_size,
+            (self.y / cell_size).round() * cell_size,
+        )
+    }
+
+    /// Rounds every component to a number of decimal places
+    ///
+    /// This gives stable, readable coordinates for level files and other text formats, though
+    /// the result is still the nearest `f64`, so most values cannot be stored exactly.
+    ///
+    /// ## Arguments
+    ///
+    /// * `decimals` - The number of digits to keep after the decimal point
+    ///
+    /// ## Returns
+    ///
+    /// The rounded vector
+    pub fn round_to_decimals(&self, decimals: u32) -> Vector2 {
+        let scale = 10_f64.powf(decimals as f64);
+        Vector2::new(
+            (self.x * scale).round() / scale,
+            (self.y * scale).round() / scale,
+        )
+    }
+
+    /// Rounds every component down to a multiple of a step size
+    ///
+    /// Unlike [`Vector2::snap_to_grid`], which moves to the nearest grid point, this always picks
+    /// the grid point below, so every position inside a cell maps to the same corner.
+    ///
+    /// ## Arguments
+    ///
+    /// * `step` - The size of each step
+    ///
+    /// ## Returns
+    ///
+    /// The vector with every component rounded down to a multiple of `step`
+    ///
+    /// ## Panics
+    ///
+    /// If `step` is not positive
+    pub fn quantize(&self, step: f64) -> Vector2 {
+        assert!(step > 0., "step must be positive");
+        Vector2::new(
+            (self.x / step).floor() * step,
+            (self.y / step).floor() * step,
+        )
+    }
+
+    /// Clamps every component of the vector to a range in place
+    ///
+    /// ## Arguments
+    ///
+    /// * `min` - The smallest allowed value
+    /// * `max` - The largest allowed value
+    ///
+    /// ## Panics
+    ///
+    /// If `min` is greater than `max`, or either is `NaN`
+    pub fn clamp_mut(&mut self, min: f64, max: f64) {
+        assert!(min <= max, "min must not be greater than max");
+        self.x = self.x.clamp(min, max);
+        self.y = self.y.clamp(min, max);
+    }
 }
 
 impl Vector for Vector2 {
@@ -147,6 +463,10 @@ impl Vector for Vector2 {
             y: self.y / mag,
         }
     }
+
+    fn zero() -> Self {
+        Vector2 { x: 0., y: 0. }
+    }
 }
 
 impl Add for Vector2 {
@@ -193,6 +513,14 @@ impl Div<f64> for Vector2 {
     }
 }
 
+impl Neg for Vector2 {
+    type Output = Vector2;
+
+    fn neg(self) -> Vector2 {
+        Vector2::new(-self.x, -self.y)
+    }
+}
+
 impl From<(f64, f64)> for Vector2 {
     fn from(v: (f64, f64)) -> Self {
         Vector2 { x: v.0, y: v.1 }
@@ -217,9 +545,89 @@ impl From<Vector2> for [f64; 2] {
     }
 }
 
+impl PartialEq<[f64; 2]> for Vector2 {
+    fn eq(&self, other: &[f64; 2]) -> bool {
+        self.x == other[0] && self.y == other[1]
+    }
+}
+
+impl PartialEq<Vector2> for [f64; 2] {
+    fn eq(&self, other: &Vector2) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<(f64, f64)> for Vector2 {
+    fn eq(&self, other: &(f64, f64)) -> bool {
+        self.x == other.0 && self.y == other.1
+    }
+}
+
+impl TryFrom<&[f64]> for Vector2 {
+    type Error = VectorError;
+
+    /// Converts a slice with exactly 2 elements
+    fn try_from(values: &[f64]) -> Result<Self, VectorError> {
+        let values = slice_to_array::<2>(values)?;
+        Ok(Vector2::new(values[0], values[1]))
+    }
+}
+
+impl FromStr for Vector2 {
+    type Err = VectorError;
+
+    /// Parses 2 comma separated numbers, optionally wrapped in parentheses or brackets
+    fn from_str(s: &str) -> Result<Self, VectorError> {
+        let values = parse_array::<2>(s)?;
+        Ok(Vector2::new(values[0], values[1]))
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{Vector, Vector2};
+    use crate::{Degrees, Radians, Vector, Vector2, VectorError};
+    use core::f64::consts::{FRAC_PI_2, PI};
+
+    #[test]
+    fn test_angles() {
+        assert_eq!(Vector2::new(0., 3.).angle(), Radians(FRAC_PI_2));
+        assert_eq!(Vector2::new(-1., 0.).angle(), Radians(PI));
+        let a = Vector2::new(2., 0.);
+        assert_eq!(
+            a.angle_between(&Vector2::new(-1., 1.)),
+            Radians(3. * PI / 4.)
+        );
+        assert_eq!(a.angle_between(&Vector2::new(0., 0.)), Radians(0.));
+
+        let rotated = Vector2::new(1., 2.).rotate(Degrees(-90.));
+        assert!((rotated - Vector2::new(2., -1.)).magnitude() < 1e-12);
+        // Plain floats are radians
+        let rotated = Vector2::new(1., 2.).rotate(PI);
+        assert!((rotated - Vector2::new(-1., -2.)).magnitude() < 1e-12);
+        let unit = Vector2::from_angle(Degrees(90.));
+        assert!((unit - Vector2::new(0., 1.)).magnitude() < 1e-12);
+    }
+
+    #[test]
+    fn test_fallible() {
+        assert_eq!("(1, -2)".parse(), Ok(Vector2::new(1., -2.)));
+        assert_eq!(
+            "1, 2, 3".parse::<Vector2>(),
+            Err(VectorError::LengthMismatch {
+                expected: 2,
+                found: 3
+            })
+        );
+        assert_eq!(Vector2::try_from(&[3., 4.][..]), Ok(Vector2::new(3., 4.)));
+        assert_eq!(
+            Vector2::new(0., 0.).try_normalize(),
+            Err(VectorError::ZeroMagnitude)
+        );
+        assert_eq!(
+            Vector2::new(0., 2.).try_normalize(),
+            Ok(Vector2::new(0., 1.))
+        );
+    }
 
     #[test]
     fn test_magnitude() {
@@ -262,6 +670,20 @@ mod tests {
         assert_eq!(result.y, 3.);
     }
 
+    #[test]
+    fn test_eq_arrays() {
+        let v = Vector2::new(1., 2.);
+        assert_eq!(v, [1., 2.]);
+        assert_eq!([1., 2.], v);
+        assert_eq!(v, (1., 2.));
+        assert!(v != (2., 1.));
+    }
+
+    #[test]
+    fn test_neg() {
+        assert_eq!(-Vector2::new(1., -2.), Vector2::new(-1., 2.));
+    }
+
     #[test]
     fn test_mul() {
         let a = Vector2 { x: 2., y: 3. };
@@ -323,4 +745,62 @@ mod tests {
         let b = Vector2::from(array);
         assert_eq!(a, b);
     }
+
+    #[test]
+    fn test_in_place() {
+        let mut v = Vector2::new(1., -2.);
+        v.add_mut(&Vector2::new(1., 1.));
+        assert_eq!(v, Vector2::new(2., -1.));
+        v.scale_mut(3.);
+        assert_eq!(v, Vector2::new(6., -3.));
+        v.clamp_mut(-1., 4.);
+        assert_eq!(v, Vector2::new(4., -1.));
+        v = Vector2::new(3., 4.);
+        v.normalize_mut();
+        assert_eq!(v, Vector2::new(0.6, 0.8));
+    }
+
+    #[test]
+    fn test_clamp_length() {
+        let v = Vector2::new(3., 4.);
+        assert_eq!(v.clamp_length(2.5), Vector2::new(1.5, 2.));
+        assert_eq!(v.clamp_length(5.), v);
+        assert_eq!(v.clamp_length_between(10., 20.), Vector2::new(6., 8.));
+        assert_eq!(
+            Vector2::new(0., 0.).clamp_length_between(1., 2.),
+            Vector2::new(0., 0.)
+        );
+    }
+
+    #[test]
+    fn test_with_magnitude() {
+        let mut v = Vector2::new(3., 4.);
+        assert_eq!(v.with_magnitude(-5.), Some(Vector2::new(-3., -4.)));
+        v.set_magnitude(10.);
+        assert_eq!(v, Vector2::new(6., 8.));
+        let mut zero = Vector2::new(0., 0.);
+        zero.set_magnitude(1.);
+        assert_eq!(zero, Vector2::new(0., 0.));
+    }
+
+    #[test]
+    fn test_snapping() {
+        let v = Vector2::new(2.7, -1.2);
+        assert_eq!(v.snap_to_grid(0.5), Vector2::new(2.5, -1.));
+        assert_eq!(v.quantize(0.5), Vector2::new(2.5, -1.5));
+        assert_eq!(v.round_to_decimals(0), Vector2::new(3., -1.));
+        assert_eq!(
+            Vector2::new(0.1 + 0.2, 1.).round_to_decimals(3),
+            Vector2::new(0.3, 1.)
+        );
+    }
+
+    #[test]
+    fn test_zero() {
+        assert_eq!(Vector2::zero(), Vector2::new(0., 0.));
+        assert_eq!(Vector2::new(3., 4.).zero_like(), Vector2::zero());
+        assert!(Vector2::new(0., -0.).is_zero(0.));
+        assert!(Vector2::new(3e-7, 4e-7).is_zero(5e-7));
+        assert!(!Vector2::new(3e-7, 4e-7).is_zero(4e-7));
+    }
 }
@@ -1,8 +1,13 @@
-use crate::Vector;
-use std::convert::{From, Into};
-use std::ops::{Add, Div, Mul, Sub};
+use crate::error::{parse_array, slice_to_array, VectorError};
+#[cfg(not(feature = "std"))]
+use crate::math::FloatMath;
+use crate::{Radians, Vector};
+use core::convert::{From, Into};
+use core::ops::{Add, Div, Mul, Neg, Sub};
+use core::str::FromStr;
 
 #[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[repr(C)]
 pub struct Vector3 {
     pub x: f64,
     pub y: f64,
@@ -71,6 +76,249 @@ impl Vector3 {
             z: self.x * other.y - self.y * other.x,
         }
     }
+
+    /// Calculates the unsigned angle between two vectors
+    ///
+    /// This uses the length of the cross product as well as the dot product, so it stays accurate
+    /// for nearly parallel vectors where `acos` of the dot product does not.
+    ///
+    /// ## Arguments
+    ///
+    /// * `other` - The other vector
+    ///
+    /// ## Returns
+    ///
+    /// The angle, in the range `[0, PI]`. It is zero if either vector is zero
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::{Degrees, Vector3};
+    ///
+    /// let angle = Vector3::new(1., 0., 0.).angle_between(&Vector3::new(-1., 0., 0.));
+    ///
+    /// assert_eq!(Degrees::from(angle), Degrees(180.));
+    /// ```
+    pub fn angle_between(&self, other: &Vector3) -> Radians {
+        Radians(self.cross(other).magnitude().atan2(self.dot(other)))
+    }
+
+    /// Rescales the vector to an exact length, keeping its direction
+    ///
+    /// ## Arguments
+    ///
+    /// * `length` - The magnitude of the new vector. A negative length reverses the direction
+    ///
+    /// ## Returns
+    ///
+    /// The rescaled vector, or `None` if the vector is zero and so has no direction
+    pub fn with_magnitude(&self, length: f64) -> Option<Self> {
+        let magnitude = self.magnitude();
+        if magnitude == 0. {
+            return None;
+        }
+        Some(*self * (length / magnitude))
+    }
+
+    /// Rescales the vector in place to an exact length, keeping its direction
+    ///
+    /// A zero vector has no direction, so it is left unchanged. Use `with_magnitude` to detect
+    /// that case.
+    ///
+    /// ## Arguments
+    ///
+    /// * `length` - The new magnitude of the vector
+    pub fn set_magnitude(&mut self, length: f64) {
+        if let Some(rescaled) = self.with_magnitude(length) {
+            *self = rescaled;
+        }
+    }
+
+    /// Shortens the vector if it is longer than a maximum length
+    ///
+    /// ## Arguments
+    ///
+    /// * `max` - The largest allowed magnitude
+    ///
+    /// ## Returns
+    ///
+    /// A vector in the same direction with a magnitude of at most `max`
+    pub fn clamp_length(&self, max: f64) -> Self {
+        self.clamp_length_between(0., max)
+    }
+
+    /// Rescales the vector so its length lies within a range
+    ///
+    /// A zero vector has no direction, so it is returned unchanged even if `min` is positive.
+    ///
+    /// ## Arguments
+    ///
+    /// * `min` - The smallest allowed magnitude
+    /// * `max` - The largest allowed magnitude
+    ///
+    /// ## Returns
+    ///
+    /// A vector in the same direction with a magnitude between `min` and `max`
+    ///
+    /// ## Panics
+    ///
+    /// If `min` is greater than `max`
+    pub fn clamp_length_between(&self, min: f64, max: f64) -> Self {
+        assert!(min <= max, "min must not be greater than max");
+        let magnitude = self.magnitude();
+        if magnitude > max {
+            *self * (max / magnitude)
+        } else if magnitude < min && magnitude > 0. {
+            *self * (min / magnitude)
+        } else {
+            *self
+        }
+    }
+
+    /// Normalizes the vector, failing instead of producing NaNs for a zero vector
+    ///
+    /// ## Returns
+    ///
+    /// The unit vector in the same direction, or [`VectorError::ZeroMagnitude`]
+    pub fn try_normalize(&self) -> Result<Vector3, VectorError> {
+        if self.magnitude() == 0. {
+            return Err(VectorError::ZeroMagnitude);
+        }
+        Ok(self.normalize())
+    }
+
+    /// Normalizes the vector in place
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::Vector3;
+    ///
+    /// let mut a = Vector3::new(0., 3., 4.);
+    /// a.normalize_mut();
+    ///
+    /// assert_eq!(a, Vector3::new(0., 0.6, 0.8));
+    /// ```
+    pub fn normalize_mut(&mut self) {
+        *self = self.normalize();
+    }
+
+    /// Multiplies the vector by a scalar in place
+    ///
+    /// ## Arguments
+    ///
+    /// * `scalar` - The value to multiply each component by
+    pub fn scale_mut(&mut self, scalar: f64) {
+        self.x *= scalar;
+        self.y *= scalar;
+        self.z *= scalar;
+    }
+
+    /// Adds another vector to this one in place
+    ///
+    /// ## Arguments
+    ///
+    /// * `other` - The vector to add
+    pub fn add_mut(&mut self, other: &Self) {
+        self.x += other.x;
+        self.y += other.y;
+        self.z += other.z;
+    }
+
+    /// Moves the vector to the nearest point of a square grid
+    ///
+    /// ## Arguments
+    ///
+    /// * `cell_size` - The distance between grid lines
+    ///
+    /// ## Returns
+    ///
+    /// The vector with every component rounded to the nearest multiple of `cell_size`
+    ///
+    /// ## Panics
+    ///
+    /// If `cell_size` is not positive
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::Vector3;
+    ///
+    /// let snapped = Vector3::new(1.3, -0.2, 2.).snap_to_grid(0.5);
+    ///
+    /// assert_eq!(snapped, Vector3::new(1.5, 0., 2.));
+    /// ```
+    pub fn snap_to_grid(&self, cell_size: f64) -> Vector3 {
+        assert!(cell_size > 0., "cell size must be positive");
+        Vector3::new(
+            (self.x / cell_size).round() * cell_size,
+            (self.y / cell_size).round() * cell_size,
+            (self.z / cell_size).round() * cell_size,
+        )
+    }
+
+    /// Rounds every component to a number of decimal places
+    ///
+    /// This gives stable, readable coordinates for level files and other text formats, though
+    /// the result is still the nearest `f64`, so most values cannot be stored exactly.
+    ///
+    /// ## Arguments
+    ///
+    /// * `decimals` - The number of digits to keep after the decimal point
+    ///
+    /// ## Returns
+    ///
+    /// The rounded vector
+    pub fn round_to_decimals(&self, decimals: u32) -> Vector3 {
+        let scale = 10_f64.powf(decimals as f64);
+        Vector3::new(
+            (self.x * scale).round() / scale,
+            (self.y * scale).round() / scale,
+            (self.z * scale).round() / scale,
+        )
+    }
+
+    /// Rounds every component down to a multiple of a step size
+    ///
+    /// Unlike [`Vector3::snap_to_grid`], which moves to the nearest grid point, this always picks
+    /// the grid point below, so every position inside a cell maps to the same corner.
+    ///
+    /// ## Arguments
+    ///
+    /// * `step` - The size of each step
+    ///
+    /// ## Returns
+    ///
+    /// The vector with every component rounded down to a multiple of `step`
+    ///
+    /// ## Panics
+    ///
+    /// If `step` is not positive
+    pub fn quantize(&self, step: f64) -> Vector3 {
+        assert!(step > 0., "step must be positive");
+        Vector3::new(
+            (self.x / step).floor() * step,
+            (self.y / step).floor() * step,
+            (self.z / step).floor() * step,
+        )
+    }
+
+    /// Clamps every component of the vector to a range in place
+    ///
+    /// ## Arguments
+    ///
+    /// * `min` - The smallest allowed value
+    /// * `max` - The largest allowed value
+    ///
+    /// ## Panics
+    ///
+    /// If `min` is greater than `max`, or either is `NaN`
+    pub fn clamp_mut(&mut self, min: f64, max: f64) {
+        assert!(min <= max, "min must not be greater than max");
+        self.x = self.x.clamp(min, max);
+        self.y = self.y.clamp(min, max);
+        self.z = self.z.clamp(min, max);
+    }
 }
 
 impl Vector for Vector3 {
@@ -90,6 +338,14 @@ impl Vector for Vector3 {
             z: self.z / mag,
         }
     }
+
+    fn zero() -> Self {
+        Vector3 {
+            x: 0.,
+            y: 0.,
+            z: 0.,
+        }
+    }
 }
 
 impl From<[f64; 3]> for Vector3 {
@@ -148,10 +404,71 @@ impl Div<f64> for Vector3 {
     }
 }
 
+impl Neg for Vector3 {
+    type Output = Vector3;
+
+    fn neg(self) -> Vector3 {
+        Vector3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl PartialEq<[f64; 3]> for Vector3 {
+    fn eq(&self, other: &[f64; 3]) -> bool {
+        self.x == other[0] && self.y == other[1] && self.z == other[2]
+    }
+}
+
+impl PartialEq<Vector3> for [f64; 3] {
+    fn eq(&self, other: &Vector3) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<(f64, f64, f64)> for Vector3 {
+    fn eq(&self, other: &(f64, f64, f64)) -> bool {
+        self.x == other.0 && self.y == other.1 && self.z == other.2
+    }
+}
+
+impl TryFrom<&[f64]> for Vector3 {
+    type Error = VectorError;
+
+    /// Converts a slice with exactly 3 elements
+    fn try_from(values: &[f64]) -> Result<Self, VectorError> {
+        let values = slice_to_array::<3>(values)?;
+        Ok(Vector3::new(values[0], values[1], values[2]))
+    }
+}
+
+impl FromStr for Vector3 {
+    type Err = VectorError;
+
+    /// Parses 3 comma separated numbers, optionally wrapped in parentheses or brackets
+    fn from_str(s: &str) -> Result<Self, VectorError> {
+        let values = parse_array::<3>(s)?;
+        Ok(Vector3::new(values[0], values[1], values[2]))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_angle_between() {
+        use core::f64::consts::FRAC_PI_2;
+
+        let a = Vector3::new(0., 0., 2.);
+        assert_eq!(
+            a.angle_between(&Vector3::new(0., 5., 0.)),
+            Radians(FRAC_PI_2)
+        );
+        assert_eq!(a.angle_between(&a), Radians(0.));
+        // Tiny angles survive, where acos of the dot product would round to zero
+        let b = Vector3::new(1e-9, 0., 1.);
+        assert!((a.angle_between(&b).0 - 1e-9).abs() < 1e-20);
+    }
+
     #[test]
     fn test_cross() {
         let a = Vector3 {
@@ -386,4 +703,61 @@ mod tests {
         assert!(a < b);
         assert!(b > a);
     }
+
+    #[test]
+    fn test_in_place() {
+        let mut v = Vector3::new(1., -2., 0.5);
+        v.add_mut(&Vector3::new(1., 1., 1.));
+        assert_eq!(v, Vector3::new(2., -1., 1.5));
+        v.scale_mut(2.);
+        assert_eq!(v, Vector3::new(4., -2., 3.));
+        v.clamp_mut(-1., 3.);
+        assert_eq!(v, Vector3::new(3., -1., 3.));
+        v = Vector3::new(0., 3., 4.);
+        v.normalize_mut();
+        assert_eq!(v, Vector3::new(0., 0.6, 0.8));
+    }
+
+    #[test]
+    fn test_clamp_length() {
+        let v = Vector3::new(0., 3., 4.);
+        assert_eq!(v.clamp_length(2.5), Vector3::new(0., 1.5, 2.));
+        assert_eq!(v.clamp_length_between(1., 10.), v);
+        assert_eq!(v.clamp_length_between(10., 20.), Vector3::new(0., 6., 8.));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_clamp_mut_nan() {
+        Vector3::new(1., 2., 3.).clamp_mut(f64::NAN, 1.);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_clamp_length_between_inverted() {
+        Vector3::new(1., 0., 0.).clamp_length_between(2., 1.);
+    }
+
+    #[test]
+    fn test_with_magnitude() {
+        let mut v = Vector3::new(0., 3., 4.);
+        assert_eq!(v.with_magnitude(10.), Some(Vector3::new(0., 6., 8.)));
+        assert_eq!(Vector3::new(0., 0., 0.).with_magnitude(1.), None);
+        v.set_magnitude(2.5);
+        assert_eq!(v, Vector3::new(0., 1.5, 2.));
+    }
+
+    #[test]
+    fn test_snapping() {
+        let v = Vector3::new(7., -3., 0.25);
+        assert_eq!(v.snap_to_grid(4.), Vector3::new(8., -4., 0.));
+        assert_eq!(v.quantize(4.), Vector3::new(4., -4., 0.));
+        assert_eq!(v.round_to_decimals(0), Vector3::new(7., -3., 0.));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_snap_to_zero_grid() {
+        Vector3::new(1., 2., 3.).snap_to_grid(0.);
+    }
 }
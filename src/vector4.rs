@@ -1,12 +1,17 @@
+use crate::error::{parse_array, slice_to_array, VectorError};
+#[cfg(not(feature = "std"))]
+use crate::math::FloatMath;
 use crate::Vector;
-use std::ops::{Add, Div, Mul, Sub};
+use core::ops::{Add, Div, Mul, Neg, Sub};
+use core::str::FromStr;
 
 #[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[repr(C)]
 pub struct Vector4 {
-    a: f64,
-    b: f64,
-    c: f64,
-    d: f64,
+    pub(crate) a: f64,
+    pub(crate) b: f64,
+    pub(crate) c: f64,
+    pub(crate) d: f64,
 }
 
 impl Vector4 {
@@ -33,6 +38,148 @@ impl Vector4 {
     pub fn new(a: f64, b: f64, c: f64, d: f64) -> Self {
         Self { a, b, c, d }
     }
+
+    /// Rescales the vector to an exact length, keeping its direction
+    ///
+    /// ## Arguments
+    ///
+    /// * `length` - The magnitude of the new vector. A negative length reverses the direction
+    ///
+    /// ## Returns
+    ///
+    /// The rescaled vector, or `None` if the vector is zero and so has no direction
+    pub fn with_magnitude(&self, length: f64) -> Option<Self> {
+        let magnitude = self.magnitude();
+        if magnitude == 0. {
+            return None;
+        }
+        Some(*self * (length / magnitude))
+    }
+
+    /// Rescales the vector in place to an exact length, keeping its direction
+    ///
+    /// A zero vector has no direction, so it is left unchanged. Use `with_magnitude` to detect
+    /// that case.
+    ///
+    /// ## Arguments
+    ///
+    /// * `length` - The new magnitude of the vector
+    pub fn set_magnitude(&mut self, length: f64) {
+        if let Some(rescaled) = self.with_magnitude(length) {
+            *self = rescaled;
+        }
+    }
+
+    /// Shortens the vector if it is longer than a maximum length
+    ///
+    /// ## Arguments
+    ///
+    /// * `max` - The largest allowed magnitude
+    ///
+    /// ## Returns
+    ///
+    /// A vector in the same direction with a magnitude of at most `max`
+    pub fn clamp_length(&self, max: f64) -> Self {
+        self.clamp_length_between(0., max)
+    }
+
+    /// Rescales the vector so its length lies within a range
+    ///
+    /// A zero vector has no direction, so it is returned unchanged even if `min` is positive.
+    ///
+    /// ## Arguments
+    ///
+    /// * `min` - The smallest allowed magnitude
+    /// * `max` - The largest allowed magnitude
+    ///
+    /// ## Returns
+    ///
+    /// A vector in the same direction with a magnitude between `min` and `max`
+    ///
+    /// ## Panics
+    ///
+    /// If `min` is greater than `max`
+    pub fn clamp_length_between(&self, min: f64, max: f64) -> Self {
+        assert!(min <= max, "min must not be greater than max");
+        let magnitude = self.magnitude();
+        if magnitude > max {
+            *self * (max / magnitude)
+        } else if magnitude < min && magnitude > 0. {
+            *self * (min / magnitude)
+        } else {
+            *self
+        }
+    }
+
+    /// Normalizes the vector, failing instead of producing NaNs for a zero vector
+    ///
+    /// ## Returns
+    ///
+    /// The unit vector in the same direction, or [`VectorError::ZeroMagnitude`]
+    pub fn try_normalize(&self) -> Result<Vector4, VectorError> {
+        if self.magnitude() == 0. {
+            return Err(VectorError::ZeroMagnitude);
+        }
+        Ok(self.normalize())
+    }
+
+    /// Normalizes the vector in place
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::Vector4;
+    ///
+    /// let mut a = Vector4::new(0., 0., 3., 4.);
+    /// a.normalize_mut();
+    ///
+    /// assert_eq!(a, Vector4::new(0., 0., 0.6, 0.8));
+    /// ```
+    pub fn normalize_mut(&mut self) {
+        *self = self.normalize();
+    }
+
+    /// Multiplies the vector by a scalar in place
+    ///
+    /// ## Arguments
+    ///
+    /// * `scalar` - The value to multiply each component by
+    pub fn scale_mut(&mut self, scalar: f64) {
+        self.a *= scalar;
+        self.b *= scalar;
+        self.c *= scalar;
+        self.d *= scalar;
+    }
+
+    /// Adds another vector to this one in place
+    ///
+    /// ## Arguments
+    ///
+    /// * `other` - The vector to add
+    pub fn add_mut(&mut self, other: &Self) {
+        self.a += other.a;
+        self.b += other.b;
+        self.c += other.c;
+        self.d += other.d;
+    }
+
+    /// Clamps every component of the vector to a range in place
+    ///
+    /// ## Arguments
+    ///
+    /// * `min` - The smallest allowed value
+    /// * `max` - The largest allowed value
+    ///
+    /// ## Panics
+    ///
+    /// If `min` is greater than `max`, or either is `NaN`
+    pub fn clamp_mut(&mut self, min: f64, max: f64) {
+        assert!(min <= max, "min must not be greater than max");
+        self.a = self.a.clamp(min, max);
+        self.b = self.b.clamp(min, max);
+        self.c = self.c.clamp(min, max);
+        self.d = self.d.clamp(min, max);
+    }
 }
 
 impl Vector for Vector4 {
@@ -110,6 +257,15 @@ impl Vector for Vector4 {
             d: self.d / mag,
         }
     }
+
+    fn zero() -> Self {
+        Vector4 {
+            a: 0.,
+            b: 0.,
+            c: 0.,
+            d: 0.,
+        }
+    }
 }
 
 impl Add for Vector4 {
@@ -164,6 +320,14 @@ impl Div<f64> for Vector4 {
     }
 }
 
+impl Neg for Vector4 {
+    type Output = Vector4;
+
+    fn neg(self) -> Vector4 {
+        Vector4::new(-self.a, -self.b, -self.c, -self.d)
+    }
+}
+
 impl From<[f64; 4]> for Vector4 {
     fn from(array: [f64; 4]) -> Self {
         Vector4::new(array[0], array[1], array[2], array[3])
@@ -188,6 +352,44 @@ impl From<Vector4> for (f64, f64, f64, f64) {
     }
 }
 
+impl PartialEq<[f64; 4]> for Vector4 {
+    fn eq(&self, other: &[f64; 4]) -> bool {
+        self.a == other[0] && self.b == other[1] && self.c == other[2] && self.d == other[3]
+    }
+}
+
+impl PartialEq<Vector4> for [f64; 4] {
+    fn eq(&self, other: &Vector4) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<(f64, f64, f64, f64)> for Vector4 {
+    fn eq(&self, other: &(f64, f64, f64, f64)) -> bool {
+        self.a == other.0 && self.b == other.1 && self.c == other.2 && self.d == other.3
+    }
+}
+
+impl TryFrom<&[f64]> for Vector4 {
+    type Error = VectorError;
+
+    /// Converts a slice with exactly 4 elements
+    fn try_from(values: &[f64]) -> Result<Self, VectorError> {
+        let values = slice_to_array::<4>(values)?;
+        Ok(Vector4::new(values[0], values[1], values[2], values[3]))
+    }
+}
+
+impl FromStr for Vector4 {
+    type Err = VectorError;
+
+    /// Parses 4 comma separated numbers, optionally wrapped in parentheses or brackets
+    fn from_str(s: &str) -> Result<Self, VectorError> {
+        let values = parse_array::<4>(s)?;
+        Ok(Vector4::new(values[0], values[1], values[2], values[3]))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -207,6 +409,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_eq_arrays() {
+        let v = Vector4::new(1., 2., 3., 4.);
+        assert_eq!(v, [1., 2., 3., 4.]);
+        assert_eq!(v, (1., 2., 3., 4.));
+        assert!([1., 2., 3., 5.] != v);
+    }
+
     #[test]
     fn test_add() {
         let a = Vector4::new(1., 2., 3., 4.);
@@ -266,4 +476,35 @@ mod tests {
         let result: (f64, f64, f64, f64) = vector.into();
         assert_eq!(result, (1., 2., 3., 4.));
     }
+
+    #[test]
+    fn test_in_place() {
+        let mut v = Vector4::new(1., -2., 0.5, 0.);
+        v.add_mut(&Vector4::new(1., 1., 1., 1.));
+        assert_eq!(v, Vector4::new(2., -1., 1.5, 1.));
+        v.scale_mut(2.);
+        assert_eq!(v, Vector4::new(4., -2., 3., 2.));
+        v.clamp_mut(-1., 3.);
+        assert_eq!(v, Vector4::new(3., -1., 3., 2.));
+        v = Vector4::new(0., 0., 3., 4.);
+        v.normalize_mut();
+        assert_eq!(v, Vector4::new(0., 0., 0.6, 0.8));
+    }
+
+    #[test]
+    fn test_clamp_length() {
+        let v = Vector4::new(0., 0., 3., 4.);
+        assert_eq!(v.clamp_length(2.5), Vector4::new(0., 0., 1.5, 2.));
+        assert_eq!(
+            v.clamp_length_between(10., 20.),
+            Vector4::new(0., 0., 6., 8.)
+        );
+    }
+
+    #[test]
+    fn test_with_magnitude() {
+        let v = Vector4::new(0., 0., 3., 4.);
+        assert_eq!(v.with_magnitude(10.), Some(Vector4::new(0., 0., 6., 8.)));
+        assert_eq!(Vector4::new(0., 0., 0., 0.).with_magnitude(1.), None);
+    }
 }
@@ -0,0 +1,566 @@
+#[cfg(not(feature = "std"))]
+use crate::math::FloatMath;
+use crate::{lerp, Vector2, Vector3};
+use alloc::vec::Vec;
+
+/// Splits a coordinate measured in cells into the two neighbouring nodes and the blend between
+/// them, clamping positions outside the grid onto its edge
+fn cell_weights(coordinate: f64, nodes: usize) -> (usize, usize, f64) {
+    let last = (nodes - 1) as f64;
+    let clamped = if coordinate.is_nan() {
+        0.
+    } else {
+        coordinate.clamp(0., last)
+    };
+    let lower = (clamped.floor() as usize).min(nodes - 1);
+    let upper = (lower + 1).min(nodes - 1);
+    (lower, upper, clamped - lower as f64)
+}
+
+/// Finds the neighbours used for a finite difference at a node and the distance between them in
+/// cells, falling back to one-sided differences on the edges of the grid
+fn difference_nodes(index: usize, nodes: usize) -> (usize, usize, f64) {
+    let lower = index.saturating_sub(1);
+    let upper = (index + 1).min(nodes - 1);
+    (lower, upper, (upper - lower) as f64)
+}
+
+/// A regular 2D grid of vectors, such as a velocity or wind field
+///
+/// Node `(x, y)` sits at `origin + (x, y) * cell_size`. Between the nodes the field is
+/// interpolated bilinearly, and outside the grid it takes the value of the nearest edge.
+///
+/// ## Example
+///
+/// ```
+/// use libvector::{Vector2, VectorField2};
+///
+/// // A field rotating counter-clockwise around the origin
+/// let field = VectorField2::from_fn(5, 5, Vector2::new(-2., -2.), 1., |p| Vector2::new(-p.y, p.x));
+///
+/// assert_eq!(field.sample(&Vector2::new(0.5, 0.)), Vector2::new(0., 0.5));
+/// assert_eq!(field.curl(2, 2), 2.);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct VectorField2 {
+    width: usize,
+    height: usize,
+    origin: Vector2,
+    cell_size: f64,
+    data: Vec<Vector2>,
+}
+
+/// A regular 3D grid of vectors, such as a velocity or wind field
+///
+/// Node `(x, y, z)` sits at `origin + (x, y, z) * cell_size`. Between the nodes the field is
+/// interpolated trilinearly, and outside the grid it takes the value of the nearest face.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VectorField3 {
+    width: usize,
+    height: usize,
+    depth: usize,
+    origin: Vector3,
+    cell_size: f64,
+    data: Vec<Vector3>,
+}
+
+impl VectorField2 {
+    /// Creates a field with every vector set to zero
+    ///
+    /// ## Arguments
+    ///
+    /// * `width` - The number of nodes along the x axis
+    /// * `height` - The number of nodes along the y axis
+    /// * `origin` - The position of node `(0, 0)`
+    /// * `cell_size` - The distance between neighbouring nodes
+    ///
+    /// ## Returns
+    ///
+    /// A new vector field
+    ///
+    /// ## Panics
+    ///
+    /// If `width` or `height` is zero, or `cell_size` is not positive
+    pub fn new(width: usize, height: usize, origin: Vector2, cell_size: f64) -> VectorField2 {
+        VectorField2::from_fn(width, height, origin, cell_size, |_| Vector2::new(0., 0.))
+    }
+
+    /// Creates a field by evaluating a function at the position of every node
+    ///
+    /// ## Arguments
+    ///
+    /// * `width` - The number of nodes along the x axis
+    /// * `height` - The number of nodes along the y axis
+    /// * `origin` - The position of node `(0, 0)`
+    /// * `cell_size` - The distance between neighbouring nodes
+    /// * `f` - Calculates the vector at a position
+    ///
+    /// ## Returns
+    ///
+    /// A new vector field
+    ///
+    /// ## Panics
+    ///
+    /// If `width` or `height` is zero, or `cell_size` is not positive
+    pub fn from_fn(
+        width: usize,
+        height: usize,
+        origin: Vector2,
+        cell_size: f64,
+        mut f: impl FnMut(Vector2) -> Vector2,
+    ) -> VectorField2 {
+        assert!(width > 0 && height > 0, "a field needs at least one node");
+        assert!(cell_size > 0., "cell size must be positive");
+        let mut data = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                data.push(f(origin + Vector2::new(x as f64, y as f64) * cell_size));
+            }
+        }
+        VectorField2 {
+            width,
+            height,
+            origin,
+            cell_size,
+            data,
+        }
+    }
+
+    /// Gets the number of nodes along the x axis
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Gets the number of nodes along the y axis
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Gets the position of node `(0, 0)`
+    pub fn origin(&self) -> Vector2 {
+        self.origin
+    }
+
+    /// Gets the distance between neighbouring nodes
+    pub fn cell_size(&self) -> f64 {
+        self.cell_size
+    }
+
+    /// Finds where a node lies in the data, panicking if it is outside the grid
+    fn index(&self, x: usize, y: usize) -> usize {
+        assert!(
+            x < self.width && y < self.height,
+            "node is outside the grid"
+        );
+        y * self.width + x
+    }
+
+    /// Gets the vector at a node
+    ///
+    /// ## Panics
+    ///
+    /// If the node is outside the grid
+    pub fn get(&self, x: usize, y: usize) -> Vector2 {
+        self.data[self.index(x, y)]
+    }
+
+    /// Sets the vector at a node
+    ///
+    /// ## Panics
+    ///
+    /// If the node is outside the grid
+    pub fn set(&mut self, x: usize, y: usize, value: Vector2) {
+        let index = self.index(x, y);
+        self.data[index] = value;
+    }
+
+    /// Gets the position of a node
+    pub fn position(&self, x: usize, y: usize) -> Vector2 {
+        self.origin + Vector2::new(x as f64, y as f64) * self.cell_size
+    }
+
+    /// Calculates the field at any position by interpolating bilinearly between the nodes
+    ///
+    /// ## Arguments
+    ///
+    /// * `position` - Where to sample. Positions outside the grid are clamped onto its edge
+    ///
+    /// ## Returns
+    ///
+    /// The interpolated vector
+    pub fn sample(&self, position: &Vector2) -> Vector2 {
+        let local = (*position - self.origin) / self.cell_size;
+        let (x0, x1, tx) = cell_weights(local.x, self.width);
+        let (y0, y1, ty) = cell_weights(local.y, self.height);
+        let bottom = lerp(self.get(x0, y0), self.get(x1, y0), tx);
+        let top = lerp(self.get(x0, y1), self.get(x1, y1), tx);
+        lerp(bottom, top, ty)
+    }
+
+    /// Estimates the derivatives of the field along the x and y axes at a node with central
+    /// differences
+    fn derivatives(&self, x: usize, y: usize) -> (Vector2, Vector2) {
+        let (x0, x1, dx) = difference_nodes(x, self.width);
+        let (y0, y1, dy) = difference_nodes(y, self.height);
+        let ddx = if dx > 0. {
+            (self.get(x1, y) - self.get(x0, y)) / (dx * self.cell_size)
+        } else {
+            Vector2::new(0., 0.)
+        };
+        let ddy = if dy > 0. {
+            (self.get(x, y1) - self.get(x, y0)) / (dy * self.cell_size)
+        } else {
+            Vector2::new(0., 0.)
+        };
+        (ddx, ddy)
+    }
+
+    /// Estimates the divergence of the field at a node, how much it flows outwards
+    ///
+    /// Central differences are used inside the grid and one-sided differences on its edges.
+    /// Along an axis with a single node the derivative is taken to be zero.
+    ///
+    /// ## Returns
+    ///
+    /// The divergence, `dVx/dx + dVy/dy`
+    ///
+    /// ## Panics
+    ///
+    /// If the node is outside the grid
+    pub fn divergence(&self, x: usize, y: usize) -> f64 {
+        self.index(x, y);
+        let (ddx, ddy) = self.derivatives(x, y);
+        ddx.x + ddy.y
+    }
+
+    /// Estimates the curl of the field at a node, how much it rotates counter-clockwise
+    ///
+    /// Derivatives are estimated the same way as in [`VectorField2::divergence`].
+    ///
+    /// ## Returns
+    ///
+    /// The scalar curl, `dVy/dx - dVx/dy`
+    ///
+    /// ## Panics
+    ///
+    /// If the node is outside the grid
+    pub fn curl(&self, x: usize, y: usize) -> f64 {
+        self.index(x, y);
+        let (ddx, ddy) = self.derivatives(x, y);
+        ddx.y - ddy.x
+    }
+
+    /// Iterates over the nodes as pairs of position and vector, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Vector2, &Vector2)> + '_ {
+        self.data
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (self.position(i % self.width, i / self.width), v))
+    }
+
+    /// Iterates mutably over the nodes as pairs of position and vector, row by row
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Vector2, &mut Vector2)> + '_ {
+        let (width, origin, cell_size) = (self.width, self.origin, self.cell_size);
+        self.data.iter_mut().enumerate().map(move |(i, v)| {
+            let node = Vector2::new((i % width) as f64, (i / width) as f64);
+            (origin + node * cell_size, v)
+        })
+    }
+}
+
+impl VectorField3 {
+    /// Creates a field with every vector set to zero
+    ///
+    /// ## Arguments
+    ///
+    /// * `width` - The number of nodes along the x axis
+    /// * `height` - The number of nodes along the y axis
+    /// * `depth` - The number of nodes along the z axis
+    /// * `origin` - The position of node `(0, 0, 0)`
+    /// * `cell_size` - The distance between neighbouring nodes
+    ///
+    /// ## Returns
+    ///
+    /// A new vector field
+    ///
+    /// ## Panics
+    ///
+    /// If `width`, `height` or `depth` is zero, or `cell_size` is not positive
+    pub fn new(
+        width: usize,
+        height: usize,
+        depth: usize,
+        origin: Vector3,
+        cell_size: f64,
+    ) -> VectorField3 {
+        VectorField3::from_fn(width, height, depth, origin, cell_size, |_| {
+            Vector3::new(0., 0., 0.)
+        })
+    }
+
+    /// Creates a field by evaluating a function at the position of every node
+    ///
+    /// ## Arguments
+    ///
+    /// * `width` - The number of nodes along the x axis
+    /// * `height` - The number of nodes along the y axis
+    /// * `depth` - The number of nodes along the z axis
+    /// * `origin` - The position of node `(0, 0, 0)`
+    /// * `cell_size` - The distance between neighbouring nodes
+    /// * `f` - Calculates the vector at a position
+    ///
+    /// ## Returns
+    ///
+    /// A new vector field
+    ///
+    /// ## Panics
+    ///
+    /// If `width`, `height` or `depth` is zero, or `cell_size` is not positive
+    pub fn from_fn(
+        width: usize,
+        height: usize,
+        depth: usize,
+        origin: Vector3,
+        cell_size: f64,
+        mut f: impl FnMut(Vector3) -> Vector3,
+    ) -> VectorField3 {
+        assert!(
+            width > 0 && height > 0 && depth > 0,
+            "a field needs at least one node"
+        );
+        assert!(cell_size > 0., "cell size must be positive");
+        let mut data = Vec::with_capacity(width * height * depth);
+        for z in 0..depth {
+            for y in 0..height {
+                for x in 0..width {
+                    let node = Vector3::new(x as f64, y as f64, z as f64);
+                    data.push(f(origin + node * cell_size));
+                }
+            }
+        }
+        VectorField3 {
+            width,
+            height,
+            depth,
+            origin,
+            cell_size,
+            data,
+        }
+    }
+
+    /// Gets the number of nodes along the x axis
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Gets the number of nodes along the y axis
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Gets the number of nodes along the z axis
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Gets the position of node `(0, 0, 0)`
+    pub fn origin(&self) -> Vector3 {
+        self.origin
+    }
+
+    /// Gets the distance between neighbouring nodes
+    pub fn cell_size(&self) -> f64 {
+        self.cell_size
+    }
+
+    /// Finds where a node lies in the data, panicking if it is outside the grid
+    fn index(&self, x: usize, y: usize, z: usize) -> usize {
+        assert!(
+            x < self.width && y < self.height && z < self.depth,
+            "node is outside the grid"
+        );
+        (z * self.height + y) * self.width + x
+    }
+
+    /// Gets the vector at a node
+    ///
+    /// ## Panics
+    ///
+    /// If the node is outside the grid
+    pub fn get(&self, x: usize, y: usize, z: usize) -> Vector3 {
+        self.data[self.index(x, y, z)]
+    }
+
+    /// Sets the vector at a node
+    ///
+    /// ## Panics
+    ///
+    /// If the node is outside the grid
+    pub fn set(&mut self, x: usize, y: usize, z: usize, value: Vector3) {
+        let index = self.index(x, y, z);
+        self.data[index] = value;
+    }
+
+    /// Gets the position of a node
+    pub fn position(&self, x: usize, y: usize, z: usize) -> Vector3 {
+        self.origin + Vector3::new(x as f64, y as f64, z as f64) * self.cell_size
+    }
+
+    /// Calculates the field at any position by interpolating trilinearly between the nodes
+    ///
+    /// ## Arguments
+    ///
+    /// * `position` - Where to sample. Positions outside the grid are clamped onto its faces
+    ///
+    /// ## Returns
+    ///
+    /// The interpolated vector
+    pub fn sample(&self, position: &Vector3) -> Vector3 {
+        let local = (*position - self.origin) / self.cell_size;
+        let (x0, x1, tx) = cell_weights(local.x, self.width);
+        let (y0, y1, ty) = cell_weights(local.y, self.height);
+        let (z0, z1, tz) = cell_weights(local.z, self.depth);
+        let layer = |z| {
+            let bottom = lerp(self.get(x0, y0, z), self.get(x1, y0, z), tx);
+            let top = lerp(self.get(x0, y1, z), self.get(x1, y1, z), tx);
+            lerp(bottom, top, ty)
+        };
+        lerp(layer(z0), layer(z1), tz)
+    }
+
+    /// Estimates the derivatives of the field along the x, y and z axes at a node with central
+    /// differences
+    fn derivatives(&self, x: usize, y: usize, z: usize) -> [Vector3; 3] {
+        let (x0, x1, dx) = difference_nodes(x, self.width);
+        let (y0, y1, dy) = difference_nodes(y, self.height);
+        let (z0, z1, dz) = difference_nodes(z, self.depth);
+        let derivative = |lower: Vector3, upper: Vector3, distance: f64| {
+            if distance > 0. {
+                (upper - lower) / (distance * self.cell_size)
+            } else {
+                Vector3::new(0., 0., 0.)
+            }
+        };
+        [
+            derivative(self.get(x0, y, z), self.get(x1, y, z), dx),
+            derivative(self.get(x, y0, z), self.get(x, y1, z), dy),
+            derivative(self.get(x, y, z0), self.get(x, y, z1), dz),
+        ]
+    }
+
+    /// Estimates the divergence of the field at a node, how much it flows outwards
+    ///
+    /// Central differences are used inside the grid and one-sided differences on its faces.
+    /// Along an axis with a single node the derivative is taken to be zero.
+    ///
+    /// ## Returns
+    ///
+    /// The divergence, `dVx/dx + dVy/dy + dVz/dz`
+    ///
+    /// ## Panics
+    ///
+    /// If the node is outside the grid
+    pub fn divergence(&self, x: usize, y: usize, z: usize) -> f64 {
+        self.index(x, y, z);
+        let [ddx, ddy, ddz] = self.derivatives(x, y, z);
+        ddx.x + ddy.y + ddz.z
+    }
+
+    /// Estimates the curl of the field at a node, the axis and rate of its local rotation
+    ///
+    /// Derivatives are estimated the same way as in [`VectorField3::divergence`].
+    ///
+    /// ## Returns
+    ///
+    /// The curl vector
+    ///
+    /// ## Panics
+    ///
+    /// If the node is outside the grid
+    pub fn curl(&self, x: usize, y: usize, z: usize) -> Vector3 {
+        self.index(x, y, z);
+        let [ddx, ddy, ddz] = self.derivatives(x, y, z);
+        Vector3::new(ddy.z - ddz.y, ddz.x - ddx.z, ddx.y - ddy.x)
+    }
+
+    /// Iterates over the nodes as pairs of position and vector, row by row and then layer by
+    /// layer
+    pub fn iter(&self) -> impl Iterator<Item = (Vector3, &Vector3)> + '_ {
+        let (width, height) = (self.width, self.height);
+        self.data.iter().enumerate().map(move |(i, v)| {
+            let (x, y, z) = (i % width, i / width % height, i / (width * height));
+            (self.position(x, y, z), v)
+        })
+    }
+
+    /// Iterates mutably over the nodes as pairs of position and vector, row by row and then
+    /// layer by layer
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Vector3, &mut Vector3)> + '_ {
+        let (width, height) = (self.width, self.height);
+        let (origin, cell_size) = (self.origin, self.cell_size);
+        self.data.iter_mut().enumerate().map(move |(i, v)| {
+            let node = Vector3::new(
+                (i % width) as f64,
+                (i / width % height) as f64,
+                (i / (width * height)) as f64,
+            );
+            (origin + node * cell_size, v)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample2() {
+        let mut field = VectorField2::new(2, 2, Vector2::new(1., 1.), 2.);
+        field.set(1, 1, Vector2::new(4., -4.));
+        assert_eq!(field.sample(&Vector2::new(2., 2.)), Vector2::new(1., -1.));
+        assert_eq!(field.sample(&Vector2::new(3., 3.)), Vector2::new(4., -4.));
+        // Outside the grid the nearest edge is used
+        assert_eq!(field.sample(&Vector2::new(9., 9.)), Vector2::new(4., -4.));
+        assert_eq!(field.sample(&Vector2::new(-9., 3.)), Vector2::new(0., 0.));
+    }
+
+    #[test]
+    fn test_divergence_and_curl2() {
+        // An outward flow with divergence 2 and no rotation
+        let field = VectorField2::from_fn(4, 3, Vector2::new(0., 0.), 0.5, |p| p);
+        for (x, y) in [(0, 0), (1, 1), (3, 2)] {
+            assert_eq!(field.divergence(x, y), 2.);
+            assert_eq!(field.curl(x, y), 0.);
+        }
+        let single = VectorField2::new(1, 1, Vector2::new(0., 0.), 1.);
+        assert_eq!(single.divergence(0, 0), 0.);
+    }
+
+    #[test]
+    fn test_field3() {
+        // A rotation around the z axis with curl (0, 0, 2)
+        let mut field = VectorField3::from_fn(3, 3, 3, Vector3::new(-1., -1., -1.), 1., |p| {
+            Vector3::new(-p.y, p.x, 0.)
+        });
+        assert_eq!(field.curl(1, 1, 1), Vector3::new(0., 0., 2.));
+        assert_eq!(field.divergence(0, 2, 1), 0.);
+        assert_eq!(
+            field.sample(&Vector3::new(0.5, 0.5, 0.25)),
+            Vector3::new(-0.5, 0.5, 0.)
+        );
+        assert_eq!(field.get(2, 0, 1), Vector3::new(1., 1., 0.));
+
+        let (position, _) = field.iter().nth(5).unwrap();
+        assert_eq!(position, Vector3::new(1., 0., -1.));
+        for (position, v) in field.iter_mut() {
+            *v = position;
+        }
+        assert_eq!(field.divergence(1, 1, 1), 3.);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_outside_grid() {
+        VectorField2::new(2, 2, Vector2::new(0., 0.), 1.).get(2, 0);
+    }
+}
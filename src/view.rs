@@ -0,0 +1,271 @@
+#[cfg(not(feature = "std"))]
+use crate::math::FloatMath;
+use crate::DynamicVector;
+
+/// A read-only view of every `stride`-th element of a [`DynamicVector`], starting at `start`
+///
+/// Created with [`DynamicVector::strided_view`]. Views of interleaved data, such as the `x`
+/// components of `xyzxyz...` positions, can be measured and multiplied without first copying
+/// them out.
+#[derive(Debug, Clone, Copy)]
+pub struct StridedView<'a> {
+    data: &'a [f64],
+    start: usize,
+    stride: usize,
+    length: usize,
+}
+
+/// A read-only view of the elements of a [`DynamicVector`] whose mask entry is `true`
+///
+/// Created with [`DynamicVector::masked_view`].
+#[derive(Debug, Clone, Copy)]
+pub struct MaskedView<'a> {
+    data: &'a [f64],
+    mask: &'a [bool],
+    length: usize,
+}
+
+impl DynamicVector {
+    /// Creates a view of every `stride`-th element, starting at `start`
+    ///
+    /// ## Arguments
+    ///
+    /// * `start` - The index of the first element in the view
+    /// * `stride` - The distance between consecutive elements of the view
+    ///
+    /// ## Returns
+    ///
+    /// The view, which is empty if `start` is past the end of the vector
+    ///
+    /// ## Panics
+    ///
+    /// If `stride` is zero
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::DynamicVector;
+    ///
+    /// // Two points stored as x, y, z, x, y, z
+    /// let positions = DynamicVector::from(vec![1., 2., 3., 4., 5., 6.]);
+    /// let ys = positions.strided_view(1, 3);
+    ///
+    /// assert_eq!(ys.len(), 2);
+    /// assert_eq!(ys.get(1), 5.);
+    /// assert_eq!(ys.dot(&positions.strided_view(0, 3)), 2. * 1. + 5. * 4.);
+    /// ```
+    pub fn strided_view(&self, start: usize, stride: usize) -> StridedView<'_> {
+        assert!(stride > 0, "the stride of a view must be at least 1");
+        let data = self.as_slice();
+        let length = if start < data.len() {
+            (data.len() - start - 1) / stride + 1
+        } else {
+            0
+        };
+        StridedView {
+            data,
+            start,
+            stride,
+            length,
+        }
+    }
+
+    /// Creates a view of the elements selected by a mask
+    ///
+    /// ## Arguments
+    ///
+    /// * `mask` - One entry per element, `true` for the elements to include
+    ///
+    /// ## Returns
+    ///
+    /// The view
+    ///
+    /// ## Panics
+    ///
+    /// If `mask` has a different length than the vector
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use libvector::DynamicVector;
+    ///
+    /// let v = DynamicVector::from(vec![3., -1., 4., -1.]);
+    /// let mask: Vec<bool> = v.as_slice().iter().map(|&x| x > 0.).collect();
+    /// let positive = v.masked_view(&mask);
+    ///
+    /// assert_eq!(positive.magnitude(), 5.);
+    /// ```
+    pub fn masked_view<'a>(&'a self, mask: &'a [bool]) -> MaskedView<'a> {
+        let data = self.as_slice();
+        assert_eq!(
+            data.len(),
+            mask.len(),
+            "the mask must have one entry per element"
+        );
+        MaskedView {
+            data,
+            mask,
+            length: mask.iter().filter(|&&m| m).count(),
+        }
+    }
+}
+
+// Implements the read-only vector operations shared by every view type.
+macro_rules! view_operations {
+    ($View:ident) => {
+        impl<'a> $View<'a> {
+            /// Gets the number of elements in the view
+            pub fn len(&self) -> usize {
+                self.length
+            }
+
+            /// Checks whether the view has no elements
+            pub fn is_empty(&self) -> bool {
+                self.length == 0
+            }
+
+            /// Calculates the dot product of two views
+            ///
+            /// ## Arguments
+            ///
+            /// * `other` - The other view
+            ///
+            /// ## Returns
+            ///
+            /// The dot product of the two views
+            ///
+            /// ## Panics
+            ///
+            /// If the views have different lengths
+            pub fn dot(&self, other: &$View) -> f64 {
+                assert_eq!(self.len(), other.len(), "views must have the same length");
+                self.iter().zip(other.iter()).map(|(a, b)| a * b).sum()
+            }
+
+            /// Calculates the magnitude of the view
+            pub fn magnitude(&self) -> f64 {
+                self.iter().map(|a| a * a).sum::<f64>().sqrt()
+            }
+
+            /// Calculates the sum of the elements of the view
+            pub fn sum(&self) -> f64 {
+                self.iter().sum()
+            }
+
+            /// Copies the elements of the view into a new vector
+            pub fn to_vector(&self) -> DynamicVector {
+                self.iter().collect()
+            }
+        }
+    };
+}
+
+view_operations!(StridedView);
+view_operations!(MaskedView);
+
+impl<'a> StridedView<'a> {
+    /// Gets a value from the view
+    ///
+    /// ## Arguments
+    ///
+    /// * `index` - The index of the value within the view
+    ///
+    /// ## Returns
+    ///
+    /// The value at the specified index
+    ///
+    /// ## Panics
+    ///
+    /// If `index` is out of bounds
+    pub fn get(&self, index: usize) -> f64 {
+        assert!(index < self.length, "index out of bounds");
+        self.data[self.start + index * self.stride]
+    }
+
+    /// Iterates over the elements of the view
+    pub fn iter(&self) -> impl Iterator<Item = f64> + 'a {
+        let data: &'a [f64] = self.data;
+        data.iter().skip(self.start).step_by(self.stride).copied()
+    }
+}
+
+impl<'a> MaskedView<'a> {
+    /// Gets a value from the view
+    ///
+    /// Finding an element means counting through the mask, so this takes `O(n)` time. Use
+    /// [`MaskedView::iter`] to visit every element.
+    ///
+    /// ## Arguments
+    ///
+    /// * `index` - The index of the value within the view
+    ///
+    /// ## Returns
+    ///
+    /// The value at the specified index
+    ///
+    /// ## Panics
+    ///
+    /// If `index` is out of bounds
+    pub fn get(&self, index: usize) -> f64 {
+        self.iter().nth(index).expect("index out of bounds")
+    }
+
+    /// Iterates over the elements of the view
+    pub fn iter(&self) -> impl Iterator<Item = f64> + 'a {
+        let (data, mask): (&'a [f64], &'a [bool]) = (self.data, self.mask);
+        data.iter()
+            .zip(mask)
+            .filter(|(_, &m)| m)
+            .map(|(&value, _)| value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_strided_view() {
+        let v: DynamicVector = (0..10).map(|i| i as f64).collect();
+        let view = v.strided_view(2, 3);
+        assert_eq!(view.len(), 3);
+        assert_eq!(view.to_vector(), [2., 5., 8.]);
+        assert_eq!(view.get(2), 8.);
+        assert_eq!(view.sum(), 15.);
+        assert_eq!(v.strided_view(9, 4).to_vector(), [9.]);
+        assert!(v.strided_view(10, 1).is_empty());
+        assert!(v.strided_view(20, 2).is_empty());
+        assert_eq!(
+            v.strided_view(0, 1).magnitude(),
+            v.as_slice().iter().map(|a| a * a).sum::<f64>().sqrt()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_strided_view_out_of_bounds() {
+        let v = DynamicVector::from(vec![1., 2., 3., 4.]);
+        // Index 2 of the view would be element 4, one past the end
+        v.strided_view(0, 2).get(2);
+    }
+
+    #[test]
+    fn test_masked_view() {
+        let v = DynamicVector::from(vec![1., 2., 3., 4.]);
+        let mask = [true, false, false, true];
+        let view = v.masked_view(&mask);
+        assert_eq!(view.len(), 2);
+        assert_eq!(view.get(1), 4.);
+        assert_eq!(view.to_vector(), [1., 4.]);
+        let other = [false, true, true, false];
+        assert_eq!(view.dot(&v.masked_view(&other)), 1. * 2. + 4. * 3.);
+        assert!(v.masked_view(&[false; 4]).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_masked_view_length_mismatch() {
+        DynamicVector::from(vec![1., 2.]).masked_view(&[true]);
+    }
+}
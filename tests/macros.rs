@@ -0,0 +1,159 @@
+use libvector::{vector, DynamicVector, Float, Vector, Vector2, Vector3, Vector4};
+
+vector!(5);
+
+vector!(Velocity<T: Float> { x, y, z });
+
+#[derive(Vector, Debug, PartialEq, Clone, Copy)]
+struct Wind {
+    u: f64,
+    v: f64,
+    w: f64,
+}
+
+#[derive(Vector, Debug, PartialEq, Clone, Copy)]
+struct Mixed(f32, f64);
+
+#[test]
+fn test_sized_vector_operators() {
+    let a = Vector5::new(1., 2., 3., 4., 5.);
+    let b = Vector5::from([1.; 5]);
+
+    assert_eq!(a + b, Vector5::new(2., 3., 4., 5., 6.));
+    assert_eq!(a - b, Vector5::new(0., 1., 2., 3., 4.));
+    assert_eq!(a * 2., Vector5::new(2., 4., 6., 8., 10.));
+    assert_eq!(a / 2., Vector5::new(0.5, 1., 1.5, 2., 2.5));
+    assert_eq!(-a, Vector5::new(-1., -2., -3., -4., -5.));
+    assert!(b < a);
+}
+
+#[test]
+fn test_sized_vector_trait() {
+    let a = Vector5::new(3., 0., 0., 4., 0.);
+
+    assert_eq!(a.dot(&Vector5::from([1.; 5])), 7.);
+    assert_eq!(a.magnitude(), 5.);
+    assert_eq!(a.normalize(), Vector5::new(0.6, 0., 0., 0.8, 0.));
+    assert_eq!(Vector5::zero(), Vector5::from([0.; 5]));
+}
+
+#[test]
+fn test_sized_vector_conversions() {
+    let a = Vector5::from([1., 2., 3., 4., 5.]);
+    let array: [f64; 5] = a.into();
+
+    assert_eq!(a.e, 5.);
+    assert_eq!(array, [1., 2., 3., 4., 5.]);
+    assert_eq!(a.to_string(), "(1, 2, 3, 4, 5)");
+    assert_eq!((a * 0.5).to_string(), "(0.5, 1, 1.5, 2, 2.5)");
+}
+
+#[test]
+fn test_literals() {
+    assert_eq!(vector![1., 2.], Vector2::new(1., 2.));
+    assert_eq!(vector![1., 2., 3.], Vector3::new(1., 2., 3.));
+    assert_eq!(vector![1., 2., 3., 4.], Vector4::new(1., 2., 3., 4.));
+
+    let five: DynamicVector = vector![1., 2., 3., 4., 5.];
+    assert_eq!(five.as_slice(), &[1., 2., 3., 4., 5.]);
+    let one: DynamicVector = vector![5.];
+    assert_eq!(one.as_slice(), &[5.]);
+    let x = 2.;
+    assert_eq!(vector![x * 2., -x], Vector2::new(4., -2.));
+}
+
+#[test]
+fn test_generic_scalar() {
+    let single: Velocity<f32> = Velocity::new(0., 3., 4.);
+    let double = Velocity::new(0., 3., 4.) * 2_f64;
+
+    assert_eq!(single.magnitude(), 5.);
+    assert_eq!(single.normalize(), Velocity::new(0., 0.6, 0.8));
+    assert_eq!(double.z, 8.);
+    assert_eq!(double.dot(&Velocity::from([1., 1., 1.])), 14.);
+    assert_eq!(single + single, single * 2.);
+    assert_eq!(single - single, Velocity::zero());
+    assert_eq!(-single / 2., Velocity::new(-0., -1.5, -2.));
+
+    let array: [f32; 3] = single.into();
+    assert_eq!(array, [0., 3., 4.]);
+    assert_eq!(single.to_string(), "(0, 3, 4)");
+}
+
+#[test]
+fn test_derive() {
+    let gust = Wind {
+        u: 3.,
+        v: 4.,
+        w: 0.,
+    };
+    let calm = Wind::zero();
+
+    assert_eq!(gust.magnitude(), 5.);
+    assert_eq!(gust.dot(&gust), 25.);
+    assert_eq!(
+        gust.normalize(),
+        Wind {
+            u: 0.6,
+            v: 0.8,
+            w: 0.
+        }
+    );
+    assert_eq!(gust + calm, gust);
+    assert_eq!(gust - gust, calm);
+    assert_eq!(
+        gust * 2.,
+        Wind {
+            u: 6.,
+            v: 8.,
+            w: 0.
+        }
+    );
+    assert_eq!(
+        gust / 2.,
+        Wind {
+            u: 1.5,
+            v: 2.,
+            w: 0.
+        }
+    );
+    assert_eq!(
+        -gust,
+        Wind {
+            u: -3.,
+            v: -4.,
+            w: -0.
+        }
+    );
+
+    let mixed = Mixed(3., 4.);
+    assert_eq!(mixed.magnitude(), 5.);
+    assert_eq!(mixed * 0.5, Mixed(1.5, 2.));
+}
+
+/// The generated code must not depend on what the caller has imported
+mod without_imports {
+    libvector::vector!(6);
+
+    libvector::vector!(Speed<T: libvector::Float> { x, y });
+
+    #[derive(libvector::Vector, Debug, PartialEq, Clone, Copy)]
+    struct Drift {
+        x: f64,
+        y: f64,
+    }
+
+    #[test]
+    fn test_expansions() {
+        let v = Vector6::from([2.; 6]);
+        assert_eq!(libvector::Vector::dot(&v, &v), 24.);
+        let w = Vector6::new(0., 0., 0., 0., 3., 4.);
+        assert_eq!(libvector::Vector::normalize(&w).f, 0.8);
+
+        let s = Speed::new(3_f32, 4.);
+        assert_eq!(libvector::Vector::normalize(&s), Speed::new(0.6, 0.8));
+
+        let d = Drift { x: 0., y: 2. };
+        assert_eq!(libvector::Vector::normalize(&d), Drift { x: 0., y: 1. });
+    }
+}
@@ -2,6 +2,9 @@ use proc_macro::TokenStream;
 use quote::{format_ident, quote, ToTokens};
 use syn;
 
+// The examples in this crate are marked `ignore`, as a proc-macro crate's doctests cannot depend
+// on libvector. They are expanded and checked by libvector's `tests/macros.rs` instead.
+
 fn letter_by_index(index: i32) -> char {
     if index > 25 {
         panic!("Above maximum length of Vector. Vector must be at most 26 fields")
@@ -24,19 +27,267 @@ fn letter_by_index(index: i32) -> char {
 ///
 /// The struct generated will be called Vector + the length given. So `vector!(5)` will create a struct called Vector5
 ///
+/// ## Generated implementations
+///
+/// Like the hand-written vector types, the struct derives `Debug`, `PartialEq`, `PartialOrd`,
+/// `Clone` and `Copy`, and implements `Vector`, `Add`, `Sub`, `Mul<f64>`, `Div<f64>` and `Neg`. It
+/// can be converted to and from an array of its length, and is displayed as `(a, b, ...)`.
+///
+/// ## Vector literals
+///
+/// Given a list of values instead of a length, as in `vector![1., 2., 3.]`, the macro builds a
+/// vector like `vec!` builds a `Vec`. Two, three and four values create a `Vector2`, `Vector3`
+/// and `Vector4` respectively, and any other number of values creates a `DynamicVector`. A single
+/// value must be written as a float, such as `vector![5.]`, as a lone integer defines a struct.
+///
+/// ## Generic scalars
+///
+/// A struct with named fields whose components are any [`Float`](../libvector/trait.Float.html)
+/// type can be defined with `vector!(Name<T: Float> { x, y, z })`. It gets the same
+/// implementations as a `VectorN` struct, with `Mul` and `Div` taking a `T`. The `Vector` methods
+/// still work in `f64`, so `dot` and `magnitude` convert their result.
+///
+/// ```ignore
+/// use libvector::{vector, Float, Vector};
+///
+/// vector!(Velocity<T: Float> { x, y, z });
+///
+/// let single: Velocity<f32> = Velocity::new(0., 3., 4.);
+/// let double = Velocity::new(0., 3., 4.) * 2_f64;
+///
+/// assert_eq!(single.magnitude(), 5.);
+/// assert_eq!(double.z, 8.);
+/// ```
+///
 /// ## Examples
 ///
+/// ```ignore
+/// use libvector::{vector, Vector3};
+///
+/// assert_eq!(vector![1., 2., 3.], Vector3::new(1., 2., 3.));
+/// assert_eq!(vector![1., 2., 3., 4., 5.].as_slice(), &[1., 2., 3., 4., 5.]);
 /// ```
+///
+/// ```ignore
 /// // Import both the macro and the shared Vector trait
-/// use libvector::{vector, Vector}
+/// use libvector::{vector, Vector};
 ///
-/// // Generates a Vector with 4 fields called `Vector5`
+/// // Generates a Vector with 5 fields called `Vector5`
 /// vector!(5);
+///
+/// let v = Vector5::from([1., 2., 3., 4., 5.]) * 2.;
+///
+/// assert_eq!(v.e, 10.);
+/// assert_eq!(-v, Vector5::new(-2., -4., -6., -8., -10.));
+/// assert_eq!(v.to_string(), "(2, 4, 6, 8, 10)");
 /// ```
 #[proc_macro]
 pub fn vector_macro(input: TokenStream) -> TokenStream {
-    let input = proc_macro2::TokenStream::from(input);
-    let literal: syn::LitInt = syn::parse2(input).unwrap();
+    if let Ok(literal) = syn::parse::<syn::LitInt>(input.clone()) {
+        return define_vector(literal);
+    }
+    match syn::parse::<GenericVector>(input.clone()) {
+        Ok(definition) => define_generic_vector(definition),
+        Err(_) => construct_vector(input),
+    }
+}
+
+/// The input to `vector!(Name<T: Float> { x, y, z })`
+struct GenericVector {
+    ident: syn::Ident,
+    generics: syn::Generics,
+    fields: Vec<syn::Ident>,
+}
+
+impl syn::parse::Parse for GenericVector {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let ident = input.parse()?;
+        let generics = input.parse()?;
+        let content;
+        syn::braced!(content in input);
+        let fields = content
+            .parse_terminated(<syn::Ident as syn::parse::Parse>::parse, syn::Token![,])?
+            .into_iter()
+            .collect();
+        Ok(GenericVector {
+            ident,
+            generics,
+            fields,
+        })
+    }
+}
+
+/// Generates a vector struct with the given fields whose components have a generic scalar type
+fn define_generic_vector(definition: GenericVector) -> TokenStream {
+    let GenericVector {
+        ident: title,
+        generics,
+        fields,
+    } = definition;
+
+    let scalar = match generics.type_params().collect::<Vec<_>>()[..] {
+        [param] => param.ident.clone(),
+        _ => {
+            return syn::Error::new_spanned(
+                &generics,
+                "Generic vectors must have exactly one type parameter for the scalar",
+            )
+            .to_compile_error()
+            .into()
+        }
+    };
+    if fields.len() < 2 {
+        return syn::Error::new_spanned(&title, "Vector length must be greater than one")
+            .to_compile_error()
+            .into();
+    }
+
+    // Every impl additionally needs the scalar to behave like a float
+    let mut impl_generics = generics.clone();
+    impl_generics
+        .make_where_clause()
+        .predicates
+        .push(syn::parse_quote!(#scalar: ::libvector::Float));
+    let (impl_generics, type_generics, where_clause) = impl_generics.split_for_impl();
+
+    let length = fields.len();
+    let indices = 0..length;
+    let display_format = format!("({})", vec!["{}"; length].join(", "));
+
+    let expanded = quote! {
+        #[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+        #[repr(C)]
+        pub struct #title #generics {
+            #(pub #fields: #scalar,)*
+        }
+
+        impl #impl_generics #title #type_generics #where_clause {
+            pub fn new(#(#fields: #scalar),*) -> Self {
+                Self { #(#fields,)* }
+            }
+        }
+
+        impl #impl_generics ::libvector::Vector for #title #type_generics #where_clause {
+            fn dot(&self, other: &Self) -> f64 {
+                let dot = <#scalar as ::libvector::Float>::ZERO #(+ self.#fields * other.#fields)*;
+                ::libvector::Float::to_f64(dot)
+            }
+
+            fn magnitude(&self) -> f64 {
+                ::libvector::Float::sqrt(::libvector::Vector::dot(self, self))
+            }
+
+            fn normalize(&self) -> Self {
+                let mag = ::libvector::Vector::magnitude(self);
+                let mag = <#scalar as ::libvector::Float>::from_f64(mag);
+                Self {
+                    #(#fields: self.#fields / mag,)*
+                }
+            }
+
+            fn zero() -> Self {
+                Self {
+                    #(#fields: <#scalar as ::libvector::Float>::ZERO,)*
+                }
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<[#scalar; #length]> for #title #type_generics #where_clause {
+            fn from(arr: [#scalar; #length]) -> Self {
+                Self {
+                    #(#fields: arr[#indices],)*
+                }
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<#title #type_generics> for [#scalar; #length] #where_clause {
+            fn from(vector: #title #type_generics) -> Self {
+                [#(vector.#fields),*]
+            }
+        }
+
+        impl #impl_generics ::core::ops::Add for #title #type_generics #where_clause {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self {
+                    #(#fields: self.#fields + other.#fields,)*
+                }
+            }
+        }
+
+        impl #impl_generics ::core::ops::Sub for #title #type_generics #where_clause {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self {
+                    #(#fields: self.#fields - other.#fields,)*
+                }
+            }
+        }
+
+        impl #impl_generics ::core::ops::Mul<#scalar> for #title #type_generics #where_clause {
+            type Output = Self;
+
+            fn mul(self, scalar: #scalar) -> Self {
+                Self {
+                    #(#fields: self.#fields * scalar,)*
+                }
+            }
+        }
+
+        impl #impl_generics ::core::ops::Div<#scalar> for #title #type_generics #where_clause {
+            type Output = Self;
+
+            fn div(self, scalar: #scalar) -> Self {
+                Self {
+                    #(#fields: self.#fields / scalar,)*
+                }
+            }
+        }
+
+        impl #impl_generics ::core::ops::Neg for #title #type_generics #where_clause {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self {
+                    #(#fields: -self.#fields,)*
+                }
+            }
+        }
+
+        impl #impl_generics ::core::fmt::Display for #title #type_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                write!(f, #display_format, #(self.#fields),*)
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// Expands `vector![...]` into a constructor call for the type matching the number of elements
+fn construct_vector(input: TokenStream) -> TokenStream {
+    let parser = syn::punctuated::Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated;
+    let elements = match syn::parse::Parser::parse(parser, input) {
+        Ok(elements) => elements.into_iter().collect::<Vec<_>>(),
+        Err(error) => return error.to_compile_error().into(),
+    };
+
+    let expanded = match elements.len() {
+        2 => quote! { ::libvector::Vector2::new(#(#elements),*) },
+        3 => quote! { ::libvector::Vector3::new(#(#elements),*) },
+        4 => quote! { ::libvector::Vector4::new(#(#elements),*) },
+        _ => quote! {
+            <::libvector::DynamicVector as ::core::iter::FromIterator<f64>>::from_iter([#(#elements),*])
+        },
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// Generates the `VectorN` struct for `vector!(N)`
+fn define_vector(literal: syn::LitInt) -> TokenStream {
     let length: i32 = literal.base10_parse().unwrap();
     let mut fields = quote! {};
     let mut args = quote! {};
@@ -45,9 +296,11 @@ pub fn vector_macro(input: TokenStream) -> TokenStream {
     let mut dot_product_statement = quote! {};
     let mut magnitude_statement = quote! {let magnitude_square = };
     let mut normalize_statements = quote! {};
+    let mut names = Vec::new();
 
     for i in 0..length {
         let name = format_ident!("{}", letter_by_index(i));
+        names.push(name.clone());
         let field = quote! {
             pub #name: f64,
         };
@@ -82,18 +335,24 @@ pub fn vector_macro(input: TokenStream) -> TokenStream {
     quote! {0.}.to_tokens(&mut dot_product_statement);
     quote! {0.}.to_tokens(&mut magnitude_statement);
 
-    quote! {; magnitude_square.sqrt()}.to_tokens(&mut magnitude_statement);
+    quote! {; ::libvector::Float::sqrt(magnitude_square)}.to_tokens(&mut magnitude_statement);
 
     let normalize_func = quote! {
-        let mag = self.magnitude();
+        let mag = ::libvector::Vector::magnitude(self);
         Self {
             #normalize_statements
         }
     };
 
     let title = format_ident!("Vector{}", length as u32);
+    let array_length = length as usize;
+    let indices = 0..array_length;
+    let display_format = vec!["{}"; array_length].join(", ");
+    let display_format = format!("({})", display_format);
 
     let expanded = quote! {
+        #[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+        #[repr(C)]
         pub struct #title {
             #fields
         }
@@ -106,7 +365,7 @@ pub fn vector_macro(input: TokenStream) -> TokenStream {
             }
         }
 
-        impl Vector for #title {
+        impl ::libvector::Vector for #title {
             fn dot(&self, other: &Self) -> f64 {
                 #dot_product_statement
             }
@@ -118,6 +377,228 @@ pub fn vector_macro(input: TokenStream) -> TokenStream {
             fn normalize(&self) -> Self {
                 #normalize_func
             }
+
+            fn zero() -> Self {
+                Self::from([0.; #array_length])
+            }
+        }
+
+        impl ::core::convert::From<[f64; #array_length]> for #title {
+            fn from(arr: [f64; #array_length]) -> Self {
+                Self {
+                    #(#names: arr[#indices],)*
+                }
+            }
+        }
+
+        impl ::core::convert::From<#title> for [f64; #array_length] {
+            fn from(vector: #title) -> Self {
+                [#(vector.#names),*]
+            }
+        }
+
+        impl ::core::ops::Add for #title {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self {
+                    #(#names: self.#names + other.#names,)*
+                }
+            }
+        }
+
+        impl ::core::ops::Sub for #title {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self {
+                    #(#names: self.#names - other.#names,)*
+                }
+            }
+        }
+
+        impl ::core::ops::Mul<f64> for #title {
+            type Output = Self;
+
+            fn mul(self, scalar: f64) -> Self {
+                Self {
+                    #(#names: self.#names * scalar,)*
+                }
+            }
+        }
+
+        impl ::core::ops::Div<f64> for #title {
+            type Output = Self;
+
+            fn div(self, scalar: f64) -> Self {
+                Self {
+                    #(#names: self.#names / scalar,)*
+                }
+            }
+        }
+
+        impl ::core::ops::Neg for #title {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self {
+                    #(#names: -self.#names,)*
+                }
+            }
+        }
+
+        impl ::core::fmt::Display for #title {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                write!(f, #display_format, #(self.#names),*)
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// Returns the float type of a field, or `None` if it is not `f32` or `f64`
+fn float_type(ty: &syn::Type) -> Option<&syn::Ident> {
+    match ty {
+        syn::Type::Path(path) if path.qself.is_none() => path
+            .path
+            .get_ident()
+            .filter(|ident| *ident == "f32" || *ident == "f64"),
+        _ => None,
+    }
+}
+
+/// Implements `Vector` and arithmetic for a struct whose fields are all floats
+///
+/// Every field must be an `f32` or an `f64`. The generated code implements `Vector`, treating
+/// each field as one component, as well as `Add`, `Sub`, `Mul<f64>`, `Div<f64>` and `Neg`,
+/// which act on every field. This lets domain types take part in the crate's math without
+/// converting to and from one of the built-in vector types.
+///
+/// ## Example
+///
+/// ```ignore
+/// use libvector::Vector;
+///
+/// #[derive(Vector, Debug, PartialEq, Clone, Copy)]
+/// struct Wind {
+///     u: f64,
+///     v: f64,
+///     w: f64,
+/// }
+///
+/// let gust = Wind { u: 3., v: 4., w: 0. };
+///
+/// assert_eq!(gust.magnitude(), 5.);
+/// assert_eq!(gust * 2., Wind { u: 6., v: 8., w: 0. });
+/// ```
+#[proc_macro_derive(Vector)]
+pub fn derive_vector(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    let title = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let fields = match &input.data {
+        syn::Data::Struct(data) if !data.fields.is_empty() => &data.fields,
+        _ => {
+            return syn::Error::new_spanned(
+                &input.ident,
+                "Vector can only be derived for structs with at least one field",
+            )
+            .to_compile_error()
+            .into()
+        }
+    };
+
+    let mut members = Vec::new();
+    let mut types = Vec::new();
+    for (i, field) in fields.iter().enumerate() {
+        match float_type(&field.ty) {
+            Some(ty) => types.push(ty),
+            None => {
+                return syn::Error::new_spanned(&field.ty, "Vector fields must be f32 or f64")
+                    .to_compile_error()
+                    .into()
+            }
+        }
+        members.push(match &field.ident {
+            Some(ident) => syn::Member::Named(ident.clone()),
+            None => syn::Member::Unnamed(syn::Index::from(i)),
+        });
+    }
+
+    let expanded = quote! {
+        impl #impl_generics ::libvector::Vector for #title #type_generics #where_clause {
+            fn dot(&self, other: &Self) -> f64 {
+                0. #(+ self.#members as f64 * other.#members as f64)*
+            }
+
+            fn magnitude(&self) -> f64 {
+                ::libvector::Float::sqrt(::libvector::Vector::dot(self, self))
+            }
+
+            fn normalize(&self) -> Self {
+                let mag = ::libvector::Vector::magnitude(self);
+                Self {
+                    #(#members: (self.#members as f64 / mag) as #types,)*
+                }
+            }
+
+            fn zero() -> Self {
+                Self {
+                    #(#members: 0. as #types,)*
+                }
+            }
+        }
+
+        impl #impl_generics ::core::ops::Add for #title #type_generics #where_clause {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self {
+                    #(#members: self.#members + other.#members,)*
+                }
+            }
+        }
+
+        impl #impl_generics ::core::ops::Sub for #title #type_generics #where_clause {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self {
+                    #(#members: self.#members - other.#members,)*
+                }
+            }
+        }
+
+        impl #impl_generics ::core::ops::Mul<f64> for #title #type_generics #where_clause {
+            type Output = Self;
+
+            fn mul(self, scalar: f64) -> Self {
+                Self {
+                    #(#members: (self.#members as f64 * scalar) as #types,)*
+                }
+            }
+        }
+
+        impl #impl_generics ::core::ops::Div<f64> for #title #type_generics #where_clause {
+            type Output = Self;
+
+            fn div(self, scalar: f64) -> Self {
+                Self {
+                    #(#members: (self.#members as f64 / scalar) as #types,)*
+                }
+            }
+        }
+
+        impl #impl_generics ::core::ops::Neg for #title #type_generics #where_clause {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self {
+                    #(#members: -self.#members,)*
+                }
+            }
         }
     };
 